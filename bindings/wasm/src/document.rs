@@ -7,6 +7,7 @@ use identity::crypto::merkle_key::MerkleKey;
 use identity::crypto::merkle_key::MerkleTag;
 use identity::crypto::merkle_key::Sha256;
 use identity::crypto::merkle_tree::Proof;
+use identity::crypto::ProofPurpose;
 use identity::crypto::PublicKey;
 use identity::crypto::SecretKey;
 use identity::did::verifiable;
@@ -154,7 +155,8 @@ impl Document {
   /// Verification Method.
   ///
   /// An additional `proof` property is required if using a Merkle Key
-  /// Collection verification Method.
+  /// Collection verification Method. The optional `purpose` property sets the
+  /// proof purpose of the signature and defaults to `assertionMethod`.
   #[wasm_bindgen(js_name = signData)]
   pub fn sign_data(&self, data: &JsValue, args: &JsValue) -> Result<JsValue, JsValue> {
     #[derive(Deserialize)]
@@ -165,10 +167,12 @@ impl Document {
         public: String,
         secret: String,
         proof: String,
+        purpose: Option<ProofPurpose>,
       },
       Default {
         method: String,
         secret: String,
+        purpose: Option<ProofPurpose>,
       },
     }
 
@@ -181,6 +185,7 @@ impl Document {
         public,
         secret,
         proof,
+        purpose,
       } => {
         let merkle_key: Vec<u8> = self
           .0
@@ -194,7 +199,11 @@ impl Document {
         let digest: MerkleTag = MerkleKey::extract_tags(&merkle_key).map_err(err)?.1;
         let proof: Vec<u8> = decode_b58(&proof).map_err(err)?;
 
-        let signer: _ = self.0.signer(&secret).method(&method);
+        let signer: _ = self
+          .0
+          .signer(&secret)
+          .method(&method)
+          .purpose(purpose.unwrap_or(ProofPurpose::AssertionMethod));
 
         match digest {
           MerkleTag::SHA256 => match Proof::<Sha256>::decode(&proof) {
@@ -204,10 +213,20 @@ impl Document {
           _ => return Err("Invalid Merkle Key Digest".into()),
         }
       }
-      Args::Default { method, secret } => {
+      Args::Default {
+        method,
+        secret,
+        purpose,
+      } => {
         let secret: SecretKey = decode_b58(&secret).map_err(err).map(Into::into)?;
 
-        self.0.signer(&secret).method(&method).sign(&mut data).map_err(err)?;
+        self
+          .0
+          .signer(&secret)
+          .method(&method)
+          .purpose(purpose.unwrap_or(ProofPurpose::AssertionMethod))
+          .sign(&mut data)
+          .map_err(err)?;
       }
     }
 
@@ -12,8 +12,10 @@ use identity::credential::Credential;
 use identity::credential::CredentialBuilder;
 use identity::credential::Subject;
 use identity::crypto::KeyPair;
+use identity::did::MethodScope;
 use identity::iota::Client;
 use identity::iota::Document;
+use identity::iota::Method;
 use identity::iota::Result;
 
 // A helper function to generate a new DID Document/KeyPair, sign the
@@ -35,6 +37,23 @@ pub async fn create_did_document(client: &Client) -> Result<(Document, KeyPair)>
   Ok((document, keypair))
 }
 
+// A helper function to add a new assertion method for signing credentials to
+// the given DID Document, publish the update to the Tangle, and return the
+// KeyPair of the new method.
+pub async fn add_assertion_method(client: &Client, document: &mut Document, keypair: &KeyPair) -> Result<KeyPair> {
+  let assertion: KeyPair = KeyPair::new_ed25519()?;
+  let method: Method = Method::from_did(document.id().clone(), &assertion, "key-assertion")?;
+
+  document.insert_method(MethodScope::AssertionMethod, method);
+
+  // Sign and publish the updated document
+  document.set_previous_message_id(*document.message_id());
+  document.sign(keypair.secret())?;
+  document.publish(client).await?;
+
+  Ok(assertion)
+}
+
 // Helper that takes two DID Documents (identities) for issuer and subject, and
 // creates a credential with claims about subject by issuer.
 pub fn issue_degree(issuer: &Document, subject: &Document) -> Result<Credential> {
@@ -48,7 +48,7 @@ async fn main() -> Result<()> {
   // We can sign arbitrary structs using the DID Document signer if they implement the trait
   // `identity::crypto::SetSignature`
   //
-  // e.g. document.signer(keypair.secret()).method("#authentication").sign(&mut test);
+  // e.g. document.signer(keypair.secret()).method("#authentication").purpose(ProofPurpose::Authentication).sign(&mut test);
 
   Ok(())
 }
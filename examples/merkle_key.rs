@@ -7,22 +7,18 @@
 
 mod common;
 
-use identity::core::BitSet;
 use identity::core::FromJson;
-use identity::core::ToJson;
 use identity::core::Url;
 use identity::credential::Credential;
 use identity::credential::CredentialBuilder;
 use identity::credential::Subject;
 use identity::crypto::merkle_key::Sha256;
-use identity::crypto::merkle_tree::Proof;
-use identity::crypto::KeyCollection;
-use identity::crypto::PublicKey;
-use identity::crypto::SecretKey;
+use identity::crypto::KeyType;
 use identity::did::resolution::resolve;
 use identity::did::resolution::Resolution;
 use identity::did::MethodScope;
-use identity::iota::Method;
+use identity::iota::DocumentDiff;
+use identity::iota::MerkleKeyCollection;
 use identity::iota::TangleRef;
 use identity::prelude::*;
 use rand::rngs::OsRng;
@@ -39,14 +35,11 @@ async fn main() -> Result<()> {
   let (mut doc, auth): (Document, KeyPair) = common::create_did_document(&client).await?;
 
   // Generate a collection of ed25519 keys for signing credentials
-  let keys: KeyCollection = KeyCollection::new_ed25519(LEAVES)?;
+  // with SHA-256 as the digest algorithm.
+  let keys: MerkleKeyCollection<Sha256> = MerkleKeyCollection::new(KeyType::Ed25519, LEAVES, "key-collection")?;
 
-  // Generate a Merkle Key Collection Verification Method
-  // with SHA-256 as  the digest algorithm.
-  let method: Method = Method::create_merkle_key::<Sha256, _>(doc.id().clone(), &keys, "key-collection")?;
-
-  // Append the new verification method to the set of existing methods
-  doc.insert_method(MethodScope::VerificationMethod, method);
+  // Append the Merkle Key Collection Verification Method to the set of assertion methods
+  keys.embed(&mut doc, MethodScope::AssertionMethod)?;
 
   // Sign and publish the updated document
   doc.set_previous_message_id(*doc.message_id());
@@ -67,18 +60,8 @@ async fn main() -> Result<()> {
   // Select a random key from the collection
   let index: usize = OsRng.gen_range(0..LEAVES);
 
-  let public: &PublicKey = keys.public(index).unwrap();
-  let secret: &SecretKey = keys.secret(index).unwrap();
-
-  // Generate an inclusion proof for the selected key
-  let proof: Proof<Sha256> = keys.merkle_proof(index).unwrap();
-
-  // Sign the Verifiable Credential with the DID Document
-  doc
-    .signer(secret)
-    .method("key-collection")
-    .merkle_key((public, &proof))
-    .sign(&mut credential)?;
+  // Sign the Verifiable Credential with the selected key
+  keys.sign(&doc, index, &mut credential)?;
 
   println!("credential (signed): {:#}", credential);
 
@@ -87,24 +70,15 @@ async fn main() -> Result<()> {
   println!("verified: {:?}", verified.is_ok());
 
   // Revoke the previously used key - assume it was compromised
-  let mut revocation: BitSet = BitSet::new();
-
-  revocation.insert(index as u32);
+  let mut diff: DocumentDiff = keys.revoke_diff(&doc, &[index], *doc.message_id(), auth.secret())?;
 
-  unsafe {
-    doc
-      .as_document_mut()
-      .try_resolve_mut("key-collection")?
-      .properties_mut()
-      .insert("revocation".into(), revocation.to_json_value()?);
-  }
+  // Publish the diff with the updated revocation state
+  diff.publish(doc.message_id(), &client).await?;
 
-  // Publish the new document with the updated revocation state
-  doc.set_previous_message_id(*doc.message_id());
-  doc.sign(auth.secret())?;
-  doc.publish(&client).await?;
+  println!("diff: {:#?}", diff);
 
-  println!("document: {:#}", doc);
+  // Apply the revocation to the local document
+  doc.merge(&diff)?;
 
   // Set false claims about the credential subject
   let subject = credential.credential_subject.get_mut(0).unwrap();
@@ -112,11 +86,7 @@ async fn main() -> Result<()> {
   subject.properties.insert("new-claim".into(), "not-false".into());
 
   // Sign the Credential with the compromised key
-  doc
-    .signer(secret)
-    .method("key-collection")
-    .merkle_key((public, &proof))
-    .sign(&mut credential)?;
+  keys.sign(&doc, index, &mut credential)?;
 
   println!("credential (compro-signed): {:#}", credential);
 
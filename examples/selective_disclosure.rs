@@ -0,0 +1,94 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! An example that signs a credential with BBS+ and presents a derived
+//! credential disclosing only some of its claims.
+//!
+//! cargo run --example selective_disclosure
+
+mod common;
+
+use identity::core::ToJson;
+use identity::core::Url;
+use identity::credential::Credential;
+use identity::credential::Presentation;
+use identity::credential::PresentationBuilder;
+use identity::crypto::ProofPurpose;
+use identity::did::MethodScope;
+use identity::iota::CredentialValidator;
+use identity::iota::Method;
+use identity::iota::PresentationValidation;
+use identity::prelude::*;
+
+#[tokio::main]
+async fn main() -> Result<()> {
+  // Create a Client to interact with the IOTA Tangle.
+  let client: Client = Client::new().await?;
+
+  // Create a signed DID Document/KeyPair for the credential issuer (see previous example).
+  let (mut doc_iss, key_iss): (Document, KeyPair) = common::create_did_document(&client).await?;
+
+  // Create a signed DID Document/KeyPair for the credential subject (see previous example).
+  let (doc_sub, key_sub): (Document, KeyPair) = common::create_did_document(&client).await?;
+
+  // Generate a BLS12-381 G2 key for creating BBS+ signatures
+  let key_bbs: KeyPair = KeyPair::new_bls12381g2()?;
+
+  // Add the new verification method to the issuer document
+  let method: Method = Method::from_did(doc_iss.id().clone(), &key_bbs, "key-bbs")?;
+
+  doc_iss.insert_method(MethodScope::AssertionMethod, method);
+
+  // Sign and publish the updated document
+  doc_iss.set_previous_message_id(*doc_iss.message_id());
+  doc_iss.sign(key_iss.secret())?;
+  doc_iss.publish(&client).await?;
+
+  // Create an unsigned Credential with claims about `subject` specified by `issuer`.
+  let mut credential: Credential = common::issue_degree(&doc_iss, &doc_sub)?;
+
+  // Sign the Credential with the BBS+ key - every claim is signed separately.
+  doc_iss
+    .signer(key_bbs.secret())
+    .method("key-bbs")
+    .purpose(ProofPurpose::AssertionMethod)
+    .sign(&mut credential)?;
+
+  println!("Credential (signed) > {:#}", credential);
+  println!();
+
+  // The holder derives a new Credential that only discloses the degree type;
+  // the nonce would be provided by the verifier.
+  let derived: Credential = doc_iss.derive_data(
+    &credential,
+    &[
+      "/@context",
+      "/type",
+      "/issuer",
+      "/issuanceDate",
+      "/credentialSubject/id",
+      "/credentialSubject/degree/type",
+    ],
+    b"verifier-nonce",
+  )?;
+
+  println!("Credential (derived) > {:#}", derived);
+  println!();
+
+  // Build and sign a Presentation containing the derived Credential.
+  let mut presentation: Presentation = PresentationBuilder::default()
+    .credential(derived)
+    .holder(Url::parse(doc_sub.id().as_ref())?)
+    .build()?;
+
+  doc_sub.sign_data(&mut presentation, key_sub.secret())?;
+
+  // Validate the Presentation and the derived Credential.
+  let validator: CredentialValidator = CredentialValidator::new(&client).nonce("verifier-nonce");
+  let validation: PresentationValidation = validator.check_presentation(&presentation.to_json()?).await?;
+
+  println!("Presentation Validation > {:#?}", validation);
+  println!();
+
+  Ok(())
+}
@@ -17,6 +17,7 @@ use identity::credential::Credential;
 use identity::credential::CredentialBuilder;
 use identity::credential::Subject;
 use identity::crypto::KeyPair;
+use identity::crypto::ProofPurpose;
 use identity::iota::Client;
 use identity::iota::CredentialValidation;
 use identity::iota::CredentialValidator;
@@ -50,7 +51,10 @@ async fn main() -> Result<()> {
   let client: Client = Client::new().await?;
 
   // Create a signed DID Document/KeyPair for the credential issuer (see previous example).
-  let (doc_iss, key_iss): (Document, KeyPair) = common::create_did_document(&client).await?;
+  let (mut doc_iss, key_iss): (Document, KeyPair) = common::create_did_document(&client).await?;
+
+  // Add a verification method for signing credentials to the issuer document.
+  let key_assert: KeyPair = common::add_assertion_method(&client, &mut doc_iss, &key_iss).await?;
 
   // Create a signed DID Document/KeyPair for the credential subject (see previous example).
   let (doc_sub, _key_sub): (Document, KeyPair) = common::create_did_document(&client).await?;
@@ -58,8 +62,12 @@ async fn main() -> Result<()> {
   // Create an unsigned Credential with claims about `subject` specified by `issuer`.
   let mut credential: Credential = issue_degree(&doc_iss, &doc_sub)?;
 
-  // Sign the Credential with the issuer secret key - the result is a Verifiable Credential.
-  doc_iss.sign_data(&mut credential, key_iss.secret())?;
+  // Sign the Credential with the issuer assertion key - the result is a Verifiable Credential.
+  doc_iss
+    .signer(key_assert.secret())
+    .method("key-assertion")
+    .purpose(ProofPurpose::AssertionMethod)
+    .sign(&mut credential)?;
 
   println!("Credential > {:#}", credential);
   println!();
@@ -13,6 +13,7 @@ use identity::credential::Credential;
 use identity::credential::Presentation;
 use identity::credential::PresentationBuilder;
 use identity::crypto::KeyPair;
+use identity::crypto::ProofPurpose;
 use identity::iota::Client;
 use identity::iota::Document;
 use identity::iota::Result;
@@ -23,7 +24,10 @@ async fn main() -> Result<()> {
   let client: Client = Client::new().await?;
 
   // Create a signed DID Document/KeyPair for the credential issuer (see previous example).
-  let (doc_iss, key_iss): (Document, KeyPair) = common::create_did_document(&client).await?;
+  let (mut doc_iss, key_iss): (Document, KeyPair) = common::create_did_document(&client).await?;
+
+  // Add a verification method for signing credentials to the issuer document.
+  let key_assert: KeyPair = common::add_assertion_method(&client, &mut doc_iss, &key_iss).await?;
 
   // Create a signed DID Document/KeyPair for the credential subject (see previous example).
   let (doc_sub, _key_sub): (Document, KeyPair) = common::create_did_document(&client).await?;
@@ -31,8 +35,12 @@ async fn main() -> Result<()> {
   // Create an unsigned Credential with claims about `subject` specified by `issuer`.
   let mut credential: Credential = common::issue_degree(&doc_iss, &doc_sub)?;
 
-  // Sign the Credential with the issuer secret key - the result is a VerifiableCredential.
-  doc_iss.sign_data(&mut credential, key_iss.secret())?;
+  // Sign the Credential with the issuer assertion key - the result is a VerifiableCredential.
+  doc_iss
+    .signer(key_assert.secret())
+    .method("key-assertion")
+    .purpose(ProofPurpose::AssertionMethod)
+    .sign(&mut credential)?;
 
   // Prepare parameters and build our Presentation.
   let id_url: Url = Url::parse("asdf:foo:a87w3guasbdfuasbdfs")?;
@@ -51,6 +59,7 @@ async fn main() -> Result<()> {
   doc_iss
     .signer(key_iss.secret())
     .method("#authentication")
+    .purpose(ProofPurpose::Authentication)
     .sign(&mut presentation)?;
 
   println!("Verifiable Presentation > {:#}", presentation);
@@ -9,6 +9,7 @@ mod snapshot;
 mod status;
 mod store;
 mod vault;
+mod vault_key;
 
 pub use self::context::Context;
 pub use self::context::Password;
@@ -22,6 +23,7 @@ pub use self::snapshot::Snapshot;
 pub use self::status::SnapshotStatus;
 pub use self::store::Store;
 pub use self::vault::Vault;
+pub use self::vault_key::VaultKey;
 
 #[cfg(test)]
 mod tests;
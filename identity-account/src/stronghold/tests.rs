@@ -3,6 +3,15 @@
 
 use core::iter;
 use futures::executor::block_on;
+use identity_core::common::Value;
+use identity_core::crypto::Ed25519;
+use identity_core::crypto::HandleSigner;
+use identity_core::crypto::JcsEd25519;
+use identity_core::crypto::KeyHandle;
+use identity_core::crypto::KeyPair;
+use identity_core::crypto::SignatureValue;
+use identity_core::crypto::Verifier;
+use identity_core::json;
 use iota_stronghold::Location;
 use rand::distributions::Alphanumeric;
 use rand::rngs::OsRng;
@@ -16,9 +25,12 @@ use std::time::Duration;
 use std::time::Instant;
 
 use crate::error::Error;
+use crate::stronghold::default_hint;
 use crate::stronghold::Snapshot;
 use crate::stronghold::SnapshotStatus;
 use crate::stronghold::Store;
+use crate::stronghold::Vault;
+use crate::stronghold::VaultKey;
 use crate::utils::derive_encryption_key;
 use crate::utils::EncryptionKey;
 
@@ -261,4 +273,31 @@ rusty_fork_test! {
       }
     })
   }
+
+  #[test]
+  fn test_vault_key_sign() {
+    block_on(async {
+      let password: EncryptionKey = derive_encryption_key("my-password:test_vault_key_sign");
+      let snapshot: Snapshot = open_snapshot(&generate_filename(), password).await;
+
+      let keypair: KeyPair = KeyPair::new_ed25519().unwrap();
+      let vault: Vault = snapshot.vault(b"vault", &[]);
+
+      vault
+        .insert(location("key"), keypair.secret().as_ref(), default_hint(), &[])
+        .await
+        .unwrap();
+
+      let key: VaultKey = vault.key(location("key"));
+
+      assert_eq!(key.public_key().await.unwrap().as_ref(), keypair.public().as_ref());
+
+      let data: Value = json!({ "msg": "IOTA Identity" });
+      let signature: SignatureValue = HandleSigner::<JcsEd25519>::sign(&data, &key).await.unwrap();
+
+      assert!(JcsEd25519::<Ed25519>::verify(&data, &signature, keypair.public().as_ref()).is_ok());
+
+      snapshot.unload(false).await.unwrap();
+    })
+  }
 }
@@ -16,6 +16,7 @@ use crate::error::PleaseDontMakeYourOwnResult;
 use crate::error::Result;
 use crate::stronghold::Context;
 use crate::stronghold::ProcedureResult;
+use crate::stronghold::VaultKey;
 
 pub type Record = (usize, RecordHint);
 
@@ -38,6 +39,11 @@ impl<'snapshot> Vault<'snapshot> {
       flags: flags.to_vec(),
     }
   }
+
+  /// Returns a [`VaultKey`] for the `Ed25519` private key at `location`.
+  pub fn key(&self, location: Location) -> VaultKey<'_, 'snapshot> {
+    VaultKey::new(self, location)
+  }
 }
 
 impl Vault<'_> {
@@ -0,0 +1,70 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use async_trait::async_trait;
+use identity_core::crypto::KeyHandle;
+use identity_core::crypto::KeyType;
+use identity_core::crypto::PublicKey;
+use identity_core::error::Error as CoreError;
+use identity_core::error::Result as CoreResult;
+use iota_stronghold::Location;
+
+use crate::error::Error;
+use crate::stronghold::Vault;
+
+/// A [`KeyHandle`] referencing an `Ed25519` private key stored in a
+/// Stronghold [`Vault`].
+///
+/// Signatures are created by the Stronghold runtime; the private key never
+/// leaves the vault.
+#[derive(Debug)]
+pub struct VaultKey<'vault, 'snapshot> {
+  vault: &'vault Vault<'snapshot>,
+  location: Location,
+}
+
+impl<'vault, 'snapshot> VaultKey<'vault, 'snapshot> {
+  /// Creates a new [`VaultKey`] for the private key at `location`.
+  pub fn new(vault: &'vault Vault<'snapshot>, location: Location) -> Self {
+    Self { vault, location }
+  }
+
+  /// Returns the [`Vault`] storing the private key.
+  pub fn vault(&self) -> &Vault<'snapshot> {
+    self.vault
+  }
+
+  /// Returns the location of the private key.
+  pub fn location(&self) -> &Location {
+    &self.location
+  }
+}
+
+#[async_trait(?Send)]
+impl KeyHandle for VaultKey<'_, '_> {
+  fn key_type(&self) -> KeyType {
+    KeyType::Ed25519
+  }
+
+  async fn public_key(&self) -> CoreResult<PublicKey> {
+    self
+      .vault
+      .ed25519_public_key(self.location.clone())
+      .await
+      .map(|public| public.to_vec().into())
+      .map_err(into_core_error)
+  }
+
+  async fn sign(&self, message: &[u8]) -> CoreResult<Vec<u8>> {
+    self
+      .vault
+      .ed25519_sign(message.to_vec(), self.location.clone())
+      .await
+      .map(|signature| signature.to_vec())
+      .map_err(into_core_error)
+  }
+}
+
+fn into_core_error(error: Error) -> CoreError {
+  CoreError::KeyHandle(format!("{:?}", error))
+}
@@ -62,6 +62,11 @@ impl BitSet {
     }
   }
 
+  /// Returns an iterator over the values in the set, in ascending order.
+  pub fn iter(&self) -> impl Iterator<Item = u32> + '_ {
+    self.0.iter()
+  }
+
   /// Removes the specified `index` from the set.
   pub fn remove(&mut self, index: u32) -> bool {
     self.0.remove(index)
@@ -3,6 +3,7 @@
 
 use chrono::DateTime;
 use chrono::SecondsFormat;
+use chrono::TimeZone;
 use chrono::Timelike;
 use chrono::Utc;
 use core::convert::TryFrom;
@@ -35,6 +36,15 @@ impl Timestamp {
     Self(Self::truncate(Utc::now()))
   }
 
+  /// Creates a new [`Timestamp`] from the given Unix timestamp.
+  pub fn from_unix(seconds: i64) -> Result<Self> {
+    Utc
+      .timestamp_opt(seconds, 0)
+      .single()
+      .map(Self)
+      .ok_or(Error::InvalidUnixTimestamp(seconds))
+  }
+
   /// Returns the [`Timestamp`] as a Unix timestamp.
   pub fn to_unix(&self) -> i64 {
     self.0.timestamp()
@@ -145,6 +155,15 @@ mod tests {
     Timestamp::parse("2020/01/01 03:30:16").unwrap();
   }
 
+  #[test]
+  fn test_unix_roundtrip() {
+    let timestamp: Timestamp = Timestamp::parse("2020-01-01T00:00:00Z").unwrap();
+
+    assert_eq!(timestamp.to_unix(), 1577836800);
+    assert_eq!(Timestamp::from_unix(1577836800).unwrap(), timestamp);
+    assert!(Timestamp::from_unix(i64::MAX).is_err());
+  }
+
   #[test]
   fn test_json_roundtrip() {
     let time1: Timestamp = Timestamp::now();
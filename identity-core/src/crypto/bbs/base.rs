@@ -0,0 +1,46 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use bbs::prelude::DeterministicPublicKey;
+use bbs::prelude::KeyGenOption;
+use bbs::prelude::PublicKey;
+use bbs::prelude::SecretKey;
+
+use crate::error::Error;
+use crate::error::Result;
+
+/// Common utilities for working with BBS+ Signatures.
+#[derive(Clone, Copy, Debug)]
+pub struct BbsPlus;
+
+impl BbsPlus {
+  /// The `type` value of a BLS12-381 G2 Verification Method.
+  pub const TYPE_KEY: &'static str = "Bls12381G2Key2020";
+
+  /// The `type` value of a BBS+ Signature.
+  pub const TYPE_SIG: &'static str = "BbsBlsSignature2020";
+
+  /// The `type` value of a BBS+ Signature Proof of Knowledge.
+  pub const TYPE_PROOF: &'static str = "BbsBlsSignatureProof2020";
+
+  /// Parses a compressed BLS12-381 G2 public key and expands it to a BBS+
+  /// public key supporting `count` messages.
+  pub fn public_key(data: &[u8], count: usize) -> Result<PublicKey> {
+    DeterministicPublicKey::from_bytes_compressed_form(data)
+      .map_err(|_| Error::InvalidKeyFormat)?
+      .to_public_key(count)
+      .map_err(|_| Error::InvalidKeyFormat)
+  }
+
+  /// Parses a BLS12-381 secret key and derives a BBS+ public key supporting
+  /// `count` messages.
+  pub fn keypair(data: &[u8], count: usize) -> Result<(PublicKey, SecretKey)> {
+    let secret: SecretKey = SecretKey::from_bytes_compressed_form(data).map_err(|_| Error::InvalidKeyFormat)?;
+    let option: KeyGenOption = KeyGenOption::FromSecretKey(secret);
+    let (public, secret): (DeterministicPublicKey, SecretKey) = DeterministicPublicKey::new(Some(option));
+
+    let public: PublicKey = public.to_public_key(count).map_err(|_| Error::InvalidKeyFormat)?;
+
+    Ok((public, secret))
+  }
+}
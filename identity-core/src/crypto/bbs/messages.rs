@@ -0,0 +1,198 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use bbs::prelude::SignatureMessage;
+use core::iter;
+use serde::Serialize;
+use serde_json::Map;
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+
+use crate::common::Object;
+use crate::common::Value;
+use crate::convert::FromJson;
+use crate::convert::ToJson;
+use crate::error::Error;
+use crate::error::Result;
+
+/// The set of BBS+ messages extracted from a JSON document.
+///
+/// Every leaf value of the document is identified by its [JSON Pointer][RFC]
+/// and signed as a separate message. Arrays and empty objects are treated as
+/// leaf values.
+///
+/// The proof options - the top-level `proof` without its value and `type` -
+/// are signed as the first message and are always disclosed, so any change to
+/// them invalidates the signature and every proof derived from it. The `type`
+/// is excluded since it differs between a signature and its derived proofs.
+///
+/// Messages are ordered by pointer so a document with a subset of the original
+/// properties produces the same messages at the same relative positions.
+///
+/// [RFC]: https://tools.ietf.org/html/rfc6901
+#[derive(Clone, Debug, PartialEq)]
+pub struct BbsMessages {
+  options: Object,
+  values: BTreeMap<String, Value>,
+}
+
+impl BbsMessages {
+  /// Extracts the set of messages from the given `data`.
+  ///
+  /// # Errors
+  ///
+  /// Fails if `data` can not be serialized as a JSON object or does not
+  /// contain a `proof`.
+  pub fn from_data<X>(data: &X) -> Result<Self>
+  where
+    X: Serialize,
+  {
+    let mut object: Object = Object::from_json_value(data.to_json_value()?)?;
+    let mut values: BTreeMap<String, Value> = BTreeMap::new();
+
+    let mut options: Object = match object.remove("proof") {
+      // The last proof of a chain is signed, preceding proofs are part of the document
+      Some(Value::Array(mut proofs)) => {
+        let proof: Value = proofs.pop().ok_or(Error::MissingSignature)?;
+
+        if !proofs.is_empty() {
+          object.insert("proof".into(), Value::Array(proofs));
+        }
+
+        Object::from_json_value(proof)?
+      }
+      Some(proof) => Object::from_json_value(proof)?,
+      None => return Err(Error::MissingSignature),
+    };
+
+    options.remove("type");
+    options.remove("jws");
+    options.remove("proofValue");
+    options.remove("signatureValue");
+
+    flatten(&mut values, "", object);
+
+    Ok(Self { options, values })
+  }
+
+  /// Returns the total number of messages, including the proof options.
+  pub fn len(&self) -> usize {
+    self.values.len() + 1
+  }
+
+  /// Returns `true` if there are no messages.
+  ///
+  /// This is never the case since the proof options are always included.
+  pub fn is_empty(&self) -> bool {
+    false
+  }
+
+  /// Returns an iterator over the JSON Pointers of all document messages.
+  pub fn pointers(&self) -> impl Iterator<Item = &str> + '_ {
+    self.values.keys().map(String::as_str)
+  }
+
+  /// Encodes every message as a BBS+ [`SignatureMessage`].
+  ///
+  /// The first message is the JCS-encoded proof options, followed by the
+  /// JCS-encoded `[pointer, value]` pair of every document message so the
+  /// position of a value within the document is signed along with it.
+  pub fn to_messages(&self) -> Result<Vec<SignatureMessage>> {
+    iter::once(self.options.to_jcs())
+      .chain(self.values.iter().map(|entry| entry.to_jcs()))
+      .map(|message| message.map(SignatureMessage::hash))
+      .collect()
+  }
+
+  /// Selects the messages matching the JSON Pointers in `reveal` and returns
+  /// their indices along with a document containing only those values.
+  ///
+  /// A pointer reveals the value it identifies and, if the value is an
+  /// object, every value nested within it. The proof options are always
+  /// revealed.
+  ///
+  /// # Errors
+  ///
+  /// Fails if a pointer in `reveal` does not match any message.
+  pub fn reveal(&self, reveal: &[&str]) -> Result<(BTreeSet<usize>, Object)> {
+    let mut indices: BTreeSet<usize> = BTreeSet::new();
+    let mut object: Map<String, Value> = Map::new();
+
+    indices.insert(0);
+
+    for pointer in reveal {
+      let mut found: bool = false;
+
+      for (index, (key, value)) in self.values.iter().enumerate() {
+        if !is_prefix(pointer, key) {
+          continue;
+        }
+
+        found = true;
+
+        if indices.insert(index + 1) {
+          insert(&mut object, key, value.clone())?;
+        }
+      }
+
+      if !found {
+        return Err(Error::InvalidProofValue("bbs+ - unknown pointer"));
+      }
+    }
+
+    Ok((indices, object.into_iter().collect()))
+  }
+}
+
+// =============================================================================
+// =============================================================================
+
+fn flatten(output: &mut BTreeMap<String, Value>, prefix: &str, object: Object) {
+  for (key, value) in object {
+    let pointer: String = format!("{}/{}", prefix, escape(&key));
+
+    match value {
+      Value::Object(inner) if !inner.is_empty() => {
+        flatten(output, &pointer, inner.into_iter().collect());
+      }
+      value => {
+        output.insert(pointer, value);
+      }
+    }
+  }
+}
+
+fn insert(object: &mut Map<String, Value>, pointer: &str, value: Value) -> Result<()> {
+  let mut segments: Vec<String> = pointer.split('/').skip(1).map(unescape).collect();
+  let last: String = segments
+    .pop()
+    .ok_or(Error::InvalidProofValue("bbs+ - invalid pointer"))?;
+  let mut target: &mut Map<String, Value> = object;
+
+  for segment in segments {
+    let entry: &mut Value = target.entry(segment).or_insert_with(|| Value::Object(Map::new()));
+
+    target = match entry {
+      Value::Object(inner) => inner,
+      _ => return Err(Error::InvalidProofValue("bbs+ - invalid pointer")),
+    };
+  }
+
+  target.insert(last, value);
+
+  Ok(())
+}
+
+fn is_prefix(prefix: &str, pointer: &str) -> bool {
+  prefix.is_empty()
+    || pointer == prefix
+    || (pointer.starts_with(prefix) && pointer.as_bytes().get(prefix.len()) == Some(&b'/'))
+}
+
+fn escape(segment: &str) -> String {
+  segment.replace('~', "~0").replace('/', "~1")
+}
+
+fn unescape(segment: &str) -> String {
+  segment.replace("~1", "/").replace("~0", "~")
+}
@@ -0,0 +1,20 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! An implementation of BBS+ Signatures for selective disclosure.
+
+mod base;
+mod messages;
+mod prover;
+mod signer;
+mod verifier;
+
+pub use self::base::BbsPlus;
+pub use self::messages::BbsMessages;
+pub use self::prover::BbsProver;
+pub use self::signer::BbsSigner;
+pub use self::verifier::BbsProofVerifier;
+pub use self::verifier::BbsVerifier;
+
+#[cfg(test)]
+mod tests;
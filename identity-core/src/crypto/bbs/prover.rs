@@ -0,0 +1,131 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use bbs::prelude::HiddenMessage;
+use bbs::prelude::PoKOfSignature;
+use bbs::prelude::PoKOfSignatureProof;
+use bbs::prelude::ProofChallenge;
+use bbs::prelude::ProofMessage;
+use bbs::prelude::ProofNonce;
+use bbs::prelude::ProofRequest;
+use bbs::prelude::Prover;
+use bbs::prelude::PublicKey;
+use bbs::prelude::Signature as BbsSignature;
+use bbs::prelude::SignatureMessage;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::collections::BTreeSet;
+
+use crate::common::BitSet;
+use crate::common::Object;
+use crate::common::Value;
+use crate::convert::FromJson;
+use crate::crypto::bbs::BbsMessages;
+use crate::crypto::bbs::BbsPlus;
+use crate::crypto::SetSignature;
+use crate::crypto::Signature;
+use crate::crypto::SignatureValue;
+use crate::crypto::TrySignature;
+use crate::error::Error;
+use crate::error::Result;
+use crate::utils::decode_b58;
+use crate::utils::encode_b58;
+
+/// A helper for deriving selective disclosure proofs from BBS+ Signatures.
+#[derive(Clone, Copy, Debug)]
+pub struct BbsProver;
+
+impl BbsProver {
+  /// Derives a new document from the BBS+ signed `data` which only contains
+  /// the properties identified by the [JSON Pointers][RFC] in `reveal`,
+  /// along with a [`BbsPlus::TYPE_PROOF`] proof of knowledge of the original
+  /// signature.
+  ///
+  /// The `nonce` should be provided by the verifier of the derived document.
+  ///
+  /// [RFC]: https://tools.ietf.org/html/rfc6901
+  ///
+  /// # Errors
+  ///
+  /// Fails if `data` is not signed with a valid BBS+ signature by `public`,
+  /// if `reveal` contains an unknown pointer, or if the derived document can
+  /// not be deserialized as `Y`.
+  pub fn derive<X, Y>(data: &X, public: &[u8], reveal: &[&str], nonce: &[u8]) -> Result<Y>
+  where
+    X: Serialize + TrySignature,
+    Y: DeserializeOwned + SetSignature,
+  {
+    let signature: &Signature = data.try_signature()?;
+
+    if signature.type_() != BbsPlus::TYPE_SIG {
+      return Err(Error::InvalidProofValue("signature name"));
+    }
+
+    let source: &str = signature.value().as_proof().ok_or(Error::InvalidProofValue("bbs+"))?;
+    let source: BbsSignature =
+      BbsSignature::from_bytes_compressed_form(&decode_b58(source)?).map_err(|_| Error::InvalidProofFormat)?;
+
+    let messages: BbsMessages = BbsMessages::from_data(data)?;
+    let (indices, object): (BTreeSet<usize>, Object) = messages.reveal(reveal)?;
+
+    let signature_messages: Vec<SignatureMessage> = messages.to_messages()?;
+    let verification_key: PublicKey = BbsPlus::public_key(public, messages.len())?;
+
+    // A proof of knowledge can be created for any signature, so the source
+    // signature must be verified to avoid deriving a proof that never verifies
+    match source.verify(&signature_messages, &verification_key) {
+      Ok(true) => {}
+      Ok(false) | Err(_) => return Err(Error::InvalidProofValue("bbs+")),
+    }
+
+    let proof_messages: Vec<ProofMessage> = signature_messages
+      .into_iter()
+      .enumerate()
+      .map(|(index, message)| {
+        if indices.contains(&index) {
+          ProofMessage::Revealed(message)
+        } else {
+          ProofMessage::Hidden(HiddenMessage::ProofSpecificBlinding(message))
+        }
+      })
+      .collect();
+
+    let request: ProofRequest = ProofRequest {
+      revealed_messages: indices.clone(),
+      verification_key,
+    };
+
+    let pok: PoKOfSignature =
+      Prover::commit_signature_pok(&request, &proof_messages, &source).map_err(|_| Error::InvalidProofValue("bbs+"))?;
+
+    let mut challenge: Vec<u8> = pok.to_bytes();
+    challenge.extend_from_slice(&ProofNonce::hash(nonce).to_bytes_uncompressed_form());
+
+    let proof: PoKOfSignatureProof = Prover::generate_signature_pok(pok, &ProofChallenge::hash(&challenge))
+      .map_err(|_| Error::InvalidProofValue("bbs+"))?;
+
+    let mut revealed: BitSet = BitSet::new();
+    revealed.insert_all(indices.into_iter().map(|index| index as u32));
+
+    let value: String = format!(
+      "{}.{}.{}.{}",
+      messages.len(),
+      encode_b58(&revealed.serialize_vec()?),
+      encode_b58(nonce),
+      encode_b58(&proof.to_bytes_compressed_form()),
+    );
+
+    let mut output: Y = Y::from_json_value(Value::Object(object.into_iter().collect()))?;
+    // The derived proof keeps the signed proof options of the source signature
+    let mut derived: Signature = Signature::new_with_options(
+      BbsPlus::TYPE_PROOF,
+      signature.verification_method(),
+      signature.options().clone(),
+    );
+
+    derived.set_value(SignatureValue::Proof(value));
+    output.set_signature(derived);
+
+    Ok(output)
+  }
+}
@@ -0,0 +1,46 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use bbs::prelude::PublicKey;
+use bbs::prelude::SecretKey;
+use bbs::prelude::Signature as BbsSignature;
+use bbs::prelude::SignatureMessage;
+use serde::Serialize;
+
+use crate::crypto::bbs::BbsMessages;
+use crate::crypto::bbs::BbsPlus;
+use crate::crypto::Named;
+use crate::crypto::SignatureValue;
+use crate::crypto::Signer;
+use crate::error::Error;
+use crate::error::Result;
+use crate::utils::encode_b58;
+
+/// An implementation of the [BBS+ Signature 2020][SPEC1] signature suite.
+///
+/// Every property of the document is signed as a separate message (see
+/// [`BbsMessages`]) which allows the holder of the signature to later derive
+/// a proof disclosing only a subset of the properties with [`BbsProver`][`crate::crypto::bbs::BbsProver`].
+///
+/// [SPEC1]: https://w3c-ccg.github.io/ldp-bbs2020/
+#[derive(Clone, Copy, Debug)]
+pub struct BbsSigner;
+
+impl Named for BbsSigner {
+  const NAME: &'static str = BbsPlus::TYPE_SIG;
+}
+
+impl Signer<[u8]> for BbsSigner {
+  fn sign<X>(data: &X, secret: &[u8]) -> Result<SignatureValue>
+  where
+    X: Serialize,
+  {
+    let messages: Vec<SignatureMessage> = BbsMessages::from_data(data)?.to_messages()?;
+    let (public, secret): (PublicKey, SecretKey) = BbsPlus::keypair(secret, messages.len())?;
+
+    let signature: BbsSignature =
+      BbsSignature::new(&messages, &secret, &public).map_err(|_| Error::InvalidProofValue("bbs+"))?;
+
+    Ok(SignatureValue::Proof(encode_b58(&signature.to_bytes_compressed_form())))
+  }
+}
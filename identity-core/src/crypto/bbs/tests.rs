@@ -0,0 +1,215 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::common::Object;
+use crate::common::Timestamp;
+use crate::common::Value;
+use crate::convert::FromJson;
+use crate::crypto::bbs::BbsMessages;
+use crate::crypto::bbs::BbsPlus;
+use crate::crypto::bbs::BbsProofVerifier;
+use crate::crypto::bbs::BbsProver;
+use crate::crypto::bbs::BbsSigner;
+use crate::crypto::bbs::BbsVerifier;
+use crate::crypto::KeyPair;
+use crate::crypto::ProofPurpose;
+use crate::crypto::SetSignature;
+use crate::crypto::Signature;
+use crate::crypto::SignatureOptions;
+use crate::crypto::SignatureValue;
+use crate::crypto::Signer as _;
+use crate::crypto::TrySignature;
+use crate::crypto::TrySignatureMut;
+use crate::crypto::Verifier as _;
+use crate::json;
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+struct Claims {
+  #[serde(flatten)]
+  properties: Object,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  proof: Option<Signature>,
+}
+
+impl Claims {
+  fn new(value: Value) -> Self {
+    Self {
+      properties: Object::from_json_value(value).unwrap(),
+      proof: None,
+    }
+  }
+}
+
+impl TrySignature for Claims {
+  fn signature(&self) -> Option<&Signature> {
+    self.proof.as_ref()
+  }
+}
+
+impl TrySignatureMut for Claims {
+  fn signature_mut(&mut self) -> Option<&mut Signature> {
+    self.proof.as_mut()
+  }
+}
+
+impl SetSignature for Claims {
+  fn set_signature(&mut self, signature: Signature) {
+    self.proof = Some(signature);
+  }
+}
+
+fn signed_claims(key: &KeyPair) -> Claims {
+  let mut claims: Claims = Claims::new(json!({
+    "id": "did:example:123",
+    "name": "Alice",
+    "degree": {
+      "type": "BachelorDegree",
+      "name": "Bachelor of Science and Arts",
+    },
+    "birthDate": "1990-01-01",
+  }));
+
+  let options: SignatureOptions = SignatureOptions::new()
+    .purpose(ProofPurpose::AssertionMethod)
+    .created(Timestamp::parse("2021-01-01T00:00:00Z").unwrap());
+
+  BbsSigner::create_signature_with_options(&mut claims, "#key-1", key.secret().as_ref(), options).unwrap();
+
+  claims
+}
+
+#[test]
+fn test_messages() {
+  let messages: BbsMessages = BbsMessages::from_data(&json!({
+    "a": 1,
+    "b": { "c": [1, 2], "d~/e": {} },
+    "proof": { "type": "IOTA" },
+  }))
+  .unwrap();
+
+  let pointers: Vec<&str> = messages.pointers().collect();
+
+  assert_eq!(pointers, ["/a", "/b/c", "/b/d~0~1e"]);
+  assert_eq!(messages.len(), 4);
+
+  // The proof options are always revealed
+  let (indices, object): _ = messages.reveal(&["/b"]).unwrap();
+
+  assert_eq!(indices.into_iter().collect::<Vec<_>>(), [0, 2, 3]);
+  assert_eq!(
+    Value::Object(object.into_iter().collect()),
+    json!({ "b": { "c": [1, 2], "d~/e": {} } })
+  );
+
+  assert!(messages.reveal(&["/c"]).is_err());
+}
+
+#[test]
+fn test_sign_verify() {
+  let key1: KeyPair = KeyPair::new_bls12381g2().unwrap();
+  let key2: KeyPair = KeyPair::new_bls12381g2().unwrap();
+
+  let claims: Claims = signed_claims(&key1);
+
+  assert_eq!(claims.proof.as_ref().unwrap().type_(), BbsPlus::TYPE_SIG);
+
+  // The signature should be valid
+  assert!(BbsVerifier::verify_signature(&claims, key1.public().as_ref()).is_ok());
+
+  // A modified key should be invalid
+  assert!(BbsVerifier::verify_signature(&claims, key2.public().as_ref()).is_err());
+
+  // Modified data should be invalid
+  let mut modified: Claims = claims.clone();
+  modified.properties.insert("name".into(), "Mallory".into());
+  assert!(BbsVerifier::verify_signature(&modified, key1.public().as_ref()).is_err());
+
+  // Modified proof options should be invalid
+  let mut modified: Claims = claims.clone();
+  modified.signature_mut().unwrap().options_mut().purpose = Some(ProofPurpose::Authentication);
+  assert!(BbsVerifier::verify_signature(&modified, key1.public().as_ref()).is_err());
+
+  let mut modified: Claims = claims.clone();
+  modified.signature_mut().unwrap().options_mut().challenge = Some("challenge".into());
+  assert!(BbsVerifier::verify_signature(&modified, key1.public().as_ref()).is_err());
+
+  // A mutated signature should be invalid
+  let mut modified: Claims = claims;
+  modified.set_signature(Signature::new(BbsPlus::TYPE_SIG, "#key-1"));
+  modified
+    .signature_mut()
+    .unwrap()
+    .set_value(SignatureValue::Proof("IOTA".into()));
+  assert!(BbsVerifier::verify_signature(&modified, key1.public().as_ref()).is_err());
+}
+
+#[test]
+fn test_derive_verify() {
+  let key1: KeyPair = KeyPair::new_bls12381g2().unwrap();
+  let key2: KeyPair = KeyPair::new_bls12381g2().unwrap();
+
+  let claims: Claims = signed_claims(&key1);
+  let nonce: &[u8] = b"verifier-nonce";
+
+  let derived: Claims = BbsProver::derive(&claims, key1.public().as_ref(), &["/id", "/degree/type"], nonce).unwrap();
+  let proof: &Signature = derived.try_signature().unwrap();
+
+  assert_eq!(proof.type_(), BbsPlus::TYPE_PROOF);
+  assert_eq!(proof.verification_method(), "#key-1");
+  assert_eq!(proof.options(), claims.proof.as_ref().unwrap().options());
+  assert_eq!(BbsProofVerifier::extract_nonce(proof.value()).unwrap(), nonce);
+
+  // Only the revealed properties should be disclosed
+  assert_eq!(
+    Value::Object(derived.properties.clone().into_iter().collect()),
+    json!({ "id": "did:example:123", "degree": { "type": "BachelorDegree" } })
+  );
+
+  // The derived proof should be valid
+  assert!(BbsProofVerifier::verify_signature(&derived, key1.public().as_ref()).is_ok());
+
+  // A modified key should be invalid
+  assert!(BbsProofVerifier::verify_signature(&derived, key2.public().as_ref()).is_err());
+
+  // Modified disclosed data should be invalid
+  let mut modified: Claims = derived.clone();
+  modified.properties.insert("id".into(), "did:example:456".into());
+  assert!(BbsProofVerifier::verify_signature(&modified, key1.public().as_ref()).is_err());
+
+  // Additional undisclosed data should be invalid
+  let mut modified: Claims = derived.clone();
+  modified.properties.insert("name".into(), "Alice".into());
+  assert!(BbsProofVerifier::verify_signature(&modified, key1.public().as_ref()).is_err());
+
+  // Modified proof options should be invalid
+  let mut modified: Claims = derived.clone();
+  modified.signature_mut().unwrap().options_mut().purpose = Some(ProofPurpose::Authentication);
+  assert!(BbsProofVerifier::verify_signature(&modified, key1.public().as_ref()).is_err());
+
+  let mut modified: Claims = derived.clone();
+  modified.signature_mut().unwrap().options_mut().expires = Some(Timestamp::now());
+  assert!(BbsProofVerifier::verify_signature(&modified, key1.public().as_ref()).is_err());
+
+  // A derived proof is not a signature
+  assert!(BbsVerifier::verify_signature(&derived, key1.public().as_ref()).is_err());
+
+  // Derived proofs can't be derived again
+  assert!(BbsProver::derive::<_, Claims>(&derived, key1.public().as_ref(), &["/id"], nonce).is_err());
+}
+
+#[test]
+fn test_derive_invalid_signature() {
+  let key1: KeyPair = KeyPair::new_bls12381g2().unwrap();
+  let key2: KeyPair = KeyPair::new_bls12381g2().unwrap();
+
+  let claims: Claims = signed_claims(&key1);
+  let nonce: &[u8] = b"verifier-nonce";
+
+  // Proofs can't be derived for a different key
+  assert!(BbsProver::derive::<_, Claims>(&claims, key2.public().as_ref(), &["/id"], nonce).is_err());
+
+  // Proofs can't be derived from modified data
+  let mut modified: Claims = claims;
+  modified.properties.insert("name".into(), "Mallory".into());
+  assert!(BbsProver::derive::<_, Claims>(&modified, key1.public().as_ref(), &["/id"], nonce).is_err());
+}
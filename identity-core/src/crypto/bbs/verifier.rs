@@ -0,0 +1,140 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use bbs::prelude::PoKOfSignatureProof;
+use bbs::prelude::ProofNonce;
+use bbs::prelude::ProofRequest;
+use bbs::prelude::PublicKey;
+use bbs::prelude::Signature as BbsSignature;
+use bbs::prelude::SignatureMessage;
+use bbs::prelude::SignatureProof;
+use bbs::prelude::Verifier as BbsVerifierImpl;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+
+use crate::common::BitSet;
+use crate::crypto::bbs::BbsMessages;
+use crate::crypto::bbs::BbsPlus;
+use crate::crypto::Named;
+use crate::crypto::SignatureValue;
+use crate::crypto::Verifier;
+use crate::error::Error;
+use crate::error::Result;
+use crate::utils::decode_b58;
+
+/// A signature verification helper for [BBS+ Signatures][`crate::crypto::bbs::BbsSigner`].
+#[derive(Clone, Copy, Debug)]
+pub struct BbsVerifier;
+
+impl Named for BbsVerifier {
+  const NAME: &'static str = BbsPlus::TYPE_SIG;
+}
+
+impl Verifier<[u8]> for BbsVerifier {
+  fn verify<X>(data: &X, signature: &SignatureValue, public: &[u8]) -> Result<()>
+  where
+    X: Serialize,
+  {
+    let signature: &str = signature.as_proof().ok_or(Error::InvalidProofValue("bbs+"))?;
+    let signature: Vec<u8> = decode_b58(signature)?;
+    let signature: BbsSignature =
+      BbsSignature::from_bytes_compressed_form(&signature).map_err(|_| Error::InvalidProofFormat)?;
+
+    let messages: Vec<SignatureMessage> = BbsMessages::from_data(data)?.to_messages()?;
+    let public: PublicKey = BbsPlus::public_key(public, messages.len())?;
+
+    match signature.verify(&messages, &public) {
+      Ok(true) => Ok(()),
+      Ok(false) | Err(_) => Err(Error::InvalidProofValue("bbs+")),
+    }
+  }
+}
+
+// =============================================================================
+// =============================================================================
+
+/// A verification helper for proofs derived from BBS+ Signatures with
+/// [`BbsProver`][`crate::crypto::bbs::BbsProver`].
+///
+/// The proof value is encoded as `total.revealed.nonce.proof` where `total`
+/// is the number of messages in the original document, `revealed` is the
+/// [`BitSet`] of disclosed message indices, and `nonce` and `proof` are the
+/// raw nonce and proof of knowledge; all but `total` are base58-encoded.
+#[derive(Clone, Copy, Debug)]
+pub struct BbsProofVerifier;
+
+impl BbsProofVerifier {
+  /// Returns the nonce used to create the derived proof `signature`.
+  ///
+  /// Verifiers should compare this against the nonce they provided to the
+  /// holder to prevent the replay of a derived proof.
+  pub fn extract_nonce(signature: &SignatureValue) -> Result<Vec<u8>> {
+    expand_signature_value(signature).map(|(_, _, nonce, _)| nonce)
+  }
+}
+
+impl Named for BbsProofVerifier {
+  const NAME: &'static str = BbsPlus::TYPE_PROOF;
+}
+
+impl Verifier<[u8]> for BbsProofVerifier {
+  fn verify<X>(data: &X, signature: &SignatureValue, public: &[u8]) -> Result<()>
+  where
+    X: Serialize,
+  {
+    let (total, revealed, nonce, proof): _ = expand_signature_value(signature)?;
+
+    let messages: Vec<SignatureMessage> = BbsMessages::from_data(data)?.to_messages()?;
+
+    // Every disclosed message must be accounted for
+    if revealed.len() != messages.len() as u64 {
+      return Err(Error::InvalidProofValue("bbs+ - bad disclosure"));
+    }
+
+    let indices: Vec<usize> = revealed.iter().map(|index| index as usize).collect();
+
+    if indices.iter().any(|index| *index >= total) {
+      return Err(Error::InvalidProofValue("bbs+ - bad disclosure"));
+    }
+
+    let request: ProofRequest = ProofRequest {
+      revealed_messages: indices.iter().copied().collect::<BTreeSet<usize>>(),
+      verification_key: BbsPlus::public_key(public, total)?,
+    };
+
+    let proof: SignatureProof = SignatureProof {
+      revealed_messages: indices
+        .into_iter()
+        .zip(messages)
+        .collect::<BTreeMap<usize, SignatureMessage>>(),
+      proof: PoKOfSignatureProof::from_bytes_compressed_form(&proof).map_err(|_| Error::InvalidProofFormat)?,
+    };
+
+    BbsVerifierImpl::verify_signature_pok(&request, &proof, &ProofNonce::hash(&nonce))
+      .map_err(|_| Error::InvalidProofValue("bbs+"))?;
+
+    Ok(())
+  }
+}
+
+// =============================================================================
+// =============================================================================
+
+fn expand_signature_value(signature: &SignatureValue) -> Result<(usize, BitSet, Vec<u8>, Vec<u8>)> {
+  let data: &str = signature.as_proof().ok_or(Error::InvalidProofValue("bbs+"))?;
+  let mut parts: _ = data.split('.');
+
+  // Split the signature data into `total/revealed/nonce/proof`
+  let total: &str = parts.next().ok_or(Error::InvalidProofFormat)?;
+  let revealed: &str = parts.next().ok_or(Error::InvalidProofFormat)?;
+  let nonce: &str = parts.next().ok_or(Error::InvalidProofFormat)?;
+  let proof: &str = parts.next().ok_or(Error::InvalidProofFormat)?;
+
+  let total: usize = total.parse().map_err(|_| Error::InvalidProofFormat)?;
+  let revealed: BitSet = BitSet::deserialize_slice(&decode_b58(revealed)?)?;
+  let nonce: Vec<u8> = decode_b58(nonce)?;
+  let proof: Vec<u8> = decode_b58(proof)?;
+
+  Ok((total, revealed, nonce, proof))
+}
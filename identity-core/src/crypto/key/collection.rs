@@ -22,7 +22,11 @@ use crate::crypto::PublicKey;
 use crate::crypto::SecretKey;
 use crate::error::Error;
 use crate::error::Result;
+use crate::utils::generate_bls12381g2_keypairs;
 use crate::utils::generate_ed25519_keypairs;
+use crate::utils::generate_p256_keypairs;
+use crate::utils::generate_secp256k1_keypairs;
+use crate::utils::generate_x25519_keypairs;
 
 /// A collection of cryptographic keys.
 #[derive(Clone, Debug)]
@@ -61,10 +65,19 @@ impl KeyCollection {
     Self::new(KeyType::Ed25519, count)
   }
 
+  /// Creates a new [`KeyCollection`] with [`Secp256k1`][`KeyType::Secp256k1`] keys.
+  pub fn new_secp256k1(count: usize) -> Result<Self> {
+    Self::new(KeyType::Secp256k1, count)
+  }
+
   /// Creates a new [`KeyCollection`] with the given [`key type`][`KeyType`].
   pub fn new(type_: KeyType, count: usize) -> Result<Self> {
     let keys: Vec<(PublicKey, SecretKey)> = match type_ {
       KeyType::Ed25519 => generate_ed25519_keypairs(count)?,
+      KeyType::Secp256k1 => generate_secp256k1_keypairs(count)?,
+      KeyType::P256 => generate_p256_keypairs(count)?,
+      KeyType::X25519 => generate_x25519_keypairs(count)?,
+      KeyType::Bls12381G2 => generate_bls12381g2_keypairs(count)?,
     };
 
     Self::from_iterator(type_, keys.into_iter())
@@ -160,12 +173,30 @@ impl KeyCollection {
 
   /// Creates a DID Document public key value for the Merkle root of
   /// the key collection.
+  ///
+  /// # Panics
+  ///
+  /// Panics if the keys of the collection can't be used to create signatures,
+  /// see [`KeyCollection::try_encode_merkle_key`] for a fallible version.
   pub fn encode_merkle_key<D>(&self) -> Vec<u8>
   where
     D: MerkleDigest,
   {
     self.type_.encode_merkle_key::<D>(&self.merkle_root())
   }
+
+  /// Creates a DID Document public key value for the Merkle root of
+  /// the key collection.
+  ///
+  /// # Errors
+  ///
+  /// Fails if the keys of the collection can't be used to create signatures.
+  pub fn try_encode_merkle_key<D>(&self) -> Result<Vec<u8>>
+  where
+    D: MerkleDigest,
+  {
+    self.type_.try_encode_merkle_key::<D>(&self.merkle_root())
+  }
 }
 
 impl<I> Index<I> for KeyCollection
@@ -200,6 +231,7 @@ impl IntoIterator for KeyCollection {
 #[cfg(test)]
 mod tests {
   use super::*;
+  use crate::crypto::merkle_key::Sha256;
 
   #[test]
   fn test_ed25519() {
@@ -232,4 +264,19 @@ mod tests {
       assert_eq!(secret.as_ref(), keys.secret(index).unwrap().as_ref());
     }
   }
+
+  #[test]
+  fn test_encode_merkle_key() {
+    let keys: KeyCollection = KeyCollection::new_ed25519(8).unwrap();
+
+    assert_eq!(
+      keys.try_encode_merkle_key::<Sha256>().unwrap(),
+      keys.encode_merkle_key::<Sha256>()
+    );
+
+    // Key agreement keys can not be used in a Merkle Key Collection
+    let keys: KeyCollection = KeyCollection::new(KeyType::X25519, 8).unwrap();
+
+    assert!(keys.try_encode_merkle_key::<Sha256>().is_err());
+  }
 }
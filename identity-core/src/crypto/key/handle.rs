@@ -0,0 +1,70 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use async_trait::async_trait;
+
+use crate::crypto::Ed25519;
+use crate::crypto::KeyPair;
+use crate::crypto::KeyType;
+use crate::crypto::PublicKey;
+use crate::crypto::Secp256k1;
+use crate::crypto::Sign;
+use crate::crypto::P256;
+use crate::error::Error;
+use crate::error::Result;
+
+/// A reference to a private key that can be used to create signatures
+/// without exposing the key material.
+///
+/// Implementations may keep the secret key in a secure enclave, a hardware
+/// device, or a remote service.
+#[async_trait(?Send)]
+pub trait KeyHandle {
+  /// Returns the [`type`][`KeyType`] of the referenced key.
+  fn key_type(&self) -> KeyType;
+
+  /// Returns the public key of the referenced key pair.
+  async fn public_key(&self) -> Result<PublicKey>;
+
+  /// Signs the given `message` with the referenced key and returns the raw
+  /// signature bytes.
+  async fn sign(&self, message: &[u8]) -> Result<Vec<u8>>;
+}
+
+#[async_trait(?Send)]
+impl<T> KeyHandle for &'_ T
+where
+  T: KeyHandle + ?Sized,
+{
+  fn key_type(&self) -> KeyType {
+    (**self).key_type()
+  }
+
+  async fn public_key(&self) -> Result<PublicKey> {
+    (**self).public_key().await
+  }
+
+  async fn sign(&self, message: &[u8]) -> Result<Vec<u8>> {
+    (**self).sign(message).await
+  }
+}
+
+#[async_trait(?Send)]
+impl KeyHandle for KeyPair {
+  fn key_type(&self) -> KeyType {
+    self.type_()
+  }
+
+  async fn public_key(&self) -> Result<PublicKey> {
+    Ok(self.public().clone())
+  }
+
+  async fn sign(&self, message: &[u8]) -> Result<Vec<u8>> {
+    match self.type_() {
+      KeyType::Ed25519 => Ed25519::sign(message, self.secret().as_ref()).map(|signature| signature.to_vec()),
+      KeyType::Secp256k1 => Secp256k1::sign(message, self.secret().as_ref()),
+      KeyType::P256 => P256::sign(message, self.secret().as_ref()),
+      KeyType::X25519 | KeyType::Bls12381G2 => Err(Error::InvalidKeyFormat),
+    }
+  }
+}
@@ -4,12 +4,14 @@
 #![allow(clippy::module_inception)]
 
 mod collection;
+mod handle;
 mod key;
 mod pair;
 mod reference;
 mod type_;
 
 pub use self::collection::KeyCollection;
+pub use self::handle::KeyHandle;
 pub use self::key::PublicKey;
 pub use self::key::SecretKey;
 pub use self::pair::KeyPair;
@@ -7,8 +7,14 @@ use crate::crypto::KeyRef;
 use crate::crypto::KeyType;
 use crate::crypto::PublicKey;
 use crate::crypto::SecretKey;
+use crate::error::Error;
 use crate::error::Result;
+use crate::utils::ed25519_to_x25519_keypair;
+use crate::utils::generate_bls12381g2_keypair;
 use crate::utils::generate_ed25519_keypair;
+use crate::utils::generate_p256_keypair;
+use crate::utils::generate_secp256k1_keypair;
+use crate::utils::generate_x25519_keypair;
 
 /// A convenient type for representing a pair of cryptographic keys.
 #[derive(Clone, Debug)]
@@ -24,10 +30,34 @@ impl KeyPair {
     Self::new(KeyType::Ed25519)
   }
 
+  /// Creates a new [`Secp256k1`][`KeyType::Secp256k1`] [`KeyPair`].
+  pub fn new_secp256k1() -> Result<Self> {
+    Self::new(KeyType::Secp256k1)
+  }
+
+  /// Creates a new [`P256`][`KeyType::P256`] [`KeyPair`].
+  pub fn new_p256() -> Result<Self> {
+    Self::new(KeyType::P256)
+  }
+
+  /// Creates a new [`X25519`][`KeyType::X25519`] [`KeyPair`].
+  pub fn new_x25519() -> Result<Self> {
+    Self::new(KeyType::X25519)
+  }
+
+  /// Creates a new [`Bls12381G2`][`KeyType::Bls12381G2`] [`KeyPair`].
+  pub fn new_bls12381g2() -> Result<Self> {
+    Self::new(KeyType::Bls12381G2)
+  }
+
   /// Creates a new [`KeyPair`] with the given [`key type`][`KeyType`].
   pub fn new(type_: KeyType) -> Result<Self> {
     let (public, secret): (PublicKey, SecretKey) = match type_ {
       KeyType::Ed25519 => generate_ed25519_keypair()?,
+      KeyType::Secp256k1 => generate_secp256k1_keypair()?,
+      KeyType::P256 => generate_p256_keypair()?,
+      KeyType::X25519 => generate_x25519_keypair()?,
+      KeyType::Bls12381G2 => generate_bls12381g2_keypair()?,
     };
 
     Ok(Self { type_, public, secret })
@@ -57,6 +87,26 @@ impl KeyPair {
   pub fn secret_ref(&self) -> KeyRef<'_> {
     KeyRef::new(self.type_, self.secret.as_ref())
   }
+
+  /// Derives an [`X25519`][`KeyType::X25519`] [`KeyPair`] for key agreement
+  /// from an [`Ed25519`][`KeyType::Ed25519`] [`KeyPair`].
+  ///
+  /// # Errors
+  ///
+  /// Fails if `self` is not an `Ed25519` key pair.
+  pub fn to_x25519(&self) -> Result<Self> {
+    if self.type_ != KeyType::Ed25519 {
+      return Err(Error::InvalidKeyFormat);
+    }
+
+    let (public, secret): (PublicKey, SecretKey) = ed25519_to_x25519_keypair(self.secret.as_ref())?;
+
+    Ok(Self {
+      type_: KeyType::X25519,
+      public,
+      secret,
+    })
+  }
 }
 
 impl Drop for KeyPair {
@@ -86,6 +136,7 @@ impl From<(KeyType, PublicKey, SecretKey)> for KeyPair {
 #[cfg(test)]
 mod tests {
   use super::*;
+  use crate::utils::decode_b16;
 
   #[test]
   fn test_new_ed25519() {
@@ -94,4 +145,56 @@ mod tests {
     assert_eq!(keypair.public().as_ref().len(), 32);
     assert_eq!(keypair.secret().as_ref().len(), 32);
   }
+
+  #[test]
+  fn test_new_secp256k1() {
+    let keypair: KeyPair = KeyPair::new_secp256k1().unwrap();
+    assert_eq!(keypair.type_(), KeyType::Secp256k1);
+    assert_eq!(keypair.public().as_ref().len(), 33);
+    assert_eq!(keypair.secret().as_ref().len(), 32);
+  }
+
+  #[test]
+  fn test_new_p256() {
+    let keypair: KeyPair = KeyPair::new_p256().unwrap();
+    assert_eq!(keypair.type_(), KeyType::P256);
+    assert_eq!(keypair.public().as_ref().len(), 33);
+    assert_eq!(keypair.secret().as_ref().len(), 32);
+  }
+
+  #[test]
+  fn test_new_x25519() {
+    let keypair: KeyPair = KeyPair::new_x25519().unwrap();
+    assert_eq!(keypair.type_(), KeyType::X25519);
+    assert_eq!(keypair.public().as_ref().len(), 32);
+    assert_eq!(keypair.secret().as_ref().len(), 32);
+  }
+
+  #[test]
+  fn test_new_bls12381g2() {
+    let keypair: KeyPair = KeyPair::new_bls12381g2().unwrap();
+    assert_eq!(keypair.type_(), KeyType::Bls12381G2);
+    assert_eq!(keypair.public().as_ref().len(), 96);
+    assert_eq!(keypair.secret().as_ref().len(), 32);
+  }
+
+  #[test]
+  fn test_ed25519_to_x25519() {
+    // RFC 8032 - Test Vector 1
+    const ED25519_PUBLIC: &str = "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a";
+    const ED25519_SECRET: &str = "9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60";
+    const X25519_PUBLIC: &str = "d85e07ec22b0ad881537c2f44d662d1a143cf830c57aca4305d85c7a90f6b62e";
+    const X25519_SECRET: &str = "307c83864f2833cb427a2ef1c00a013cfdff2768d980c0a3a520f006904de94f";
+
+    let public: PublicKey = decode_b16(ED25519_PUBLIC).unwrap().into();
+    let secret: SecretKey = decode_b16(ED25519_SECRET).unwrap().into();
+    let keypair: KeyPair = (KeyType::Ed25519, public, secret).into();
+    let derived: KeyPair = keypair.to_x25519().unwrap();
+
+    assert_eq!(derived.type_(), KeyType::X25519);
+    assert_eq!(derived.public().as_ref(), &decode_b16(X25519_PUBLIC).unwrap()[..]);
+    assert_eq!(derived.secret().as_ref(), &decode_b16(X25519_SECRET).unwrap()[..]);
+
+    assert!(derived.to_x25519().is_err());
+  }
 }
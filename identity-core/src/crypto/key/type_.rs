@@ -7,6 +7,7 @@ use crate::crypto::merkle_key::MerkleDigest;
 use crate::crypto::merkle_key::MerkleKey;
 use crate::crypto::merkle_tree::Hash;
 use crate::crypto::Ed25519;
+use crate::crypto::Secp256k1;
 use crate::error::Error;
 use crate::error::Result;
 
@@ -16,6 +17,18 @@ pub enum KeyType {
   /// Identifies an `Ed25519` public/secret key.
   #[serde(rename = "ed25519")]
   Ed25519,
+  /// Identifies a `secp256k1` public/secret key.
+  #[serde(rename = "secp256k1")]
+  Secp256k1,
+  /// Identifies a `P-256` public/secret key.
+  #[serde(rename = "p256")]
+  P256,
+  /// Identifies an `X25519` public/secret key used for key agreement.
+  #[serde(rename = "x25519")]
+  X25519,
+  /// Identifies a `BLS12-381` G2 public/secret key used for BBS+ signatures.
+  #[serde(rename = "bls12381g2")]
+  Bls12381G2,
 }
 
 impl KeyType {
@@ -23,16 +36,42 @@ impl KeyType {
   pub const fn as_str(&self) -> &'static str {
     match self {
       Self::Ed25519 => "ed25519",
+      Self::Secp256k1 => "secp256k1",
+      Self::P256 => "p256",
+      Self::X25519 => "x25519",
+      Self::Bls12381G2 => "bls12381g2",
     }
   }
 
   /// Creates a DID Document public key value for the given Merkle `root`.
+  ///
+  /// # Panics
+  ///
+  /// Panics if the key type is not supported by Merkle Key Collections, see
+  /// [`KeyType::try_encode_merkle_key`] for a fallible version.
   pub fn encode_merkle_key<D>(&self, root: &Hash<D>) -> Vec<u8>
+  where
+    D: MerkleDigest,
+  {
+    match self.try_encode_merkle_key(root) {
+      Ok(data) => data,
+      Err(_) => panic!("unsupported Merkle Key Collection type: {}", self.as_str()),
+    }
+  }
+
+  /// Creates a DID Document public key value for the given Merkle `root`.
+  ///
+  /// # Errors
+  ///
+  /// Fails if the key type is not supported by Merkle Key Collections.
+  pub fn try_encode_merkle_key<D>(&self, root: &Hash<D>) -> Result<Vec<u8>>
   where
     D: MerkleDigest,
   {
     match self {
-      Self::Ed25519 => MerkleKey::encode_key::<D, Ed25519>(root),
+      Self::Ed25519 => Ok(MerkleKey::encode_key::<D, Ed25519>(root)),
+      Self::Secp256k1 => Ok(MerkleKey::encode_key::<D, Secp256k1>(root)),
+      Self::P256 | Self::X25519 | Self::Bls12381G2 => Err(Error::InvalidMerkleKeyTag(None)),
     }
   }
 }
@@ -43,6 +82,14 @@ impl FromStr for KeyType {
   fn from_str(string: &str) -> Result<Self, Self::Err> {
     if string.eq_ignore_ascii_case("ed25519") {
       Ok(Self::Ed25519)
+    } else if string.eq_ignore_ascii_case("secp256k1") {
+      Ok(Self::Secp256k1)
+    } else if string.eq_ignore_ascii_case("p256") {
+      Ok(Self::P256)
+    } else if string.eq_ignore_ascii_case("x25519") {
+      Ok(Self::X25519)
+    } else if string.eq_ignore_ascii_case("bls12381g2") {
+      Ok(Self::Bls12381G2)
     } else {
       Err(Error::InvalidKeyFormat)
     }
@@ -57,8 +57,10 @@ mod tests {
   use crate::crypto::merkle_key::MerkleSignature;
   use crate::crypto::merkle_key::MerkleTag;
   use crate::crypto::merkle_key::Sha256;
+  use crate::crypto::merkle_key::Sha512Trunc256;
   use crate::crypto::merkle_tree::Hash;
   use crate::crypto::Ed25519;
+  use crate::crypto::Secp256k1;
 
   fn assert_tag<D, S>()
   where
@@ -83,4 +85,16 @@ mod tests {
   fn test_sha256_tag() {
     assert_tag::<Sha256, Ed25519>();
   }
+
+  #[test]
+  fn test_sha512_256_tag() {
+    assert_tag::<Sha512Trunc256, Ed25519>();
+  }
+
+  #[test]
+  fn test_secp256k1_tag() {
+    assert_tag::<Sha256, Secp256k1>();
+    assert_tag::<Blake2b256, Secp256k1>();
+    assert_tag::<Sha512Trunc256, Secp256k1>();
+  }
 }
@@ -7,10 +7,14 @@ pub use crypto::hashes::sha::Sha256;
 #[doc(inline)]
 pub use crypto::hashes::blake2b::Blake2b256;
 
+#[doc(inline)]
+pub use sha2::Sha512Trunc256;
+
 use crate::crypto::merkle_key::MerkleDigest;
 use crate::crypto::merkle_key::MerkleSignature;
 use crate::crypto::merkle_key::MerkleTag;
 use crate::crypto::Ed25519;
+use crate::crypto::Secp256k1;
 
 // Add support for using SHA-256 as a Merkle Key Collection digest algorithm.
 impl MerkleDigest for Sha256 {
@@ -22,7 +26,17 @@ impl MerkleDigest for Blake2b256 {
   const TAG: MerkleTag = MerkleTag::BLAKE2B_256;
 }
 
+// Add support for using SHA-512/256 as a Merkle Key Collection digest algorithm.
+impl MerkleDigest for Sha512Trunc256 {
+  const TAG: MerkleTag = MerkleTag::SHA512_256;
+}
+
 // Add support for using Ed25519 as a Merkle Key Collection signature algorithm.
 impl<T: ?Sized> MerkleSignature for Ed25519<T> {
   const TAG: MerkleTag = MerkleTag::ED25519;
 }
+
+// Add support for using secp256k1 as a Merkle Key Collection signature algorithm.
+impl<T: ?Sized> MerkleSignature for Secp256k1<T> {
+  const TAG: MerkleTag = MerkleTag::SECP256K1;
+}
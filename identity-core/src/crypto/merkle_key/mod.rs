@@ -13,6 +13,7 @@ mod verifier;
 pub use self::base::MerkleKey;
 pub use self::impls::Blake2b256;
 pub use self::impls::Sha256;
+pub use self::impls::Sha512Trunc256;
 pub use self::signer::MerkleSigner;
 pub use self::signer::MerkleSigningKey;
 pub use self::signer::SigningKey;
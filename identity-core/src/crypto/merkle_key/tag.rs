@@ -10,12 +10,18 @@ impl MerkleTag {
   /// A Merkle Key Collection tag specifying `Ed25519` as the signature algorithm.
   pub const ED25519: Self = Self::new(0x0);
 
+  /// A Merkle Key Collection tag specifying `secp256k1` as the signature algorithm.
+  pub const SECP256K1: Self = Self::new(0x1);
+
   /// A Merkle Key Collection tag specifying `SHA-256` as the digest algorithm.
   pub const SHA256: Self = Self::new(0x0);
 
   /// A Merkle Key Collection tag specifying `Blake2b-256` as the digest algorithm.
   pub const BLAKE2B_256: Self = Self::new(0x1);
 
+  /// A Merkle Key Collection tag specifying `SHA-512/256` as the digest algorithm.
+  pub const SHA512_256: Self = Self::new(0x2);
+
   /// Creates a new [`MerkleTag`] object.
   pub const fn new(tag: u8) -> Self {
     Self(tag)
@@ -13,6 +13,7 @@ use crate::crypto::merkle_key::MerkleSigner;
 use crate::crypto::merkle_key::MerkleTag;
 use crate::crypto::merkle_key::MerkleVerifier;
 use crate::crypto::merkle_key::Sha256;
+use crate::crypto::merkle_key::Sha512Trunc256;
 use crate::crypto::merkle_key::SigningKey;
 use crate::crypto::merkle_key::VerificationKey;
 use crate::crypto::Ed25519;
@@ -108,3 +109,8 @@ fn test_sign_verify_sha256_ed25519() {
 fn test_sign_verify_blake2b_ed25519() {
   __test_sign_verify::<Blake2b256, Ed25519>();
 }
+
+#[test]
+fn test_sign_verify_sha512_256_ed25519() {
+  __test_sign_verify::<Sha512Trunc256, Ed25519>();
+}
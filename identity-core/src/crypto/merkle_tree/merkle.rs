@@ -4,6 +4,7 @@
 use crate::crypto::merkle_tree::AsLeaf;
 use crate::crypto::merkle_tree::DigestExt;
 use crate::crypto::merkle_tree::Hash;
+use crate::crypto::merkle_tree::MultiProof;
 use crate::crypto::merkle_tree::Node;
 use crate::crypto::merkle_tree::Proof;
 
@@ -92,8 +93,59 @@ where
   }
 }
 
+/// Generate a compact proof-of-inclusion for the leaf nodes at the specified
+/// `indices`.
+///
+/// Duplicate indices are ignored and the indices of the resulting proof are
+/// sorted in ascending order.
+pub fn compute_merkle_multi_proof<D, L>(leaves: &[L], indices: &[usize]) -> Option<MultiProof<D>>
+where
+  D: DigestExt,
+  L: AsLeaf<D>,
+{
+  #[inline]
+  fn __generate<D, L>(nodes: &mut Vec<Hash<D>>, leaves: &[L], offset: usize, indices: &[usize])
+  where
+    D: DigestExt,
+    L: AsLeaf<D>,
+  {
+    if indices.is_empty() {
+      nodes.push(compute_merkle_root::<D, L>(leaves));
+    } else if leaves.len() > 1 {
+      let (this, that): _ = __split_pow2(leaves);
+      let split: usize = indices
+        .iter()
+        .position(|index| *index >= offset + this.len())
+        .unwrap_or(indices.len());
+
+      __generate::<D, L>(nodes, this, offset, &indices[..split]);
+      __generate::<D, L>(nodes, that, offset + this.len(), &indices[split..]);
+    }
+  }
+
+  let mut indices: Vec<usize> = indices.to_vec();
+
+  indices.sort_unstable();
+  indices.dedup();
+
+  match indices.last().copied() {
+    Some(last) if last < leaves.len() => {
+      let mut nodes: Vec<Hash<D>> = Vec::new();
+
+      __generate(&mut nodes, leaves, 0, &indices);
+
+      Some(MultiProof::new(
+        leaves.len(),
+        indices.into_boxed_slice(),
+        nodes.into_boxed_slice(),
+      ))
+    }
+    _ => None,
+  }
+}
+
 #[inline]
-fn __pow2(value: u32) -> usize {
+pub(crate) fn __pow2(value: u32) -> usize {
   1 << __log2c(value)
 }
 
@@ -109,13 +161,16 @@ fn __split_pow2<T>(slice: &[T]) -> (&[T], &[T]) {
 
 #[cfg(test)]
 mod tests {
+  use crypto::hashes::blake2b::Blake2b256;
   use crypto::hashes::sha::Sha256;
 
+  use crate::crypto::merkle_tree::compute_merkle_multi_proof;
   use crate::crypto::merkle_tree::compute_merkle_proof;
   use crate::crypto::merkle_tree::compute_merkle_root;
   use crate::crypto::merkle_tree::Digest;
   use crate::crypto::merkle_tree::DigestExt;
   use crate::crypto::merkle_tree::Hash;
+  use crate::crypto::merkle_tree::MultiProof;
   use crate::crypto::merkle_tree::Proof;
 
   macro_rules! h {
@@ -152,6 +207,62 @@ mod tests {
     }
   }
 
+  #[test]
+  fn test_compute_multi_proof() {
+    for size in 1..20 {
+      let nodes: Vec<[u8; 4]> = (0..size).map(u32::to_be_bytes).collect();
+      let root: Sha256Hash = compute_merkle_root(&nodes);
+      let size: usize = size as usize;
+
+      let sets: Vec<Vec<usize>> = vec![
+        vec![0],
+        vec![size - 1],
+        (0..size).collect(),
+        (0..size).step_by(2).collect(),
+        (0..size).step_by(3).collect(),
+      ];
+
+      for indices in sets {
+        let proof: MultiProof<Sha256> = compute_merkle_multi_proof(&nodes, &indices).unwrap();
+        let targets: Vec<[u8; 4]> = indices.iter().map(|index| nodes[*index]).collect();
+
+        assert_eq!(proof.leaves(), size);
+        assert_eq!(proof.indices(), &indices[..]);
+        assert!(proof.verify(&root, &targets));
+
+        // Proving every leaf requires no sibling hashes
+        if indices.len() == size {
+          assert!(proof.nodes().is_empty());
+        }
+      }
+
+      assert!(compute_merkle_multi_proof::<Sha256, _>(&nodes, &[]).is_none());
+      assert!(compute_merkle_multi_proof::<Sha256, _>(&nodes, &[size]).is_none());
+    }
+  }
+
+  #[test]
+  fn test_compute_multi_proof_shared_nodes() {
+    let mut digest: Sha256 = Sha256::new();
+
+    let nodes: Vec<[u8; 4]> = (0..8_u32).map(u32::to_be_bytes).collect();
+    let hashes: Vec<Sha256Hash> = nodes.iter().map(|node| digest.hash_leaf(node.as_ref())).collect();
+    let root: Sha256Hash = compute_merkle_root(&hashes);
+
+    // Duplicate and unsorted indices are normalized
+    let proof: MultiProof<Sha256> = compute_merkle_multi_proof(&hashes, &[3, 0, 3, 1]).unwrap();
+
+    assert_eq!(proof.indices(), &[0, 1, 3]);
+    assert_eq!(proof.nodes(), &[hashes[2], compute_merkle_root(&hashes[4..])]);
+    assert!(proof.verify_hashes(&root, &[hashes[0], hashes[1], hashes[3]]));
+    assert!(!proof.verify_hashes(&root, &[hashes[0], hashes[1], hashes[2]]));
+
+    let proof: MultiProof<Blake2b256> = compute_merkle_multi_proof(&nodes, &[2, 7]).unwrap();
+    let root: Hash<Blake2b256> = compute_merkle_root(&nodes);
+
+    assert!(proof.verify(&root, &[nodes[2], nodes[7]]));
+  }
+
   #[test]
   #[allow(non_snake_case)]
   fn test_root() {
@@ -8,6 +8,7 @@
 mod digest;
 mod hash;
 mod merkle;
+mod multi_proof;
 mod node;
 mod proof;
 mod serde;
@@ -17,8 +18,10 @@ pub use self::digest::Digest;
 pub use self::digest::DigestExt;
 pub use self::digest::Output;
 pub use self::hash::Hash;
+pub use self::merkle::compute_merkle_multi_proof;
 pub use self::merkle::compute_merkle_proof;
 pub use self::merkle::compute_merkle_root;
+pub use self::multi_proof::MultiProof;
 pub use self::node::Node;
 pub use self::proof::Proof;
 pub use self::traits::AsLeaf;
@@ -0,0 +1,210 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::fmt::Debug;
+use core::fmt::Formatter;
+use core::fmt::Result;
+use core::slice::Iter;
+use subtle::ConstantTimeEq;
+
+use crate::crypto::merkle_tree::merkle::__pow2;
+use crate::crypto::merkle_tree::AsLeaf;
+use crate::crypto::merkle_tree::DigestExt;
+use crate::crypto::merkle_tree::Hash;
+
+/// A Merkle tree inclusion proof that allows proving the existence of a set
+/// of leaves in a Merkle tree with a single list of sibling hashes.
+///
+/// Sibling hashes shared by the proven leaves are only included once.
+pub struct MultiProof<D: DigestExt> {
+  leaves: usize,
+  indices: Box<[usize]>,
+  nodes: Box<[Hash<D>]>,
+}
+
+impl<D: DigestExt> MultiProof<D> {
+  /// Creates a new [`MultiProof`] from the total number of `leaves` in the
+  /// tree, the sorted `indices` of the proven leaves, and a boxed slice of
+  /// sibling hashes.
+  pub fn new(leaves: usize, indices: Box<[usize]>, nodes: Box<[Hash<D>]>) -> Self {
+    Self { leaves, indices, nodes }
+  }
+
+  /// Returns the total number of leaves in the Merkle tree.
+  pub fn leaves(&self) -> usize {
+    self.leaves
+  }
+
+  /// Returns the indices of the proven leaf nodes as a slice.
+  pub fn indices(&self) -> &[usize] {
+    &self.indices
+  }
+
+  /// Returns the sibling hashes as a slice.
+  pub fn nodes(&self) -> &[Hash<D>] {
+    &self.nodes
+  }
+
+  /// Verifies the computed root of `self` with the given `root` hash.
+  ///
+  /// `targets` must be given in the same order as [`MultiProof::indices`].
+  pub fn verify<T>(&self, root: &Hash<D>, targets: &[T]) -> bool
+  where
+    T: AsLeaf<D>,
+  {
+    let mut digest: D = D::new();
+    let hashes: Vec<Hash<D>> = targets.iter().map(|target| target.hash(&mut digest)).collect();
+
+    self.verify_hashes(root, &hashes)
+  }
+
+  /// Verifies the computed root of `self` with the given `root` hash and
+  /// a slice of pre-computed target `hashes`.
+  pub fn verify_hashes(&self, root: &Hash<D>, hashes: &[Hash<D>]) -> bool {
+    self.root(hashes).map_or(false, |computed| computed.ct_eq(root).into())
+  }
+
+  /// Computes the root hash from `targets` using a default digest.
+  ///
+  /// Returns `None` if the proof is malformed or `targets` does not match
+  /// the proven indices.
+  pub fn root(&self, targets: &[Hash<D>]) -> Option<Hash<D>> {
+    self.root_with(&mut D::new(), targets)
+  }
+
+  /// Computes the root hash from `targets` using the given `digest`.
+  ///
+  /// Returns `None` if the proof is malformed or `targets` does not match
+  /// the proven indices.
+  pub fn root_with(&self, digest: &mut D, targets: &[Hash<D>]) -> Option<Hash<D>> {
+    fn __compute<D>(
+      digest: &mut D,
+      len: usize,
+      offset: usize,
+      indices: &[usize],
+      targets: &mut Iter<'_, Hash<D>>,
+      nodes: &mut Iter<'_, Hash<D>>,
+    ) -> Option<Hash<D>>
+    where
+      D: DigestExt,
+    {
+      if indices.is_empty() {
+        return nodes.next().map(|node| node.hash(digest));
+      }
+
+      if len == 1 {
+        return targets.next().map(|target| target.hash(digest));
+      }
+
+      let k: usize = __pow2(len as u32 - 1);
+      let split: usize = indices
+        .iter()
+        .position(|index| *index >= offset + k)
+        .unwrap_or(indices.len());
+
+      let (this, that): _ = indices.split_at(split);
+
+      let lhs: Hash<D> = __compute(digest, k, offset, this, targets, nodes)?;
+      let rhs: Hash<D> = __compute(digest, len - k, offset + k, that, targets, nodes)?;
+
+      Some(digest.hash_node(&lhs, &rhs))
+    }
+
+    if self.indices.is_empty() || self.indices.len() != targets.len() {
+      return None;
+    }
+
+    // Indices MUST be unique, sorted, and within the bounds of the tree
+    if self.indices.windows(2).any(|pair| pair[0] >= pair[1]) {
+      return None;
+    }
+
+    if self.indices.last().copied()? >= self.leaves {
+      return None;
+    }
+
+    let mut targets: Iter<'_, Hash<D>> = targets.iter();
+    let mut nodes: Iter<'_, Hash<D>> = self.nodes.iter();
+
+    let root: Hash<D> = __compute(digest, self.leaves, 0, &self.indices, &mut targets, &mut nodes)?;
+
+    // All targets and sibling hashes MUST be consumed
+    if targets.next().is_some() || nodes.next().is_some() {
+      return None;
+    }
+
+    Some(root)
+  }
+}
+
+impl<D: DigestExt> Clone for MultiProof<D>
+where
+  Hash<D>: Clone,
+{
+  fn clone(&self) -> Self {
+    Self {
+      leaves: self.leaves,
+      indices: self.indices.clone(),
+      nodes: self.nodes.clone(),
+    }
+  }
+}
+
+impl<D: DigestExt> Debug for MultiProof<D> {
+  fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+    f.debug_struct("MultiProof")
+      .field("leaves", &self.leaves)
+      .field("indices", &self.indices)
+      .field("nodes", &self.nodes)
+      .finish()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crypto::hashes::sha::Sha256;
+
+  use crate::crypto::merkle_tree::compute_merkle_multi_proof;
+  use crate::crypto::merkle_tree::compute_merkle_root;
+  use crate::crypto::merkle_tree::Hash;
+  use crate::crypto::merkle_tree::MultiProof;
+
+  #[test]
+  fn test_encode_decode() {
+    let leaves: Vec<[u8; 4]> = (0..11_u32).map(u32::to_be_bytes).collect();
+    let root: Hash<Sha256> = compute_merkle_root(&leaves);
+
+    let proof: MultiProof<Sha256> = compute_merkle_multi_proof(&leaves, &[1, 4, 10]).unwrap();
+    let encoded: Vec<u8> = proof.encode();
+    let decoded: MultiProof<Sha256> = MultiProof::decode(&encoded).unwrap();
+
+    assert_eq!(decoded.leaves(), proof.leaves());
+    assert_eq!(decoded.indices(), proof.indices());
+    assert_eq!(decoded.nodes(), proof.nodes());
+    assert!(decoded.verify(&root, &[leaves[1], leaves[4], leaves[10]]));
+
+    assert!(MultiProof::<Sha256>::decode(&encoded[..encoded.len() - 1]).is_none());
+  }
+
+  #[test]
+  fn test_malformed() {
+    let leaves: Vec<[u8; 4]> = (0..8_u32).map(u32::to_be_bytes).collect();
+    let root: Hash<Sha256> = compute_merkle_root(&leaves);
+    let proof: MultiProof<Sha256> = compute_merkle_multi_proof(&leaves, &[2, 5]).unwrap();
+
+    // Targets in the wrong order or of the wrong length
+    assert!(!proof.verify(&root, &[leaves[5], leaves[2]]));
+    assert!(!proof.verify(&root, &[leaves[2]]));
+    assert!(!proof.verify(&root, &[leaves[2], leaves[5], leaves[6]]));
+
+    // Unsorted indices
+    let unsorted: MultiProof<Sha256> = MultiProof::new(8, vec![5, 2].into(), proof.nodes().to_vec().into());
+    assert!(!unsorted.verify(&root, &[leaves[5], leaves[2]]));
+
+    // Extra sibling hashes
+    let mut nodes: Vec<Hash<Sha256>> = proof.nodes().to_vec();
+    nodes.push(root);
+    let extended: MultiProof<Sha256> = MultiProof::new(8, proof.indices().into(), nodes.into());
+    assert!(!extended.verify(&root, &[leaves[2], leaves[5]]));
+  }
+}
@@ -5,6 +5,7 @@ use core::convert::TryInto;
 
 use crate::crypto::merkle_tree::DigestExt;
 use crate::crypto::merkle_tree::Hash;
+use crate::crypto::merkle_tree::MultiProof;
 use crate::crypto::merkle_tree::Node;
 use crate::crypto::merkle_tree::Proof;
 
@@ -114,6 +115,75 @@ where
   }
 }
 
+impl<D> MultiProof<D>
+where
+  D: DigestExt,
+{
+  /// Encodes `self` as a vector of bytes.
+  ///
+  /// See [`MultiProof::encode_into`] for more details.
+  pub fn encode(&self) -> Vec<u8> {
+    let capacity: usize = __multi_proof_len::<D>(self.indices().len(), self.nodes().len());
+    let mut output: Vec<u8> = Vec::with_capacity(capacity);
+
+    self.encode_into(&mut output);
+
+    output
+  }
+
+  /// Encodes `self` as a vector of bytes, appended to `output`.
+  ///
+  /// The vector of bytes will have the following layout:
+  ///
+  ///   ```text
+  ///   Indices = [ U32(Index0), U32(Index1), ..., U32(IndexN-1) ]
+  ///   Hashes = [ Hash0, Hash1, ..., HashN-1 ]
+  ///
+  ///   [ U32(LEAVES) | U32(LEN(Indices)) | Indices | U32(LEN(Hashes)) | Hashes ]
+  ///   ```
+  pub fn encode_into(&self, output: &mut Vec<u8>) {
+    output.extend_from_slice(&(self.leaves() as u32).to_be_bytes());
+    output.extend_from_slice(&(self.indices().len() as u32).to_be_bytes());
+
+    for index in self.indices() {
+      output.extend_from_slice(&(*index as u32).to_be_bytes());
+    }
+
+    output.extend_from_slice(&(self.nodes().len() as u32).to_be_bytes());
+
+    for node in self.nodes() {
+      output.extend_from_slice(node.as_slice());
+    }
+  }
+
+  /// Decodes a [`MultiProof`] from a slice of bytes.
+  ///
+  /// See [`MultiProof::encode_into`] for more details on the expected input format.
+  pub fn decode(slice: &[u8]) -> Option<Self> {
+    let leaves: usize = __decode_u32(slice)?;
+
+    let size: usize = __decode_u32(slice.get(4..)?)?;
+    let mut indices: Vec<usize> = Vec::with_capacity(size);
+    let mut slice: &[u8] = slice.get(8..)?;
+
+    for _ in 0..size {
+      indices.push(__decode_u32(slice)?);
+      slice = slice.get(4..)?;
+    }
+
+    let size: usize = __decode_u32(slice)?;
+    let mut nodes: Vec<Hash<D>> = Vec::with_capacity(size);
+    let mut slice: &[u8] = slice.get(4..)?;
+
+    for _ in 0..size {
+      nodes.push(__decode_hash(slice)?);
+      slice = slice.get(D::OUTPUT_SIZE..)?;
+    }
+
+    Some(Self::new(leaves, indices.into_boxed_slice(), nodes.into_boxed_slice()))
+  }
+}
+
 // LEN + (NODES * (TAG + HASH))
 #[inline]
 fn __proof_len<D>(nodes: usize) -> usize
@@ -123,6 +193,22 @@ where
   4 + (nodes * (1 + D::OUTPUT_SIZE))
 }
 
+// LEAVES + LEN + (INDICES * U32) + LEN + (NODES * HASH)
+#[inline]
+fn __multi_proof_len<D>(indices: usize, nodes: usize) -> usize
+where
+  D: DigestExt,
+{
+  4 + 4 + (indices * 4) + 4 + (nodes * D::OUTPUT_SIZE)
+}
+
+#[inline]
+fn __decode_u32(slice: &[u8]) -> Option<usize> {
+  let value: [u8; 4] = slice.get(0..4)?.try_into().ok()?;
+
+  u32::from_be_bytes(value).try_into().ok()
+}
+
 #[inline]
 fn __decode_hash<D>(slice: &[u8]) -> Option<Hash<D>>
 where
@@ -7,24 +7,36 @@ mod key;
 mod proof;
 mod signature;
 
+pub mod bbs;
 pub mod merkle_key;
 pub mod merkle_tree;
 
 pub use self::key::KeyCollection;
+pub use self::key::KeyHandle;
 pub use self::key::KeyPair;
 pub use self::key::KeyRef;
 pub use self::key::KeyType;
 pub use self::key::PublicKey;
 pub use self::key::SecretKey;
+pub use self::proof::EcdsaSecp256k1;
+pub use self::proof::Ed25519Signature2018;
 pub use self::proof::JcsEd25519;
+pub use self::proof::JsonWebSignature2020;
 pub use self::signature::Ed25519;
+pub use self::signature::HandleSigner;
+pub use self::signature::JwsAlgorithm;
 pub use self::signature::Named;
+pub use self::signature::ProofPurpose;
+pub use self::signature::Secp256k1;
 pub use self::signature::SetSignature;
 pub use self::signature::Sign;
 pub use self::signature::Signature;
+pub use self::signature::SignatureOptions;
+pub use self::signature::SignatureSet;
 pub use self::signature::SignatureValue;
 pub use self::signature::Signer;
 pub use self::signature::TrySignature;
 pub use self::signature::TrySignatureMut;
 pub use self::signature::Verifier;
 pub use self::signature::Verify;
+pub use self::signature::P256;
@@ -0,0 +1,200 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Helpers for Linked Data Proofs encoded as a detached JWS with an unencoded
+//! payload ([RFC 7797][SPEC]).
+//!
+//! [SPEC]: https://tools.ietf.org/html/rfc7797
+
+use crypto::hashes::sha::Sha256;
+use crypto::hashes::Digest;
+use serde::Serialize;
+
+use crate::common::Object;
+use crate::common::Value;
+use crate::convert::FromJson;
+use crate::convert::ToJson;
+use crate::crypto::SignatureValue;
+use crate::error::Error;
+use crate::error::Result;
+use crate::jsonld::normalize;
+use crate::jsonld::undefined_terms;
+use crate::jsonld::BundledLoader;
+
+/// Implements a [Linked Data Proof][SPEC] signature suite that signs the
+/// URDNA2015-canonicalized document and proof options as a detached JWS.
+///
+/// The JWS algorithm is taken from the [`JwsAlgorithm`][`crate::crypto::JwsAlgorithm`]
+/// implementation of the underlying signature algorithm.
+///
+/// [SPEC]: https://w3c-ccg.github.io/ld-proofs/
+macro_rules! impl_detached_jws {
+  ($ident:ident, $name:expr) => {
+    impl<T> Named for $ident<T> {
+      const NAME: &'static str = $name;
+    }
+
+    impl<T> SignatureSuite for $ident<T>
+    where
+      T: JwsAlgorithm,
+    {
+      fn signing_input<X>(data: &X) -> Result<Vec<u8>>
+      where
+        X: Serialize,
+      {
+        detached_jws::signing_input(&detached_jws::encode_header(T::ALG), data)
+      }
+
+      fn encode_signature(signature: &[u8]) -> SignatureValue {
+        detached_jws::encode_signature(&detached_jws::encode_header(T::ALG), signature)
+      }
+    }
+
+    impl<T> Signer<T::Secret> for $ident<T>
+    where
+      T: Sign + JwsAlgorithm,
+      T::Output: AsRef<[u8]>,
+    {
+      fn sign<X>(data: &X, secret: &T::Secret) -> Result<SignatureValue>
+      where
+        X: Serialize,
+      {
+        let message: Vec<u8> = Self::signing_input(data)?;
+        let signature: T::Output = T::sign(&message, secret)?;
+
+        Ok(Self::encode_signature(signature.as_ref()))
+      }
+    }
+
+    impl<T> Verifier<T::Public> for $ident<T>
+    where
+      T: Verify + JwsAlgorithm,
+    {
+      fn verify<X>(data: &X, signature: &SignatureValue, public: &T::Public) -> Result<()>
+      where
+        X: Serialize,
+      {
+        let (header, signature): (&str, Vec<u8>) = detached_jws::decode_signature(signature, T::ALG)?;
+        let message: Vec<u8> = detached_jws::signing_input(header, data)?;
+
+        T::verify(&message, &signature, public)?;
+
+        Ok(())
+      }
+    }
+  };
+}
+
+/// Returns the base64url-encoded JWS header
+/// `{"alg":<alg>,"b64":false,"crit":["b64"]}`.
+pub(crate) fn encode_header(alg: &str) -> String {
+  let header: String = format!(r#"{{"alg":"{}","b64":false,"crit":["b64"]}}"#, alg);
+
+  base64::encode_config(header.as_bytes(), base64::URL_SAFE_NO_PAD)
+}
+
+/// Returns the JWS signing input of the given `data`: the encoded header
+/// followed by the SHA-256 hashes of the canonical proof options and the
+/// canonical document.
+///
+/// The document and the proof options are canonicalized with URDNA2015. Terms
+/// not defined by the JSON-LD context would be silently dropped from the
+/// canonical form, so they are rejected instead.
+pub(crate) fn signing_input<X>(header: &str, data: &X) -> Result<Vec<u8>>
+where
+  X: Serialize,
+{
+  let mut document: Object = Object::from_json_value(data.to_json_value()?)?;
+
+  let mut proof: Object = match document.remove("proof") {
+    // The last proof of a chain is signed, preceding proofs are part of the document
+    Some(Value::Array(mut proofs)) => {
+      let proof: Value = proofs.pop().ok_or(Error::MissingSignature)?;
+
+      if !proofs.is_empty() {
+        document.insert("proof".into(), Value::Array(proofs));
+      }
+
+      Object::from_json_value(proof)?
+    }
+    Some(proof) => Object::from_json_value(proof)?,
+    None => return Err(Error::MissingSignature),
+  };
+
+  proof.remove("jws");
+  proof.remove("proofValue");
+  proof.remove("signatureValue");
+
+  if let Some(context) = document.get("@context") {
+    proof.insert("@context".into(), context.clone());
+  }
+
+  let proof: Value = proof.to_json_value()?;
+  let document: Value = document.to_json_value()?;
+
+  if !undefined_terms(&proof, &BundledLoader)?.is_empty() || !undefined_terms(&document, &BundledLoader)?.is_empty() {
+    return Err(Error::InvalidJsonLd("undefined term"));
+  }
+
+  let proof: String = normalize(&proof, &BundledLoader)?;
+  let document: String = normalize(&document, &BundledLoader)?;
+
+  let mut input: Vec<u8> = format!("{}.", header).into_bytes();
+
+  input.extend_from_slice(&Sha256::digest(proof.as_bytes()));
+  input.extend_from_slice(&Sha256::digest(document.as_bytes()));
+
+  Ok(input)
+}
+
+/// Encodes the raw `signature` bytes as a detached JWS.
+pub(crate) fn encode_signature(header: &str, signature: &[u8]) -> SignatureValue {
+  let signature: String = base64::encode_config(signature, base64::URL_SAFE_NO_PAD);
+
+  SignatureValue::Jws(format!("{}..{}", header, signature))
+}
+
+/// Decodes a detached JWS and returns the encoded header and the raw signature
+/// bytes.
+///
+/// Fails if the header does not specify the algorithm `alg` with an unencoded
+/// payload.
+pub(crate) fn decode_signature<'a>(signature: &'a SignatureValue, alg: &str) -> Result<(&'a str, Vec<u8>)> {
+  let jws: &str = signature.as_jws().ok_or(Error::InvalidProofValue("jws"))?;
+
+  let (header, signature): (&str, &str) = match jws.split('.').collect::<Vec<_>>().as_slice() {
+    [header, "", signature] => (header, signature),
+    _ => return Err(Error::InvalidProofValue("detached jws")),
+  };
+
+  let decoded: Vec<u8> = base64::decode_config(header, base64::URL_SAFE_NO_PAD)?;
+  let decoded: Object = Object::from_json_slice(&decoded)?;
+
+  if decoded.get("alg") != Some(&Value::from(alg))
+    || decoded.get("b64") != Some(&Value::Bool(false))
+    || decoded.get("crit") != Some(&Value::from(vec!["b64"]))
+  {
+    return Err(Error::InvalidProofValue("detached jws header"));
+  }
+
+  let signature: Vec<u8> = base64::decode_config(signature, base64::URL_SAFE_NO_PAD)?;
+
+  Ok((header, signature))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::encode_header;
+
+  #[test]
+  fn test_encode_header() {
+    assert_eq!(
+      encode_header("EdDSA"),
+      "eyJhbGciOiJFZERTQSIsImI2NCI6ZmFsc2UsImNyaXQiOlsiYjY0Il19"
+    );
+    assert_eq!(
+      encode_header("ES256K"),
+      "eyJhbGciOiJFUzI1NksiLCJiNjQiOmZhbHNlLCJjcml0IjpbImI2NCJdfQ"
+    );
+  }
+}
@@ -0,0 +1,111 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::marker::PhantomData;
+use serde::Serialize;
+
+use crate::crypto::proof::detached_jws;
+use crate::crypto::JwsAlgorithm;
+use crate::crypto::Named;
+use crate::crypto::Secp256k1;
+use crate::crypto::Sign;
+use crate::crypto::SignatureSuite;
+use crate::crypto::SignatureValue;
+use crate::crypto::Signer;
+use crate::crypto::Verifier;
+use crate::crypto::Verify;
+use crate::error::Result;
+
+/// An implementation of the [ECDSA secp256k1 Signature 2019][SPEC1] signature
+/// suite for [Linked Data Proofs][SPEC2].
+///
+/// The document and the proof options are canonicalized with
+/// [URDNA2015][SPEC3] and signed as a detached JWS using the `ES256K`
+/// algorithm.
+///
+/// Users should use the [`Sign`]/[`Verify`] traits to access
+/// this implementation.
+///
+/// [SPEC1]: https://w3c-ccg.github.io/lds-ecdsa-secp256k1-2019/
+/// [SPEC2]: https://w3c-ccg.github.io/ld-proofs/
+/// [SPEC3]: https://json-ld.github.io/rdf-dataset-canonicalization/spec/
+pub struct EcdsaSecp256k1<T = Secp256k1>(PhantomData<T>);
+
+impl_detached_jws!(EcdsaSecp256k1, "EcdsaSecp256k1Signature2019");
+
+#[cfg(test)]
+mod tests {
+  use crate::common::Value;
+  use crate::crypto::EcdsaSecp256k1;
+  use crate::crypto::KeyPair;
+  use crate::crypto::PublicKey;
+  use crate::crypto::Secp256k1;
+  use crate::crypto::SecretKey;
+  use crate::crypto::SignatureValue;
+  use crate::crypto::Signer as _;
+  use crate::crypto::Verifier as _;
+  use crate::json;
+
+  type Signer = EcdsaSecp256k1<Secp256k1<SecretKey>>;
+
+  type Verifier = EcdsaSecp256k1<Secp256k1<PublicKey>>;
+
+  fn credential() -> Value {
+    json!({
+      "@context": "https://www.w3.org/2018/credentials/v1",
+      "id": "http://example.edu/credentials/3732",
+      "type": ["VerifiableCredential"],
+      "issuer": "did:example:issuer",
+      "issuanceDate": "2010-01-01T19:23:24Z",
+      "credentialSubject": { "id": "did:example:subject" },
+      "proof": {
+        "type": "EcdsaSecp256k1Signature2019",
+        "verificationMethod": "did:example:issuer#key-1",
+      },
+    })
+  }
+
+  #[test]
+  fn test_sign_verify() {
+    let key1: KeyPair = KeyPair::new_secp256k1().unwrap();
+    let key2: KeyPair = KeyPair::new_secp256k1().unwrap();
+
+    let data: Value = credential();
+    let signature: SignatureValue = Signer::sign(&data, key1.secret()).unwrap();
+
+    // The signature is a detached JWS with the `ES256K` algorithm
+    assert!(signature
+      .as_jws()
+      .unwrap()
+      .starts_with("eyJhbGciOiJFUzI1NksiLCJiNjQiOmZhbHNlLCJjcml0IjpbImI2NCJdfQ.."));
+
+    // The signature should be valid
+    assert!(Verifier::verify(&data, &signature, key1.public()).is_ok());
+
+    // A modified key should be invalid
+    assert!(Verifier::verify(&data, &signature, key2.public()).is_err());
+
+    // Modified data should be invalid
+    let mut modified: Value = data.clone();
+    modified["issuer"] = json!("did:example:mallory");
+    assert!(Verifier::verify(&modified, &signature, key1.public()).is_err());
+
+    // A mutated signature should be invalid
+    let signature: SignatureValue = SignatureValue::Jws("IOTA".into());
+    assert!(Verifier::verify(&data, &signature, key1.public()).is_err());
+  }
+
+  #[test]
+  fn test_undefined_terms() {
+    let key: KeyPair = KeyPair::new_secp256k1().unwrap();
+
+    // Claims that are not covered by the canonical form can not be signed
+    let mut data: Value = credential();
+    data["unknown"] = json!("IOTA Identity");
+    assert!(Signer::sign(&data, key.secret()).is_err());
+
+    // Documents without a JSON-LD context can not be signed
+    let data: Value = json!({ "msg": "IOTA Identity", "proof": { "type": "EcdsaSecp256k1Signature2019" } });
+    assert!(Signer::sign(&data, key.secret()).is_err());
+  }
+}
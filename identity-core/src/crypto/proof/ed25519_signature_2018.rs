@@ -0,0 +1,162 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::marker::PhantomData;
+use serde::Serialize;
+
+use crate::crypto::proof::detached_jws;
+use crate::crypto::Ed25519;
+use crate::crypto::JwsAlgorithm;
+use crate::crypto::Named;
+use crate::crypto::Sign;
+use crate::crypto::SignatureSuite;
+use crate::crypto::SignatureValue;
+use crate::crypto::Signer;
+use crate::crypto::Verifier;
+use crate::crypto::Verify;
+use crate::error::Result;
+
+/// An implementation of the [Ed25519 Signature 2018][SPEC1] signature suite
+/// for [Linked Data Proofs][SPEC2].
+///
+/// The document and the proof options are canonicalized with
+/// [URDNA2015][SPEC3] and signed as a detached JWS. JSON-LD contexts are
+/// resolved with the [`BundledLoader`][`crate::jsonld::BundledLoader`] so the
+/// network is never accessed.
+///
+/// Users should use the [`Sign`]/[`Verify`] traits to access
+/// this implementation.
+///
+/// [SPEC1]: https://w3c-ccg.github.io/lds-ed25519-2018/
+/// [SPEC2]: https://w3c-ccg.github.io/ld-proofs/
+/// [SPEC3]: https://json-ld.github.io/rdf-dataset-canonicalization/spec/
+pub struct Ed25519Signature2018<T = Ed25519>(PhantomData<T>);
+
+impl_detached_jws!(Ed25519Signature2018, "Ed25519Signature2018");
+
+#[cfg(test)]
+mod tests {
+  use crate::common::Object;
+  use crate::common::Value;
+  use crate::convert::FromJson;
+  use crate::crypto::Ed25519;
+  use crate::crypto::Ed25519Signature2018;
+  use crate::crypto::KeyPair;
+  use crate::crypto::PublicKey;
+  use crate::crypto::SecretKey;
+  use crate::crypto::SignatureValue;
+  use crate::crypto::Signer as _;
+  use crate::crypto::Verifier as _;
+  use crate::json;
+  use crate::jsonld::normalize;
+  use crate::jsonld::BundledLoader;
+  use crate::utils::decode_b16;
+
+  type Signer = Ed25519Signature2018<Ed25519<SecretKey>>;
+
+  type Verifier = Ed25519Signature2018<Ed25519<PublicKey>>;
+
+  // https://tools.ietf.org/html/rfc8032#section-7.1 (TEST 1)
+  const PUBLIC: &str = "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a";
+  const SECRET: &str = "9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60";
+
+  // The canonical proof options and document of `fixture()`
+  const PROOF_NQUADS: &str = r#"_:c14n0 <http://purl.org/dc/terms/created> "2021-01-01T00:00:00Z"^^<http://www.w3.org/2001/XMLSchema#dateTime> .
+_:c14n0 <http://www.w3.org/1999/02/22-rdf-syntax-ns#type> <https://w3id.org/security#Ed25519Signature2018> .
+_:c14n0 <https://w3id.org/security#proofPurpose> <https://w3id.org/security#assertionMethod> .
+_:c14n0 <https://w3id.org/security#verificationMethod> <did:example:issuer#key-1> .
+"#;
+
+  const DOCUMENT_NQUADS: &str = r#"<http://example.edu/credentials/3732> <http://www.w3.org/1999/02/22-rdf-syntax-ns#type> <https://www.w3.org/2018/credentials#VerifiableCredential> .
+<http://example.edu/credentials/3732> <https://www.w3.org/2018/credentials#credentialSubject> <did:example:subject> .
+<http://example.edu/credentials/3732> <https://www.w3.org/2018/credentials#issuanceDate> "2010-01-01T19:23:24Z"^^<http://www.w3.org/2001/XMLSchema#dateTime> .
+<http://example.edu/credentials/3732> <https://www.w3.org/2018/credentials#issuer> <did:example:issuer> .
+"#;
+
+  // The EdDSA signature of `header || "." || SHA-256(PROOF_NQUADS) || SHA-256(DOCUMENT_NQUADS)`
+  // created with the RFC 8032 key by an independent Ed25519 implementation
+  const JWS: &str = "eyJhbGciOiJFZERTQSIsImI2NCI6ZmFsc2UsImNyaXQiOlsiYjY0Il19..1Pgt_Bh5WFiWkZUWLc04Fero7-RRa-w3wkSms1aPUHWsuKZ-GdPolf8PpTlpRrK9xCzDo1RErV_rmnKv72RKBQ";
+
+  fn credential() -> Value {
+    json!({
+      "@context": "https://www.w3.org/2018/credentials/v1",
+      "id": "http://example.edu/credentials/3732",
+      "type": ["VerifiableCredential"],
+      "issuer": "did:example:issuer",
+      "issuanceDate": "2010-01-01T19:23:24Z",
+      "credentialSubject": { "id": "did:example:subject" },
+      "proof": {
+        "type": "Ed25519Signature2018",
+        "verificationMethod": "did:example:issuer#key-1",
+      },
+    })
+  }
+
+  fn fixture() -> Value {
+    let mut data: Value = credential();
+
+    data["proof"]["created"] = json!("2021-01-01T00:00:00Z");
+    data["proof"]["proofPurpose"] = json!("assertionMethod");
+    data
+  }
+
+  #[test]
+  fn test_canonical_fixture() {
+    let mut document: Value = fixture();
+    let mut proof: Value = document["proof"].take();
+
+    document.as_object_mut().unwrap().remove("proof");
+    proof["@context"] = document["@context"].clone();
+
+    assert_eq!(normalize(&proof, &BundledLoader).unwrap(), PROOF_NQUADS);
+    assert_eq!(normalize(&document, &BundledLoader).unwrap(), DOCUMENT_NQUADS);
+  }
+
+  #[test]
+  fn test_sign_fixture() {
+    let public: PublicKey = decode_b16(PUBLIC).unwrap().into();
+    let secret: SecretKey = decode_b16(SECRET).unwrap().into();
+
+    let signature: SignatureValue = Signer::sign(&fixture(), &secret).unwrap();
+
+    assert_eq!(signature.as_jws().unwrap(), JWS);
+
+    let signature: SignatureValue = SignatureValue::Jws(JWS.into());
+
+    assert!(Verifier::verify(&fixture(), &signature, &public).is_ok());
+  }
+
+  #[test]
+  fn test_sign_verify() {
+    let key1: KeyPair = KeyPair::new_ed25519().unwrap();
+    let key2: KeyPair = KeyPair::new_ed25519().unwrap();
+
+    let data: Value = credential();
+    let signature: SignatureValue = Signer::sign(&data, key1.secret()).unwrap();
+
+    // The signature should be valid
+    assert!(Verifier::verify(&data, &signature, key1.public()).is_ok());
+
+    // A modified key should be invalid
+    assert!(Verifier::verify(&data, &signature, key2.public()).is_err());
+
+    // Modified data should be invalid
+    let mut modified: Value = data.clone();
+    modified["issuer"] = json!("did:example:mallory");
+    assert!(Verifier::verify(&modified, &signature, key1.public()).is_err());
+
+    // Modified proof options should be invalid
+    let mut modified: Value = data.clone();
+    modified["proof"]["verificationMethod"] = json!("did:example:mallory#key-1");
+    assert!(Verifier::verify(&modified, &signature, key1.public()).is_err());
+
+    // Changes that do not affect the canonical form are still valid
+    let mut reordered: Object = Object::from_json_value(data.clone()).unwrap();
+    reordered.insert("type".into(), json!("VerifiableCredential"));
+    assert!(Verifier::verify(&reordered, &signature, key1.public()).is_ok());
+
+    // A mutated signature should be invalid
+    let signature: SignatureValue = SignatureValue::Jws("IOTA".into());
+    assert!(Verifier::verify(&data, &signature, key1.public()).is_err());
+  }
+}
@@ -8,6 +8,7 @@ use crate::convert::ToJson;
 use crate::crypto::Ed25519;
 use crate::crypto::Named;
 use crate::crypto::Sign;
+use crate::crypto::SignatureSuite;
 use crate::crypto::SignatureValue;
 use crate::crypto::Signer;
 use crate::crypto::Verifier;
@@ -33,6 +34,19 @@ impl<T> Named for JcsEd25519<T> {
   const NAME: &'static str = "JcsEd25519Signature2020";
 }
 
+impl<T> SignatureSuite for JcsEd25519<T> {
+  fn signing_input<X>(data: &X) -> Result<Vec<u8>>
+  where
+    X: Serialize,
+  {
+    data.to_jcs()
+  }
+
+  fn encode_signature(signature: &[u8]) -> SignatureValue {
+    SignatureValue::Signature(encode_b58(signature))
+  }
+}
+
 impl<T> Signer<T::Secret> for JcsEd25519<T>
 where
   T: Sign,
@@ -42,11 +56,10 @@ where
   where
     X: Serialize,
   {
-    let message: Vec<u8> = data.to_jcs()?;
+    let message: Vec<u8> = Self::signing_input(data)?;
     let signature: T::Output = T::sign(&message, secret)?;
-    let signature: String = encode_b58(signature.as_ref());
 
-    Ok(SignatureValue::Signature(signature))
+    Ok(Self::encode_signature(signature.as_ref()))
   }
 }
 
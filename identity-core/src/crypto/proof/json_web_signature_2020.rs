@@ -0,0 +1,138 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::marker::PhantomData;
+use serde::Serialize;
+
+use crate::crypto::proof::detached_jws;
+use crate::crypto::Ed25519;
+use crate::crypto::JwsAlgorithm;
+use crate::crypto::Named;
+use crate::crypto::Sign;
+use crate::crypto::SignatureSuite;
+use crate::crypto::SignatureValue;
+use crate::crypto::Signer;
+use crate::crypto::Verifier;
+use crate::crypto::Verify;
+use crate::error::Result;
+
+/// An implementation of the [JSON Web Signature 2020][SPEC1] signature suite
+/// for [Linked Data Proofs][SPEC2].
+///
+/// The document and the proof options are canonicalized with
+/// [URDNA2015][SPEC3] and signed as a detached JWS. The JWS algorithm is
+/// determined by the signature algorithm `T`: `EdDSA` for [`Ed25519`],
+/// `ES256K` for [`Secp256k1`][`crate::crypto::Secp256k1`] and `ES256` for
+/// [`P256`][`crate::crypto::P256`].
+///
+/// Users should use the [`Sign`]/[`Verify`] traits to access
+/// this implementation.
+///
+/// [SPEC1]: https://w3c-ccg.github.io/lds-jws2020/
+/// [SPEC2]: https://w3c-ccg.github.io/ld-proofs/
+/// [SPEC3]: https://json-ld.github.io/rdf-dataset-canonicalization/spec/
+pub struct JsonWebSignature2020<T = Ed25519>(PhantomData<T>);
+
+impl_detached_jws!(JsonWebSignature2020, "JsonWebSignature2020");
+
+#[cfg(test)]
+mod tests {
+  use crate::common::Value;
+  use crate::crypto::Ed25519;
+  use crate::crypto::JsonWebSignature2020;
+  use crate::crypto::KeyPair;
+  use crate::crypto::PublicKey;
+  use crate::crypto::Secp256k1;
+  use crate::crypto::SecretKey;
+  use crate::crypto::SignatureValue;
+  use crate::crypto::Signer;
+  use crate::crypto::Verifier;
+  use crate::crypto::P256;
+  use crate::json;
+
+  fn credential() -> Value {
+    json!({
+      "@context": [
+        "https://www.w3.org/2018/credentials/v1",
+        "https://w3id.org/security/suites/jws-2020/v1",
+      ],
+      "id": "http://example.edu/credentials/3732",
+      "type": ["VerifiableCredential"],
+      "issuer": "did:example:issuer",
+      "issuanceDate": "2010-01-01T19:23:24Z",
+      "credentialSubject": { "id": "did:example:subject" },
+      "proof": {
+        "type": "JsonWebSignature2020",
+        "created": "2021-01-01T00:00:00Z",
+        "verificationMethod": "did:example:issuer#key-1",
+        "proofPurpose": "assertionMethod",
+      },
+    })
+  }
+
+  fn sign_verify<S, V>(key1: &KeyPair, key2: &KeyPair, header: &str)
+  where
+    S: Signer<SecretKey>,
+    V: Verifier<PublicKey>,
+  {
+    let data: Value = credential();
+    let signature: SignatureValue = S::sign(&data, key1.secret()).unwrap();
+
+    // The signature is a detached JWS with the algorithm of the key
+    assert!(signature.as_jws().unwrap().starts_with(header));
+
+    // The signature should be valid
+    assert!(V::verify(&data, &signature, key1.public()).is_ok());
+
+    // A modified key should be invalid
+    assert!(V::verify(&data, &signature, key2.public()).is_err());
+
+    // Modified data should be invalid
+    let mut modified: Value = data.clone();
+    modified["issuer"] = json!("did:example:mallory");
+    assert!(V::verify(&modified, &signature, key1.public()).is_err());
+
+    // Modified proof options should be invalid
+    let mut modified: Value = data;
+    modified["proof"]["proofPurpose"] = json!("authentication");
+    assert!(V::verify(&modified, &signature, key1.public()).is_err());
+  }
+
+  #[test]
+  fn test_sign_verify_ed25519() {
+    sign_verify::<JsonWebSignature2020<Ed25519<SecretKey>>, JsonWebSignature2020<Ed25519<PublicKey>>>(
+      &KeyPair::new_ed25519().unwrap(),
+      &KeyPair::new_ed25519().unwrap(),
+      "eyJhbGciOiJFZERTQSIsImI2NCI6ZmFsc2UsImNyaXQiOlsiYjY0Il19..",
+    );
+  }
+
+  #[test]
+  fn test_sign_verify_secp256k1() {
+    sign_verify::<JsonWebSignature2020<Secp256k1<SecretKey>>, JsonWebSignature2020<Secp256k1<PublicKey>>>(
+      &KeyPair::new_secp256k1().unwrap(),
+      &KeyPair::new_secp256k1().unwrap(),
+      "eyJhbGciOiJFUzI1NksiLCJiNjQiOmZhbHNlLCJjcml0IjpbImI2NCJdfQ..",
+    );
+  }
+
+  #[test]
+  fn test_sign_verify_p256() {
+    sign_verify::<JsonWebSignature2020<P256<SecretKey>>, JsonWebSignature2020<P256<PublicKey>>>(
+      &KeyPair::new_p256().unwrap(),
+      &KeyPair::new_p256().unwrap(),
+      "eyJhbGciOiJFUzI1NiIsImI2NCI6ZmFsc2UsImNyaXQiOlsiYjY0Il19..",
+    );
+  }
+
+  #[test]
+  fn test_algorithm_mismatch() {
+    let key: KeyPair = KeyPair::new_ed25519().unwrap();
+    let data: Value = credential();
+
+    let signature: SignatureValue = JsonWebSignature2020::<Ed25519<SecretKey>>::sign(&data, key.secret()).unwrap();
+
+    // The JWS header must match the algorithm of the verification key
+    assert!(JsonWebSignature2020::<P256<PublicKey>>::verify(&data, &signature, key.public()).is_err());
+  }
+}
@@ -4,6 +4,15 @@
 //! Types and traits for helping ensure the authenticity and integrity of
 //! DID Documents and Verifiable Credentials.
 
+#[macro_use]
+mod detached_jws;
+
+mod ecdsa_secp256k1;
+mod ed25519_signature_2018;
 mod jcs_ed25519;
+mod json_web_signature_2020;
 
+pub use self::ecdsa_secp256k1::EcdsaSecp256k1;
+pub use self::ed25519_signature_2018::Ed25519Signature2018;
 pub use self::jcs_ed25519::JcsEd25519;
+pub use self::json_web_signature_2020::JsonWebSignature2020;
@@ -0,0 +1,72 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+/// Implements `ECDSA` signatures over the curve of the given `iota-crypto`
+/// signature module.
+///
+/// Public keys may be encoded as compressed or uncompressed SEC1 points, or as
+/// the concatenated `x` and `y` coordinates of the curve point.
+macro_rules! impl_ecdsa {
+  ($ident:ident, $module:ident, $key_type:expr, $alg:expr, $doc:expr) => {
+    /// The length of an encoded secret key.
+    const SECRET_KEY_LENGTH: usize = 32;
+
+    #[derive(Clone, Copy, Debug)]
+    #[doc = $doc]
+    ///
+    /// Public keys may be encoded as compressed or uncompressed SEC1 points, or
+    /// as the concatenated `x` and `y` coordinates of the curve point.
+    pub struct $ident<T: ?Sized = [u8]>(PhantomData<T>);
+
+    impl<T> Sign for $ident<T>
+    where
+      T: AsRef<[u8]> + ?Sized,
+    {
+      type Secret = T;
+      type Output = Vec<u8>;
+
+      fn sign(message: &[u8], key: &Self::Secret) -> Result<Self::Output> {
+        let key: $module::SecretKey = parse_secret(key.as_ref())?;
+        let sig: _ = key.sign(message)?;
+
+        Ok(sig.as_ref().to_vec())
+      }
+    }
+
+    impl<T> Verify for $ident<T>
+    where
+      T: AsRef<[u8]> + ?Sized,
+    {
+      type Public = T;
+
+      fn verify(message: &[u8], signature: &[u8], key: &Self::Public) -> Result<()> {
+        let key: $module::PublicKey = parse_public(key.as_ref())?;
+
+        key
+          .verify(message, signature)
+          .map_err(|_| Error::InvalidProofValue(stringify!($module)))
+      }
+    }
+
+    impl<T> JwsAlgorithm for $ident<T>
+    where
+      T: ?Sized,
+    {
+      const ALG: &'static str = $alg;
+    }
+
+    fn parse_public(slice: &[u8]) -> Result<$module::PublicKey> {
+      let public: Vec<u8> = decode_ec_public($key_type, slice)?;
+
+      $module::PublicKey::from_bytes(&public).map_err(|_| Error::InvalidKeyFormat)
+    }
+
+    fn parse_secret(slice: &[u8]) -> Result<$module::SecretKey> {
+      if slice.len() != SECRET_KEY_LENGTH {
+        return Err(Error::InvalidKeyLength(slice.len(), SECRET_KEY_LENGTH));
+      }
+
+      $module::SecretKey::from_bytes(slice).map_err(|_| Error::InvalidKeyFormat)
+    }
+  };
+}
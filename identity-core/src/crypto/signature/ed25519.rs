@@ -8,6 +8,7 @@ use crypto::signatures::ed25519::COMPRESSED_PUBLIC_KEY_LENGTH as PUBLIC_KEY_LENG
 use crypto::signatures::ed25519::SECRET_KEY_LENGTH;
 use crypto::signatures::ed25519::SIGNATURE_LENGTH;
 
+use crate::crypto::JwsAlgorithm;
 use crate::crypto::Sign;
 use crate::crypto::Verify;
 use crate::error::Error;
@@ -47,6 +48,13 @@ where
   }
 }
 
+impl<T> JwsAlgorithm for Ed25519<T>
+where
+  T: ?Sized,
+{
+  const ALG: &'static str = "EdDSA";
+}
+
 fn parse_public(slice: &[u8]) -> Result<ed25519::PublicKey> {
   let bytes: [u8; PUBLIC_KEY_LENGTH] = slice
     .get(..PUBLIC_KEY_LENGTH)
@@ -0,0 +1,105 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::marker::PhantomData;
+use serde::Serialize;
+
+use crate::crypto::signature::traits::chain_options;
+use crate::crypto::KeyHandle;
+use crate::crypto::SetSignature;
+use crate::crypto::Signature;
+use crate::crypto::SignatureOptions;
+use crate::crypto::SignatureSuite;
+use crate::crypto::SignatureValue;
+use crate::error::Result;
+
+/// Creates signatures for a [`SignatureSuite`] with a [`KeyHandle`] instead
+/// of a raw secret key.
+///
+/// This is the asynchronous counterpart of [`Signer`][`crate::crypto::Signer`].
+pub struct HandleSigner<S>(PhantomData<S>);
+
+impl<S> HandleSigner<S>
+where
+  S: SignatureSuite,
+{
+  /// Signs the given `data` with `handle` and returns a digital signature.
+  pub async fn sign<T, H>(data: &T, handle: &H) -> Result<SignatureValue>
+  where
+    T: Serialize,
+    H: KeyHandle + ?Sized,
+  {
+    let message: Vec<u8> = S::signing_input(data)?;
+    let signature: Vec<u8> = handle.sign(&message).await?;
+
+    Ok(S::encode_signature(&signature))
+  }
+
+  /// Creates and applies a [signature][`Signature`] to the given `data`.
+  pub async fn create_signature<T, H>(data: &mut T, method: &str, handle: &H, options: SignatureOptions) -> Result<()>
+  where
+    T: Serialize + SetSignature,
+    H: KeyHandle + ?Sized,
+  {
+    data.set_signature(Signature::new_with_options(S::NAME, method, options));
+
+    let value: SignatureValue = Self::sign(&*data, handle).await?;
+
+    data.try_signature_mut()?.set_value(value);
+
+    Ok(())
+  }
+
+  /// Creates a [signature][`Signature`] and adds it to the proof set of the
+  /// given `data`.
+  ///
+  /// The new signature does not cover any existing signatures.
+  pub async fn append_signature<T, H>(data: &mut T, method: &str, handle: &H, options: SignatureOptions) -> Result<()>
+  where
+    T: Serialize + SetSignature,
+    H: KeyHandle + ?Sized,
+  {
+    let signature: Signature = Signature::new_with_options(S::NAME, method, options);
+
+    Self::extend_signature(data, signature, handle).await
+  }
+
+  /// Creates a [signature][`Signature`] and adds it to the proof chain of the
+  /// given `data`.
+  ///
+  /// The new signature references the last existing signature with its
+  /// `previousProof` property and covers it.
+  ///
+  /// # Errors
+  ///
+  /// Fails if the last existing signature has no `id`.
+  pub async fn chain_signature<T, H>(data: &mut T, method: &str, handle: &H, options: SignatureOptions) -> Result<()>
+  where
+    T: Serialize + SetSignature,
+    H: KeyHandle + ?Sized,
+  {
+    let options: SignatureOptions = chain_options(data, options)?;
+    let signature: Signature = Signature::new_with_options(S::NAME, method, options);
+
+    Self::extend_signature(data, signature, handle).await
+  }
+
+  async fn extend_signature<T, H>(data: &mut T, signature: Signature, handle: &H) -> Result<()>
+  where
+    T: Serialize + SetSignature,
+    H: KeyHandle + ?Sized,
+  {
+    data.push_signature(signature);
+    data.focus_signature(data.signatures().len().checked_sub(1));
+
+    let message: Result<Vec<u8>> = S::signing_input(&*data);
+
+    data.focus_signature(None);
+
+    let signature: Vec<u8> = handle.sign(&message?).await?;
+
+    data.try_signature_mut()?.set_value(S::encode_signature(&signature));
+
+    Ok(())
+  }
+}
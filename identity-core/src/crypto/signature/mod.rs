@@ -3,17 +3,34 @@
 
 #![allow(clippy::module_inception)]
 
+#[macro_use]
+mod ecdsa;
+
 mod ed25519;
+mod handle_signer;
+mod p256;
+mod proof_purpose;
+mod secp256k1;
 mod signature;
+mod signature_options;
+mod signature_set;
 mod signature_value;
 mod traits;
 
 pub use self::ed25519::Ed25519;
+pub use self::handle_signer::HandleSigner;
+pub use self::p256::P256;
+pub use self::proof_purpose::ProofPurpose;
+pub use self::secp256k1::Secp256k1;
 pub use self::signature::Signature;
+pub use self::signature_options::SignatureOptions;
+pub use self::signature_set::SignatureSet;
 pub use self::signature_value::SignatureValue;
+pub use self::traits::JwsAlgorithm;
 pub use self::traits::Named;
 pub use self::traits::SetSignature;
 pub use self::traits::Sign;
+pub use self::traits::SignatureSuite;
 pub use self::traits::Signer;
 pub use self::traits::TrySignature;
 pub use self::traits::TrySignatureMut;
@@ -0,0 +1,56 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::marker::PhantomData;
+use crypto::signatures::p256;
+
+use crate::crypto::JwsAlgorithm;
+use crate::crypto::KeyType;
+use crate::crypto::Sign;
+use crate::crypto::Verify;
+use crate::error::Error;
+use crate::error::Result;
+use crate::utils::decode_ec_public;
+
+impl_ecdsa!(
+  P256,
+  p256,
+  KeyType::P256,
+  "ES256",
+  "An implementation of `ECDSA` signatures over the `P-256` curve."
+);
+
+#[cfg(test)]
+mod tests {
+  use crate::crypto::KeyPair;
+  use crate::crypto::KeyType;
+  use crate::crypto::Sign;
+  use crate::crypto::Verify;
+  use crate::crypto::P256;
+  use crate::utils::decode_ec_public;
+
+  #[test]
+  fn test_p256_can_sign_and_verify() {
+    let keypair: KeyPair = KeyPair::new_p256().unwrap();
+    let badpair: KeyPair = KeyPair::new_p256().unwrap();
+
+    let signature: _ = P256::sign(b"hello", keypair.secret()).unwrap();
+
+    assert!(P256::verify(b"hello", &signature, keypair.public()).is_ok());
+    assert!(P256::verify(b"world", &signature, keypair.public()).is_err());
+    assert!(P256::verify(b"hello", &signature, badpair.public()).is_err());
+  }
+
+  #[test]
+  fn test_p256_public_key_encodings() {
+    let keypair: KeyPair = KeyPair::new_p256().unwrap();
+    let signature: _ = P256::sign(b"hello", keypair.secret()).unwrap();
+
+    // Generated keys are compressed SEC1 points
+    assert_eq!(keypair.public().as_ref().len(), 33);
+
+    let coordinates: Vec<u8> = decode_ec_public(KeyType::P256, keypair.public().as_ref()).unwrap();
+
+    assert!(P256::verify(b"hello", &signature, &coordinates[..]).is_ok());
+  }
+}
@@ -0,0 +1,44 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::fmt::Result as FmtResult;
+
+/// The intended use of a digital signature, expressed by the `proofPurpose`
+/// property.
+///
+/// [More Info](https://w3c-ccg.github.io/ld-proofs/#proof-purpose)
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ProofPurpose {
+  /// The signature asserts a claim, e.g. the issuance of a credential.
+  AssertionMethod,
+  /// The signature proves control of the DID, e.g. in a presentation.
+  Authentication,
+  /// The signature is used in a key agreement protocol.
+  KeyAgreement,
+  /// The signature delegates a capability.
+  CapabilityDelegation,
+  /// The signature invokes a capability.
+  CapabilityInvocation,
+}
+
+impl ProofPurpose {
+  /// Returns the `proofPurpose` value as a string slice.
+  pub const fn as_str(&self) -> &'static str {
+    match self {
+      Self::AssertionMethod => "assertionMethod",
+      Self::Authentication => "authentication",
+      Self::KeyAgreement => "keyAgreement",
+      Self::CapabilityDelegation => "capabilityDelegation",
+      Self::CapabilityInvocation => "capabilityInvocation",
+    }
+  }
+}
+
+impl Display for ProofPurpose {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    f.write_str(self.as_str())
+  }
+}
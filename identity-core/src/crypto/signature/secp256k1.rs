@@ -0,0 +1,59 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::marker::PhantomData;
+use crypto::signatures::secp256k1;
+
+use crate::crypto::JwsAlgorithm;
+use crate::crypto::KeyType;
+use crate::crypto::Sign;
+use crate::crypto::Verify;
+use crate::error::Error;
+use crate::error::Result;
+use crate::utils::decode_ec_public;
+
+impl_ecdsa!(
+  Secp256k1,
+  secp256k1,
+  KeyType::Secp256k1,
+  "ES256K",
+  "An implementation of `ECDSA` signatures over the `secp256k1` curve."
+);
+
+#[cfg(test)]
+mod tests {
+  use crate::crypto::KeyPair;
+  use crate::crypto::KeyType;
+  use crate::crypto::Secp256k1;
+  use crate::crypto::Sign;
+  use crate::crypto::Verify;
+  use crate::utils::decode_ec_public;
+
+  #[test]
+  fn test_secp256k1_can_sign_and_verify() {
+    let keypair: KeyPair = KeyPair::new_secp256k1().unwrap();
+    let badpair: KeyPair = KeyPair::new_secp256k1().unwrap();
+
+    let signature: _ = Secp256k1::sign(b"hello", keypair.secret()).unwrap();
+
+    assert!(Secp256k1::verify(b"hello", &signature, keypair.public()).is_ok());
+    assert!(Secp256k1::verify(b"world", &signature, keypair.public()).is_err());
+    assert!(Secp256k1::verify(b"hello", &signature, badpair.public()).is_err());
+  }
+
+  #[test]
+  fn test_secp256k1_public_key_encodings() {
+    let keypair: KeyPair = KeyPair::new_secp256k1().unwrap();
+    let signature: _ = Secp256k1::sign(b"hello", keypair.secret()).unwrap();
+
+    // Generated keys are compressed SEC1 points
+    assert_eq!(keypair.public().as_ref().len(), 33);
+
+    let coordinates: Vec<u8> = decode_ec_public(KeyType::Secp256k1, keypair.public().as_ref()).unwrap();
+    let uncompressed: Vec<u8> = [&[0x04][..], &coordinates[..]].concat();
+
+    assert!(Secp256k1::verify(b"hello", &signature, &coordinates[..]).is_ok());
+    assert!(Secp256k1::verify(b"hello", &signature, &uncompressed[..]).is_ok());
+    assert!(Secp256k1::verify(b"hello", &signature, &uncompressed[1..33]).is_err());
+  }
+}
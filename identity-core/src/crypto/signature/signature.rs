@@ -10,6 +10,7 @@ use serde::ser::SerializeMap;
 use serde::ser::Serializer;
 use serde::Serialize;
 
+use crate::crypto::SignatureOptions;
 use crate::crypto::SignatureValue;
 use crate::error::Result;
 
@@ -22,18 +23,30 @@ pub struct Signature {
   value: SignatureValue,
   #[serde(rename = "verificationMethod")]
   method: String,
+  #[serde(flatten)]
+  options: SignatureOptions,
   #[serde(default, skip_deserializing)]
   hidden: Cell<bool>,
+  #[serde(default, skip_deserializing)]
+  excluded: Cell<bool>,
 }
 
 impl Signature {
   /// Creates a new [`Signature`] instance with the given `type_` and `method`.
   pub fn new(type_: impl Into<String>, method: impl Into<String>) -> Self {
+    Self::new_with_options(type_, method, SignatureOptions::new())
+  }
+
+  /// Creates a new [`Signature`] instance with the given `type_`, `method`,
+  /// and proof `options`.
+  pub fn new_with_options(type_: impl Into<String>, method: impl Into<String>, options: SignatureOptions) -> Self {
     Self {
       type_: type_.into(),
       value: SignatureValue::None,
       method: method.into(),
+      options,
       hidden: Cell::new(false),
+      excluded: Cell::new(false),
     }
   }
 
@@ -47,6 +60,16 @@ impl Signature {
     &*self.method
   }
 
+  /// Returns a reference to the proof options of the signature.
+  pub const fn options(&self) -> &SignatureOptions {
+    &self.options
+  }
+
+  /// Returns a mutable reference to the proof options of the signature.
+  pub fn options_mut(&mut self) -> &mut SignatureOptions {
+    &mut self.options
+  }
+
   /// Returns a reference to the signature `value`.
   pub const fn value(&self) -> &SignatureValue {
     &self.value
@@ -77,6 +100,18 @@ impl Signature {
     self.hidden.set(false);
   }
 
+  /// Returns `true` if the signature is excluded from serialization because
+  /// another signature of its proof set is focused.
+  ///
+  /// See [`TrySignature::focus_signature`][crate::crypto::TrySignature::focus_signature].
+  pub fn is_excluded(&self) -> bool {
+    self.excluded.get()
+  }
+
+  pub(crate) fn set_excluded(&self, value: bool) {
+    self.excluded.set(value);
+  }
+
   fn __hide(&self) -> bool {
     self.hidden.get() || self.value.is_none()
   }
@@ -88,6 +123,7 @@ impl Debug for Signature {
       .field("type_", &self.type_)
       .field("value", &self.value)
       .field("method", &self.method)
+      .field("options", &self.options)
       .finish()
   }
 }
@@ -108,6 +144,8 @@ impl Serialize for Signature {
     state.serialize_entry("type", &self.type_)?;
     state.serialize_entry("verificationMethod", &self.method)?;
 
+    Serialize::serialize(&self.options, FlatMapSerializer(&mut state))?;
+
     if !hide {
       Serialize::serialize(&self.value, FlatMapSerializer(&mut state))?;
     }
@@ -0,0 +1,101 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::common::Timestamp;
+use crate::crypto::ProofPurpose;
+
+/// Optional properties of a [`Signature`][crate::crypto::Signature] that are
+/// covered by the signature value.
+///
+/// [More Info](https://w3c-ccg.github.io/ld-proofs/#proof-options)
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Deserialize, Serialize)]
+pub struct SignatureOptions {
+  /// An identifier of the signature, referenced by chained signatures.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub id: Option<String>,
+  /// The intended use of the signature.
+  #[serde(rename = "proofPurpose", skip_serializing_if = "Option::is_none")]
+  pub purpose: Option<ProofPurpose>,
+  /// The time the signature was created.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub created: Option<Timestamp>,
+  /// The time after which the signature is no longer valid.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub expires: Option<Timestamp>,
+  /// The operational domain the signature is restricted to.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub domain: Option<String>,
+  /// A verifier-provided value used to prevent replay attacks.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub challenge: Option<String>,
+  /// A signer-provided random value.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub nonce: Option<String>,
+  /// The identifier of the signature this signature is chained to.
+  #[serde(rename = "previousProof", skip_serializing_if = "Option::is_none")]
+  pub previous_proof: Option<String>,
+}
+
+impl SignatureOptions {
+  /// Creates a new empty [`SignatureOptions`] object.
+  pub const fn new() -> Self {
+    Self {
+      id: None,
+      purpose: None,
+      created: None,
+      expires: None,
+      domain: None,
+      challenge: None,
+      nonce: None,
+      previous_proof: None,
+    }
+  }
+
+  /// Sets the value of the `id` property.
+  pub fn id(mut self, value: impl Into<String>) -> Self {
+    self.id = Some(value.into());
+    self
+  }
+
+  /// Sets the value of the `proofPurpose` property.
+  pub fn purpose(mut self, value: ProofPurpose) -> Self {
+    self.purpose = Some(value);
+    self
+  }
+
+  /// Sets the value of the `created` property.
+  pub fn created(mut self, value: Timestamp) -> Self {
+    self.created = Some(value);
+    self
+  }
+
+  /// Sets the value of the `expires` property.
+  pub fn expires(mut self, value: Timestamp) -> Self {
+    self.expires = Some(value);
+    self
+  }
+
+  /// Sets the value of the `domain` property.
+  pub fn domain(mut self, value: impl Into<String>) -> Self {
+    self.domain = Some(value.into());
+    self
+  }
+
+  /// Sets the value of the `challenge` property.
+  pub fn challenge(mut self, value: impl Into<String>) -> Self {
+    self.challenge = Some(value.into());
+    self
+  }
+
+  /// Sets the value of the `nonce` property.
+  pub fn nonce(mut self, value: impl Into<String>) -> Self {
+    self.nonce = Some(value.into());
+    self
+  }
+
+  /// Sets the value of the `previousProof` property.
+  pub fn previous_proof(mut self, value: impl Into<String>) -> Self {
+    self.previous_proof = Some(value.into());
+    self
+  }
+}
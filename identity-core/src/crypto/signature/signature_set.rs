@@ -0,0 +1,216 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::fmt::Debug;
+use core::fmt::Formatter;
+use core::fmt::Result as FmtResult;
+use core::ops::Deref;
+use serde::Deserialize;
+use serde::Deserializer;
+use serde::Serialize;
+use serde::Serializer;
+
+use crate::common::OneOrMany;
+use crate::crypto::Signature;
+use crate::crypto::TrySignature;
+
+/// An ordered collection of [signatures][`Signature`] forming a proof set or
+/// a proof chain.
+///
+/// Signatures in a proof set are independent of each other and only cover the
+/// signed document. Signatures with a `previousProof` property form a proof
+/// chain and additionally cover the signature they reference.
+///
+/// A set with a single signature is serialized as a JSON object, larger sets
+/// are serialized as a JSON array.
+#[derive(Clone, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct SignatureSet {
+  signatures: Vec<Signature>,
+}
+
+impl SignatureSet {
+  /// Creates a new empty [`SignatureSet`].
+  pub const fn new() -> Self {
+    Self { signatures: Vec::new() }
+  }
+
+  /// Returns the number of signatures in the set.
+  pub fn len(&self) -> usize {
+    self.signatures.len()
+  }
+
+  /// Returns `true` if the set contains no signatures.
+  pub fn is_empty(&self) -> bool {
+    self.signatures.is_empty()
+  }
+
+  /// Returns the signatures of the set as a slice.
+  pub fn as_slice(&self) -> &[Signature] {
+    &self.signatures
+  }
+
+  /// Returns the focused signature, or the last signature of the set.
+  pub fn current(&self) -> Option<&Signature> {
+    self.signatures.iter().rev().find(|signature| !signature.is_excluded())
+  }
+
+  /// Returns a mutable reference to the focused signature, or the last
+  /// signature of the set.
+  pub fn current_mut(&mut self) -> Option<&mut Signature> {
+    self.signatures.iter_mut().rev().find(|signature| !signature.is_excluded())
+  }
+
+  /// Replaces the contents of the set with a single `signature`.
+  pub fn set(&mut self, signature: Signature) {
+    self.signatures.clear();
+    self.signatures.push(signature);
+  }
+
+  /// Adds a new `signature` to the end of the set.
+  pub fn push(&mut self, signature: Signature) {
+    self.signatures.push(signature);
+  }
+
+  /// Removes all signatures from the set.
+  pub fn clear(&mut self) {
+    self.signatures.clear();
+  }
+}
+
+impl Debug for SignatureSet {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    f.debug_list().entries(self.signatures.iter()).finish()
+  }
+}
+
+impl Deref for SignatureSet {
+  type Target = [Signature];
+
+  fn deref(&self) -> &Self::Target {
+    &self.signatures
+  }
+}
+
+impl From<Signature> for SignatureSet {
+  fn from(other: Signature) -> Self {
+    Self::from(vec![other])
+  }
+}
+
+impl From<Vec<Signature>> for SignatureSet {
+  fn from(other: Vec<Signature>) -> Self {
+    Self { signatures: other }
+  }
+}
+
+impl From<SignatureSet> for Vec<Signature> {
+  fn from(other: SignatureSet) -> Self {
+    other.signatures
+  }
+}
+
+impl Serialize for SignatureSet {
+  fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: Serializer,
+  {
+    let visible: Vec<&Signature> = self.signatures.iter().filter(|signature| !signature.is_excluded()).collect();
+
+    match visible.as_slice() {
+      [signature] => signature.serialize(serializer),
+      signatures => signatures.serialize(serializer),
+    }
+  }
+}
+
+impl TrySignature for SignatureSet {
+  fn signature(&self) -> Option<&Signature> {
+    self.current()
+  }
+
+  fn signatures(&self) -> Vec<&Signature> {
+    self.signatures.iter().collect()
+  }
+}
+
+impl<'de> Deserialize<'de> for SignatureSet {
+  fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+  where
+    D: Deserializer<'de>,
+  {
+    OneOrMany::deserialize(deserializer)
+      .map(OneOrMany::into_vec)
+      .map(Into::into)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::common::Value;
+  use crate::convert::FromJson;
+  use crate::convert::ToJson;
+  use crate::crypto::Signature;
+  use crate::crypto::SignatureOptions;
+  use crate::crypto::SignatureSet;
+  use crate::crypto::TrySignature;
+  use crate::json;
+
+  fn signature(method: &str, options: SignatureOptions) -> Signature {
+    Signature::new_with_options("JcsEd25519Signature2020", method, options)
+  }
+
+  #[test]
+  fn test_serde_one_or_many() {
+    let mut set: SignatureSet = SignatureSet::new();
+
+    set.push(signature("#key-1", SignatureOptions::new().id("urn:proof:1")));
+
+    assert_eq!(
+      set.to_json_value().unwrap(),
+      json!({ "type": "JcsEd25519Signature2020", "verificationMethod": "#key-1", "id": "urn:proof:1" })
+    );
+
+    set.push(signature("#key-2", SignatureOptions::new().previous_proof("urn:proof:1")));
+
+    let json: Value = set.to_json_value().unwrap();
+
+    assert_eq!(
+      json,
+      json!([
+        { "type": "JcsEd25519Signature2020", "verificationMethod": "#key-1", "id": "urn:proof:1" },
+        { "type": "JcsEd25519Signature2020", "verificationMethod": "#key-2", "previousProof": "urn:proof:1" },
+      ])
+    );
+
+    assert_eq!(SignatureSet::from_json_value(json).unwrap(), set);
+  }
+
+  #[test]
+  fn test_focus() {
+    let set: SignatureSet = vec![
+      signature("#key-1", SignatureOptions::new().id("urn:proof:1")),
+      signature("#key-2", SignatureOptions::new().id("urn:proof:2")),
+      signature("#key-3", SignatureOptions::new().previous_proof("urn:proof:1")),
+      signature("#key-4", SignatureOptions::new().previous_proof("urn:proof:5")),
+    ]
+    .into();
+
+    assert!(set.focus_signature(Some(1)));
+    assert_eq!(set.current().unwrap().verification_method(), "#key-2");
+    assert_eq!(set.to_json_value().unwrap()["verificationMethod"], "#key-2");
+
+    // A chained signature covers the signature it references
+    assert!(set.focus_signature(Some(2)));
+    assert_eq!(set.current().unwrap().verification_method(), "#key-3");
+    assert_eq!(set.to_json_value().unwrap()[0]["id"], "urn:proof:1");
+    assert_eq!(set.to_json_value().unwrap().as_array().unwrap().len(), 2);
+
+    // The referenced signature does not exist
+    assert!(!set.focus_signature(Some(3)));
+    assert!(!set.focus_signature(Some(4)));
+
+    assert!(set.focus_signature(None));
+    assert_eq!(set.current().unwrap().verification_method(), "#key-4");
+    assert_eq!(set.to_json_value().unwrap().as_array().unwrap().len(), 4);
+  }
+}
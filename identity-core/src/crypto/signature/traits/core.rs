@@ -5,6 +5,7 @@ use serde::Serialize;
 
 use crate::crypto::SetSignature;
 use crate::crypto::Signature;
+use crate::crypto::SignatureOptions;
 use crate::crypto::SignatureValue;
 use crate::crypto::TrySignature;
 use crate::error::Error;
@@ -46,6 +47,36 @@ pub trait Named {
 // =============================================================================
 // =============================================================================
 
+/// A common interface for signature algorithms with a registered [JWS][SPEC]
+/// algorithm name.
+///
+/// [SPEC]: https://www.iana.org/assignments/jose/jose.xhtml#web-signature-encryption-algorithms
+pub trait JwsAlgorithm {
+  /// The `alg` header parameter of JWS signatures created by this algorithm.
+  const ALG: &'static str;
+}
+
+// =============================================================================
+// =============================================================================
+
+/// A common interface for signature suites with a message encoding that is
+/// independent of the underlying signature algorithm.
+///
+/// This allows signatures to be created by a [`KeyHandle`][`crate::crypto::KeyHandle`]
+/// without access to the secret key.
+pub trait SignatureSuite: Named {
+  /// Returns the message that is signed for the given `data`.
+  fn signing_input<T>(data: &T) -> Result<Vec<u8>>
+  where
+    T: Serialize;
+
+  /// Encodes the raw `signature` bytes as a [`SignatureValue`].
+  fn encode_signature(signature: &[u8]) -> SignatureValue;
+}
+
+// =============================================================================
+// =============================================================================
+
 /// A common interface for digital signature creation.
 pub trait Signer<Secret: ?Sized>: Named {
   /// Signs the given `data` and returns a digital signature.
@@ -58,7 +89,21 @@ pub trait Signer<Secret: ?Sized>: Named {
   where
     T: Serialize + SetSignature,
   {
-    data.set_signature(Signature::new(Self::NAME, method));
+    Self::create_signature_with_options(data, method, secret, SignatureOptions::new())
+  }
+
+  /// Creates and applies a [signature][`Signature`] with the given proof
+  /// `options` to the given `data`.
+  fn create_signature_with_options<T>(
+    data: &mut T,
+    method: &str,
+    secret: &Secret,
+    options: SignatureOptions,
+  ) -> Result<()>
+  where
+    T: Serialize + SetSignature,
+  {
+    data.set_signature(Signature::new_with_options(Self::NAME, method, options));
 
     let value: SignatureValue = Self::sign(&data, secret)?;
     let write: &mut Signature = data.try_signature_mut()?;
@@ -67,6 +112,69 @@ pub trait Signer<Secret: ?Sized>: Named {
 
     Ok(())
   }
+
+  /// Creates a [signature][`Signature`] and adds it to the proof set of the
+  /// given `data`.
+  ///
+  /// The new signature does not cover any existing signatures.
+  fn append_signature<T>(data: &mut T, method: &str, secret: &Secret, options: SignatureOptions) -> Result<()>
+  where
+    T: Serialize + SetSignature,
+  {
+    let signature: Signature = Signature::new_with_options(Self::NAME, method, options);
+
+    extend_signature::<Self, T, Secret>(data, signature, secret)
+  }
+
+  /// Creates a [signature][`Signature`] and adds it to the proof chain of the
+  /// given `data`.
+  ///
+  /// The new signature references the last existing signature with its
+  /// `previousProof` property and covers it.
+  ///
+  /// # Errors
+  ///
+  /// Fails if the last existing signature has no `id`.
+  fn chain_signature<T>(data: &mut T, method: &str, secret: &Secret, options: SignatureOptions) -> Result<()>
+  where
+    T: Serialize + SetSignature,
+  {
+    let options: SignatureOptions = chain_options(data, options)?;
+    let signature: Signature = Signature::new_with_options(Self::NAME, method, options);
+
+    extend_signature::<Self, T, Secret>(data, signature, secret)
+  }
+}
+
+/// Returns the given proof `options` chained to the last signature of `data`.
+pub(crate) fn chain_options<T>(data: &T, options: SignatureOptions) -> Result<SignatureOptions>
+where
+  T: TrySignature,
+{
+  data
+    .signatures()
+    .last()
+    .and_then(|signature| signature.options().id.clone())
+    .map(|id| options.previous_proof(id))
+    .ok_or(Error::InvalidProofChain)
+}
+
+fn extend_signature<S, T, Secret>(data: &mut T, signature: Signature, secret: &Secret) -> Result<()>
+where
+  S: Signer<Secret> + ?Sized,
+  T: Serialize + SetSignature,
+  Secret: ?Sized,
+{
+  data.push_signature(signature);
+  data.focus_signature(data.signatures().len().checked_sub(1));
+
+  let value: Result<SignatureValue> = S::sign(&data, secret);
+
+  data.focus_signature(None);
+
+  data.try_signature_mut()?.set_value(value?);
+
+  Ok(())
 }
 
 // =============================================================================
@@ -92,10 +200,27 @@ pub trait Verifier<Public: ?Sized>: Named {
 
     signature.hide_value();
 
-    Self::verify(&data, signature.value(), public)?;
+    let result: Result<()> = Self::verify(&data, signature.value(), public);
 
     signature.show_value();
 
-    Ok(())
+    result
+  }
+
+  /// Extracts and verifies the [signature][`Signature`] at `index` of the
+  /// proof set of the given `data`.
+  fn verify_signature_at<T>(data: &T, index: usize, public: &Public) -> Result<()>
+  where
+    T: Serialize + TrySignature,
+  {
+    if !data.focus_signature(Some(index)) {
+      return Err(Error::MissingSignature);
+    }
+
+    let result: Result<()> = Self::verify_signature(data, public);
+
+    data.focus_signature(None);
+
+    result
   }
 }
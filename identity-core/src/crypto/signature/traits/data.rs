@@ -18,6 +18,57 @@ pub trait TrySignature {
   fn try_signature(&self) -> Result<&Signature> {
     self.signature().ok_or(Error::MissingSignature)
   }
+
+  /// Returns every [`Signature`] object of the proof set, in order.
+  ///
+  /// Types that hold a single signature return at most one element.
+  fn signatures(&self) -> Vec<&Signature> {
+    self.signature().into_iter().collect()
+  }
+
+  /// Focuses the [`Signature`] at `index` of the proof set; passing `None`
+  /// removes the focus.
+  ///
+  /// While focused, every signature not covered by the selected signature -
+  /// that is, every signature except the selected one and the signature
+  /// referenced by its `previousProof` - is
+  /// [excluded][Signature::is_excluded] from serialization, and
+  /// [`signature`][TrySignature::signature] is expected to return the last
+  /// signature that is not excluded.
+  ///
+  /// Returns `false` if no signature exists at `index` or the signature
+  /// references a previous proof that does not precede it.
+  fn focus_signature(&self, index: Option<usize>) -> bool {
+    let signatures: Vec<&Signature> = self.signatures();
+
+    let index: usize = match index {
+      Some(index) => index,
+      None => {
+        for signature in signatures {
+          signature.set_excluded(false);
+        }
+
+        return true;
+      }
+    };
+
+    let previous: Option<&str> = match signatures.get(index) {
+      Some(signature) => signature.options().previous_proof.as_deref(),
+      None => return false,
+    };
+
+    let is_previous = |signature: &Signature| previous.is_some() && signature.options().id.as_deref() == previous;
+
+    if previous.is_some() && !signatures[..index].iter().any(|signature| is_previous(*signature)) {
+      return false;
+    }
+
+    for (position, signature) in signatures.iter().enumerate() {
+      signature.set_excluded(position != index && !(position < index && is_previous(*signature)));
+    }
+
+    true
+  }
 }
 
 impl<'a, T> TrySignature for &'a T
@@ -27,6 +78,10 @@ where
   fn signature(&self) -> Option<&Signature> {
     (**self).signature()
   }
+
+  fn signatures(&self) -> Vec<&Signature> {
+    (**self).signatures()
+  }
 }
 
 impl<'a, T> TrySignature for &'a mut T
@@ -36,6 +91,10 @@ where
   fn signature(&self) -> Option<&Signature> {
     (**self).signature()
   }
+
+  fn signatures(&self) -> Vec<&Signature> {
+    (**self).signatures()
+  }
 }
 
 // =============================================================================
@@ -70,8 +129,15 @@ where
 
 /// A trait for types that can store a digital [signature][`Signature`].
 pub trait SetSignature: TrySignatureMut {
-  /// Sets the [`Signature`] object of `self`.
+  /// Sets the [`Signature`] object of `self`, replacing any existing signatures.
   fn set_signature(&mut self, signature: Signature);
+
+  /// Adds a [`Signature`] object to the end of the proof set of `self`.
+  ///
+  /// Types that hold a single signature replace the existing one.
+  fn push_signature(&mut self, signature: Signature) {
+    self.set_signature(signature);
+  }
 }
 
 impl<'a, T> SetSignature for &'a mut T
@@ -81,4 +147,8 @@ where
   fn set_signature(&mut self, signature: Signature) {
     (**self).set_signature(signature);
   }
+
+  fn push_signature(&mut self, signature: Signature) {
+    (**self).push_signature(signature);
+  }
 }
@@ -4,8 +4,11 @@
 mod core;
 mod data;
 
+pub(crate) use self::core::chain_options;
+pub use self::core::JwsAlgorithm;
 pub use self::core::Named;
 pub use self::core::Sign;
+pub use self::core::SignatureSuite;
 pub use self::core::Signer;
 pub use self::core::Verifier;
 pub use self::core::Verify;
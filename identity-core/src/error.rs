@@ -44,6 +44,9 @@ pub enum Error {
   /// Caused by attempting to parse an invalid `Timestamp`.
   #[error("Invalid Timestamp: {0}")]
   InvalidTimestamp(#[from] chrono::ParseError),
+  /// Caused by attempting to create a `Timestamp` from an out-of-range Unix timestamp.
+  #[error("Invalid Unix Timestamp: {0}")]
+  InvalidUnixTimestamp(i64),
   /// Raised by a validation attempt against an invalid DID proof.
   #[error("Invalid Proof Value: {0}")]
   InvalidProofValue(&'static str),
@@ -65,9 +68,29 @@ pub enum Error {
   /// Caused by a failed attempt at retrieving a digital signature.
   #[error("Signature Not Found")]
   MissingSignature,
+  /// Caused by chaining a signature to a signature without an identifier.
+  #[error("Invalid Proof Chain")]
+  InvalidProofChain,
   /// Caused by attempting to create a KeyCollection of invalid size.
   #[error("Invalid Key Collection Size: {0}")]
   InvalidKeyCollectionSize(usize),
+  /// Caused by attempting to process an invalid JSON-LD document.
+  #[error("Invalid JSON-LD: {0}")]
+  InvalidJsonLd(&'static str),
+  /// Caused by attempting to parse an invalid N-Quads document.
+  #[error("Invalid N-Quads: {0}")]
+  InvalidNQuads(&'static str),
+  /// Caused by a failure to load a JSON-LD context.
+  #[error("Failed to load JSON-LD context: {0}")]
+  LoadContext(String),
+  /// Caused by an RDF dataset that exceeds the resource limits of
+  /// canonicalization.
+  #[error("Canonicalization Limit Exceeded")]
+  CanonicalizationLimit,
+  /// Caused by a failure to access the key referenced by a
+  /// [`KeyHandle`][crate::crypto::KeyHandle].
+  #[error("Key Handle Error: {0}")]
+  KeyHandle(String),
 }
 
 impl From<crypto::Error> for Error {
@@ -0,0 +1,888 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+
+use crate::common::Value;
+use crate::error::Result;
+use crate::jsonld::context::ActiveContext;
+use crate::jsonld::context::TermDefinition;
+use crate::jsonld::expand;
+use crate::jsonld::expansion::is_graph;
+use crate::jsonld::expansion::is_list;
+use crate::jsonld::expansion::is_value;
+use crate::jsonld::DocumentLoader;
+
+type Map = serde_json::Map<String, Value>;
+
+/// Compacts the JSON-LD `document` with the given `context`, replacing
+/// absolute IRIs with the terms and compact IRIs it defines.
+///
+/// The document is expanded first, so terms of the input contexts are
+/// resolved before compaction. Remote contexts are retrieved with the given
+/// `loader`.
+///
+/// See the [JSON-LD 1.1 Compaction Algorithm][SPEC] for details. Compaction of
+/// `@nest` properties, base directions and relative IRIs is not supported.
+///
+/// # Errors
+///
+/// Fails if `document` or `context` is not valid JSON-LD or a context can not
+/// be loaded.
+///
+/// [SPEC]: https://www.w3.org/TR/json-ld11-api/#compaction-algorithm
+pub fn compact<L>(document: &Value, context: &Value, loader: &L) -> Result<Value>
+where
+  L: DocumentLoader + ?Sized,
+{
+  // A context document is accepted in place of its `@context` value.
+  let context: &Value = context.get("@context").unwrap_or(context);
+
+  let expanded: Value = expand(document, loader)?;
+  let active: ActiveContext = ActiveContext::default().process(loader, context, None, false, true)?;
+  let compacted: Value = Compactor { loader }.compact(&active, None, &expanded)?;
+
+  let mut output: Map = Map::new();
+
+  let empty: bool = match context {
+    Value::Null => true,
+    Value::Array(contexts) => contexts.is_empty(),
+    Value::Object(object) => object.is_empty(),
+    _ => false,
+  };
+
+  if !empty {
+    output.insert("@context".to_string(), context.clone());
+  }
+
+  match compacted {
+    Value::Array(items) if items.is_empty() => {}
+    Value::Array(items) => {
+      let inverse: InverseContext = InverseContext::new(&active);
+      output.insert(
+        compact_iri(&active, &inverse, "@graph", None, true, false),
+        Value::Array(items),
+      );
+    }
+    Value::Object(object) => output.extend(object),
+    _ => {}
+  }
+
+  Ok(Value::Object(output))
+}
+
+struct Compactor<'a, L: ?Sized> {
+  loader: &'a L,
+}
+
+impl<L> Compactor<'_, L>
+where
+  L: DocumentLoader + ?Sized,
+{
+  fn compact(&self, active: &ActiveContext, property: Option<&str>, element: &Value) -> Result<Value> {
+    match element {
+      Value::Array(items) => {
+        let mut output: Vec<Value> = Vec::with_capacity(items.len());
+
+        for item in items {
+          let compacted: Value = self.compact(active, property, item)?;
+
+          if !compacted.is_null() {
+            output.push(compacted);
+          }
+        }
+
+        let keep: bool = matches!(property, Some("@graph") | Some("@set"))
+          || property
+            .and_then(|property| active.term(property))
+            .map_or(false, |definition| {
+              definition.has_container("@list") || definition.has_container("@set")
+            });
+
+        if output.len() == 1 && !keep {
+          Ok(output.pop().unwrap())
+        } else {
+          Ok(Value::Array(output))
+        }
+      }
+      Value::Object(object) => self.compact_object(active, property, object),
+      scalar => Ok(scalar.clone()),
+    }
+  }
+
+  fn compact_object(&self, active: &ActiveContext, property: Option<&str>, object: &Map) -> Result<Value> {
+    let type_scoped: &ActiveContext = active;
+    let mut active: Cow<'_, ActiveContext> = Cow::Borrowed(active);
+
+    // Type-scoped contexts do not apply to nested node objects.
+    if let Some(previous) = active.previous() {
+      let retain: bool = object.contains_key("@value") || (object.len() == 1 && object.contains_key("@id"));
+
+      if !retain {
+        active = Cow::Owned(previous.clone());
+      }
+    }
+
+    let scoped: Option<&TermDefinition> = property.and_then(|property| type_scoped.term(property));
+
+    if let Some(definition) = scoped {
+      if let Some(context) = definition.context.as_ref() {
+        active = Cow::Owned(active.process(self.loader, context, definition.base_url.as_deref(), true, true)?);
+      }
+    }
+
+    if object.contains_key("@value") || object.contains_key("@id") {
+      let inverse: InverseContext = InverseContext::new(&active);
+
+      if let Some(value) = compact_value(&active, &inverse, property, object) {
+        return Ok(value);
+      }
+    }
+
+    if let (Some(list), true) = (
+      object.get("@list"),
+      property
+        .and_then(|property| active.term(property))
+        .map_or(false, |definition| definition.has_container("@list")),
+    ) {
+      return self.compact(&active, property, list);
+    }
+
+    let inside_reverse: bool = property == Some("@reverse");
+    let type_context: ActiveContext = active.clone().into_owned();
+    let type_inverse: InverseContext = InverseContext::new(&type_context);
+
+    if let Some(types) = object.get("@type") {
+      let mut terms: Vec<String> = types
+        .as_array()
+        .map(Vec::as_slice)
+        .unwrap_or_else(|| core::slice::from_ref(types))
+        .iter()
+        .filter_map(Value::as_str)
+        .map(|type_| compact_iri(&type_context, &type_inverse, type_, None, true, false))
+        .collect();
+
+      terms.sort_unstable();
+
+      for term in terms {
+        if let Some(definition) = type_context.term(&term) {
+          if let Some(context) = definition.context.as_ref() {
+            active = Cow::Owned(active.process(self.loader, context, definition.base_url.as_deref(), false, false)?);
+          }
+        }
+      }
+    }
+
+    let inverse: InverseContext = InverseContext::new(&active);
+    let alias = |keyword: &str| compact_iri(&active, &inverse, keyword, None, true, false);
+    let mut result: Map = Map::new();
+
+    for key in sorted_keys(object) {
+      let value: &Value = &object[key];
+
+      match key.as_str() {
+        "@id" => {
+          if let Value::String(id) = value {
+            result.insert(
+              alias("@id"),
+              Value::String(compact_iri(&active, &inverse, id, None, false, false)),
+            );
+          }
+
+          continue;
+        }
+        "@type" => {
+          let compact_type = |type_: &Value| {
+            type_
+              .as_str()
+              .map(|type_| compact_iri(&type_context, &type_inverse, type_, None, true, false))
+              .map_or(Value::Null, Value::String)
+          };
+
+          let type_alias: String = alias("@type");
+
+          let as_array: bool = active
+            .term(&type_alias)
+            .map_or(false, |definition| definition.has_container("@set"));
+
+          let compacted: Value = match value {
+            Value::Array(types) if types.len() == 1 && !as_array => compact_type(&types[0]),
+            Value::Array(types) => Value::Array(types.iter().map(compact_type).collect()),
+            type_ => compact_type(type_),
+          };
+
+          add_value(&mut result, &type_alias, compacted, as_array);
+          continue;
+        }
+        "@reverse" => {
+          if let Value::Object(mut compacted) = self.compact(&active, Some("@reverse"), value)? {
+            let reversed: Vec<String> = compacted
+              .keys()
+              .filter(|key| active.term(key).map_or(false, |definition| definition.reverse))
+              .cloned()
+              .collect();
+
+            for key in reversed {
+              let as_array: bool = active
+                .term(&key)
+                .map_or(false, |definition| definition.has_container("@set"));
+
+              if let Some(value) = compacted.remove(&key) {
+                add_value(&mut result, &key, value, as_array);
+              }
+            }
+
+            if !compacted.is_empty() {
+              result.insert(alias("@reverse"), Value::Object(compacted));
+            }
+          }
+
+          continue;
+        }
+        "@preserve" => continue,
+        "@index"
+          if property
+            .and_then(|property| active.term(property))
+            .map_or(false, |definition| definition.has_container("@index")) =>
+        {
+          continue;
+        }
+        "@direction" | "@index" | "@language" | "@value" => {
+          result.insert(alias(key.as_str()), value.clone());
+          continue;
+        }
+        _ => {}
+      }
+
+      let items: &[Value] = value.as_array().map(Vec::as_slice).unwrap_or_default();
+
+      if items.is_empty() {
+        let term: String = compact_iri(&active, &inverse, key, Some(value), true, inside_reverse);
+        add_value(&mut result, &term, Value::Array(Vec::new()), true);
+        continue;
+      }
+
+      for item in items {
+        let term: String = compact_iri(&active, &inverse, key, Some(item), true, inside_reverse);
+        let definition: Option<&TermDefinition> = active.term(&term);
+        let has_container =
+          |container: &str| definition.map_or(false, |definition| definition.has_container(container));
+        let as_array: bool = has_container("@set") || term == "@graph" || term == "@list";
+
+        let inner: &Value = match (item.get("@list"), item.get("@graph")) {
+          (Some(list), _) => list,
+          (None, Some(graph)) if is_graph(item) => graph,
+          _ => item,
+        };
+
+        let mut compacted: Value = self.compact(&active, Some(&term), inner)?;
+
+        if is_list(item) {
+          if !compacted.is_array() {
+            compacted = Value::Array(vec![compacted]);
+          }
+
+          if has_container("@list") {
+            result.insert(term, compacted);
+          } else {
+            let mut list: Map = Map::new();
+
+            list.insert(alias("@list"), compacted);
+
+            if let Some(index) = item.get("@index") {
+              list.insert(alias("@index"), index.clone());
+            }
+
+            add_value(&mut result, &term, Value::Object(list), as_array);
+          }
+        } else if is_graph(item) {
+          if has_container("@graph") && has_container("@id") {
+            let map_key: String = match item.get("@id").and_then(Value::as_str) {
+              Some(id) => compact_iri(&active, &inverse, id, None, false, false),
+              None => alias("@none"),
+            };
+
+            add_value(map_object(&mut result, &term), &map_key, compacted, as_array);
+          } else if has_container("@graph") && has_container("@index") && item.get("@id").is_none() {
+            let map_key: String = match item.get("@index").and_then(Value::as_str) {
+              Some(index) => index.to_string(),
+              None => alias("@none"),
+            };
+
+            add_value(map_object(&mut result, &term), &map_key, compacted, as_array);
+          } else if has_container("@graph") && item.get("@id").is_none() {
+            if compacted.as_array().map_or(false, |items| items.len() > 1) {
+              let mut included: Map = Map::new();
+              included.insert(alias("@included"), compacted);
+              compacted = Value::Object(included);
+            }
+
+            add_value(&mut result, &term, compacted, as_array);
+          } else {
+            let mut graph: Map = Map::new();
+
+            graph.insert(alias("@graph"), compacted);
+
+            if let Some(id) = item.get("@id").and_then(Value::as_str) {
+              graph.insert(
+                alias("@id"),
+                Value::String(compact_iri(&active, &inverse, id, None, false, false)),
+              );
+            }
+
+            if let Some(index) = item.get("@index") {
+              graph.insert(alias("@index"), index.clone());
+            }
+
+            add_value(&mut result, &term, Value::Object(graph), as_array);
+          }
+        } else if !has_container("@graph")
+          && (has_container("@language") || has_container("@index") || has_container("@id") || has_container("@type"))
+        {
+          let map_key: Option<String> = if has_container("@language") {
+            if let Some(value) = item.get("@value") {
+              compacted = value.clone();
+            }
+
+            item.get("@language").and_then(Value::as_str).map(ToString::to_string)
+          } else if has_container("@index") {
+            match definition.and_then(|definition| definition.index.as_deref()) {
+              Some(index) if index != "@index" => {
+                let property: String = compact_iri(&active, &inverse, index, None, true, false);
+                take_first(&mut compacted, &property)
+              }
+              _ => item.get("@index").and_then(Value::as_str).map(ToString::to_string),
+            }
+          } else if has_container("@id") {
+            take_first(&mut compacted, &alias("@id"))
+          } else {
+            let map_key: Option<String> = take_first(&mut compacted, &alias("@type"));
+
+            // Node references are compacted again to apply the term type mapping
+            if compacted
+              .as_object()
+              .map_or(false, |object| object.len() == 1 && object.contains_key(&alias("@id")))
+            {
+              if let Some(id) = item.get("@id") {
+                let mut reference: Map = Map::new();
+                reference.insert("@id".to_string(), id.clone());
+                compacted = self.compact(&active, Some(&term), &Value::Object(reference))?;
+              }
+            }
+
+            map_key
+          };
+
+          let map_key: String = map_key.unwrap_or_else(|| alias("@none"));
+
+          add_value(map_object(&mut result, &term), &map_key, compacted, as_array);
+        } else {
+          add_value(&mut result, &term, compacted, as_array);
+        }
+      }
+    }
+
+    Ok(Value::Object(result))
+  }
+}
+
+// =============================================================================
+// Inverse Context
+// =============================================================================
+
+/// The terms of a container mapping, selected by type or language.
+#[derive(Debug, Default)]
+struct Selection {
+  language: BTreeMap<String, String>,
+  type_: BTreeMap<String, String>,
+  any: BTreeMap<String, String>,
+}
+
+impl Selection {
+  fn get(&self, key: &str) -> &BTreeMap<String, String> {
+    match key {
+      "@language" => &self.language,
+      "@type" => &self.type_,
+      _ => &self.any,
+    }
+  }
+}
+
+/// A lookup table of the terms of an [`ActiveContext`] by IRI, container,
+/// type and language.
+///
+/// See [Inverse Context Creation][SPEC].
+///
+/// [SPEC]: https://www.w3.org/TR/json-ld11-api/#inverse-context-creation
+#[derive(Debug, Default)]
+struct InverseContext {
+  entries: HashMap<String, BTreeMap<String, Selection>>,
+}
+
+impl InverseContext {
+  fn new(active: &ActiveContext) -> Self {
+    let default_language: &str = active.language().unwrap_or("@none");
+
+    let mut terms: Vec<(&String, &TermDefinition)> = active.terms().collect();
+
+    // Shorter terms are preferred, ties are broken lexicographically
+    terms.sort_unstable_by(|(a, _), (b, _)| a.len().cmp(&b.len()).then_with(|| a.cmp(b)));
+
+    let mut entries: HashMap<String, BTreeMap<String, Selection>> = HashMap::new();
+
+    for (term, definition) in terms {
+      let iri: &str = match definition.iri.as_deref() {
+        Some(iri) => iri,
+        None => continue,
+      };
+
+      let mut container: Vec<&str> = definition.container.iter().map(String::as_str).collect();
+
+      container.sort_unstable();
+
+      let container: String = if container.is_empty() {
+        "@none".to_string()
+      } else {
+        container.concat()
+      };
+
+      let selection: &mut Selection = entries
+        .entry(iri.to_string())
+        .or_default()
+        .entry(container)
+        .or_default();
+
+      let insert = |map: &mut BTreeMap<String, String>, key: &str| {
+        map.entry(key.to_string()).or_insert_with(|| term.clone());
+      };
+
+      insert(&mut selection.any, "@none");
+
+      if definition.reverse {
+        insert(&mut selection.type_, "@reverse");
+        continue;
+      }
+
+      match (definition.type_.as_deref(), definition.language.as_ref()) {
+        (Some("@none"), _) => {
+          insert(&mut selection.language, "@any");
+          insert(&mut selection.type_, "@any");
+        }
+        (Some(type_), _) => {
+          insert(&mut selection.type_, type_);
+        }
+        (None, Some(language)) => {
+          insert(&mut selection.language, language.as_deref().unwrap_or("@null"));
+        }
+        (None, None) => {
+          insert(&mut selection.language, default_language);
+          insert(&mut selection.language, "@none");
+          insert(&mut selection.type_, "@none");
+        }
+      }
+    }
+
+    Self { entries }
+  }
+
+  /// Returns the term of `iri` with the first matching container, type or
+  /// language, and preferred value.
+  ///
+  /// See [Term Selection][SPEC].
+  ///
+  /// [SPEC]: https://www.w3.org/TR/json-ld11-api/#term-selection
+  fn select(&self, iri: &str, containers: &[&str], type_language: &str, preferred: &[String]) -> Option<String> {
+    let entry: &BTreeMap<String, Selection> = self.entries.get(iri)?;
+
+    containers
+      .iter()
+      .filter_map(|container| entry.get(*container))
+      .map(|selection| selection.get(type_language))
+      .find_map(|terms| preferred.iter().find_map(|value| terms.get(value)))
+      .cloned()
+  }
+}
+
+// =============================================================================
+// IRI Compaction
+// =============================================================================
+
+/// Compacts `iri` to a term, compact IRI or keyword alias.
+///
+/// See [IRI Compaction][SPEC].
+///
+/// [SPEC]: https://www.w3.org/TR/json-ld11-api/#iri-compaction
+fn compact_iri(
+  active: &ActiveContext,
+  inverse: &InverseContext,
+  iri: &str,
+  value: Option<&Value>,
+  vocab: bool,
+  reverse: bool,
+) -> String {
+  if vocab {
+    if let Some(term) = select_term(active, inverse, iri, value, reverse) {
+      return term;
+    }
+
+    if let Some(suffix) = active.vocab().and_then(|vocab| iri.strip_prefix(vocab)) {
+      if !suffix.is_empty() && active.term(suffix).is_none() {
+        return suffix.to_string();
+      }
+    }
+  }
+
+  let mut compact: Option<String> = None;
+
+  for (term, definition) in active.terms() {
+    let suffix: &str = match definition.iri.as_deref() {
+      Some(prefix) if definition.prefix && prefix != iri => match iri.strip_prefix(prefix) {
+        Some(suffix) => suffix,
+        None => continue,
+      },
+      _ => continue,
+    };
+
+    let candidate: String = format!("{}:{}", term, suffix);
+
+    let preferred: bool = compact.as_ref().map_or(true, |compact| {
+      candidate.len() < compact.len() || (candidate.len() == compact.len() && candidate < *compact)
+    });
+
+    let available: bool = match active.term(&candidate) {
+      Some(definition) => definition.iri.as_deref() == Some(iri) && value.is_none(),
+      None => true,
+    };
+
+    if preferred && available {
+      compact = Some(candidate);
+    }
+  }
+
+  compact.unwrap_or_else(|| iri.to_string())
+}
+
+fn select_term(
+  active: &ActiveContext,
+  inverse: &InverseContext,
+  iri: &str,
+  value: Option<&Value>,
+  reverse: bool,
+) -> Option<String> {
+  if !inverse.entries.contains_key(iri) {
+    return None;
+  }
+
+  let object: Option<&Map> = value.and_then(Value::as_object);
+  let has = |key: &str| object.map_or(false, |object| object.contains_key(key));
+  let graph: bool = value.map_or(false, is_graph);
+
+  let mut containers: Vec<&str> = Vec::new();
+  let mut type_language: &str = "@language";
+  let mut type_language_value: String = "@null".to_string();
+
+  if has("@index") && !graph {
+    containers.extend_from_slice(&["@index", "@index@set"]);
+  }
+
+  if reverse {
+    type_language = "@type";
+    type_language_value = "@reverse".to_string();
+    containers.push("@set");
+  } else if let Some(list) = object.and_then(|object| object.get("@list")) {
+    if !has("@index") {
+      containers.push("@list");
+    }
+
+    let items: &[Value] = list.as_array().map(Vec::as_slice).unwrap_or_default();
+    let default_language: &str = active.language().unwrap_or("@none");
+
+    let mut common_language: Option<&str> = if items.is_empty() { Some(default_language) } else { None };
+
+    let mut common_type: Option<&str> = None;
+
+    for item in items {
+      let (item_language, item_type): (&str, &str) = if is_value(item) {
+        match (item.get("@language"), item.get("@type")) {
+          (Some(language), _) => (language.as_str().unwrap_or("@none"), "@none"),
+          (None, Some(type_)) => ("@none", type_.as_str().unwrap_or("@none")),
+          (None, None) => ("@null", "@none"),
+        }
+      } else {
+        ("@none", "@id")
+      };
+
+      common_language = match common_language {
+        None => Some(item_language),
+        Some(language) if language != item_language && is_value(item) => Some("@none"),
+        language => language,
+      };
+
+      common_type = match common_type {
+        None => Some(item_type),
+        Some(type_) if type_ != item_type => Some("@none"),
+        type_ => type_,
+      };
+
+      if common_language == Some("@none") && common_type == Some("@none") {
+        break;
+      }
+    }
+
+    match common_type.unwrap_or("@none") {
+      "@none" => type_language_value = common_language.unwrap_or("@none").to_string(),
+      type_ => {
+        type_language = "@type";
+        type_language_value = type_.to_string();
+      }
+    }
+  } else if graph {
+    if has("@index") {
+      containers.extend_from_slice(&["@graph@index", "@graph@index@set"]);
+    }
+
+    if has("@id") {
+      containers.extend_from_slice(&["@graph@id", "@graph@id@set"]);
+    }
+
+    containers.extend_from_slice(&["@graph", "@graph@set", "@set"]);
+
+    if !has("@index") {
+      containers.extend_from_slice(&["@graph@index", "@graph@index@set"]);
+    }
+
+    if !has("@id") {
+      containers.extend_from_slice(&["@graph@id", "@graph@id@set"]);
+    }
+
+    containers.extend_from_slice(&["@index", "@index@set"]);
+
+    type_language = "@type";
+    type_language_value = "@id".to_string();
+  } else {
+    if value.map_or(false, is_value) {
+      match (
+        object.and_then(|object| object.get("@language")),
+        object.and_then(|object| object.get("@type")),
+      ) {
+        (Some(Value::String(language)), _) if !has("@index") => {
+          type_language_value = language.clone();
+          containers.extend_from_slice(&["@language", "@language@set"]);
+        }
+        (_, Some(Value::String(type_))) => {
+          type_language = "@type";
+          type_language_value = type_.clone();
+        }
+        _ => {}
+      }
+    } else {
+      type_language = "@type";
+      type_language_value = "@id".to_string();
+      containers.extend_from_slice(&["@id", "@id@set", "@type", "@set@type"]);
+    }
+
+    containers.push("@set");
+  }
+
+  containers.push("@none");
+
+  if !has("@index") {
+    containers.extend_from_slice(&["@index", "@index@set"]);
+  }
+
+  if object.map_or(false, |object| object.len() == 1 && object.contains_key("@value")) {
+    containers.extend_from_slice(&["@language", "@language@set"]);
+  }
+
+  let mut preferred: Vec<String> = Vec::new();
+
+  if type_language_value == "@reverse" {
+    preferred.push("@reverse".to_string());
+  }
+
+  let id: Option<&str> = object.and_then(|object| object.get("@id")).and_then(Value::as_str);
+  let reference: bool = type_language_value == "@id" || type_language_value == "@reverse";
+
+  match (reference, id) {
+    (true, Some(id)) => {
+      let compacted: String = compact_iri(active, inverse, id, None, true, false);
+
+      let order: &[&str] = if active
+        .term(&compacted)
+        .map_or(false, |definition| definition.iri.as_deref() == Some(id))
+      {
+        &["@vocab", "@id", "@none"]
+      } else {
+        &["@id", "@vocab", "@none"]
+      };
+
+      preferred.extend(order.iter().map(ToString::to_string));
+    }
+    _ => {
+      preferred.push(type_language_value);
+      preferred.push("@none".to_string());
+
+      let empty_list: bool = object
+        .and_then(|object| object.get("@list"))
+        .and_then(Value::as_array)
+        .map_or(false, Vec::is_empty);
+
+      if empty_list {
+        type_language = "@any";
+      }
+    }
+  }
+
+  preferred.push("@any".to_string());
+
+  inverse.select(iri, &containers, type_language, &preferred)
+}
+
+// =============================================================================
+// Value Compaction
+// =============================================================================
+
+/// Compacts a value object or node reference to a scalar, if allowed by the
+/// definition of `property`.
+///
+/// See [Value Compaction][SPEC].
+///
+/// [SPEC]: https://www.w3.org/TR/json-ld11-api/#value-compaction
+fn compact_value(
+  active: &ActiveContext,
+  inverse: &InverseContext,
+  property: Option<&str>,
+  value: &Map,
+) -> Option<Value> {
+  let definition: Option<&TermDefinition> = property.and_then(|property| active.term(property));
+  let type_mapping: Option<&str> = definition.and_then(|definition| definition.type_.as_deref());
+  let index: bool = definition.map_or(false, |definition| definition.has_container("@index"));
+
+  if !index && value.contains_key("@index") {
+    return None;
+  }
+
+  if let Some(id) = value.get("@id") {
+    if value.len() > 1 + usize::from(value.contains_key("@index")) {
+      return None;
+    }
+
+    let id: &str = id.as_str()?;
+
+    return match type_mapping {
+      Some("@id") => Some(Value::String(compact_iri(active, inverse, id, None, false, false))),
+      Some("@vocab") => Some(Value::String(compact_iri(active, inverse, id, None, true, false))),
+      _ => None,
+    };
+  }
+
+  let inner: &Value = value.get("@value")?;
+
+  match (value.get("@type").and_then(Value::as_str), type_mapping) {
+    (Some(type_), Some(mapping)) if type_ == mapping => return Some(inner.clone()),
+    (Some(_), _) | (None, Some("@none")) => return None,
+    (None, _) => {}
+  }
+
+  if value.contains_key("@direction") {
+    return None;
+  }
+
+  if !inner.is_string() {
+    return Some(inner.clone());
+  }
+
+  let language: Option<&str> = match definition.and_then(|definition| definition.language.as_ref()) {
+    Some(language) => language.as_deref(),
+    None => active.language(),
+  };
+
+  let matches: bool = match (value.get("@language").and_then(Value::as_str), language) {
+    (Some(value), Some(language)) => value.eq_ignore_ascii_case(language),
+    (None, None) => true,
+    _ => false,
+  };
+
+  if matches {
+    Some(inner.clone())
+  } else {
+    None
+  }
+}
+
+// =============================================================================
+// Helpers
+// =============================================================================
+
+fn sorted_keys(object: &Map) -> Vec<&String> {
+  let mut keys: Vec<&String> = object.keys().collect();
+  keys.sort_unstable();
+  keys
+}
+
+/// Adds `value` to the entry `key` of `object`, converting the entry to an
+/// array if it already exists or `as_array` is set.
+fn add_value(object: &mut Map, key: &str, value: Value, as_array: bool) {
+  if as_array {
+    let entry: &mut Value = object.entry(key).or_insert_with(|| Value::Array(Vec::new()));
+
+    if !entry.is_array() {
+      *entry = Value::Array(vec![entry.take()]);
+    }
+  }
+
+  if let Value::Array(values) = value {
+    for value in values {
+      add_value(object, key, value, as_array);
+    }
+
+    return;
+  }
+
+  match object.get_mut(key) {
+    Some(Value::Array(entry)) => entry.push(value),
+    Some(entry) => *entry = Value::Array(vec![entry.take(), value]),
+    None => {
+      object.insert(key.to_string(), value);
+    }
+  }
+}
+
+/// Returns the map object of the container entry `key` of `object`.
+fn map_object<'a>(object: &'a mut Map, key: &str) -> &'a mut Map {
+  let entry: &mut Value = object.entry(key).or_insert_with(|| Value::Object(Map::new()));
+
+  if !entry.is_object() {
+    *entry = Value::Object(Map::new());
+  }
+
+  entry.as_object_mut().unwrap()
+}
+
+/// Removes the first string value of the entry `key` from `value`, removing
+/// the entry if no values remain.
+fn take_first(value: &mut Value, key: &str) -> Option<String> {
+  let object: &mut Map = value.as_object_mut()?;
+
+  match object.remove(key)? {
+    Value::String(first) => Some(first),
+    Value::Array(mut values) if !values.is_empty() => {
+      let first: Value = values.remove(0);
+
+      match values.len() {
+        0 => {}
+        1 => {
+          object.insert(key.to_string(), values.remove(0));
+        }
+        _ => {
+          object.insert(key.to_string(), Value::Array(values));
+        }
+      }
+
+      first.as_str().map(ToString::to_string)
+    }
+    _ => None,
+  }
+}
@@ -0,0 +1,703 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+use crate::common::Value;
+use crate::error::Error;
+use crate::error::Result;
+use crate::jsonld::DocumentLoader;
+
+type Map = serde_json::Map<String, Value>;
+
+/// The maximum depth of nested remote contexts.
+const MAX_REMOTE_CONTEXTS: usize = 32;
+
+const KEYWORDS: &[&str] = &[
+  "@base",
+  "@container",
+  "@context",
+  "@direction",
+  "@graph",
+  "@id",
+  "@import",
+  "@included",
+  "@index",
+  "@json",
+  "@language",
+  "@list",
+  "@nest",
+  "@none",
+  "@prefix",
+  "@propagate",
+  "@protected",
+  "@reverse",
+  "@set",
+  "@type",
+  "@value",
+  "@version",
+  "@vocab",
+];
+
+const CONTAINERS: &[&str] = &["@graph", "@id", "@index", "@language", "@list", "@set", "@type"];
+
+const CONTEXT_KEYWORDS: &[&str] = &[
+  "@base",
+  "@direction",
+  "@import",
+  "@language",
+  "@propagate",
+  "@protected",
+  "@version",
+  "@vocab",
+];
+
+const DEFINITION_KEYWORDS: &[&str] = &[
+  "@container",
+  "@context",
+  "@direction",
+  "@id",
+  "@index",
+  "@language",
+  "@nest",
+  "@prefix",
+  "@protected",
+  "@reverse",
+  "@type",
+];
+
+/// A term definition of an [`ActiveContext`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub(crate) struct TermDefinition {
+  pub(crate) iri: Option<String>,
+  pub(crate) prefix: bool,
+  pub(crate) protected: bool,
+  pub(crate) reverse: bool,
+  pub(crate) base_url: Option<String>,
+  pub(crate) context: Option<Value>,
+  pub(crate) container: Vec<String>,
+  pub(crate) index: Option<String>,
+  pub(crate) language: Option<Option<String>>,
+  pub(crate) type_: Option<String>,
+}
+
+impl TermDefinition {
+  /// Returns `true` if the container mapping includes `container`.
+  pub(crate) fn has_container(&self, container: &str) -> bool {
+    self.container.iter().any(|value| value == container)
+  }
+
+  fn same_as(&self, other: &Self) -> bool {
+    Self {
+      protected: other.protected,
+      ..self.clone()
+    } == *other
+  }
+}
+
+/// The state used to interpret terms, IRIs and values of a JSON-LD document.
+///
+/// Implements the [Context Processing][SPEC] algorithms of JSON-LD 1.1.
+///
+/// [SPEC]: https://www.w3.org/TR/json-ld11-api/#context-processing-algorithms
+#[derive(Clone, Debug, Default)]
+pub(crate) struct ActiveContext {
+  terms: HashMap<String, TermDefinition>,
+  base: Option<String>,
+  original_base: Option<String>,
+  vocab: Option<String>,
+  language: Option<String>,
+  previous: Option<Box<ActiveContext>>,
+}
+
+impl ActiveContext {
+  /// Returns the definition of `term`, if any.
+  pub(crate) fn term(&self, term: &str) -> Option<&TermDefinition> {
+    self.terms.get(term)
+  }
+
+  /// Returns an iterator over all terms and their definitions.
+  pub(crate) fn terms(&self) -> impl Iterator<Item = (&String, &TermDefinition)> + '_ {
+    self.terms.iter()
+  }
+
+  /// Returns the vocabulary mapping, if any.
+  pub(crate) fn vocab(&self) -> Option<&str> {
+    self.vocab.as_deref()
+  }
+
+  /// Returns the default language of string values.
+  pub(crate) fn language(&self) -> Option<&str> {
+    self.language.as_deref()
+  }
+
+  /// Returns the context that was active before a non-propagated context was
+  /// applied.
+  pub(crate) fn previous(&self) -> Option<&Self> {
+    self.previous.as_deref()
+  }
+
+  /// Returns a new context with the `local` context applied to `self`.
+  pub(crate) fn process<L>(
+    &self,
+    loader: &L,
+    local: &Value,
+    base_url: Option<&str>,
+    override_protected: bool,
+    propagate: bool,
+  ) -> Result<Self>
+  where
+    L: DocumentLoader + ?Sized,
+  {
+    self.process_inner(loader, local, &[], base_url, override_protected, propagate)
+  }
+
+  fn process_inner<L>(
+    &self,
+    loader: &L,
+    local: &Value,
+    remote: &[String],
+    base_url: Option<&str>,
+    override_protected: bool,
+    mut propagate: bool,
+  ) -> Result<Self>
+  where
+    L: DocumentLoader + ?Sized,
+  {
+    let mut result: Self = self.clone();
+
+    if let Some(value) = local.get("@propagate") {
+      propagate = value
+        .as_bool()
+        .ok_or(Error::InvalidJsonLd("invalid @propagate value"))?;
+    }
+
+    if !propagate && result.previous.is_none() {
+      result.previous = Some(Box::new(self.clone()));
+    }
+
+    let contexts: &[Value] = match local {
+      Value::Array(contexts) => contexts,
+      context => core::slice::from_ref(context),
+    };
+
+    for context in contexts {
+      match context {
+        Value::Null => {
+          if !override_protected && result.terms.values().any(|term| term.protected) {
+            return Err(Error::InvalidJsonLd("invalid context nullification"));
+          }
+
+          let previous: Option<Box<Self>> = if propagate {
+            None
+          } else {
+            Some(Box::new(result.clone()))
+          };
+
+          result = Self {
+            base: self.original_base.clone(),
+            original_base: self.original_base.clone(),
+            previous,
+            ..Self::default()
+          };
+        }
+        Value::String(iri) => {
+          let iri: String = resolve_iri(base_url, iri);
+
+          if remote.len() >= MAX_REMOTE_CONTEXTS {
+            return Err(Error::InvalidJsonLd("context overflow"));
+          }
+
+          let document: Value = loader.load(&iri)?;
+
+          let context: &Value = document
+            .get("@context")
+            .ok_or_else(|| Error::LoadContext(iri.clone()))?;
+
+          let mut remote: Vec<String> = remote.to_vec();
+
+          remote.push(iri.clone());
+
+          result = result.process_inner(loader, context, &remote, Some(&iri), false, true)?;
+        }
+        Value::Object(context) => {
+          result.process_object(loader, context, remote, base_url, override_protected)?;
+        }
+        _ => {
+          return Err(Error::InvalidJsonLd("invalid local context"));
+        }
+      }
+    }
+
+    Ok(result)
+  }
+
+  fn process_object<L>(
+    &mut self,
+    loader: &L,
+    context: &Map,
+    remote: &[String],
+    base_url: Option<&str>,
+    override_protected: bool,
+  ) -> Result<()>
+  where
+    L: DocumentLoader + ?Sized,
+  {
+    if let Some(version) = context.get("@version") {
+      if version.as_f64() != Some(1.1) {
+        return Err(Error::InvalidJsonLd("invalid @version value"));
+      }
+    }
+
+    let context: Cow<'_, Map> = match context.get("@import") {
+      Some(Value::String(import)) => {
+        let iri: String = resolve_iri(base_url, import);
+        let document: Value = loader.load(&iri)?;
+
+        let mut imported: Map = document
+          .get("@context")
+          .and_then(Value::as_object)
+          .cloned()
+          .ok_or_else(|| Error::LoadContext(iri.clone()))?;
+
+        if imported.contains_key("@import") {
+          return Err(Error::InvalidJsonLd("invalid context entry"));
+        }
+
+        for (key, value) in context {
+          imported.insert(key.clone(), value.clone());
+        }
+
+        Cow::Owned(imported)
+      }
+      Some(_) => return Err(Error::InvalidJsonLd("invalid @import value")),
+      None => Cow::Borrowed(context),
+    };
+
+    if let (Some(base), true) = (context.get("@base"), remote.is_empty()) {
+      self.base = match base {
+        Value::Null => None,
+        Value::String(base) if is_absolute_iri(base) => Some(base.clone()),
+        Value::String(base) => Some(resolve_iri(self.base.as_deref(), base)),
+        _ => return Err(Error::InvalidJsonLd("invalid base IRI")),
+      };
+    }
+
+    if let Some(vocab) = context.get("@vocab") {
+      self.vocab = match vocab {
+        Value::Null => None,
+        Value::String(vocab) => match self.expand_iri(vocab, true, true) {
+          Some(vocab) if is_absolute_iri(&vocab) || is_blank_node(&vocab) => Some(vocab),
+          _ => return Err(Error::InvalidJsonLd("invalid vocab mapping")),
+        },
+        _ => return Err(Error::InvalidJsonLd("invalid vocab mapping")),
+      };
+    }
+
+    if let Some(language) = context.get("@language") {
+      self.language = match language {
+        Value::Null => None,
+        Value::String(language) => Some(language.to_lowercase()),
+        _ => return Err(Error::InvalidJsonLd("invalid default language")),
+      };
+    }
+
+    let protected: bool = match context.get("@protected") {
+      Some(Value::Bool(protected)) => *protected,
+      Some(_) => return Err(Error::InvalidJsonLd("invalid @protected value")),
+      None => false,
+    };
+
+    let mut definer: TermDefiner<'_> = TermDefiner {
+      local: &context,
+      defined: HashMap::new(),
+      base_url,
+      protected,
+      override_protected,
+    };
+
+    for term in context.keys() {
+      if !CONTEXT_KEYWORDS.contains(&term.as_str()) {
+        definer.define(self, term)?;
+      }
+    }
+
+    Ok(())
+  }
+
+  /// Expands `value` to an absolute IRI, keyword, or blank node identifier.
+  ///
+  /// Returns `None` if `value` is mapped to `null`.
+  pub(crate) fn expand_iri(&self, value: &str, document_relative: bool, vocab: bool) -> Option<String> {
+    if is_keyword(value) {
+      return Some(value.to_string());
+    }
+
+    if looks_like_keyword(value) {
+      return None;
+    }
+
+    if let Some(definition) = self.terms.get(value) {
+      if vocab || definition.iri.as_deref().map_or(false, is_keyword) {
+        return definition.iri.clone();
+      }
+    }
+
+    if let Some((prefix, suffix)) = split_compact_iri(value) {
+      if prefix == "_" || suffix.starts_with("//") {
+        return Some(value.to_string());
+      }
+
+      if let Some(definition) = self.terms.get(prefix) {
+        if let (Some(iri), true) = (definition.iri.as_deref(), definition.prefix) {
+          return Some(format!("{}{}", iri, suffix));
+        }
+      }
+
+      if is_absolute_iri(value) {
+        return Some(value.to_string());
+      }
+    }
+
+    if let (Some(vocab), true) = (self.vocab.as_deref(), vocab) {
+      return Some(format!("{}{}", vocab, value));
+    }
+
+    if document_relative {
+      return Some(resolve_iri(self.base.as_deref(), value));
+    }
+
+    Some(value.to_string())
+  }
+}
+
+// =============================================================================
+// Create Term Definition
+// =============================================================================
+
+struct TermDefiner<'a> {
+  local: &'a Map,
+  defined: HashMap<String, bool>,
+  base_url: Option<&'a str>,
+  protected: bool,
+  override_protected: bool,
+}
+
+impl TermDefiner<'_> {
+  fn define(&mut self, active: &mut ActiveContext, term: &str) -> Result<()> {
+    match self.defined.get(term) {
+      Some(true) => return Ok(()),
+      Some(false) => return Err(Error::InvalidJsonLd("cyclic IRI mapping")),
+      None => {}
+    }
+
+    if term.is_empty() {
+      return Err(Error::InvalidJsonLd("invalid term definition"));
+    }
+
+    self.defined.insert(term.to_string(), false);
+
+    let local: &Map = self.local;
+    let value: &Value = local.get(term).unwrap_or(&Value::Null);
+
+    if term == "@type" {
+      let valid: bool = value.as_object().map_or(false, |object| {
+        !object.is_empty()
+          && object
+            .iter()
+            .all(|(key, value)| (key == "@container" && value == "@set") || key == "@protected")
+      });
+
+      if !valid {
+        return Err(Error::InvalidJsonLd("keyword redefinition"));
+      }
+    } else if is_keyword(term) {
+      return Err(Error::InvalidJsonLd("keyword redefinition"));
+    } else if looks_like_keyword(term) {
+      self.defined.insert(term.to_string(), true);
+      return Ok(());
+    }
+
+    let previous: Option<TermDefinition> = active.terms.remove(term);
+
+    let (value, simple): (Cow<'_, Map>, bool) = match value {
+      Value::Null => (Cow::Owned(single("@id", Value::Null)), false),
+      Value::String(iri) => (Cow::Owned(single("@id", Value::String(iri.clone()))), true),
+      Value::Object(object) => (Cow::Borrowed(object), false),
+      _ => return Err(Error::InvalidJsonLd("invalid term definition")),
+    };
+
+    let mut definition: TermDefinition = TermDefinition {
+      protected: match value.get("@protected") {
+        Some(Value::Bool(protected)) => *protected,
+        Some(_) => return Err(Error::InvalidJsonLd("invalid @protected value")),
+        None => self.protected,
+      },
+      ..TermDefinition::default()
+    };
+
+    if let Some(type_) = value.get("@type") {
+      let type_: &str = type_.as_str().ok_or(Error::InvalidJsonLd("invalid type mapping"))?;
+
+      match self.expand_iri(active, type_, false, true)? {
+        Some(type_) if is_absolute_iri(&type_) || matches!(type_.as_str(), "@id" | "@json" | "@none" | "@vocab") => {
+          definition.type_ = Some(type_);
+        }
+        _ => return Err(Error::InvalidJsonLd("invalid type mapping")),
+      }
+    }
+
+    if let Some(reverse) = value.get("@reverse") {
+      if value.contains_key("@id") || value.contains_key("@nest") {
+        return Err(Error::InvalidJsonLd("invalid reverse property"));
+      }
+
+      let reverse: &str = reverse.as_str().ok_or(Error::InvalidJsonLd("invalid IRI mapping"))?;
+
+      if looks_like_keyword(reverse) {
+        self.defined.insert(term.to_string(), true);
+        return Ok(());
+      }
+
+      match self.expand_iri(active, reverse, false, true)? {
+        Some(iri) if is_absolute_iri(&iri) || is_blank_node(&iri) => {
+          definition.iri = Some(iri);
+        }
+        _ => return Err(Error::InvalidJsonLd("invalid IRI mapping")),
+      }
+
+      match value.get("@container") {
+        None | Some(Value::Null) => {}
+        Some(Value::String(container)) if container == "@set" || container == "@index" => {
+          definition.container = vec![container.clone()];
+        }
+        Some(_) => return Err(Error::InvalidJsonLd("invalid reverse property")),
+      }
+
+      definition.reverse = true;
+
+      active.terms.insert(term.to_string(), definition);
+      self.defined.insert(term.to_string(), true);
+
+      return Ok(());
+    }
+
+    match value.get("@id") {
+      Some(Value::Null) => {}
+      Some(Value::String(id)) if id != term => {
+        if !is_keyword(id) && looks_like_keyword(id) {
+          self.defined.insert(term.to_string(), true);
+          return Ok(());
+        }
+
+        let iri: String = match self.expand_iri(active, id, false, true)? {
+          Some(iri) if is_keyword(&iri) || is_absolute_iri(&iri) || is_blank_node(&iri) => iri,
+          _ => return Err(Error::InvalidJsonLd("invalid IRI mapping")),
+        };
+
+        if iri == "@context" {
+          return Err(Error::InvalidJsonLd("invalid keyword alias"));
+        }
+
+        if split_compact_iri(term).is_some() || term.contains('/') {
+          self.defined.insert(term.to_string(), true);
+
+          if self.expand_iri(active, term, false, true)?.as_deref() != Some(iri.as_str()) {
+            return Err(Error::InvalidJsonLd("invalid IRI mapping"));
+          }
+        }
+
+        if !term.contains(':') && !term.contains('/') && simple {
+          definition.prefix =
+            is_blank_node(&iri) || iri.ends_with(|c| matches!(c, ':' | '/' | '?' | '#' | '[' | ']' | '@'));
+        }
+
+        definition.iri = Some(iri);
+      }
+      Some(Value::String(_)) | None => {
+        if let Some((prefix, suffix)) = split_compact_iri(term) {
+          if self.local.contains_key(prefix) {
+            self.define(active, prefix)?;
+          }
+
+          definition.iri = match active.terms.get(prefix).and_then(|prefix| prefix.iri.as_deref()) {
+            Some(iri) => Some(format!("{}{}", iri, suffix)),
+            None => Some(term.to_string()),
+          };
+        } else if term.contains('/') {
+          match self.expand_iri(active, term, false, true)? {
+            Some(iri) if is_absolute_iri(&iri) => definition.iri = Some(iri),
+            _ => return Err(Error::InvalidJsonLd("invalid IRI mapping")),
+          }
+        } else if term == "@type" {
+          definition.iri = Some(term.to_string());
+        } else if let Some(vocab) = active.vocab.as_deref() {
+          definition.iri = Some(format!("{}{}", vocab, term));
+        } else {
+          return Err(Error::InvalidJsonLd("invalid IRI mapping"));
+        }
+      }
+      Some(_) => return Err(Error::InvalidJsonLd("invalid IRI mapping")),
+    }
+
+    if let Some(container) = value.get("@container") {
+      let container: Vec<String> = match container {
+        Value::String(container) => vec![container.clone()],
+        Value::Array(containers) => containers
+          .iter()
+          .map(|container| container.as_str().map(ToString::to_string))
+          .collect::<Option<_>>()
+          .ok_or(Error::InvalidJsonLd("invalid container mapping"))?,
+        _ => return Err(Error::InvalidJsonLd("invalid container mapping")),
+      };
+
+      if container.iter().any(|value| !CONTAINERS.contains(&value.as_str()))
+        || (container.len() > 1 && container.iter().any(|value| value == "@list"))
+      {
+        return Err(Error::InvalidJsonLd("invalid container mapping"));
+      }
+
+      definition.container = container;
+    }
+
+    if let Some(index) = value.get("@index") {
+      definition.index = Some(
+        index
+          .as_str()
+          .map(ToString::to_string)
+          .ok_or(Error::InvalidJsonLd("invalid term definition"))?,
+      );
+    }
+
+    if let Some(context) = value.get("@context") {
+      definition.context = Some(context.clone());
+      definition.base_url = self.base_url.map(ToString::to_string);
+    }
+
+    if let (Some(language), false) = (value.get("@language"), value.contains_key("@type")) {
+      definition.language = match language {
+        Value::Null => Some(None),
+        Value::String(language) => Some(Some(language.to_lowercase())),
+        _ => return Err(Error::InvalidJsonLd("invalid language mapping")),
+      };
+    }
+
+    if let Some(prefix) = value.get("@prefix") {
+      if term.contains(':') || term.contains('/') {
+        return Err(Error::InvalidJsonLd("invalid term definition"));
+      }
+
+      definition.prefix = prefix.as_bool().ok_or(Error::InvalidJsonLd("invalid @prefix value"))?;
+
+      if definition.prefix && definition.iri.as_deref().map_or(false, is_keyword) {
+        return Err(Error::InvalidJsonLd("invalid term definition"));
+      }
+    }
+
+    if value.keys().any(|key| !DEFINITION_KEYWORDS.contains(&key.as_str())) {
+      return Err(Error::InvalidJsonLd("invalid term definition"));
+    }
+
+    if let (Some(previous), false) = (previous, self.override_protected) {
+      if previous.protected {
+        if !previous.same_as(&definition) {
+          return Err(Error::InvalidJsonLd("protected term redefinition"));
+        }
+
+        definition = previous;
+      }
+    }
+
+    active.terms.insert(term.to_string(), definition);
+    self.defined.insert(term.to_string(), true);
+
+    Ok(())
+  }
+
+  fn expand_iri(
+    &mut self,
+    active: &mut ActiveContext,
+    value: &str,
+    document_relative: bool,
+    vocab: bool,
+  ) -> Result<Option<String>> {
+    if !is_keyword(value) && !looks_like_keyword(value) {
+      if self.local.contains_key(value) && self.defined.get(value) != Some(&true) {
+        self.define(active, value)?;
+      }
+
+      if let Some((prefix, suffix)) = split_compact_iri(value) {
+        if prefix != "_"
+          && !suffix.starts_with("//")
+          && self.local.contains_key(prefix)
+          && self.defined.get(prefix) != Some(&true)
+        {
+          self.define(active, prefix)?;
+        }
+      }
+    }
+
+    Ok(active.expand_iri(value, document_relative, vocab))
+  }
+}
+
+// =============================================================================
+// Helpers
+// =============================================================================
+
+/// Returns `true` if `value` is a JSON-LD keyword.
+pub(crate) fn is_keyword(value: &str) -> bool {
+  KEYWORDS.contains(&value)
+}
+
+/// Returns `true` if `value` has the form of a keyword (`@` followed by one or
+/// more ALPHA characters).
+pub(crate) fn looks_like_keyword(value: &str) -> bool {
+  value.len() > 1 && value.starts_with('@') && value[1..].bytes().all(|byte| byte.is_ascii_alphabetic())
+}
+
+/// Returns `true` if `value` is a blank node identifier.
+pub(crate) fn is_blank_node(value: &str) -> bool {
+  value.starts_with("_:")
+}
+
+/// Returns `true` if `value` is an absolute IRI (starts with a scheme).
+pub(crate) fn is_absolute_iri(value: &str) -> bool {
+  match value.find(':') {
+    Some(index) if index > 0 => {
+      let scheme: &[u8] = value[..index].as_bytes();
+
+      scheme[0].is_ascii_alphabetic()
+        && scheme
+          .iter()
+          .all(|byte| byte.is_ascii_alphanumeric() || matches!(byte, b'+' | b'-' | b'.'))
+    }
+    _ => false,
+  }
+}
+
+/// Splits `value` at the first colon following the first character.
+fn split_compact_iri(value: &str) -> Option<(&str, &str)> {
+  value
+    .char_indices()
+    .skip(1)
+    .find(|(_, char)| *char == ':')
+    .map(|(index, _)| (&value[..index], &value[index + 1..]))
+}
+
+/// Resolves `value` against the `base` IRI, returning `value` unchanged if it
+/// can not be resolved.
+fn resolve_iri(base: Option<&str>, value: &str) -> String {
+  base
+    .and_then(|base| ::url::Url::parse(base).ok())
+    .and_then(|base| base.join(value).ok())
+    .map(::url::Url::into_string)
+    .unwrap_or_else(|| value.to_string())
+}
+
+fn single(key: &str, value: Value) -> Map {
+  let mut map: Map = Map::new();
+  map.insert(key.to_string(), value);
+  map
+}
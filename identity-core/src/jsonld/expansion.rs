@@ -0,0 +1,650 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::borrow::Cow;
+use std::cell::RefCell;
+use std::collections::BTreeSet;
+
+use crate::common::Value;
+use crate::error::Error;
+use crate::error::Result;
+use crate::jsonld::context::is_absolute_iri;
+use crate::jsonld::context::is_keyword;
+use crate::jsonld::context::ActiveContext;
+use crate::jsonld::context::TermDefinition;
+use crate::jsonld::DocumentLoader;
+
+type Map = serde_json::Map<String, Value>;
+
+/// Expands the JSON-LD `document`, removing its contexts and replacing all
+/// terms with absolute IRIs.
+///
+/// Remote contexts are retrieved with the given `loader`. The result is always
+/// an array of node objects.
+///
+/// See the [JSON-LD 1.1 Expansion Algorithm][SPEC] for details.
+///
+/// # Errors
+///
+/// Fails if `document` is not valid JSON-LD or a context can not be loaded.
+///
+/// [SPEC]: https://www.w3.org/TR/json-ld11-api/#expansion-algorithm
+pub fn expand<L>(document: &Value, loader: &L) -> Result<Value>
+where
+  L: DocumentLoader + ?Sized,
+{
+  Expander::new(loader).run(document)
+}
+
+/// Expands the JSON-LD `document` and returns the terms and types that are not
+/// defined by its contexts, in lexicographical order.
+///
+/// Properties with undefined terms are silently dropped by [`expand`], so their
+/// claims are not covered by the canonical form of a document.
+///
+/// # Errors
+///
+/// Fails if `document` is not valid JSON-LD or a context can not be loaded.
+pub fn undefined_terms<L>(document: &Value, loader: &L) -> Result<Vec<String>>
+where
+  L: DocumentLoader + ?Sized,
+{
+  let expander: Expander<'_, L> = Expander::new(loader);
+
+  expander.run(document)?;
+
+  Ok(expander.undefined.into_inner().into_iter().collect())
+}
+
+struct Expander<'a, L: ?Sized> {
+  loader: &'a L,
+  undefined: RefCell<BTreeSet<String>>,
+}
+
+impl<'a, L> Expander<'a, L>
+where
+  L: DocumentLoader + ?Sized,
+{
+  fn new(loader: &'a L) -> Self {
+    Self {
+      loader,
+      undefined: RefCell::new(BTreeSet::new()),
+    }
+  }
+
+  fn run(&self, document: &Value) -> Result<Value> {
+    let context: ActiveContext = ActiveContext::default();
+    let expanded: Value = self.expand(&context, None, document, None, false)?;
+
+    let expanded: Value = match expanded {
+      Value::Object(mut object) if object.len() == 1 && object.contains_key("@graph") => {
+        object.remove("@graph").unwrap()
+      }
+      expanded => expanded,
+    };
+
+    Ok(Value::Array(into_array(expanded)))
+  }
+
+  fn expand(
+    &self,
+    active: &ActiveContext,
+    property: Option<&str>,
+    element: &Value,
+    base_url: Option<&str>,
+    from_map: bool,
+  ) -> Result<Value> {
+    let definition: Option<&TermDefinition> = property.and_then(|property| active.term(property));
+
+    match element {
+      Value::Null => Ok(Value::Null),
+      Value::Array(items) => {
+        let list: bool = definition.map_or(false, |definition| definition.has_container("@list"));
+        let mut output: Vec<Value> = Vec::with_capacity(items.len());
+
+        for item in items {
+          let mut expanded: Value = self.expand(active, property, item, base_url, from_map)?;
+
+          if list && expanded.is_array() {
+            expanded = single("@list", expanded);
+          }
+
+          match expanded {
+            Value::Null => {}
+            Value::Array(inner) => output.extend(inner),
+            expanded => output.push(expanded),
+          }
+        }
+
+        Ok(Value::Array(output))
+      }
+      Value::Object(object) => self.expand_object(active, property, object, base_url, from_map),
+      scalar => {
+        if property.is_none() || property == Some("@graph") {
+          return Ok(Value::Null);
+        }
+
+        match definition.and_then(|definition| definition.context.as_ref().map(|context| (context, definition))) {
+          Some((context, definition)) => {
+            let active: ActiveContext =
+              active.process(self.loader, context, definition.base_url.as_deref(), true, true)?;
+
+            expand_value(&active, property, scalar)
+          }
+          None => expand_value(active, property, scalar),
+        }
+      }
+    }
+  }
+
+  fn expand_object(
+    &self,
+    active: &ActiveContext,
+    property: Option<&str>,
+    object: &Map,
+    base_url: Option<&str>,
+    from_map: bool,
+  ) -> Result<Value> {
+    let scoped: Option<(Value, Option<String>)> =
+      property
+        .and_then(|property| active.term(property))
+        .and_then(|definition| {
+          definition
+            .context
+            .clone()
+            .map(|context| (context, definition.base_url.clone()))
+        });
+
+    let mut active: Cow<'_, ActiveContext> = Cow::Borrowed(active);
+
+    // Type-scoped contexts do not apply to nested node objects.
+    if let (Some(previous), false) = (active.previous(), from_map) {
+      let expands_to = |key: &String, keyword: &str| active.expand_iri(key, false, true).as_deref() == Some(keyword);
+
+      let retain: bool = object.keys().any(|key| expands_to(key, "@value"))
+        || (object.len() == 1 && object.keys().all(|key| expands_to(key, "@id")));
+
+      if !retain {
+        active = Cow::Owned(previous.clone());
+      }
+    }
+
+    if let Some((context, url)) = scoped {
+      active = Cow::Owned(active.process(self.loader, &context, url.as_deref(), true, true)?);
+    }
+
+    if let Some(context) = object.get("@context") {
+      active = Cow::Owned(active.process(self.loader, context, base_url, false, true)?);
+    }
+
+    let type_scoped: ActiveContext = active.clone().into_owned();
+
+    for key in sorted_keys(object) {
+      if type_scoped.expand_iri(key, false, true).as_deref() != Some("@type") {
+        continue;
+      }
+
+      let mut terms: Vec<&str> = match &object[key] {
+        Value::String(term) => vec![term.as_str()],
+        Value::Array(terms) => terms.iter().filter_map(Value::as_str).collect(),
+        _ => Vec::new(),
+      };
+
+      terms.sort_unstable();
+
+      for term in terms {
+        if let Some(definition) = type_scoped.term(term) {
+          if let Some(context) = definition.context.as_ref() {
+            active = Cow::Owned(active.process(self.loader, context, definition.base_url.as_deref(), false, false)?);
+          }
+        }
+      }
+    }
+
+    let mut result: Map = Map::new();
+
+    for key in sorted_keys(object) {
+      let value: &Value = &object[key];
+
+      if key == "@context" {
+        continue;
+      }
+
+      let expanded: String = match active.expand_iri(key, false, true) {
+        Some(expanded) if expanded.contains(':') || is_keyword(&expanded) => expanded,
+        Some(_) => {
+          self.undefined.borrow_mut().insert(key.clone());
+          continue;
+        }
+        None => continue,
+      };
+
+      if is_keyword(&expanded) {
+        if property == Some("@reverse") {
+          return Err(Error::InvalidJsonLd("invalid reverse property map"));
+        }
+
+        if result.contains_key(&expanded) && expanded != "@included" && expanded != "@type" {
+          return Err(Error::InvalidJsonLd("colliding keywords"));
+        }
+
+        let expanded_value: Value = match expanded.as_str() {
+          "@id" => match value {
+            Value::String(id) => active.expand_iri(id, true, false).map_or(Value::Null, Value::String),
+            _ => return Err(Error::InvalidJsonLd("invalid @id value")),
+          },
+          "@type" => {
+            let types: Vec<Value> = match value {
+              Value::String(type_) => vec![type_.as_str()],
+              Value::Array(types) => types
+                .iter()
+                .map(Value::as_str)
+                .collect::<Option<_>>()
+                .ok_or(Error::InvalidJsonLd("invalid type value"))?,
+              _ => return Err(Error::InvalidJsonLd("invalid type value")),
+            }
+            .into_iter()
+            .map(|type_| match type_scoped.expand_iri(type_, true, true) {
+              Some(iri) => {
+                if !iri.contains(':') && !is_keyword(&iri) {
+                  self.undefined.borrow_mut().insert(type_.to_string());
+                }
+
+                Value::String(iri)
+              }
+              None => Value::Null,
+            })
+            .collect();
+
+            match (result.remove("@type"), value) {
+              (None, Value::String(_)) => types.into_iter().next().unwrap_or(Value::Null),
+              (None, _) => Value::Array(types),
+              (Some(existing), _) => Value::Array(into_array(existing).into_iter().chain(types).collect()),
+            }
+          }
+          "@graph" => Value::Array(into_array(self.expand(
+            &active,
+            Some("@graph"),
+            value,
+            base_url,
+            false,
+          )?)),
+          "@included" => {
+            let included: Vec<Value> = into_array(self.expand(&active, None, value, base_url, false)?);
+
+            if !included.iter().all(is_node) {
+              return Err(Error::InvalidJsonLd("invalid @included value"));
+            }
+
+            match result.remove("@included") {
+              Some(existing) => Value::Array(into_array(existing).into_iter().chain(included).collect()),
+              None => Value::Array(included),
+            }
+          }
+          "@value" => match value {
+            Value::Array(_) | Value::Object(_) => {
+              return Err(Error::InvalidJsonLd("invalid value object value"));
+            }
+            Value::Null => {
+              result.insert(expanded, Value::Null);
+              continue;
+            }
+            value => value.clone(),
+          },
+          "@language" => match value {
+            Value::String(language) => Value::String(language.to_lowercase()),
+            _ => return Err(Error::InvalidJsonLd("invalid language-tagged string")),
+          },
+          "@direction" => match value {
+            Value::String(direction) if direction == "ltr" || direction == "rtl" => value.clone(),
+            _ => return Err(Error::InvalidJsonLd("invalid base direction")),
+          },
+          "@index" => match value {
+            Value::String(_) => value.clone(),
+            _ => return Err(Error::InvalidJsonLd("invalid @index value")),
+          },
+          "@list" => {
+            if property.is_none() || property == Some("@graph") {
+              continue;
+            }
+
+            Value::Array(into_array(self.expand(&active, property, value, base_url, false)?))
+          }
+          "@set" => self.expand(&active, property, value, base_url, false)?,
+          "@reverse" => {
+            if !value.is_object() {
+              return Err(Error::InvalidJsonLd("invalid @reverse value"));
+            }
+
+            if let Value::Object(mut reversed) = self.expand(&active, Some("@reverse"), value, base_url, false)? {
+              if let Some(Value::Object(forward)) = reversed.remove("@reverse") {
+                for (property, items) in forward {
+                  for item in into_array(items) {
+                    add_value(&mut result, &property, item);
+                  }
+                }
+              }
+
+              for (property, items) in reversed {
+                for item in into_array(items) {
+                  if is_value(&item) || is_list(&item) {
+                    return Err(Error::InvalidJsonLd("invalid reverse property value"));
+                  }
+
+                  add_value(reverse_map(&mut result)?, &property, item);
+                }
+              }
+            }
+
+            continue;
+          }
+          _ => continue,
+        };
+
+        if !expanded_value.is_null() {
+          result.insert(expanded, expanded_value);
+        }
+
+        continue;
+      }
+
+      let definition: Option<&TermDefinition> = active.term(key);
+      let has_container = |container: &str| definition.map_or(false, |definition| definition.has_container(container));
+
+      let mut expanded_value: Value = if definition.and_then(|definition| definition.type_.as_deref()) == Some("@json")
+      {
+        let mut output: Map = single_map("@value", value.clone());
+        output.insert("@type".to_string(), Value::String("@json".to_string()));
+        Value::Object(output)
+      } else if let (true, Value::Object(languages)) = (has_container("@language"), value) {
+        let mut output: Vec<Value> = Vec::new();
+
+        for language in sorted_keys(languages) {
+          let none: bool = language == "@none" || active.expand_iri(language, false, true).as_deref() == Some("@none");
+
+          for item in into_array(languages[language].clone()) {
+            match item {
+              Value::Null => {}
+              Value::String(_) => {
+                let mut item: Map = single_map("@value", item);
+
+                if !none {
+                  item.insert("@language".to_string(), Value::String(language.to_lowercase()));
+                }
+
+                output.push(Value::Object(item));
+              }
+              _ => return Err(Error::InvalidJsonLd("invalid language map value")),
+            }
+          }
+        }
+
+        Value::Array(output)
+      } else if let (true, Value::Object(indices)) = (
+        has_container("@index") || has_container("@id") || has_container("@type"),
+        value,
+      ) {
+        let mut output: Vec<Value> = Vec::new();
+
+        for index in sorted_keys(indices) {
+          let mut map_context: Cow<'_, ActiveContext> = Cow::Borrowed(&*active);
+
+          if has_container("@id") || has_container("@type") {
+            if let Some(previous) = active.previous() {
+              map_context = Cow::Borrowed(previous);
+            }
+          }
+
+          if has_container("@type") {
+            let scoped: Option<(Value, Option<String>)> = map_context.term(index).and_then(|definition| {
+              definition
+                .context
+                .clone()
+                .map(|context| (context, definition.base_url.clone()))
+            });
+
+            if let Some((context, url)) = scoped {
+              map_context = Cow::Owned(map_context.process(self.loader, &context, url.as_deref(), false, true)?);
+            }
+          }
+
+          let expanded_index: Option<String> = active.expand_iri(index, false, true);
+          let none: bool = expanded_index.as_deref() == Some("@none");
+          let items: Value = self.expand(&map_context, Some(key), &indices[index], base_url, true)?;
+
+          for mut item in into_array(items) {
+            if has_container("@graph") && !is_graph(&item) {
+              item = single("@graph", Value::Array(vec![item]));
+            }
+
+            if let (Value::Object(item), false) = (&mut item, none) {
+              if has_container("@index") {
+                item.entry("@index").or_insert_with(|| Value::String(index.clone()));
+              } else if has_container("@id") {
+                if !item.contains_key("@id") {
+                  let id: Option<String> = active.expand_iri(index, true, false);
+                  item.insert("@id".to_string(), id.map_or(Value::Null, Value::String));
+                }
+              } else if has_container("@type") {
+                let types: Vec<Value> = item.remove("@type").map(into_array).unwrap_or_default();
+                let index: Value = expanded_index.clone().map_or(Value::Null, Value::String);
+
+                item.insert(
+                  "@type".to_string(),
+                  Value::Array(Some(index).into_iter().chain(types).collect()),
+                );
+              }
+            }
+
+            output.push(item);
+          }
+        }
+
+        Value::Array(output)
+      } else {
+        self.expand(&active, Some(key), value, base_url, false)?
+      };
+
+      if expanded_value.is_null() {
+        continue;
+      }
+
+      if has_container("@list") && !is_list(&expanded_value) {
+        expanded_value = single("@list", Value::Array(into_array(expanded_value)));
+      }
+
+      if has_container("@graph") && !has_container("@id") && !has_container("@index") {
+        expanded_value = Value::Array(
+          into_array(expanded_value)
+            .into_iter()
+            .map(|item| single("@graph", Value::Array(into_array(item))))
+            .collect(),
+        );
+      }
+
+      if definition.map_or(false, |definition| definition.reverse) {
+        let reverse: &mut Map = reverse_map(&mut result)?;
+
+        for item in into_array(expanded_value) {
+          if is_value(&item) || is_list(&item) {
+            return Err(Error::InvalidJsonLd("invalid reverse property value"));
+          }
+
+          add_value(reverse, &expanded, item);
+        }
+      } else {
+        let entry: &mut Value = result
+          .entry(expanded.clone())
+          .or_insert_with(|| Value::Array(Vec::new()));
+
+        if let Value::Array(entry) = entry {
+          entry.extend(into_array(expanded_value));
+        }
+      }
+    }
+
+    if let Some(value) = result.get("@value") {
+      if result
+        .keys()
+        .any(|key| !matches!(key.as_str(), "@direction" | "@index" | "@language" | "@type" | "@value"))
+        || (result.contains_key("@language") && result.contains_key("@type"))
+      {
+        return Err(Error::InvalidJsonLd("invalid value object"));
+      }
+
+      if value.is_null() {
+        return Ok(Value::Null);
+      }
+
+      if !value.is_string() && result.contains_key("@language") {
+        return Err(Error::InvalidJsonLd("invalid language-tagged value"));
+      }
+
+      match result.get("@type") {
+        None => {}
+        Some(Value::String(type_)) if type_ == "@json" || is_absolute_iri(type_) => {}
+        Some(_) => return Err(Error::InvalidJsonLd("invalid typed value")),
+      }
+    } else if let Some(type_) = result.remove("@type") {
+      result.insert("@type".to_string(), Value::Array(into_array(type_)));
+    } else if result.contains_key("@set") || result.contains_key("@list") {
+      if result.len() > 2 || (result.len() == 2 && !result.contains_key("@index")) {
+        return Err(Error::InvalidJsonLd("invalid set or list object"));
+      }
+
+      if let Some(set) = result.remove("@set") {
+        return Ok(set);
+      }
+    }
+
+    if result.len() == 1 && result.contains_key("@language") {
+      return Ok(Value::Null);
+    }
+
+    if property.is_none() || property == Some("@graph") {
+      if result.is_empty() || result.contains_key("@value") || result.contains_key("@list") {
+        return Ok(Value::Null);
+      }
+
+      if result.len() == 1 && result.contains_key("@id") {
+        return Ok(Value::Null);
+      }
+    }
+
+    Ok(Value::Object(result))
+  }
+}
+
+/// Expands a scalar `value` to a value object or node reference.
+fn expand_value(active: &ActiveContext, property: Option<&str>, value: &Value) -> Result<Value> {
+  let definition: Option<&TermDefinition> = property.and_then(|property| active.term(property));
+  let type_: Option<&str> = definition.and_then(|definition| definition.type_.as_deref());
+
+  match (type_, value) {
+    (Some("@id"), Value::String(id)) => {
+      return Ok(single(
+        "@id",
+        active.expand_iri(id, true, false).map_or(Value::Null, Value::String),
+      ));
+    }
+    (Some("@vocab"), Value::String(id)) => {
+      return Ok(single(
+        "@id",
+        active.expand_iri(id, true, true).map_or(Value::Null, Value::String),
+      ));
+    }
+    _ => {}
+  }
+
+  let mut output: Map = single_map("@value", value.clone());
+
+  match type_ {
+    Some(type_) if !matches!(type_, "@id" | "@vocab" | "@none") => {
+      output.insert("@type".to_string(), Value::String(type_.to_string()));
+    }
+    _ if value.is_string() => {
+      let language: Option<&str> = match definition.and_then(|definition| definition.language.as_ref()) {
+        Some(language) => language.as_deref(),
+        None => active.language(),
+      };
+
+      if let Some(language) = language {
+        output.insert("@language".to_string(), Value::String(language.to_string()));
+      }
+    }
+    _ => {}
+  }
+
+  Ok(Value::Object(output))
+}
+
+// =============================================================================
+// Helpers
+// =============================================================================
+
+fn sorted_keys(object: &Map) -> Vec<&String> {
+  let mut keys: Vec<&String> = object.keys().collect();
+  keys.sort_unstable();
+  keys
+}
+
+fn single_map(key: &str, value: Value) -> Map {
+  let mut map: Map = Map::new();
+  map.insert(key.to_string(), value);
+  map
+}
+
+fn single(key: &str, value: Value) -> Value {
+  Value::Object(single_map(key, value))
+}
+
+fn reverse_map(result: &mut Map) -> Result<&mut Map> {
+  result
+    .entry("@reverse")
+    .or_insert_with(|| Value::Object(Map::new()))
+    .as_object_mut()
+    .ok_or(Error::InvalidJsonLd("invalid @reverse value"))
+}
+
+/// Appends `value` to the array at `property`, skipping duplicates.
+fn add_value(object: &mut Map, property: &str, value: Value) {
+  let entry: &mut Value = object.entry(property).or_insert_with(|| Value::Array(Vec::new()));
+
+  if let Value::Array(entry) = entry {
+    if !entry.contains(&value) {
+      entry.push(value);
+    }
+  }
+}
+
+pub(crate) fn into_array(value: Value) -> Vec<Value> {
+  match value {
+    Value::Null => Vec::new(),
+    Value::Array(values) => values,
+    value => vec![value],
+  }
+}
+
+pub(crate) fn is_value(value: &Value) -> bool {
+  value.get("@value").is_some()
+}
+
+pub(crate) fn is_list(value: &Value) -> bool {
+  value.get("@list").is_some()
+}
+
+pub(crate) fn is_graph(value: &Value) -> bool {
+  value.as_object().map_or(false, |object| {
+    object.contains_key("@graph")
+      && object
+        .keys()
+        .all(|key| matches!(key.as_str(), "@graph" | "@id" | "@index"))
+  })
+}
+
+/// Returns `true` if `value` is a node object or node reference.
+pub(crate) fn is_node(value: &Value) -> bool {
+  value.as_object().map_or(false, |object| {
+    !object.contains_key("@value") && !object.contains_key("@list") && !object.contains_key("@set")
+  })
+}
@@ -0,0 +1,63 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+
+/// Issues new blank node identifiers with a common prefix, keeping track of
+/// the order in which existing identifiers were relabeled.
+#[derive(Clone, Debug)]
+pub(crate) struct IdentifierIssuer {
+  prefix: &'static str,
+  counter: usize,
+  issued: HashMap<String, String>,
+  order: Vec<String>,
+}
+
+impl IdentifierIssuer {
+  pub(crate) fn new(prefix: &'static str) -> Self {
+    Self {
+      prefix,
+      counter: 0,
+      issued: HashMap::new(),
+      order: Vec::new(),
+    }
+  }
+
+  /// Returns the identifier issued for `existing`, issuing a new one if needed.
+  pub(crate) fn issue(&mut self, existing: &str) -> String {
+    if let Some(issued) = self.issued.get(existing) {
+      return issued.clone();
+    }
+
+    let issued: String = self.fresh();
+
+    self.issued.insert(existing.to_string(), issued.clone());
+    self.order.push(existing.to_string());
+
+    issued
+  }
+
+  /// Issues a new identifier that is not associated with an existing one.
+  pub(crate) fn fresh(&mut self) -> String {
+    let issued: String = format!("{}{}", self.prefix, self.counter);
+
+    self.counter += 1;
+
+    issued
+  }
+
+  /// Returns the identifier issued for `existing`, if any.
+  pub(crate) fn get(&self, existing: &str) -> Option<&str> {
+    self.issued.get(existing).map(String::as_str)
+  }
+
+  /// Returns `true` if an identifier was issued for `existing`.
+  pub(crate) fn contains(&self, existing: &str) -> bool {
+    self.issued.contains_key(existing)
+  }
+
+  /// Returns the existing identifiers in the order they were relabeled.
+  pub(crate) fn order(&self) -> &[String] {
+    &self.order
+  }
+}
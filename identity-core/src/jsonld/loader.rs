@@ -0,0 +1,117 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::BTreeMap;
+
+use crate::common::Value;
+use crate::convert::FromJson;
+use crate::error::Error;
+use crate::error::Result;
+
+/// A trait for retrieving remote JSON-LD contexts.
+pub trait DocumentLoader {
+  /// Returns the JSON document identified by the given `url`.
+  ///
+  /// # Errors
+  ///
+  /// Fails if the document is not available or is not valid JSON.
+  fn load(&self, url: &str) -> Result<Value>;
+}
+
+impl<'a, T> DocumentLoader for &'a T
+where
+  T: DocumentLoader + ?Sized,
+{
+  fn load(&self, url: &str) -> Result<Value> {
+    (**self).load(url)
+  }
+}
+
+/// A [`DocumentLoader`] that serves a fixed set of well-known contexts bundled
+/// with the library. The network is never accessed.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct BundledLoader;
+
+impl BundledLoader {
+  /// The URL of the W3C Verifiable Credentials v1 context.
+  pub const CREDENTIALS_V1: &'static str = "https://www.w3.org/2018/credentials/v1";
+
+  /// The URL of the W3C Decentralized Identifiers v1 context.
+  pub const DID_V1: &'static str = "https://www.w3.org/ns/did/v1";
+
+  /// The URL of the JSON Web Signature 2020 suite context.
+  pub const JWS_2020_V1: &'static str = "https://w3id.org/security/suites/jws-2020/v1";
+
+  /// The URL of the W3C Security Vocabulary v1 context.
+  pub const SECURITY_V1: &'static str = "https://w3id.org/security/v1";
+
+  /// The URL of the W3C Security Vocabulary v2 context.
+  pub const SECURITY_V2: &'static str = "https://w3id.org/security/v2";
+
+  /// Creates a new `BundledLoader`.
+  pub const fn new() -> Self {
+    Self
+  }
+}
+
+impl DocumentLoader for BundledLoader {
+  fn load(&self, url: &str) -> Result<Value> {
+    let document: &'static str = match url {
+      Self::CREDENTIALS_V1 => include_str!("contexts/credentials-v1.jsonld"),
+      Self::DID_V1 => include_str!("contexts/did-v1.jsonld"),
+      Self::JWS_2020_V1 => include_str!("contexts/jws-2020-v1.jsonld"),
+      Self::SECURITY_V1 => include_str!("contexts/security-v1.jsonld"),
+      Self::SECURITY_V2 => include_str!("contexts/security-v2.jsonld"),
+      _ => return Err(Error::LoadContext(url.to_string())),
+    };
+
+    Value::from_json(document)
+  }
+}
+
+/// A [`DocumentLoader`] that serves contexts registered by the application,
+/// falling back to the contexts of the [`BundledLoader`].
+///
+/// Registered contexts take precedence over bundled contexts with the same URL.
+#[derive(Clone, Debug, Default)]
+pub struct CachedLoader {
+  documents: BTreeMap<String, Value>,
+}
+
+impl CachedLoader {
+  /// Creates a new `CachedLoader` without registered contexts.
+  pub fn new() -> Self {
+    Self {
+      documents: BTreeMap::new(),
+    }
+  }
+
+  /// Registers the context `document` identified by `url`.
+  ///
+  /// Returns the document previously registered with the same `url`, if any.
+  ///
+  /// # Errors
+  ///
+  /// Fails if `document` does not have a top-level `@context` entry.
+  pub fn insert(&mut self, url: impl Into<String>, document: Value) -> Result<Option<Value>> {
+    if document.get("@context").is_none() {
+      return Err(Error::InvalidJsonLd("invalid remote context"));
+    }
+
+    Ok(self.documents.insert(url.into(), document))
+  }
+
+  /// Removes the context document identified by `url`.
+  pub fn remove(&mut self, url: &str) -> Option<Value> {
+    self.documents.remove(url)
+  }
+}
+
+impl DocumentLoader for CachedLoader {
+  fn load(&self, url: &str) -> Result<Value> {
+    match self.documents.get(url) {
+      Some(document) => Ok(document.clone()),
+      None => BundledLoader.load(url),
+    }
+  }
+}
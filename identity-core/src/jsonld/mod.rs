@@ -0,0 +1,58 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! JSON-LD processing and RDF Dataset Canonicalization.
+//!
+//! Implements the subset of [JSON-LD 1.1][SPEC1] needed to create and verify
+//! Linked Data Proofs: context processing, expansion, compaction, and
+//! conversion to RDF, along with the [URDNA2015][SPEC2] canonicalization
+//! algorithm.
+//!
+//! Contexts are never fetched from the network; they are resolved by a
+//! [`DocumentLoader`], such as the [`CachedLoader`] which serves the bundled
+//! W3C contexts and any context registered by the application.
+//!
+//! [SPEC1]: https://www.w3.org/TR/json-ld11-api/
+//! [SPEC2]: https://json-ld.github.io/rdf-dataset-canonicalization/spec/
+
+mod compaction;
+mod context;
+mod expansion;
+mod issuer;
+mod loader;
+mod rdf;
+mod to_rdf;
+mod urdna2015;
+
+#[cfg(test)]
+mod tests;
+
+pub use self::compaction::compact;
+pub use self::expansion::expand;
+pub use self::expansion::undefined_terms;
+pub use self::loader::BundledLoader;
+pub use self::loader::CachedLoader;
+pub use self::loader::DocumentLoader;
+pub use self::rdf::Dataset;
+pub use self::rdf::Quad;
+pub use self::rdf::Term;
+pub use self::urdna2015::canonicalize;
+
+use crate::common::Value;
+use crate::error::Result;
+
+/// Expands the JSON-LD `document`, converts it to an RDF dataset and returns
+/// the canonical N-Quads produced by [URDNA2015][`canonicalize`].
+///
+/// # Errors
+///
+/// Fails if `document` is not valid JSON-LD, a context can not be loaded, or
+/// canonicalization exceeds its resource limits.
+pub fn normalize<L>(document: &Value, loader: &L) -> Result<String>
+where
+  L: DocumentLoader + ?Sized,
+{
+  expand(document, loader)
+    .and_then(|expanded| Dataset::from_expanded(&expanded))
+    .and_then(|dataset| canonicalize(&dataset))
+}
@@ -0,0 +1,329 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::fmt::Result as FmtResult;
+
+use crate::common::Value;
+use crate::error::Error;
+use crate::error::Result;
+use crate::jsonld::to_rdf::to_rdf;
+
+pub(crate) const RDF_FIRST: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#first";
+pub(crate) const RDF_JSON: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#JSON";
+pub(crate) const RDF_LANGSTRING: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#langString";
+pub(crate) const RDF_NIL: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#nil";
+pub(crate) const RDF_REST: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#rest";
+pub(crate) const RDF_TYPE: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#type";
+pub(crate) const XSD_BOOLEAN: &str = "http://www.w3.org/2001/XMLSchema#boolean";
+pub(crate) const XSD_DOUBLE: &str = "http://www.w3.org/2001/XMLSchema#double";
+pub(crate) const XSD_INTEGER: &str = "http://www.w3.org/2001/XMLSchema#integer";
+pub(crate) const XSD_STRING: &str = "http://www.w3.org/2001/XMLSchema#string";
+
+/// A node or value in an RDF graph.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Term {
+  /// An absolute IRI.
+  Iri(String),
+  /// A blank node, identified by its label without the `_:` prefix.
+  BlankNode(String),
+  /// A literal value with a datatype IRI and an optional language tag.
+  Literal {
+    /// The lexical form of the literal.
+    value: String,
+    /// The datatype IRI of the literal.
+    datatype: String,
+    /// The language tag of a `rdf:langString` literal.
+    language: Option<String>,
+  },
+}
+
+impl Term {
+  /// Returns the label of a blank node term.
+  pub fn as_blank_node(&self) -> Option<&str> {
+    match self {
+      Self::BlankNode(label) => Some(label),
+      _ => None,
+    }
+  }
+}
+
+impl Display for Term {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    match self {
+      Self::Iri(iri) => write!(f, "<{}>", iri),
+      Self::BlankNode(label) => write!(f, "_:{}", label),
+      Self::Literal {
+        value,
+        datatype,
+        language,
+      } => {
+        write!(f, "\"{}\"", escape(value))?;
+
+        if let Some(language) = language {
+          write!(f, "@{}", language)
+        } else if datatype != XSD_STRING {
+          write!(f, "^^<{}>", datatype)
+        } else {
+          Ok(())
+        }
+      }
+    }
+  }
+}
+
+/// A statement in an RDF dataset.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Quad {
+  /// The subject of the statement.
+  pub subject: Term,
+  /// The predicate of the statement.
+  pub predicate: Term,
+  /// The object of the statement.
+  pub object: Term,
+  /// The name of the graph containing the statement, `None` for the default
+  /// graph.
+  pub graph: Option<Term>,
+}
+
+impl Display for Quad {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    write!(f, "{} {} {} ", self.subject, self.predicate, self.object)?;
+
+    if let Some(graph) = self.graph.as_ref() {
+      write!(f, "{} ", graph)?;
+    }
+
+    f.write_str(".")
+  }
+}
+
+/// A collection of RDF [quads][`Quad`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Dataset(Vec<Quad>);
+
+impl Dataset {
+  /// Creates a new empty `Dataset`.
+  pub const fn new() -> Self {
+    Self(Vec::new())
+  }
+
+  /// Converts an expanded JSON-LD document to a `Dataset`.
+  ///
+  /// See the [Deserialize JSON-LD to RDF Algorithm][SPEC] for details.
+  ///
+  /// # Errors
+  ///
+  /// Fails if `document` is not in expanded form.
+  ///
+  /// [SPEC]: https://www.w3.org/TR/json-ld11-api/#deserialize-json-ld-to-rdf-algorithm
+  pub fn from_expanded(document: &Value) -> Result<Self> {
+    to_rdf(document)
+  }
+
+  /// Parses a `Dataset` from a string of [N-Quads][SPEC].
+  ///
+  /// # Errors
+  ///
+  /// Fails if `input` is not a valid N-Quads document.
+  ///
+  /// [SPEC]: https://www.w3.org/TR/n-quads/
+  pub fn from_nquads(input: &str) -> Result<Self> {
+    input
+      .lines()
+      .filter_map(|line| parse_quad(line).transpose())
+      .collect::<Result<_>>()
+      .map(Self)
+  }
+
+  /// Serializes the `Dataset` as N-Quads, one quad per line.
+  pub fn to_nquads(&self) -> String {
+    self.0.iter().map(|quad| format!("{}\n", quad)).collect()
+  }
+
+  /// Returns the quads of the `Dataset`.
+  pub fn quads(&self) -> &[Quad] {
+    &self.0
+  }
+
+  /// Returns the number of quads in the `Dataset`.
+  pub fn len(&self) -> usize {
+    self.0.len()
+  }
+
+  /// Returns `true` if the `Dataset` contains no quads.
+  pub fn is_empty(&self) -> bool {
+    self.0.is_empty()
+  }
+
+  /// Adds a new quad to the `Dataset`.
+  pub fn push(&mut self, quad: Quad) {
+    self.0.push(quad);
+  }
+}
+
+// =============================================================================
+// N-Quads
+// =============================================================================
+
+fn parse_quad(line: &str) -> Result<Option<Quad>> {
+  let mut input: &str = line.trim();
+
+  if input.is_empty() || input.starts_with('#') {
+    return Ok(None);
+  }
+
+  let subject: Term = parse_term(&mut input)?;
+  let predicate: Term = parse_term(&mut input)?;
+  let object: Term = parse_term(&mut input)?;
+
+  input = input.trim_start();
+
+  let graph: Option<Term> = if input.starts_with('.') {
+    None
+  } else {
+    Some(parse_term(&mut input)?)
+  };
+
+  let input: &str = input
+    .trim_start()
+    .strip_prefix('.')
+    .ok_or(Error::InvalidNQuads("expected '.'"))?
+    .trim();
+
+  if !input.is_empty() && !input.starts_with('#') {
+    return Err(Error::InvalidNQuads("unexpected trailing input"));
+  }
+
+  Ok(Some(Quad {
+    subject,
+    predicate,
+    object,
+    graph,
+  }))
+}
+
+fn parse_term(input: &mut &str) -> Result<Term> {
+  let trimmed: &str = input.trim_start();
+
+  if let Some(rest) = trimmed.strip_prefix('<') {
+    let end: usize = rest.find('>').ok_or(Error::InvalidNQuads("unterminated IRI"))?;
+
+    *input = &rest[end + 1..];
+
+    Ok(Term::Iri(unescape(&rest[..end])?))
+  } else if let Some(rest) = trimmed.strip_prefix("_:") {
+    let end: usize = rest.find(char::is_whitespace).unwrap_or_else(|| rest.len());
+
+    *input = &rest[end..];
+
+    Ok(Term::BlankNode(rest[..end].to_string()))
+  } else if let Some(rest) = trimmed.strip_prefix('"') {
+    let mut escaped: bool = false;
+    let mut end: Option<usize> = None;
+
+    for (index, char) in rest.char_indices() {
+      match char {
+        _ if escaped => escaped = false,
+        '\\' => escaped = true,
+        '"' => {
+          end = Some(index);
+          break;
+        }
+        _ => {}
+      }
+    }
+
+    let end: usize = end.ok_or(Error::InvalidNQuads("unterminated literal"))?;
+    let value: String = unescape(&rest[..end])?;
+    let mut rest: &str = &rest[end + 1..];
+
+    let (datatype, language): (String, Option<String>) = if let Some(mut tail) = rest.strip_prefix("^^") {
+      match parse_term(&mut tail)? {
+        Term::Iri(datatype) => {
+          rest = tail;
+          (datatype, None)
+        }
+        _ => return Err(Error::InvalidNQuads("invalid datatype")),
+      }
+    } else if let Some(tail) = rest.strip_prefix('@') {
+      let end: usize = tail.find(char::is_whitespace).unwrap_or_else(|| tail.len());
+
+      rest = &tail[end..];
+
+      (RDF_LANGSTRING.to_string(), Some(tail[..end].to_string()))
+    } else {
+      (XSD_STRING.to_string(), None)
+    };
+
+    *input = rest;
+
+    Ok(Term::Literal {
+      value,
+      datatype,
+      language,
+    })
+  } else {
+    Err(Error::InvalidNQuads("invalid term"))
+  }
+}
+
+fn escape(value: &str) -> String {
+  let mut output: String = String::with_capacity(value.len());
+
+  for char in value.chars() {
+    match char {
+      '"' => output.push_str("\\\""),
+      '\\' => output.push_str("\\\\"),
+      '\n' => output.push_str("\\n"),
+      '\r' => output.push_str("\\r"),
+      char => output.push(char),
+    }
+  }
+
+  output
+}
+
+fn unescape(value: &str) -> Result<String> {
+  let mut output: String = String::with_capacity(value.len());
+  let mut chars = value.chars();
+
+  while let Some(char) = chars.next() {
+    if char != '\\' {
+      output.push(char);
+      continue;
+    }
+
+    let char: char = match chars.next() {
+      Some('t') => '\t',
+      Some('b') => '\u{8}',
+      Some('n') => '\n',
+      Some('r') => '\r',
+      Some('f') => '\u{c}',
+      Some('"') => '"',
+      Some('\'') => '\'',
+      Some('\\') => '\\',
+      Some('u') => unescape_code(&mut chars, 4)?,
+      Some('U') => unescape_code(&mut chars, 8)?,
+      _ => return Err(Error::InvalidNQuads("invalid escape sequence")),
+    };
+
+    output.push(char);
+  }
+
+  Ok(output)
+}
+
+fn unescape_code(chars: &mut impl Iterator<Item = char>, length: usize) -> Result<char> {
+  let code: String = chars.take(length).collect();
+
+  if code.len() != length {
+    return Err(Error::InvalidNQuads("invalid escape sequence"));
+  }
+
+  u32::from_str_radix(&code, 16)
+    .ok()
+    .and_then(core::char::from_u32)
+    .ok_or(Error::InvalidNQuads("invalid escape sequence"))
+}
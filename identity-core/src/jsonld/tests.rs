@@ -0,0 +1,385 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::common::Value;
+use crate::convert::FromJson;
+use crate::error::Error;
+use crate::error::Result;
+use crate::json;
+use crate::jsonld::canonicalize;
+use crate::jsonld::compact;
+use crate::jsonld::expand;
+use crate::jsonld::normalize;
+use crate::jsonld::undefined_terms;
+use crate::jsonld::BundledLoader;
+use crate::jsonld::CachedLoader;
+use crate::jsonld::Dataset;
+use crate::jsonld::DocumentLoader;
+
+struct TestVector {
+  name: &'static str,
+  input: &'static str,
+  output: &'static str,
+}
+
+struct CompactionVector {
+  name: &'static str,
+  input: &'static str,
+  context: &'static str,
+  output: &'static str,
+}
+
+const TVS: &[TestVector] = &include!("../../tests/fixtures/urdna2015.rs");
+
+const COMPACTION_TVS: &[CompactionVector] = &include!("../../tests/fixtures/compaction.rs");
+
+struct EmptyLoader;
+
+impl DocumentLoader for EmptyLoader {
+  fn load(&self, url: &str) -> Result<Value> {
+    Err(Error::LoadContext(url.to_string()))
+  }
+}
+
+fn person() -> Value {
+  json!({
+    "@context": {
+      "@vocab": "http://example.org/vocab#",
+      "ex": "http://example.org/",
+      "knows": { "@id": "ex:knows", "@type": "@id" },
+      "tags": { "@id": "ex:tags", "@container": "@list" },
+      "label": { "@id": "ex:label", "@language": "en" },
+    },
+    "@id": "ex:alice",
+    "@type": "Person",
+    "knows": "ex:bob",
+    "tags": ["a", "b"],
+    "label": "Alice",
+    "age": 42,
+    "height": 1.5,
+  })
+}
+
+fn credential() -> Value {
+  json!({
+    "@context": "https://www.w3.org/2018/credentials/v1",
+    "id": "http://example.edu/credentials/3732",
+    "type": ["VerifiableCredential"],
+    "issuer": "did:example:issuer",
+    "issuanceDate": "2010-01-01T19:23:24Z",
+    "credentialSubject": { "id": "did:example:subject" },
+  })
+}
+
+#[test]
+fn test_urdna2015_tvs() {
+  for tv in TVS {
+    let dataset: Dataset = Dataset::from_nquads(tv.input).unwrap();
+
+    assert_eq!(canonicalize(&dataset).unwrap(), tv.output.trim_start(), "{}", tv.name);
+  }
+}
+
+#[test]
+fn test_urdna2015_relabel() {
+  // Isomorphic datasets produce identical output regardless of blank node labels.
+  let a: Dataset = Dataset::from_nquads(TVS[1].input).unwrap();
+  let b: Dataset = Dataset::from_nquads(
+    &TVS[1]
+      .input
+      .replace("_:x", "_:tmp")
+      .replace("_:y", "_:x")
+      .replace("_:tmp", "_:y"),
+  )
+  .unwrap();
+
+  assert_eq!(canonicalize(&a).unwrap(), canonicalize(&b).unwrap());
+}
+
+#[test]
+fn test_urdna2015_limits() {
+  let blank = |index: usize| format!("_:n{}", index);
+
+  // A clique of indistinguishable blank nodes requires an exhaustive search
+  let clique: String = (0..10)
+    .flat_map(|a| (0..10).filter(move |b| *b != a).map(move |b| (a, b)))
+    .map(|(a, b)| format!("{} <http://example.org/vocab#p> {} .\n", blank(a), blank(b)))
+    .collect();
+
+  assert!(matches!(
+    canonicalize(&Dataset::from_nquads(&clique).unwrap()),
+    Err(Error::CanonicalizationLimit)
+  ));
+
+  // A long cycle of indistinguishable blank nodes recurses for every node
+  let cycle: String = (0..64)
+    .map(|index| {
+      format!(
+        "{} <http://example.org/vocab#next> {} .\n",
+        blank(index),
+        blank((index + 1) % 64)
+      )
+    })
+    .collect();
+
+  assert!(matches!(
+    canonicalize(&Dataset::from_nquads(&cycle).unwrap()),
+    Err(Error::CanonicalizationLimit)
+  ));
+}
+
+#[test]
+fn test_nquads_roundtrip() {
+  let dataset: Dataset = Dataset::from_nquads(TVS[2].input).unwrap();
+
+  assert_eq!(dataset.len(), 4);
+  assert_eq!(Dataset::from_nquads(&dataset.to_nquads()).unwrap(), dataset);
+
+  assert!(Dataset::from_nquads("<http://example.org/s> <http://example.org/p> .").is_err());
+  assert!(Dataset::from_nquads("<http://example.org/s> <http://example.org/p> \"o .").is_err());
+}
+
+#[test]
+fn test_expand() {
+  let expanded: Value = expand(&person(), &EmptyLoader).unwrap();
+
+  assert_eq!(
+    expanded,
+    json!([{
+      "@id": "http://example.org/alice",
+      "@type": ["http://example.org/vocab#Person"],
+      "http://example.org/vocab#age": [{ "@value": 42 }],
+      "http://example.org/vocab#height": [{ "@value": 1.5 }],
+      "http://example.org/knows": [{ "@id": "http://example.org/bob" }],
+      "http://example.org/label": [{ "@value": "Alice", "@language": "en" }],
+      "http://example.org/tags": [{ "@list": [{ "@value": "a" }, { "@value": "b" }] }],
+    }])
+  );
+}
+
+#[test]
+fn test_normalize() {
+  let normalized: String = normalize(&person(), &EmptyLoader).unwrap();
+
+  assert_eq!(
+    normalized,
+    r#"<http://example.org/alice> <http://example.org/knows> <http://example.org/bob> .
+<http://example.org/alice> <http://example.org/label> "Alice"@en .
+<http://example.org/alice> <http://example.org/tags> _:c14n1 .
+<http://example.org/alice> <http://example.org/vocab#age> "42"^^<http://www.w3.org/2001/XMLSchema#integer> .
+<http://example.org/alice> <http://example.org/vocab#height> "1.5E0"^^<http://www.w3.org/2001/XMLSchema#double> .
+<http://example.org/alice> <http://www.w3.org/1999/02/22-rdf-syntax-ns#type> <http://example.org/vocab#Person> .
+_:c14n0 <http://www.w3.org/1999/02/22-rdf-syntax-ns#first> "b" .
+_:c14n0 <http://www.w3.org/1999/02/22-rdf-syntax-ns#rest> <http://www.w3.org/1999/02/22-rdf-syntax-ns#nil> .
+_:c14n1 <http://www.w3.org/1999/02/22-rdf-syntax-ns#first> "a" .
+_:c14n1 <http://www.w3.org/1999/02/22-rdf-syntax-ns#rest> _:c14n0 .
+"#
+  );
+}
+
+#[test]
+fn test_normalize_credential() {
+  let normalized: String = normalize(&credential(), &BundledLoader).unwrap();
+
+  assert_eq!(
+    normalized,
+    r#"<http://example.edu/credentials/3732> <http://www.w3.org/1999/02/22-rdf-syntax-ns#type> <https://www.w3.org/2018/credentials#VerifiableCredential> .
+<http://example.edu/credentials/3732> <https://www.w3.org/2018/credentials#credentialSubject> <did:example:subject> .
+<http://example.edu/credentials/3732> <https://www.w3.org/2018/credentials#issuanceDate> "2010-01-01T19:23:24Z"^^<http://www.w3.org/2001/XMLSchema#dateTime> .
+<http://example.edu/credentials/3732> <https://www.w3.org/2018/credentials#issuer> <did:example:issuer> .
+"#
+  );
+}
+
+#[test]
+fn test_normalize_credential_proof() {
+  let mut credential: Value = credential();
+
+  credential["proof"] = json!({
+    "type": "Ed25519Signature2018",
+    "created": "2021-01-01T00:00:00Z",
+    "verificationMethod": "did:example:issuer#key-1",
+    "proofPurpose": "assertionMethod",
+    "jws": "abc",
+  });
+
+  let normalized: String = normalize(&credential, &BundledLoader).unwrap();
+
+  // The proof is stored in a separate named graph.
+  assert!(normalized.contains(
+    r#"<http://example.edu/credentials/3732> <https://w3id.org/security#proof> _:c14n1 .
+_:c14n0 <http://purl.org/dc/terms/created> "2021-01-01T00:00:00Z"^^<http://www.w3.org/2001/XMLSchema#dateTime> _:c14n1 .
+_:c14n0 <http://www.w3.org/1999/02/22-rdf-syntax-ns#type> <https://w3id.org/security#Ed25519Signature2018> _:c14n1 .
+_:c14n0 <https://w3id.org/security#jws> "abc" _:c14n1 .
+_:c14n0 <https://w3id.org/security#proofPurpose> <https://w3id.org/security#assertionMethod> _:c14n1 .
+_:c14n0 <https://w3id.org/security#verificationMethod> <did:example:issuer#key-1> _:c14n1 .
+"#
+  ));
+}
+
+#[test]
+fn test_protected_term_redefinition() {
+  let mut credential: Value = credential();
+
+  credential["@context"] = json!([
+    "https://www.w3.org/2018/credentials/v1",
+    { "VerifiableCredential": "http://example.org/vocab#VerifiableCredential" },
+  ]);
+
+  assert!(normalize(&credential, &BundledLoader).is_err());
+}
+
+#[test]
+fn test_normalize_json_literal() {
+  let document: Value = json!({
+    "@context": BundledLoader::JWS_2020_V1,
+    "id": "did:example:issuer#key-1",
+    "type": "JsonWebKey2020",
+    "publicKeyJwk": { "x": "11qYAYKxCrfVS_7TyWQHOg7hcvPapiMlrwIaaPcHURo", "kty": "OKP", "crv": "Ed25519" },
+  });
+
+  assert!(undefined_terms(&document, &BundledLoader).unwrap().is_empty());
+
+  let normalized: String = normalize(&document, &BundledLoader).unwrap();
+
+  assert_eq!(
+    normalized,
+    r#"<did:example:issuer#key-1> <http://www.w3.org/1999/02/22-rdf-syntax-ns#type> <https://w3id.org/security#JsonWebKey2020> .
+<did:example:issuer#key-1> <https://w3id.org/security#publicKeyJwk> "{\"crv\":\"Ed25519\",\"kty\":\"OKP\",\"x\":\"11qYAYKxCrfVS_7TyWQHOg7hcvPapiMlrwIaaPcHURo\"}"^^<http://www.w3.org/1999/02/22-rdf-syntax-ns#JSON> .
+"#
+  );
+}
+
+#[test]
+fn test_unknown_context() {
+  let mut credential: Value = credential();
+
+  credential["@context"] = json!("https://example.org/unknown/v1");
+
+  assert!(matches!(
+    normalize(&credential, &BundledLoader),
+    Err(Error::LoadContext(_))
+  ));
+}
+
+#[test]
+fn test_compact() {
+  let document: Value = person();
+  let compacted: Value = compact(&document, &document["@context"], &EmptyLoader).unwrap();
+
+  assert_eq!(compacted, document);
+}
+
+#[test]
+fn test_compact_tvs() {
+  for tv in COMPACTION_TVS {
+    let input: Value = Value::from_json(tv.input).unwrap();
+    let context: Value = Value::from_json(tv.context).unwrap();
+    let output: Value = Value::from_json(tv.output).unwrap();
+
+    assert_eq!(compact(&input, &context, &EmptyLoader).unwrap(), output, "{}", tv.name);
+  }
+}
+
+#[test]
+fn test_compact_credential() {
+  let mut credential: Value = credential();
+
+  credential["proof"] = json!({
+    "type": "Ed25519Signature2018",
+    "created": "2021-01-01T00:00:00Z",
+    "verificationMethod": "did:example:issuer#key-1",
+    "proofPurpose": "assertionMethod",
+    "jws": "abc",
+  });
+
+  let compacted: Value = compact(&credential, &credential["@context"], &BundledLoader).unwrap();
+
+  assert_eq!(compacted["@context"], credential["@context"]);
+  assert_eq!(compacted["type"], "VerifiableCredential");
+  assert_eq!(compacted["issuanceDate"], "2010-01-01T19:23:24Z");
+  assert_eq!(compacted["credentialSubject"], "did:example:subject");
+  assert_eq!(compacted["proof"], credential["proof"]);
+
+  assert_eq!(
+    expand(&compacted, &BundledLoader).unwrap(),
+    expand(&credential, &BundledLoader).unwrap()
+  );
+}
+
+#[test]
+fn test_undefined_terms() {
+  let mut credential: Value = credential();
+
+  assert!(undefined_terms(&credential, &BundledLoader).unwrap().is_empty());
+
+  credential["type"] = json!(["VerifiableCredential", "UniversityDegreeCredential"]);
+  credential["credentialSubject"]["degree"] = json!({ "type": "BachelorDegree" });
+
+  assert_eq!(
+    undefined_terms(&credential, &BundledLoader).unwrap(),
+    ["UniversityDegreeCredential", "degree"]
+  );
+}
+
+#[test]
+fn test_cached_loader() {
+  let mut loader: CachedLoader = CachedLoader::new();
+  let mut credential: Value = credential();
+
+  credential["@context"] = json!([
+    "https://www.w3.org/2018/credentials/v1",
+    "https://example.org/degree/v1",
+  ]);
+
+  credential["type"] = json!(["VerifiableCredential", "UniversityDegreeCredential"]);
+
+  // Unknown contexts are not fetched
+  assert!(matches!(
+    undefined_terms(&credential, &loader),
+    Err(Error::LoadContext(_))
+  ));
+
+  // Registered contexts must be context documents
+  assert!(loader
+    .insert("https://example.org/degree/v1", json!({ "ex": "https://example.org/" }))
+    .is_err());
+
+  loader
+    .insert(
+      "https://example.org/degree/v1",
+      json!({
+        "@context": {
+          "UniversityDegreeCredential": "https://example.org/UniversityDegreeCredential",
+        }
+      }),
+    )
+    .unwrap();
+
+  assert!(undefined_terms(&credential, &loader).unwrap().is_empty());
+  assert!(loader.remove("https://example.org/degree/v1").is_some());
+}
+
+#[test]
+fn test_bundled_did_contexts() {
+  let document: Value = json!({
+    "@context": ["https://www.w3.org/ns/did/v1", "https://w3id.org/security/v2"],
+    "id": "did:example:123",
+    "verificationMethod": [{
+      "id": "did:example:123#key-1",
+      "type": "Ed25519VerificationKey2018",
+      "controller": "did:example:123",
+      "publicKeyBase58": "H3C2AVvLMv6gmMNam3uVAjZpfkcJCwDwnZn6z3wXmqPV",
+    }],
+    "authentication": ["did:example:123#key-1"],
+  });
+
+  assert!(undefined_terms(&document, &BundledLoader).unwrap().is_empty());
+
+  let compacted: Value = compact(&document, &document["@context"], &BundledLoader).unwrap();
+
+  assert_eq!(
+    expand(&compacted, &BundledLoader).unwrap(),
+    expand(&document, &BundledLoader).unwrap()
+  );
+}
@@ -0,0 +1,459 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use serde_json::Number;
+use std::collections::BTreeMap;
+
+use crate::common::Value;
+use crate::convert::ToJson;
+use crate::error::Error;
+use crate::error::Result;
+use crate::jsonld::context::is_absolute_iri;
+use crate::jsonld::context::is_blank_node;
+use crate::jsonld::context::is_keyword;
+use crate::jsonld::expansion::is_list;
+use crate::jsonld::expansion::is_node;
+use crate::jsonld::expansion::is_value;
+use crate::jsonld::issuer::IdentifierIssuer;
+use crate::jsonld::rdf::Dataset;
+use crate::jsonld::rdf::Quad;
+use crate::jsonld::rdf::Term;
+use crate::jsonld::rdf::RDF_FIRST;
+use crate::jsonld::rdf::RDF_JSON;
+use crate::jsonld::rdf::RDF_LANGSTRING;
+use crate::jsonld::rdf::RDF_NIL;
+use crate::jsonld::rdf::RDF_REST;
+use crate::jsonld::rdf::RDF_TYPE;
+use crate::jsonld::rdf::XSD_BOOLEAN;
+use crate::jsonld::rdf::XSD_DOUBLE;
+use crate::jsonld::rdf::XSD_INTEGER;
+use crate::jsonld::rdf::XSD_STRING;
+
+type Map = serde_json::Map<String, Value>;
+
+type NodeMap = BTreeMap<String, BTreeMap<String, Map>>;
+
+const DEFAULT_GRAPH: &str = "@default";
+
+/// Converts an expanded JSON-LD document to an RDF dataset.
+pub(crate) fn to_rdf(document: &Value) -> Result<Dataset> {
+  let mut state: State = State {
+    issuer: IdentifierIssuer::new("_:b"),
+    nodes: NodeMap::new(),
+  };
+
+  state.nodes.insert(DEFAULT_GRAPH.to_string(), BTreeMap::new());
+  state.map_element(document, DEFAULT_GRAPH, None, None)?;
+
+  let nodes: NodeMap = core::mem::take(&mut state.nodes);
+  let mut dataset: Dataset = Dataset::new();
+
+  for (graph_name, graph) in nodes.iter() {
+    let graph_term: Option<Term> = if graph_name == DEFAULT_GRAPH {
+      None
+    } else {
+      match to_term(graph_name) {
+        Some(term) => Some(term),
+        None => continue,
+      }
+    };
+
+    for (subject, node) in graph.iter() {
+      let subject: Term = match to_term(subject) {
+        Some(subject) => subject,
+        None => continue,
+      };
+
+      for (property, values) in node.iter() {
+        let predicate: Term = if property == "@type" {
+          Term::Iri(RDF_TYPE.to_string())
+        } else if is_keyword(property) {
+          continue;
+        } else {
+          match to_term(property) {
+            Some(Term::Iri(iri)) => Term::Iri(iri),
+            _ => continue,
+          }
+        };
+
+        for item in values.as_array().map(Vec::as_slice).unwrap_or_default() {
+          let object: Option<Term> = if property == "@type" {
+            item.as_str().and_then(to_term)
+          } else {
+            state.object_to_rdf(item, &graph_term, &mut dataset)?
+          };
+
+          if let Some(object) = object {
+            dataset.push(Quad {
+              subject: subject.clone(),
+              predicate: predicate.clone(),
+              object,
+              graph: graph_term.clone(),
+            });
+          }
+        }
+      }
+    }
+  }
+
+  Ok(dataset)
+}
+
+struct State {
+  issuer: IdentifierIssuer,
+  nodes: NodeMap,
+}
+
+impl State {
+  /// Flattens `element` into the node map.
+  ///
+  /// See the [Node Map Generation Algorithm][SPEC] for details.
+  ///
+  /// [SPEC]: https://www.w3.org/TR/json-ld11-api/#node-map-generation
+  fn map_element(
+    &mut self,
+    element: &Value,
+    graph: &str,
+    name: Option<String>,
+    mut list: Option<&mut Vec<Value>>,
+  ) -> Result<()> {
+    let object: &Map = match element {
+      Value::Array(items) => {
+        for item in items {
+          self.map_element(item, graph, None, list.as_mut().map(|list| &mut **list))?;
+        }
+
+        return Ok(());
+      }
+      Value::Object(object) => object,
+      _ => return Err(Error::InvalidJsonLd("expected expanded document")),
+    };
+
+    if is_value(element) {
+      if let Some(list) = list {
+        list.push(self.rename_value(element));
+      }
+
+      return Ok(());
+    }
+
+    if let (Some(list), Some(items)) = (list.as_mut(), object.get("@list")) {
+      let mut inner: Vec<Value> = Vec::new();
+
+      self.map_element(items, graph, None, Some(&mut inner))?;
+
+      list.push(single("@list", Value::Array(inner)));
+
+      return Ok(());
+    }
+
+    for type_ in object.get("@type").and_then(Value::as_array).into_iter().flatten() {
+      if let Some(type_) = type_.as_str().filter(|type_| is_blank_node(type_)) {
+        self.issuer.issue(type_);
+      }
+    }
+
+    let name: String = match name {
+      Some(name) => name,
+      None => self.node_name(element),
+    };
+
+    if let Some(list) = list {
+      list.push(single("@id", Value::String(name.clone())));
+    }
+
+    self.node(graph, &name);
+
+    let mut properties: Vec<(&String, &Value)> = object.iter().collect();
+
+    properties.sort_unstable_by(|a, b| a.0.cmp(b.0));
+
+    for (property, values) in properties {
+      match property.as_str() {
+        "@id" => continue,
+        "@reverse" => {
+          let referenced: Value = single("@id", Value::String(name.clone()));
+
+          for (property, items) in values.as_object().into_iter().flatten() {
+            for item in items.as_array().into_iter().flatten() {
+              let item_name: String = self.node_name(item);
+
+              self.map_element(item, graph, Some(item_name.clone()), None)?;
+
+              add_value(self.node(graph, &item_name), property, referenced.clone());
+            }
+          }
+
+          continue;
+        }
+        "@graph" => {
+          self.nodes.entry(name.clone()).or_default();
+          self.map_element(values, &name, None, None)?;
+
+          continue;
+        }
+        "@included" => {
+          self.map_element(values, graph, None, None)?;
+
+          continue;
+        }
+        "@type" => {}
+        keyword if is_keyword(keyword) => {
+          self.node(graph, &name).insert(keyword.to_string(), values.clone());
+
+          continue;
+        }
+        _ => {}
+      }
+
+      let property: String = if is_blank_node(property) {
+        self.issuer.issue(property)
+      } else {
+        property.clone()
+      };
+
+      let values: &[Value] = values.as_array().map(Vec::as_slice).unwrap_or_default();
+
+      if values.is_empty() {
+        self
+          .node(graph, &name)
+          .entry(property)
+          .or_insert_with(|| Value::Array(Vec::new()));
+
+        continue;
+      }
+
+      for value in values {
+        if property == "@type" {
+          let type_: &str = value.as_str().ok_or(Error::InvalidJsonLd("invalid type value"))?;
+
+          let type_: String = if is_blank_node(type_) {
+            self.issuer.issue(type_)
+          } else {
+            type_.to_string()
+          };
+
+          add_value(self.node(graph, &name), &property, Value::String(type_));
+        } else if is_node(value) {
+          if value.get("@id").map_or(false, Value::is_null) {
+            continue;
+          }
+
+          let id: String = self.node_name(value);
+
+          add_value(
+            self.node(graph, &name),
+            &property,
+            single("@id", Value::String(id.clone())),
+          );
+
+          self.map_element(value, graph, Some(id), None)?;
+        } else if let Some(items) = value.get("@list") {
+          let mut inner: Vec<Value> = Vec::new();
+
+          self.map_element(items, graph, None, Some(&mut inner))?;
+
+          let entry: &mut Value = self
+            .node(graph, &name)
+            .entry(property.clone())
+            .or_insert_with(|| Value::Array(Vec::new()));
+
+          if let Value::Array(entry) = entry {
+            entry.push(single("@list", Value::Array(inner)));
+          }
+        } else {
+          let value: Value = self.rename_value(value);
+
+          add_value(self.node(graph, &name), &property, value);
+        }
+      }
+    }
+
+    Ok(())
+  }
+
+  /// Returns the node with the given `name`, creating it if needed.
+  fn node(&mut self, graph: &str, name: &str) -> &mut Map {
+    self
+      .nodes
+      .entry(graph.to_string())
+      .or_default()
+      .entry(name.to_string())
+      .or_insert_with(|| single_map("@id", Value::String(name.to_string())))
+  }
+
+  /// Returns the identifier of a node object, relabeling blank nodes.
+  fn node_name(&mut self, node: &Value) -> String {
+    match node.get("@id").and_then(Value::as_str) {
+      Some(id) if is_blank_node(id) => self.issuer.issue(id),
+      Some(id) => id.to_string(),
+      None => self.issuer.fresh(),
+    }
+  }
+
+  /// Relabels a blank node datatype of a value object.
+  fn rename_value(&mut self, value: &Value) -> Value {
+    let mut value: Value = value.clone();
+
+    if let Some(Value::String(type_)) = value.get_mut("@type") {
+      if is_blank_node(type_) {
+        *type_ = self.issuer.issue(type_);
+      }
+    }
+
+    value
+  }
+
+  fn object_to_rdf(&mut self, item: &Value, graph: &Option<Term>, dataset: &mut Dataset) -> Result<Option<Term>> {
+    if is_value(item) {
+      return value_to_rdf(item).map(Some);
+    }
+
+    if let Some(items) = item.get("@list") {
+      let items: &[Value] = items.as_array().map(Vec::as_slice).unwrap_or_default();
+
+      return self.list_to_rdf(items, graph, dataset).map(Some);
+    }
+
+    Ok(item.get("@id").and_then(Value::as_str).and_then(to_term))
+  }
+
+  fn list_to_rdf(&mut self, items: &[Value], graph: &Option<Term>, dataset: &mut Dataset) -> Result<Term> {
+    if items.is_empty() {
+      return Ok(Term::Iri(RDF_NIL.to_string()));
+    }
+
+    let nodes: Vec<Term> = items
+      .iter()
+      .map(|_| Term::BlankNode(self.issuer.fresh()[2..].to_string()))
+      .collect();
+
+    for (index, item) in items.iter().enumerate() {
+      if let Some(object) = self.object_to_rdf(item, graph, dataset)? {
+        dataset.push(Quad {
+          subject: nodes[index].clone(),
+          predicate: Term::Iri(RDF_FIRST.to_string()),
+          object,
+          graph: graph.clone(),
+        });
+      }
+
+      let rest: Term = nodes
+        .get(index + 1)
+        .cloned()
+        .unwrap_or_else(|| Term::Iri(RDF_NIL.to_string()));
+
+      dataset.push(Quad {
+        subject: nodes[index].clone(),
+        predicate: Term::Iri(RDF_REST.to_string()),
+        object: rest,
+        graph: graph.clone(),
+      });
+    }
+
+    Ok(nodes[0].clone())
+  }
+}
+
+/// Converts a value object to an RDF literal.
+fn value_to_rdf(item: &Value) -> Result<Term> {
+  let datatype: Option<&str> = item.get("@type").and_then(Value::as_str);
+  let language: Option<&str> = item.get("@language").and_then(Value::as_str);
+
+  // JSON literals are serialized in their canonical (JCS) form
+  if datatype == Some("@json") {
+    let value: Vec<u8> = item["@value"].to_jcs()?;
+
+    return Ok(Term::Literal {
+      value: String::from_utf8(value).map_err(|_| Error::InvalidJsonLd("invalid json literal"))?,
+      datatype: RDF_JSON.to_string(),
+      language: None,
+    });
+  }
+
+  let (value, datatype): (String, &str) = match &item["@value"] {
+    Value::Bool(value) => (value.to_string(), datatype.unwrap_or(XSD_BOOLEAN)),
+    Value::Number(number) if is_double(number, datatype) => {
+      let value: f64 = number
+        .as_f64()
+        .ok_or(Error::InvalidJsonLd("invalid value object value"))?;
+
+      (canonical_double(value), datatype.unwrap_or(XSD_DOUBLE))
+    }
+    Value::Number(number) => (canonical_integer(number), datatype.unwrap_or(XSD_INTEGER)),
+    Value::String(value) if language.is_some() => (value.clone(), RDF_LANGSTRING),
+    Value::String(value) => (value.clone(), datatype.unwrap_or(XSD_STRING)),
+    _ => return Err(Error::InvalidJsonLd("invalid value object value")),
+  };
+
+  Ok(Term::Literal {
+    value,
+    datatype: datatype.to_string(),
+    language: language.filter(|_| datatype == RDF_LANGSTRING).map(ToString::to_string),
+  })
+}
+
+/// Returns `true` if a number is converted to an `xsd:double` literal.
+fn is_double(number: &Number, datatype: Option<&str>) -> bool {
+  if datatype == Some(XSD_DOUBLE) {
+    return true;
+  }
+
+  match number.as_f64() {
+    Some(value) if number.is_f64() => value.fract() != 0.0 || value.abs() >= 1e21,
+    _ => false,
+  }
+}
+
+/// Formats a number in the canonical lexical form of `xsd:double`.
+fn canonical_double(value: f64) -> String {
+  let formatted: String = format!("{:.15E}", value);
+  let (mantissa, exponent): (&str, &str) = formatted.split_at(formatted.find('E').unwrap_or(formatted.len()));
+  let mantissa: &str = mantissa.trim_end_matches('0');
+
+  if mantissa.ends_with('.') {
+    format!("{}0{}", mantissa, exponent)
+  } else {
+    format!("{}{}", mantissa, exponent)
+  }
+}
+
+/// Formats a number in the canonical lexical form of `xsd:integer`.
+fn canonical_integer(number: &Number) -> String {
+  match number.as_f64() {
+    Some(value) if number.is_f64() => format!("{:.0}", value),
+    _ => number.to_string(),
+  }
+}
+
+/// Converts a node identifier to an RDF term, ignoring relative IRIs.
+fn to_term(id: &str) -> Option<Term> {
+  if is_blank_node(id) {
+    Some(Term::BlankNode(id[2..].to_string()))
+  } else if is_absolute_iri(id) {
+    Some(Term::Iri(id.to_string()))
+  } else {
+    None
+  }
+}
+
+/// Appends `value` to the array at `property`, skipping duplicates.
+fn add_value(node: &mut Map, property: &str, value: Value) {
+  let entry: &mut Value = node.entry(property).or_insert_with(|| Value::Array(Vec::new()));
+
+  if let Value::Array(entry) = entry {
+    if !entry.contains(&value) {
+      entry.push(value);
+    }
+  }
+}
+
+fn single_map(key: &str, value: Value) -> Map {
+  let mut map: Map = Map::new();
+  map.insert(key.to_string(), value);
+  map
+}
+
+fn single(key: &str, value: Value) -> Value {
+  Value::Object(single_map(key, value))
+}
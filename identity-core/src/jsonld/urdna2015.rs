@@ -0,0 +1,344 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::cell::Cell;
+use crypto::hashes::sha::Sha256;
+use crypto::hashes::Digest;
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+
+use crate::error::Error;
+use crate::error::Result;
+use crate::jsonld::issuer::IdentifierIssuer;
+use crate::jsonld::rdf::Dataset;
+use crate::jsonld::rdf::Quad;
+use crate::jsonld::rdf::Term;
+use crate::utils::encode_b16;
+
+/// The maximum number of N-degree hashing steps - recursive calls and
+/// evaluated permutations - spent on a single dataset.
+const MAX_STEPS: usize = 100_000;
+
+/// The maximum recursion depth of the N-degree hashing algorithm.
+const MAX_DEPTH: usize = 32;
+
+/// Canonicalizes an RDF dataset with the [URDNA2015][SPEC] algorithm and
+/// returns the result serialized as canonical N-Quads.
+///
+/// Blank nodes are deterministically relabeled (`_:c14n0`, `_:c14n1`, ...) so
+/// isomorphic datasets produce identical output. Quads are sorted and
+/// duplicates are removed.
+///
+/// # Errors
+///
+/// Blank nodes that can not be distinguished by their direct neighbours are
+/// labeled with an exhaustive search. Fails with
+/// [`CanonicalizationLimit`][`Error::CanonicalizationLimit`] if the search
+/// exceeds its step or recursion budget.
+///
+/// [SPEC]: https://json-ld.github.io/rdf-dataset-canonicalization/spec/
+pub fn canonicalize(dataset: &Dataset) -> Result<String> {
+  let mut state: State<'_> = State::new(dataset);
+
+  state.issue_identifiers()?;
+
+  let lines: BTreeSet<String> = dataset
+    .quads()
+    .iter()
+    .map(|quad| {
+      let quad: Quad = relabel(quad, |label| {
+        let issued: &str = state.canonical.get(label).unwrap_or(label);
+        issued.trim_start_matches("_:").to_string()
+      });
+
+      format!("{}\n", quad)
+    })
+    .collect();
+
+  Ok(lines.into_iter().collect())
+}
+
+struct State<'a> {
+  blank_nodes: BTreeMap<&'a str, Vec<&'a Quad>>,
+  canonical: IdentifierIssuer,
+  steps: Cell<usize>,
+}
+
+impl<'a> State<'a> {
+  fn new(dataset: &'a Dataset) -> Self {
+    let mut blank_nodes: BTreeMap<&'a str, Vec<&'a Quad>> = BTreeMap::new();
+
+    for quad in dataset.quads() {
+      for term in [Some(&quad.subject), Some(&quad.object), quad.graph.as_ref()].iter() {
+        if let Some(label) = term.and_then(Term::as_blank_node) {
+          let quads: &mut Vec<&'a Quad> = blank_nodes.entry(label).or_default();
+
+          // Datasets are sets: duplicate quads and quads that mention the
+          // blank node more than once are only hashed once.
+          if !quads.contains(&quad) {
+            quads.push(quad);
+          }
+        }
+      }
+    }
+
+    Self {
+      blank_nodes,
+      canonical: IdentifierIssuer::new("_:c14n"),
+      steps: Cell::new(0),
+    }
+  }
+
+  fn issue_identifiers(&mut self) -> Result<()> {
+    let mut hashes: BTreeMap<String, Vec<&'a str>> = BTreeMap::new();
+
+    for label in self.blank_nodes.keys().copied() {
+      hashes.entry(self.hash_first_degree(label)).or_default().push(label);
+    }
+
+    // Blank nodes with a unique first-degree hash are labeled first.
+    for labels in hashes.values() {
+      if let [label] = labels.as_slice() {
+        self.canonical.issue(label);
+      }
+    }
+
+    for labels in hashes.values().filter(|labels| labels.len() > 1) {
+      let mut results: Vec<(String, IdentifierIssuer)> = Vec::new();
+
+      for label in labels {
+        if self.canonical.contains(label) {
+          continue;
+        }
+
+        let mut issuer: IdentifierIssuer = IdentifierIssuer::new("_:b");
+
+        issuer.issue(label);
+
+        results.push(self.hash_n_degree(label, issuer, 0)?);
+      }
+
+      results.sort_by(|a, b| a.0.cmp(&b.0));
+
+      for (_, issuer) in results {
+        for label in issuer.order() {
+          self.canonical.issue(label);
+        }
+      }
+    }
+
+    Ok(())
+  }
+
+  fn hash_first_degree(&self, label: &str) -> String {
+    let mut lines: Vec<String> = self.blank_nodes[label]
+      .iter()
+      .map(|quad| {
+        let quad: Quad = relabel(quad, |other| String::from(if other == label { "a" } else { "z" }));
+        format!("{}\n", quad)
+      })
+      .collect();
+
+    lines.sort_unstable();
+
+    sha256(&lines.concat())
+  }
+
+  fn hash_related(&self, related: &str, quad: &Quad, issuer: &IdentifierIssuer, position: char) -> String {
+    let mut input: String = position.to_string();
+
+    if position != 'g' {
+      input.push_str(&quad.predicate.to_string());
+    }
+
+    if let Some(issued) = self.canonical.get(related).or_else(|| issuer.get(related)) {
+      input.push_str(issued);
+    } else {
+      input.push_str(&self.hash_first_degree(related));
+    }
+
+    sha256(&input)
+  }
+
+  fn hash_n_degree(
+    &self,
+    label: &str,
+    mut issuer: IdentifierIssuer,
+    depth: usize,
+  ) -> Result<(String, IdentifierIssuer)> {
+    if depth > MAX_DEPTH {
+      return Err(Error::CanonicalizationLimit);
+    }
+
+    self.step()?;
+
+    let mut hashes: BTreeMap<String, Vec<&'a str>> = BTreeMap::new();
+
+    for quad in self.blank_nodes[label].iter().copied() {
+      let terms: [(Option<&'a Term>, char); 3] = [
+        (Some(&quad.subject), 's'),
+        (Some(&quad.object), 'o'),
+        (quad.graph.as_ref(), 'g'),
+      ];
+
+      for (term, position) in terms.iter() {
+        if let Some(related) = term.and_then(Term::as_blank_node).filter(|related| *related != label) {
+          let hash: String = self.hash_related(related, quad, &issuer, *position);
+
+          hashes.entry(hash).or_default().push(related);
+        }
+      }
+    }
+
+    let mut data: String = String::new();
+
+    for (hash, related) in hashes {
+      data.push_str(&hash);
+
+      let mut chosen_path: String = String::new();
+      let mut chosen_issuer: Option<IdentifierIssuer> = None;
+
+      'permutations: for permutation in Permutations::new(related) {
+        self.step()?;
+
+        let mut issuer_copy: IdentifierIssuer = issuer.clone();
+        let mut path: String = String::new();
+        let mut recursion: Vec<&str> = Vec::new();
+
+        for related in permutation {
+          if let Some(issued) = self.canonical.get(related) {
+            path.push_str(issued);
+          } else {
+            if !issuer_copy.contains(related) {
+              recursion.push(related);
+            }
+
+            path.push_str(&issuer_copy.issue(related));
+          }
+
+          if is_worse(&path, &chosen_path) {
+            continue 'permutations;
+          }
+        }
+
+        for related in recursion {
+          let (hash, result): (String, IdentifierIssuer) =
+            self.hash_n_degree(related, issuer_copy.clone(), depth + 1)?;
+
+          path.push_str(&issuer_copy.issue(related));
+          path.push('<');
+          path.push_str(&hash);
+          path.push('>');
+
+          issuer_copy = result;
+
+          if is_worse(&path, &chosen_path) {
+            continue 'permutations;
+          }
+        }
+
+        if chosen_issuer.is_none() || path < chosen_path {
+          chosen_path = path;
+          chosen_issuer = Some(issuer_copy);
+        }
+      }
+
+      data.push_str(&chosen_path);
+
+      if let Some(chosen_issuer) = chosen_issuer {
+        issuer = chosen_issuer;
+      }
+    }
+
+    Ok((sha256(&data), issuer))
+  }
+
+  /// Consumes one step of the N-degree hashing budget.
+  fn step(&self) -> Result<()> {
+    let steps: usize = self.steps.get() + 1;
+
+    if steps > MAX_STEPS {
+      return Err(Error::CanonicalizationLimit);
+    }
+
+    self.steps.set(steps);
+
+    Ok(())
+  }
+}
+
+/// Returns `true` if `path` can not be lexicographically smaller than `chosen`.
+fn is_worse(path: &str, chosen: &str) -> bool {
+  !chosen.is_empty() && path.len() >= chosen.len() && path > chosen
+}
+
+/// An iterator over all permutations of a list, generated one at a time
+/// with Heap's algorithm.
+struct Permutations<'b> {
+  items: Vec<&'b str>,
+  counters: Vec<usize>,
+  index: usize,
+  started: bool,
+}
+
+impl<'b> Permutations<'b> {
+  fn new(items: Vec<&'b str>) -> Self {
+    Self {
+      counters: vec![0; items.len()],
+      items,
+      index: 1,
+      started: false,
+    }
+  }
+}
+
+impl<'b> Iterator for Permutations<'b> {
+  type Item = Vec<&'b str>;
+
+  fn next(&mut self) -> Option<Self::Item> {
+    if !self.started {
+      self.started = true;
+      return Some(self.items.clone());
+    }
+
+    while self.index < self.items.len() {
+      if self.counters[self.index] < self.index {
+        let swap: usize = if self.index % 2 == 0 {
+          0
+        } else {
+          self.counters[self.index]
+        };
+
+        self.items.swap(swap, self.index);
+        self.counters[self.index] += 1;
+        self.index = 1;
+
+        return Some(self.items.clone());
+      }
+
+      self.counters[self.index] = 0;
+      self.index += 1;
+    }
+
+    None
+  }
+}
+
+/// Returns a copy of `quad` with every blank node relabeled by `f`.
+fn relabel(quad: &Quad, f: impl Fn(&str) -> String) -> Quad {
+  let relabel_term = |term: &Term| match term {
+    Term::BlankNode(label) => Term::BlankNode(f(label)),
+    term => term.clone(),
+  };
+
+  Quad {
+    subject: relabel_term(&quad.subject),
+    predicate: quad.predicate.clone(),
+    object: relabel_term(&quad.object),
+    graph: quad.graph.as_ref().map(relabel_term),
+  }
+}
+
+fn sha256(input: &str) -> String {
+  encode_b16(&Sha256::digest(input.as_bytes()))
+}
@@ -0,0 +1,27 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use bbs::prelude::DeterministicPublicKey;
+use bbs::prelude::SecretKey as BbsSecretKey;
+
+use crate::crypto::PublicKey;
+use crate::crypto::SecretKey;
+use crate::error::Result;
+
+/// Generates a new pair of public/secret BLS12-381 G2 keys.
+///
+/// The public key is the compressed G2 point of a BBS+ deterministic public
+/// key which can be expanded for any number of messages.
+pub fn generate_bls12381g2_keypair() -> Result<(PublicKey, SecretKey)> {
+  let (public, secret): (DeterministicPublicKey, BbsSecretKey) = DeterministicPublicKey::new(None);
+
+  let secret: SecretKey = secret.to_bytes_compressed_form().to_vec().into();
+  let public: PublicKey = public.to_bytes_compressed_form().to_vec().into();
+
+  Ok((public, secret))
+}
+
+/// Generates a list of public/secret BLS12-381 G2 keys.
+pub fn generate_bls12381g2_keypairs(count: usize) -> Result<Vec<(PublicKey, SecretKey)>> {
+  (0..count).map(|_| generate_bls12381g2_keypair()).collect()
+}
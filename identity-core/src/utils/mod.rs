@@ -4,7 +4,19 @@
 //! Misc. utility functions.
 
 mod base_encoding;
+mod bls12381;
 mod ed25519;
+mod p256;
+mod random;
+mod sec1;
+mod secp256k1;
+mod x25519;
 
 pub use self::base_encoding::*;
+pub use self::bls12381::*;
 pub use self::ed25519::*;
+pub use self::p256::*;
+pub use self::random::*;
+pub use self::sec1::*;
+pub use self::secp256k1::*;
+pub use self::x25519::*;
@@ -0,0 +1,30 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crypto::signatures::p256;
+
+use crate::crypto::KeyType;
+use crate::crypto::PublicKey;
+use crate::crypto::SecretKey;
+use crate::error::Result;
+use crate::utils::encode_ec_public;
+
+/// Generates a new pair of public/secret P-256 keys.
+///
+/// The public key is encoded as a compressed SEC1 curve point.
+pub fn generate_p256_keypair() -> Result<(PublicKey, SecretKey)> {
+  let secret: p256::SecretKey = p256::SecretKey::generate()?;
+  let public: p256::PublicKey = secret.public_key();
+
+  let (x, y): _ = public.to_coord()?;
+
+  let secret: SecretKey = secret.to_bytes().to_vec().into();
+  let public: PublicKey = encode_ec_public(KeyType::P256, &[x.as_ref(), y.as_ref()].concat())?.into();
+
+  Ok((public, secret))
+}
+
+/// Generates a list of public/secret P-256 keys.
+pub fn generate_p256_keypairs(count: usize) -> Result<Vec<(PublicKey, SecretKey)>> {
+  (0..count).map(|_| generate_p256_keypair()).collect()
+}
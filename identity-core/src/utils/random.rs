@@ -0,0 +1,33 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crypto::utils::rand;
+
+use crate::error::Result;
+use crate::utils::encode_b58;
+
+/// The number of random bytes in a nonce.
+const NONCE_LENGTH: usize = 32;
+
+/// Generates a new base58-encoded random nonce.
+pub fn generate_nonce() -> Result<String> {
+  let mut nonce: [u8; NONCE_LENGTH] = [0; NONCE_LENGTH];
+
+  rand::fill(&mut nonce)?;
+
+  Ok(encode_b58(&nonce))
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::utils::decode_b58;
+  use crate::utils::generate_nonce;
+
+  #[test]
+  fn test_generate_nonce() {
+    let nonce: String = generate_nonce().unwrap();
+
+    assert_eq!(decode_b58(&nonce).unwrap().len(), 32);
+    assert_ne!(generate_nonce().unwrap(), nonce);
+  }
+}
@@ -0,0 +1,208 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use num_bigint_dig::BigUint;
+
+use crate::crypto::KeyType;
+use crate::error::Error;
+use crate::error::Result;
+use crate::utils::decode_b16;
+
+/// The length of an encoded curve point coordinate.
+const COORDINATE_LENGTH: usize = 32;
+
+/// The SEC1 tag of a compressed curve point with an even `y` coordinate.
+const TAG_EVEN: u8 = 0x02;
+
+/// The SEC1 tag of a compressed curve point with an odd `y` coordinate.
+const TAG_ODD: u8 = 0x03;
+
+/// The SEC1 tag of an uncompressed curve point.
+const TAG_UNCOMPRESSED: u8 = 0x04;
+
+/// The parameters of a short Weierstrass curve `y^2 = x^3 + ax + b (mod p)`.
+struct Curve {
+  p: &'static str,
+  a: &'static str,
+  b: &'static str,
+}
+
+const SECP256K1: Curve = Curve {
+  p: "fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f",
+  a: "00",
+  b: "07",
+};
+
+const P256: Curve = Curve {
+  p: "ffffffff00000001000000000000000000000000ffffffffffffffffffffffff",
+  a: "ffffffff00000001000000000000000000000000fffffffffffffffffffffffc",
+  b: "5ac635d8aa3a93e7b3ebbd55769886bc651d06b0cc53b0f63bce3c3e27d2604b",
+};
+
+/// Decodes an elliptic curve public key into the concatenated `x` and `y`
+/// coordinates of the curve point.
+///
+/// The key may be encoded as a compressed or uncompressed [SEC1][SPEC] point
+/// or as the concatenated `x` and `y` coordinates.
+///
+/// # Errors
+///
+/// Fails if the key type is not an elliptic curve or `public` is not a valid
+/// point on the curve.
+///
+/// [SPEC]: https://www.secg.org/sec1-v2.pdf
+pub fn decode_ec_public(type_: KeyType, public: &[u8]) -> Result<Vec<u8>> {
+  let curve: &Curve = __curve(type_)?;
+
+  match (public.len(), public.first()) {
+    (33, Some(&TAG_EVEN)) => __decompress(curve, &public[1..], false),
+    (33, Some(&TAG_ODD)) => __decompress(curve, &public[1..], true),
+    (65, Some(&TAG_UNCOMPRESSED)) => __validate(curve, &public[1..]),
+    (64, _) => __validate(curve, public),
+    _ => Err(Error::InvalidKeyFormat),
+  }
+}
+
+/// Encodes an elliptic curve public key as a compressed [SEC1][SPEC] point.
+///
+/// The key may be in any of the encodings accepted by [`decode_ec_public`].
+///
+/// # Errors
+///
+/// Fails if the key type is not an elliptic curve or `public` is not a valid
+/// point on the curve.
+///
+/// [SPEC]: https://www.secg.org/sec1-v2.pdf
+pub fn encode_ec_public(type_: KeyType, public: &[u8]) -> Result<Vec<u8>> {
+  let public: Vec<u8> = decode_ec_public(type_, public)?;
+  let (x, y): (&[u8], &[u8]) = public.split_at(COORDINATE_LENGTH);
+
+  let tag: u8 = if y[COORDINATE_LENGTH - 1] & 0x01 == 0x01 {
+    TAG_ODD
+  } else {
+    TAG_EVEN
+  };
+
+  Ok([&[tag][..], x].concat())
+}
+
+fn __curve(type_: KeyType) -> Result<&'static Curve> {
+  match type_ {
+    KeyType::Secp256k1 => Ok(&SECP256K1),
+    KeyType::P256 => Ok(&P256),
+    KeyType::Ed25519 | KeyType::X25519 | KeyType::Bls12381G2 => Err(Error::InvalidKeyFormat),
+  }
+}
+
+fn __params(curve: &Curve) -> Result<(BigUint, BigUint, BigUint)> {
+  let p: BigUint = BigUint::from_bytes_be(&decode_b16(curve.p)?);
+  let a: BigUint = BigUint::from_bytes_be(&decode_b16(curve.a)?);
+  let b: BigUint = BigUint::from_bytes_be(&decode_b16(curve.b)?);
+
+  Ok((p, a, b))
+}
+
+// Returns `x^3 + ax + b (mod p)`, failing if `x` is not a field element.
+fn __rhs(curve: &Curve, x: &BigUint) -> Result<(BigUint, BigUint)> {
+  let (p, a, b): (BigUint, BigUint, BigUint) = __params(curve)?;
+
+  if x >= &p {
+    return Err(Error::InvalidKeyFormat);
+  }
+
+  let rhs: BigUint = (x * x * x + a * x + b) % &p;
+
+  Ok((p, rhs))
+}
+
+fn __decompress(curve: &Curve, x: &[u8], odd: bool) -> Result<Vec<u8>> {
+  let (p, rhs): (BigUint, BigUint) = __rhs(curve, &BigUint::from_bytes_be(x))?;
+
+  // Both curves satisfy `p = 3 (mod 4)`, so the square root is `rhs^((p+1)/4)`
+  let exponent: BigUint = (&p + BigUint::from(1u32)) >> 2;
+  let y: BigUint = rhs.modpow(&exponent, &p);
+
+  if (&y * &y) % &p != rhs {
+    return Err(Error::InvalidKeyFormat);
+  }
+
+  let y: BigUint = if __is_odd(&y) == odd { y } else { &p - &y };
+
+  Ok([x, &__pad(&y)?[..]].concat())
+}
+
+fn __validate(curve: &Curve, public: &[u8]) -> Result<Vec<u8>> {
+  let (x, y): (&[u8], &[u8]) = public.split_at(COORDINATE_LENGTH);
+  let (p, rhs): (BigUint, BigUint) = __rhs(curve, &BigUint::from_bytes_be(x))?;
+  let y: BigUint = BigUint::from_bytes_be(y);
+
+  if y >= p || (&y * &y) % &p != rhs {
+    return Err(Error::InvalidKeyFormat);
+  }
+
+  Ok(public.to_vec())
+}
+
+fn __is_odd(value: &BigUint) -> bool {
+  value
+    .to_bytes_be()
+    .last()
+    .map(|byte| byte & 0x01 == 0x01)
+    .unwrap_or(false)
+}
+
+fn __pad(value: &BigUint) -> Result<Vec<u8>> {
+  let bytes: Vec<u8> = value.to_bytes_be();
+
+  if bytes.len() > COORDINATE_LENGTH {
+    return Err(Error::InvalidKeyFormat);
+  }
+
+  Ok([&vec![0; COORDINATE_LENGTH - bytes.len()][..], &bytes[..]].concat())
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::crypto::KeyType;
+  use crate::utils::decode_b16;
+  use crate::utils::decode_ec_public;
+  use crate::utils::encode_ec_public;
+
+  // The generator points of secp256k1 and P-256 (SEC 2, section 2.4.1 and 2.7.2)
+  const SECP256K1_G: &str = "79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798\
+                             483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8";
+  const P256_G: &str = "6b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296\
+                        4fe342e2fe1a7f9b8ee7eb4a7c0f9e162bce33576b315ececbb6406837bf51f5";
+
+  #[test]
+  fn test_compress_decompress() {
+    for (type_, point, tag) in [(KeyType::Secp256k1, SECP256K1_G, 0x02), (KeyType::P256, P256_G, 0x03)].iter() {
+      let point: Vec<u8> = decode_b16(point).unwrap();
+      let compressed: Vec<u8> = encode_ec_public(*type_, &point).unwrap();
+
+      assert_eq!(compressed[0], *tag);
+      assert_eq!(compressed[1..], point[..32]);
+      assert_eq!(decode_ec_public(*type_, &compressed).unwrap(), point);
+
+      let uncompressed: Vec<u8> = [&[0x04][..], &point[..]].concat();
+
+      assert_eq!(decode_ec_public(*type_, &uncompressed).unwrap(), point);
+      assert_eq!(encode_ec_public(*type_, &uncompressed).unwrap(), compressed);
+    }
+  }
+
+  #[test]
+  fn test_invalid() {
+    let mut point: Vec<u8> = decode_b16(SECP256K1_G).unwrap();
+
+    // The point is not on the other curve
+    assert!(decode_ec_public(KeyType::P256, &point).is_err());
+
+    point[63] ^= 0x01;
+
+    assert!(decode_ec_public(KeyType::Secp256k1, &point).is_err());
+    assert!(decode_ec_public(KeyType::Secp256k1, &point[..32]).is_err());
+    assert!(decode_ec_public(KeyType::Ed25519, &point[..32]).is_err());
+    assert!(decode_ec_public(KeyType::Secp256k1, &[&[0x05][..], &point[..32]].concat()).is_err());
+  }
+}
@@ -0,0 +1,151 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crypto::hashes::sha::SHA512;
+use crypto::hashes::sha::SHA512_LEN;
+use crypto::keys::x25519;
+use num_bigint_dig::BigUint;
+use zeroize::Zeroize;
+
+use crate::crypto::PublicKey;
+use crate::crypto::SecretKey;
+use crate::error::Error;
+use crate::error::Result;
+use crate::utils::decode_b16;
+
+/// The length of an Ed25519 secret key seed.
+const ED25519_SEED_LENGTH: usize = 32;
+
+/// The length of an Ed25519 public key.
+const ED25519_PUBLIC_LENGTH: usize = 32;
+
+/// The prime `2^255 - 19` of the curve25519 field.
+const FIELD_PRIME: &str = "7fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffed";
+
+/// The parameter `d` of the twisted Edwards curve `-x^2 + y^2 = 1 + dx^2y^2`.
+const EDWARDS_D: &str = "52036cee2b6ffe738cc740797779e89800700a4d4141d8ab75eb4dca135978a3";
+
+/// Generates a new pair of public/secret x25519 keys.
+pub fn generate_x25519_keypair() -> Result<(PublicKey, SecretKey)> {
+  let secret: x25519::SecretKey = x25519::SecretKey::generate()?;
+  let public: x25519::PublicKey = secret.public_key();
+
+  let secret: SecretKey = secret.to_bytes().to_vec().into();
+  let public: PublicKey = public.to_bytes().to_vec().into();
+
+  Ok((public, secret))
+}
+
+/// Generates a list of public/secret x25519 keys.
+pub fn generate_x25519_keypairs(count: usize) -> Result<Vec<(PublicKey, SecretKey)>> {
+  (0..count).map(|_| generate_x25519_keypair()).collect()
+}
+
+/// Derives a pair of public/secret x25519 keys from an ed25519 secret key.
+///
+/// The x25519 secret is the clamped lower half of the SHA-512 digest of the
+/// ed25519 seed, as described in [RFC 8032][SPEC]; the corresponding public
+/// key is the Montgomery form of the ed25519 public key.
+///
+/// [SPEC]: https://tools.ietf.org/html/rfc8032#section-5.1.5
+pub fn ed25519_to_x25519_keypair(ed25519: &[u8]) -> Result<(PublicKey, SecretKey)> {
+  let seed: &[u8] = ed25519
+    .get(..ED25519_SEED_LENGTH)
+    .ok_or_else(|| Error::InvalidKeyLength(ed25519.len(), ED25519_SEED_LENGTH))?;
+
+  let mut digest: [u8; SHA512_LEN] = [0; SHA512_LEN];
+
+  SHA512(seed, &mut digest);
+
+  digest[0] &= 248;
+  digest[31] &= 127;
+  digest[31] |= 64;
+
+  let secret: Result<x25519::SecretKey> =
+    x25519::SecretKey::from_bytes(&digest[..32]).map_err(|_| Error::InvalidKeyFormat);
+
+  digest.zeroize();
+
+  let secret: x25519::SecretKey = secret?;
+  let public: x25519::PublicKey = secret.public_key();
+
+  let secret: SecretKey = secret.to_bytes().to_vec().into();
+  let public: PublicKey = public.to_bytes().to_vec().into();
+
+  Ok((public, secret))
+}
+
+/// Converts an ed25519 public key into the x25519 public key of the same key
+/// pair.
+///
+/// The x25519 public key is the Montgomery `u = (1 + y) / (1 - y)` coordinate
+/// of the ed25519 curve point, as described in [RFC 7748][SPEC]; it matches
+/// the public key derived by [`ed25519_to_x25519_keypair`].
+///
+/// [SPEC]: https://tools.ietf.org/html/rfc7748#section-4.1
+pub fn ed25519_to_x25519_public(ed25519: &[u8]) -> Result<PublicKey> {
+  if ed25519.len() != ED25519_PUBLIC_LENGTH {
+    return Err(Error::InvalidKeyLength(ed25519.len(), ED25519_PUBLIC_LENGTH));
+  }
+
+  let p: BigUint = BigUint::from_bytes_be(&decode_b16(FIELD_PRIME)?);
+  let d: BigUint = BigUint::from_bytes_be(&decode_b16(EDWARDS_D)?);
+  let one: BigUint = BigUint::from(1u32);
+
+  // The most significant bit encodes the sign of `x`
+  let mut y: Vec<u8> = ed25519.to_vec();
+
+  y[ED25519_PUBLIC_LENGTH - 1] &= 0x7f;
+
+  let y: BigUint = BigUint::from_bytes_le(&y);
+
+  // The identity point has no Montgomery form
+  if y >= p || y == one {
+    return Err(Error::InvalidKeyFormat);
+  }
+
+  // The point is on the curve if `x^2 = (y^2 - 1) / (dy^2 + 1)` is a square
+  let yy: BigUint = (&y * &y) % &p;
+  let xx: BigUint = ((&yy + &p - &one) * __invert(&((&d * &yy + &one) % &p), &p)) % &p;
+
+  if xx.modpow(&((&p - &one) >> 1), &p) > one {
+    return Err(Error::InvalidKeyFormat);
+  }
+
+  let u: BigUint = ((&one + &y) * __invert(&(&p + &one - &y), &p)) % &p;
+  let mut public: Vec<u8> = u.to_bytes_le();
+
+  public.resize(ED25519_PUBLIC_LENGTH, 0);
+
+  Ok(public.into())
+}
+
+// Returns the multiplicative inverse of `value` in the prime field `p`.
+fn __invert(value: &BigUint, p: &BigUint) -> BigUint {
+  value.modpow(&(p - BigUint::from(2u32)), p)
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::crypto::KeyPair;
+  use crate::crypto::PublicKey;
+  use crate::utils::ed25519_to_x25519_public;
+
+  #[test]
+  fn test_ed25519_to_x25519_public() {
+    let keypair: KeyPair = KeyPair::new_ed25519().unwrap();
+    let x25519: KeyPair = keypair.to_x25519().unwrap();
+    let public: PublicKey = ed25519_to_x25519_public(keypair.public().as_ref()).unwrap();
+
+    assert_eq!(public.as_ref(), x25519.public().as_ref());
+
+    assert!(ed25519_to_x25519_public(&[0; 31]).is_err());
+
+    // The identity point can not be converted
+    let mut identity: [u8; 32] = [0; 32];
+
+    identity[0] = 1;
+
+    assert!(ed25519_to_x25519_public(&identity).is_err());
+  }
+}
@@ -0,0 +1,124 @@
+// Compaction test vectors of the W3C JSON-LD 1.1 test suite and the examples
+// of the JSON-LD 1.1 Processing Algorithms and API specification.
+//
+// https://w3c.github.io/json-ld-api/tests/compact-manifest.html
+// https://www.w3.org/TR/json-ld11-api/#compaction
+[
+  CompactionVector {
+    name: "compact/0001 drop free-floating nodes",
+    input: r#"{"@id": "http://example.org/test#example"}"#,
+    context: r#"{}"#,
+    output: r#"{}"#,
+  },
+  CompactionVector {
+    name: "compact/0002 basic",
+    input: r#"
+{
+  "@id": "http://example.com/id1",
+  "@type": "http://example.com/t1",
+  "http://example.com/term1": "v1",
+  "http://example.com/term2": {"@value": "v2", "@type": "http://example.com/t2"},
+  "http://example.com/term3": {"@value": "v3", "@language": "en"},
+  "http://example.com/term4": 4,
+  "http://example.com/term5": [50, 51]
+}
+"#,
+    context: r#"
+{
+  "@context": {
+    "t1": "http://example.com/t1",
+    "t2": "http://example.com/t2",
+    "term1": "http://example.com/term1",
+    "term2": "http://example.com/term2",
+    "term3": "http://example.com/term3",
+    "term4": "http://example.com/term4",
+    "term5": "http://example.com/term5"
+  }
+}
+"#,
+    output: r#"
+{
+  "@context": {
+    "t1": "http://example.com/t1",
+    "t2": "http://example.com/t2",
+    "term1": "http://example.com/term1",
+    "term2": "http://example.com/term2",
+    "term3": "http://example.com/term3",
+    "term4": "http://example.com/term4",
+    "term5": "http://example.com/term5"
+  },
+  "@id": "http://example.com/id1",
+  "@type": "t1",
+  "term1": "v1",
+  "term2": {"@value": "v2", "@type": "t2"},
+  "term3": {"@value": "v3", "@language": "en"},
+  "term4": 4,
+  "term5": [50, 51]
+}
+"#,
+  },
+  CompactionVector {
+    name: "compact/0003 drop null and unmapped properties",
+    input: r#"
+{
+  "@id": "http://example.com/id1",
+  "http://example.org/property": null,
+  "regularJson": {
+    "nonJsonLd": "property",
+    "deep": [{"foo": "bar"}, {"bar": "foo"}]
+  }
+}
+"#,
+    context: r#"{}"#,
+    output: r#"{}"#,
+  },
+  CompactionVector {
+    name: "api example: compacted document form",
+    input: r#"
+[{
+  "http://xmlns.com/foaf/0.1/name": ["Manu Sporny"],
+  "http://xmlns.com/foaf/0.1/homepage": [{"@id": "https://manu.sporny.org/"}],
+  "http://xmlns.com/foaf/0.1/img": [{"@id": "https://manu.sporny.org/images/manu.png"}]
+}]
+"#,
+    context: r#"
+{
+  "@context": {
+    "name": "http://xmlns.com/foaf/0.1/name",
+    "homepage": {"@id": "http://xmlns.com/foaf/0.1/homepage", "@type": "@id"},
+    "img": {"@id": "http://xmlns.com/foaf/0.1/img", "@type": "@id"}
+  }
+}
+"#,
+    output: r#"
+{
+  "@context": {
+    "name": "http://xmlns.com/foaf/0.1/name",
+    "homepage": {"@id": "http://xmlns.com/foaf/0.1/homepage", "@type": "@id"},
+    "img": {"@id": "http://xmlns.com/foaf/0.1/img", "@type": "@id"}
+  },
+  "name": "Manu Sporny",
+  "homepage": "https://manu.sporny.org/",
+  "img": "https://manu.sporny.org/images/manu.png"
+}
+"#,
+  },
+  CompactionVector {
+    name: "syntax example: compact IRIs",
+    input: r#"
+{
+  "@context": {"foaf": "http://xmlns.com/foaf/0.1/"},
+  "@type": "foaf:Person",
+  "foaf:name": "Dave Longley"
+}
+"#,
+    context: r#"{"@context": {"foaf": "http://xmlns.com/foaf/0.1/"}}"#,
+    output: r#"
+{
+  "@context": {"foaf": "http://xmlns.com/foaf/0.1/"},
+  "@type": "foaf:Person",
+  "foaf:name": "Dave Longley"
+}
+"#,
+  },
+]
@@ -0,0 +1,213 @@
+[
+  TestVector {
+    name: "cycle",
+    input: r#"
+_:a <http://example.org/vocab#next> _:b .
+_:b <http://example.org/vocab#next> _:c .
+_:c <http://example.org/vocab#next> _:a .
+"#,
+    output: r#"
+_:c14n0 <http://example.org/vocab#next> _:c14n1 .
+_:c14n1 <http://example.org/vocab#next> _:c14n2 .
+_:c14n2 <http://example.org/vocab#next> _:c14n0 .
+"#,
+  },
+  TestVector {
+    name: "symmetric",
+    input: r#"
+_:x <http://example.org/vocab#p> _:y .
+_:y <http://example.org/vocab#p> _:x .
+_:x <http://example.org/vocab#q> _:z .
+_:y <http://example.org/vocab#q> _:w .
+_:z <http://example.org/vocab#r> "1" .
+_:w <http://example.org/vocab#r> "1" .
+"#,
+    output: r#"
+_:c14n0 <http://example.org/vocab#p> _:c14n2 .
+_:c14n0 <http://example.org/vocab#q> _:c14n1 .
+_:c14n1 <http://example.org/vocab#r> "1" .
+_:c14n2 <http://example.org/vocab#p> _:c14n0 .
+_:c14n2 <http://example.org/vocab#q> _:c14n3 .
+_:c14n3 <http://example.org/vocab#r> "1" .
+"#,
+  },
+  TestVector {
+    name: "literals",
+    input: r#"
+<http://example.org/s> <http://example.org/vocab#p> "line\nbreak \"quoted\"" .
+<http://example.org/s> <http://example.org/vocab#p> "hallo"@de .
+<http://example.org/s> <http://example.org/vocab#p> "5"^^<http://www.w3.org/2001/XMLSchema#integer> .
+<http://example.org/s> <http://example.org/vocab#p> "5"^^<http://www.w3.org/2001/XMLSchema#integer> .
+"#,
+    output: r#"
+<http://example.org/s> <http://example.org/vocab#p> "5"^^<http://www.w3.org/2001/XMLSchema#integer> .
+<http://example.org/s> <http://example.org/vocab#p> "hallo"@de .
+<http://example.org/s> <http://example.org/vocab#p> "line\nbreak \"quoted\"" .
+"#,
+  },
+  // The expected output of the following vectors was cross-checked against an
+  // independent implementation of the URDNA2015 algorithm
+  TestVector {
+    name: "iris",
+    input: r#"
+<http://example.org/b> <http://example.org/vocab#p> <http://example.org/a> .
+<http://example.org/a> <http://example.org/vocab#p> <http://example.org/b> .
+<http://example.org/a> <http://example.org/vocab#p> <http://example.org/b> <http://example.org/g> .
+"#,
+    output: r#"
+<http://example.org/a> <http://example.org/vocab#p> <http://example.org/b> .
+<http://example.org/a> <http://example.org/vocab#p> <http://example.org/b> <http://example.org/g> .
+<http://example.org/b> <http://example.org/vocab#p> <http://example.org/a> .
+"#,
+  },
+  TestVector {
+    name: "named-graph",
+    input: r#"
+_:s <http://example.org/vocab#p> _:o _:g .
+_:o <http://example.org/vocab#p> "v" _:g .
+_:g <http://example.org/vocab#label> "graph" .
+"#,
+    output: r#"
+_:c14n0 <http://example.org/vocab#p> _:c14n2 _:c14n1 .
+_:c14n1 <http://example.org/vocab#label> "graph" .
+_:c14n2 <http://example.org/vocab#p> "v" _:c14n1 .
+"#,
+  },
+  TestVector {
+    name: "graph-only",
+    input: r#"
+<http://example.org/s> <http://example.org/vocab#p> "1" _:g1 .
+<http://example.org/s> <http://example.org/vocab#p> "2" _:g2 .
+"#,
+    output: r#"
+<http://example.org/s> <http://example.org/vocab#p> "1" _:c14n0 .
+<http://example.org/s> <http://example.org/vocab#p> "2" _:c14n1 .
+"#,
+  },
+  TestVector {
+    name: "clique",
+    input: r#"
+_:a <http://example.org/vocab#p> _:b .
+_:a <http://example.org/vocab#p> _:c .
+_:a <http://example.org/vocab#p> _:d .
+_:b <http://example.org/vocab#p> _:a .
+_:b <http://example.org/vocab#p> _:c .
+_:b <http://example.org/vocab#p> _:d .
+_:c <http://example.org/vocab#p> _:a .
+_:c <http://example.org/vocab#p> _:b .
+_:c <http://example.org/vocab#p> _:d .
+_:d <http://example.org/vocab#p> _:a .
+_:d <http://example.org/vocab#p> _:b .
+_:d <http://example.org/vocab#p> _:c .
+"#,
+    output: r#"
+_:c14n0 <http://example.org/vocab#p> _:c14n1 .
+_:c14n0 <http://example.org/vocab#p> _:c14n2 .
+_:c14n0 <http://example.org/vocab#p> _:c14n3 .
+_:c14n1 <http://example.org/vocab#p> _:c14n0 .
+_:c14n1 <http://example.org/vocab#p> _:c14n2 .
+_:c14n1 <http://example.org/vocab#p> _:c14n3 .
+_:c14n2 <http://example.org/vocab#p> _:c14n0 .
+_:c14n2 <http://example.org/vocab#p> _:c14n1 .
+_:c14n2 <http://example.org/vocab#p> _:c14n3 .
+_:c14n3 <http://example.org/vocab#p> _:c14n0 .
+_:c14n3 <http://example.org/vocab#p> _:c14n1 .
+_:c14n3 <http://example.org/vocab#p> _:c14n2 .
+"#,
+  },
+  TestVector {
+    name: "isomorphic-components",
+    input: r#"
+_:a1 <http://example.org/vocab#p> _:b1 .
+_:b1 <http://example.org/vocab#q> "x" .
+_:a2 <http://example.org/vocab#p> _:b2 .
+_:b2 <http://example.org/vocab#q> "x" .
+"#,
+    output: r#"
+_:c14n0 <http://example.org/vocab#q> "x" .
+_:c14n1 <http://example.org/vocab#p> _:c14n0 .
+_:c14n2 <http://example.org/vocab#q> "x" .
+_:c14n3 <http://example.org/vocab#p> _:c14n2 .
+"#,
+  },
+  TestVector {
+    name: "double-cycle",
+    input: r#"
+_:a <http://example.org/vocab#next> _:b .
+_:b <http://example.org/vocab#next> _:c .
+_:c <http://example.org/vocab#next> _:a .
+_:d <http://example.org/vocab#next> _:e .
+_:e <http://example.org/vocab#next> _:f .
+_:f <http://example.org/vocab#next> _:d .
+_:a <http://example.org/vocab#label> "start" .
+"#,
+    output: r#"
+_:c14n0 <http://example.org/vocab#label> "start" .
+_:c14n0 <http://example.org/vocab#next> _:c14n1 .
+_:c14n1 <http://example.org/vocab#next> _:c14n2 .
+_:c14n2 <http://example.org/vocab#next> _:c14n0 .
+_:c14n3 <http://example.org/vocab#next> _:c14n4 .
+_:c14n4 <http://example.org/vocab#next> _:c14n5 .
+_:c14n5 <http://example.org/vocab#next> _:c14n3 .
+"#,
+  },
+  TestVector {
+    name: "diamond",
+    input: r#"
+_:top <http://example.org/vocab#p> _:left .
+_:top <http://example.org/vocab#p> _:right .
+_:left <http://example.org/vocab#p> _:bottom .
+_:right <http://example.org/vocab#p> _:bottom .
+"#,
+    output: r#"
+_:c14n0 <http://example.org/vocab#p> _:c14n2 .
+_:c14n0 <http://example.org/vocab#p> _:c14n3 .
+_:c14n2 <http://example.org/vocab#p> _:c14n1 .
+_:c14n3 <http://example.org/vocab#p> _:c14n1 .
+"#,
+  },
+  TestVector {
+    name: "duplicate-quads",
+    input: r#"
+_:a <http://example.org/vocab#p> _:b .
+_:a <http://example.org/vocab#p> _:b .
+_:b <http://example.org/vocab#p> "v" .
+"#,
+    output: r#"
+_:c14n0 <http://example.org/vocab#p> "v" .
+_:c14n1 <http://example.org/vocab#p> _:c14n0 .
+"#,
+  },
+  TestVector {
+    name: "shared-hashes",
+    input: r#"
+_:e0 <http://example.org/vocab#p1> _:e1 .
+_:e1 <http://example.org/vocab#p2> "Foo" .
+_:e2 <http://example.org/vocab#p1> _:e3 .
+_:e3 <http://example.org/vocab#p2> "Foo" .
+_:e0 <http://example.org/vocab#p3> _:e2 .
+_:e2 <http://example.org/vocab#p3> _:e0 .
+"#,
+    output: r#"
+_:c14n0 <http://example.org/vocab#p2> "Foo" .
+_:c14n1 <http://example.org/vocab#p1> _:c14n0 .
+_:c14n1 <http://example.org/vocab#p3> _:c14n2 .
+_:c14n2 <http://example.org/vocab#p1> _:c14n3 .
+_:c14n2 <http://example.org/vocab#p3> _:c14n1 .
+_:c14n3 <http://example.org/vocab#p2> "Foo" .
+"#,
+  },
+  TestVector {
+    name: "self-loops",
+    input: r#"
+_:a <http://example.org/vocab#p> _:a .
+_:b <http://example.org/vocab#p> _:b .
+_:a <http://example.org/vocab#q> _:b .
+"#,
+    output: r#"
+_:c14n0 <http://example.org/vocab#p> _:c14n0 .
+_:c14n0 <http://example.org/vocab#q> _:c14n1 .
+_:c14n1 <http://example.org/vocab#p> _:c14n1 .
+"#,
+  },
+]
@@ -15,6 +15,8 @@ use crate::credential::Refresh;
 use crate::credential::Schema;
 use crate::credential::Status;
 use crate::credential::Subject;
+use crate::credential::SubjectClaims;
+use crate::credential::TypedSubject;
 use crate::error::Result;
 
 /// A `CredentialBuilder` is used to create a customized `Credential`.
@@ -33,6 +35,7 @@ pub struct CredentialBuilder<T = Object> {
   pub(crate) policy: Vec<Policy>,
   pub(crate) evidence: Vec<Evidence>,
   pub(crate) non_transferable: Option<bool>,
+  pub(crate) invalid_subject: bool,
   pub(crate) properties: T,
 }
 
@@ -53,6 +56,7 @@ impl<T> CredentialBuilder<T> {
       policy: Vec::new(),
       evidence: Vec::new(),
       non_transferable: None,
+      invalid_subject: false,
       properties,
     }
   }
@@ -85,6 +89,38 @@ impl<T> CredentialBuilder<T> {
     self
   }
 
+  /// Adds a strongly typed value to the `credentialSubject` set.
+  ///
+  /// The JSON-LD context and types of the claims are added to the `Credential`
+  /// if not already present.
+  ///
+  /// Note: [`build`][`CredentialBuilder::build`] fails if the claims can't be
+  /// converted into a [`Subject`].
+  #[must_use]
+  pub fn typed_subject<C>(mut self, value: TypedSubject<C>) -> Self
+  where
+    C: SubjectClaims,
+  {
+    match value.to_subject() {
+      Ok(subject) => self.subject.push(subject),
+      Err(_) => self.invalid_subject = true,
+    }
+
+    if let Some(context) = C::context() {
+      if !self.context.contains(&context) {
+        self.context.push(context);
+      }
+    }
+
+    for type_ in C::types() {
+      if !self.types.iter().any(|value| value == type_) {
+        self.types.push((*type_).to_string());
+      }
+    }
+
+    self
+  }
+
   /// Sets the value of the `Credential` `issuer`.
   #[must_use]
   pub fn issuer(mut self, value: impl Into<Issuer>) -> Self {
@@ -199,9 +235,13 @@ mod tests {
   use serde_json::json;
   use serde_json::Value;
 
+  use crate::credential::claims::tests::Claims;
+  use crate::credential::claims::tests::Degree;
+  use crate::credential::claims::tests::Identified;
   use crate::credential::Credential;
   use crate::credential::CredentialBuilder;
   use crate::credential::Subject;
+  use crate::credential::TypedSubject;
 
   fn subject() -> Subject {
     let json: Value = json!({
@@ -264,6 +304,38 @@ mod tests {
     let _: Credential = CredentialBuilder::default().issuer(issuer()).build().unwrap();
   }
 
+  #[test]
+  fn test_builder_typed_subject() {
+    let claims: Claims = Claims {
+      degree: Degree {
+        type_: "BachelorDegree".into(),
+        name: "Bachelor of Science and Arts".into(),
+      },
+    };
+
+    let credential: Credential = CredentialBuilder::default()
+      .typed_subject(TypedSubject::new(claims))
+      .issuer(issuer())
+      .build()
+      .unwrap();
+
+    assert_eq!(credential.types.get(1).unwrap(), "UniversityDegreeCredential");
+    assert_eq!(
+      credential.credential_subject.get(0).unwrap().properties["degree"]["type"],
+      "BachelorDegree"
+    );
+  }
+
+  #[test]
+  #[should_panic = "InvalidSubject"]
+  fn test_builder_invalid_typed_subject() {
+    let _: Credential = CredentialBuilder::default()
+      .typed_subject(TypedSubject::new(Identified { id: "1".into() }))
+      .issuer(issuer())
+      .build()
+      .unwrap();
+  }
+
   #[test]
   #[should_panic = "MissingIssuer"]
   fn test_builder_missing_issuer() {
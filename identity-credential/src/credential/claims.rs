@@ -0,0 +1,179 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::convert::TryFrom;
+use identity_core::common::Context;
+use identity_core::common::Object;
+use identity_core::common::Url;
+use identity_core::convert::FromJson;
+use identity_core::convert::ToJson;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::credential::Subject;
+use crate::error::Error;
+use crate::error::Result;
+
+/// A Rust type mapped to the claims of a credential [`Subject`].
+///
+/// The fields of the type are (de)serialized as the properties of the subject,
+/// the subject `id` is kept separate by [`TypedSubject`].
+///
+/// # Example
+///
+/// ```
+/// # use identity_core::common::Context;
+/// # use identity_core::common::Url;
+/// # use identity_credential::credential::SubjectClaims;
+/// #[derive(serde::Deserialize, serde::Serialize)]
+/// struct Degree {
+///   name: String,
+/// }
+///
+/// impl SubjectClaims for Degree {
+///   fn context() -> Option<Context> {
+///     Url::parse("https://www.w3.org/2018/credentials/examples/v1")
+///       .ok()
+///       .map(Context::Url)
+///   }
+///
+///   fn types() -> &'static [&'static str] {
+///     &["UniversityDegreeCredential"]
+///   }
+/// }
+/// ```
+pub trait SubjectClaims: Serialize + DeserializeOwned {
+  /// Returns the JSON-LD context defining the terms of the claims, if any.
+  fn context() -> Option<Context> {
+    None
+  }
+
+  /// Returns the credential types implied by the claims.
+  fn types() -> &'static [&'static str] {
+    &[]
+  }
+}
+
+/// A credential subject with strongly typed claims.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TypedSubject<C> {
+  /// A Url identifying the credential subject.
+  pub id: Option<Url>,
+  /// The claims about the credential subject.
+  pub claims: C,
+}
+
+impl<C> TypedSubject<C> {
+  /// Creates a new `TypedSubject` with the given `claims`.
+  pub fn new(claims: C) -> Self {
+    Self { id: None, claims }
+  }
+
+  /// Creates a new `TypedSubject` with the given `id` and `claims`.
+  pub fn with_id(id: Url, claims: C) -> Self {
+    Self { id: Some(id), claims }
+  }
+}
+
+impl<C> TypedSubject<C>
+where
+  C: SubjectClaims,
+{
+  /// Converts the typed subject into an untyped [`Subject`].
+  ///
+  /// # Errors
+  ///
+  /// Fails if the claims do not serialize to a JSON object or define an `id`.
+  pub fn to_subject(&self) -> Result<Subject> {
+    let properties: Object = Object::from_json_value(self.claims.to_json_value()?)?;
+
+    if properties.contains_key("id") {
+      return Err(Error::InvalidSubject);
+    }
+
+    Ok(Subject {
+      id: self.id.clone(),
+      properties,
+    })
+  }
+}
+
+impl<C> TryFrom<&Subject> for TypedSubject<C>
+where
+  C: SubjectClaims,
+{
+  type Error = Error;
+
+  fn try_from(other: &Subject) -> Result<Self, Self::Error> {
+    Ok(Self {
+      id: other.id.clone(),
+      claims: C::from_json_value(other.properties.to_json_value()?)?,
+    })
+  }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+  use core::convert::TryFrom;
+  use identity_core::common::Context;
+  use identity_core::common::Url;
+  use identity_core::convert::FromJson;
+
+  use crate::credential::Subject;
+  use crate::credential::SubjectClaims;
+  use crate::credential::TypedSubject;
+
+  const JSON: &str = include_str!("../../tests/fixtures/subject-2.json");
+
+  #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+  pub(crate) struct Degree {
+    #[serde(rename = "type")]
+    pub(crate) type_: String,
+    pub(crate) name: String,
+  }
+
+  #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+  pub(crate) struct Claims {
+    pub(crate) degree: Degree,
+  }
+
+  impl SubjectClaims for Claims {
+    fn context() -> Option<Context> {
+      Some(Context::Url(
+        Url::parse("https://www.w3.org/2018/credentials/examples/v1").unwrap(),
+      ))
+    }
+
+    fn types() -> &'static [&'static str] {
+      &["UniversityDegreeCredential"]
+    }
+  }
+
+  #[derive(Deserialize, Serialize)]
+  pub(crate) struct Identified {
+    pub(crate) id: String,
+  }
+
+  impl SubjectClaims for Identified {}
+
+  #[test]
+  fn test_roundtrip() {
+    let subject: Subject = Subject::from_json(JSON).unwrap();
+    let typed: TypedSubject<Claims> = TypedSubject::try_from(&subject).unwrap();
+
+    assert_eq!(typed.id, subject.id);
+    assert_eq!(typed.claims.degree.type_, "BachelorDegree");
+    assert_eq!(typed.to_subject().unwrap(), subject);
+  }
+
+  #[test]
+  fn test_invalid_claims() {
+    // The subject does not match the claims
+    let subject: Subject = Subject::with_id(Url::parse("did:example:123").unwrap());
+    assert!(TypedSubject::<Claims>::try_from(&subject).is_err());
+
+    // The claims conflict with the subject id
+    let typed: TypedSubject<Identified> = TypedSubject::new(Identified { id: "1".into() });
+    assert!(typed.to_subject().is_err());
+  }
+}
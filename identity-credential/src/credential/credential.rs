@@ -1,6 +1,7 @@
 // Copyright 2020-2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use core::convert::TryFrom;
 use core::fmt::Display;
 use core::fmt::Error as FmtError;
 use core::fmt::Formatter;
@@ -15,7 +16,11 @@ use identity_core::crypto::SetSignature;
 use identity_core::crypto::Signature;
 use identity_core::crypto::TrySignature;
 use identity_core::crypto::TrySignatureMut;
+use serde::Deserialize;
+use serde::Deserializer;
 use serde::Serialize;
+use serde::Serializer;
+use std::vec::IntoIter;
 
 use crate::credential::CredentialBuilder;
 use crate::credential::Evidence;
@@ -25,6 +30,8 @@ use crate::credential::Refresh;
 use crate::credential::Schema;
 use crate::credential::Status;
 use crate::credential::Subject;
+use crate::credential::SubjectClaims;
+use crate::credential::TypedSubject;
 use crate::error::Error;
 use crate::error::Result;
 
@@ -33,53 +40,44 @@ lazy_static! {
 }
 
 /// Represents a set of claims describing an entity.
-#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct Credential<T = Object> {
   /// The JSON-LD context(s) applicable to the `Credential`.
-  #[serde(rename = "@context")]
   pub context: OneOrMany<Context>,
   /// A unique `URI` referencing the subject of the `Credential`.
-  #[serde(skip_serializing_if = "Option::is_none")]
   pub id: Option<Url>,
   /// One or more URIs defining the type of the `Credential`.
-  #[serde(rename = "type")]
   pub types: OneOrMany<String>,
   /// One or more `Object`s representing the `Credential` subject(s).
-  #[serde(rename = "credentialSubject")]
   pub credential_subject: OneOrMany<Subject>,
   /// A reference to the issuer of the `Credential`.
   pub issuer: Issuer,
   /// A timestamp of when the `Credential` becomes valid.
-  #[serde(rename = "issuanceDate")]
   pub issuance_date: Timestamp,
   /// A timestamp of when the `Credential` should no longer be considered valid.
-  #[serde(rename = "expirationDate", skip_serializing_if = "Option::is_none")]
   pub expiration_date: Option<Timestamp>,
   /// Information used to determine the current status of the `Credential`.
-  #[serde(default, rename = "credentialStatus", skip_serializing_if = "OneOrMany::is_empty")]
   pub credential_status: OneOrMany<Status>,
   /// Information used to assist in the enforcement of a specific `Credential` structure.
-  #[serde(default, rename = "credentialSchema", skip_serializing_if = "OneOrMany::is_empty")]
   pub credential_schema: OneOrMany<Schema>,
   /// Service(s) used to refresh an expired `Credential`.
-  #[serde(default, rename = "refreshService", skip_serializing_if = "OneOrMany::is_empty")]
   pub refresh_service: OneOrMany<Refresh>,
   /// Terms-of-use specified by the `Credential` issuer.
-  #[serde(default, rename = "termsOfUse", skip_serializing_if = "OneOrMany::is_empty")]
   pub terms_of_use: OneOrMany<Policy>,
   /// Human-readable evidence used to support the claims within the `Credential`.
-  #[serde(default, skip_serializing_if = "OneOrMany::is_empty")]
   pub evidence: OneOrMany<Evidence>,
   /// Indicates that the `Credential` must only be contained within a
   /// [`Presentation`][crate::presentation::Presentation] with a proof issued from the `Credential` subject.
-  #[serde(rename = "nonTransferable", skip_serializing_if = "Option::is_none")]
   pub non_transferable: Option<bool>,
   /// Miscellaneous properties.
-  #[serde(flatten)]
   pub properties: T,
-  /// Proof(s) used to verify a `Credential`
-  #[serde(skip_serializing_if = "Option::is_none")]
+  /// Proof used to verify a `Credential`.
+  ///
+  /// If the `Credential` has a proof set, this is the first proof of the set -
+  /// see [`Credential::proofs`].
   pub proof: Option<Signature>,
+  /// The remaining proofs of a proof set.
+  pub(crate) proof_set: Vec<Signature>,
 }
 
 impl<T> Credential<T> {
@@ -102,6 +100,10 @@ impl<T> Credential<T> {
 
   /// Returns a new `Credential` based on the `CredentialBuilder` configuration.
   pub fn from_builder(builder: CredentialBuilder<T>) -> Result<Self> {
+    if builder.invalid_subject {
+      return Err(Error::InvalidSubject);
+    }
+
     let this: Self = Self {
       context: builder.context.into(),
       id: builder.id,
@@ -118,6 +120,7 @@ impl<T> Credential<T> {
       non_transferable: builder.non_transferable,
       properties: builder.properties,
       proof: None,
+      proof_set: Vec::new(),
     };
 
     this.check_structure()?;
@@ -153,14 +156,47 @@ impl<T> Credential<T> {
     Ok(())
   }
 
+  /// Returns the credential subjects as strongly typed claims.
+  ///
+  /// # Errors
+  ///
+  /// Fails if the `Credential` does not include the JSON-LD context of the
+  /// claims or a subject does not match the claims.
+  pub fn typed_subjects<C>(&self) -> Result<Vec<TypedSubject<C>>>
+  where
+    C: SubjectClaims,
+  {
+    if let Some(context) = C::context() {
+      if !self.context.iter().any(|value| value == &context) {
+        return Err(Error::MissingSubjectContext);
+      }
+    }
+
+    self.credential_subject.iter().map(TypedSubject::try_from).collect()
+  }
+
   /// Returns a reference to the proof.
+  ///
+  /// If the `Credential` has a proof set, this is the focused or last proof.
   pub fn proof(&self) -> Option<&Signature> {
-    self.proof.as_ref()
+    self.proofs().into_iter().rev().find(|proof| !proof.is_excluded())
   }
 
   /// Returns a mutable reference to the proof.
+  ///
+  /// If the `Credential` has a proof set, this is the focused or last proof.
   pub fn proof_mut(&mut self) -> Option<&mut Signature> {
-    self.proof.as_mut()
+    self
+      .proof
+      .iter_mut()
+      .chain(self.proof_set.iter_mut())
+      .rev()
+      .find(|proof| !proof.is_excluded())
+  }
+
+  /// Returns all proofs of the proof set or proof chain, in order.
+  pub fn proofs(&self) -> Vec<&Signature> {
+    self.proof.iter().chain(self.proof_set.iter()).collect()
   }
 }
 
@@ -179,26 +215,184 @@ where
 
 impl<T> TrySignature for Credential<T> {
   fn signature(&self) -> Option<&Signature> {
-    self.proof.as_ref()
+    self.proof()
+  }
+
+  fn signatures(&self) -> Vec<&Signature> {
+    self.proofs()
   }
 }
 
 impl<T> TrySignatureMut for Credential<T> {
   fn signature_mut(&mut self) -> Option<&mut Signature> {
-    self.proof.as_mut()
+    self.proof_mut()
   }
 }
 
 impl<T> SetSignature for Credential<T> {
   fn set_signature(&mut self, value: Signature) {
-    self.proof.replace(value);
+    self.proof = Some(value);
+    self.proof_set.clear();
+  }
+
+  fn push_signature(&mut self, value: Signature) {
+    if self.proof.is_none() && self.proof_set.is_empty() {
+      self.proof = Some(value);
+    } else {
+      self.proof_set.push(value);
+    }
+  }
+}
+
+impl<T> Serialize for Credential<T>
+where
+  T: Serialize,
+{
+  fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: Serializer,
+  {
+    // Proofs excluded by a focused proof are not part of the serialized form
+    let proof: Vec<&Signature> = self.proofs().into_iter().filter(|proof| !proof.is_excluded()).collect();
+
+    CredentialRef {
+      context: &self.context,
+      id: self.id.as_ref(),
+      types: &self.types,
+      credential_subject: &self.credential_subject,
+      issuer: &self.issuer,
+      issuance_date: &self.issuance_date,
+      expiration_date: self.expiration_date.as_ref(),
+      credential_status: &self.credential_status,
+      credential_schema: &self.credential_schema,
+      refresh_service: &self.refresh_service,
+      terms_of_use: &self.terms_of_use,
+      evidence: &self.evidence,
+      non_transferable: self.non_transferable.as_ref(),
+      properties: &self.properties,
+      proof: proof.into(),
+    }
+    .serialize(serializer)
+  }
+}
+
+impl<'de, T> Deserialize<'de> for Credential<T>
+where
+  T: Deserialize<'de>,
+{
+  fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+  where
+    D: Deserializer<'de>,
+  {
+    CredentialData::deserialize(deserializer).map(Into::into)
   }
 }
+
+/// The serialized form of a [`Credential`].
+#[derive(Deserialize)]
+struct CredentialData<T> {
+  #[serde(rename = "@context")]
+  context: OneOrMany<Context>,
+  id: Option<Url>,
+  #[serde(rename = "type")]
+  types: OneOrMany<String>,
+  #[serde(rename = "credentialSubject")]
+  credential_subject: OneOrMany<Subject>,
+  issuer: Issuer,
+  #[serde(rename = "issuanceDate")]
+  issuance_date: Timestamp,
+  #[serde(rename = "expirationDate")]
+  expiration_date: Option<Timestamp>,
+  #[serde(default, rename = "credentialStatus")]
+  credential_status: OneOrMany<Status>,
+  #[serde(default, rename = "credentialSchema")]
+  credential_schema: OneOrMany<Schema>,
+  #[serde(default, rename = "refreshService")]
+  refresh_service: OneOrMany<Refresh>,
+  #[serde(default, rename = "termsOfUse")]
+  terms_of_use: OneOrMany<Policy>,
+  #[serde(default)]
+  evidence: OneOrMany<Evidence>,
+  #[serde(rename = "nonTransferable")]
+  non_transferable: Option<bool>,
+  #[serde(flatten)]
+  properties: T,
+  #[serde(default)]
+  proof: OneOrMany<Signature>,
+}
+
+/// The serialized form of a borrowed [`Credential`].
+#[derive(Serialize)]
+struct CredentialRef<'a, T> {
+  #[serde(rename = "@context")]
+  context: &'a OneOrMany<Context>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  id: Option<&'a Url>,
+  #[serde(rename = "type")]
+  types: &'a OneOrMany<String>,
+  #[serde(rename = "credentialSubject")]
+  credential_subject: &'a OneOrMany<Subject>,
+  issuer: &'a Issuer,
+  #[serde(rename = "issuanceDate")]
+  issuance_date: &'a Timestamp,
+  #[serde(rename = "expirationDate", skip_serializing_if = "Option::is_none")]
+  expiration_date: Option<&'a Timestamp>,
+  #[serde(rename = "credentialStatus", skip_serializing_if = "OneOrMany::is_empty")]
+  credential_status: &'a OneOrMany<Status>,
+  #[serde(rename = "credentialSchema", skip_serializing_if = "OneOrMany::is_empty")]
+  credential_schema: &'a OneOrMany<Schema>,
+  #[serde(rename = "refreshService", skip_serializing_if = "OneOrMany::is_empty")]
+  refresh_service: &'a OneOrMany<Refresh>,
+  #[serde(rename = "termsOfUse", skip_serializing_if = "OneOrMany::is_empty")]
+  terms_of_use: &'a OneOrMany<Policy>,
+  #[serde(skip_serializing_if = "OneOrMany::is_empty")]
+  evidence: &'a OneOrMany<Evidence>,
+  #[serde(rename = "nonTransferable", skip_serializing_if = "Option::is_none")]
+  non_transferable: Option<&'a bool>,
+  #[serde(flatten)]
+  properties: &'a T,
+  #[serde(skip_serializing_if = "OneOrMany::is_empty")]
+  proof: OneOrMany<&'a Signature>,
+}
+
+impl<T> From<CredentialData<T>> for Credential<T> {
+  fn from(other: CredentialData<T>) -> Self {
+    let mut proofs: IntoIter<Signature> = other.proof.into_vec().into_iter();
+
+    Self {
+      context: other.context,
+      id: other.id,
+      types: other.types,
+      credential_subject: other.credential_subject,
+      issuer: other.issuer,
+      issuance_date: other.issuance_date,
+      expiration_date: other.expiration_date,
+      credential_status: other.credential_status,
+      credential_schema: other.credential_schema,
+      refresh_service: other.refresh_service,
+      terms_of_use: other.terms_of_use,
+      evidence: other.evidence,
+      non_transferable: other.non_transferable,
+      properties: other.properties,
+      proof: proofs.next(),
+      proof_set: proofs.collect(),
+    }
+  }
+}
+
 #[cfg(test)]
 mod tests {
+  use identity_core::common::Value;
   use identity_core::convert::FromJson;
+  use identity_core::convert::ToJson;
+  use identity_core::crypto::SetSignature;
+  use identity_core::crypto::Signature;
+  use identity_core::crypto::SignatureValue;
 
+  use crate::credential::claims::tests::Claims;
   use crate::credential::Credential;
+  use crate::credential::CredentialBuilder;
+  use crate::credential::TypedSubject;
 
   const JSON1: &str = include_str!("../../tests/fixtures/credential-1.json");
   const JSON2: &str = include_str!("../../tests/fixtures/credential-2.json");
@@ -228,4 +422,65 @@ mod tests {
     let _credential: Credential = Credential::from_json(JSON11).unwrap();
     let _credential: Credential = Credential::from_json(JSON12).unwrap();
   }
+
+  #[test]
+  fn test_typed_subjects() {
+    let credential: Credential = Credential::from_json(JSON6).unwrap();
+    let subjects: Vec<TypedSubject<Claims>> = credential.typed_subjects().unwrap();
+
+    assert_eq!(subjects.len(), 1);
+    assert_eq!(
+      subjects[0].id.as_ref().unwrap(),
+      "did:example:ebfeb1f712ebc6f1c276e12ec21"
+    );
+    assert_eq!(subjects[0].claims.degree.type_, "BachelorDegree");
+
+    let roundtrip: Credential = CredentialBuilder::default()
+      .id(credential.id.clone().unwrap())
+      .typed_subject(subjects[0].clone())
+      .issuer(credential.issuer.clone())
+      .issuance_date(credential.issuance_date)
+      .expiration_date(credential.expiration_date.unwrap())
+      .build()
+      .unwrap();
+
+    assert_eq!(roundtrip, credential);
+    assert_eq!(roundtrip.typed_subjects::<Claims>().unwrap(), subjects);
+
+    // The credential does not define the terms of the claims
+    let mut credential: Credential = credential;
+    credential.context = Credential::<()>::base_context().clone().into();
+
+    assert!(credential.typed_subjects::<Claims>().is_err());
+  }
+
+  #[test]
+  fn test_proof_set() {
+    let mut credential: Credential = Credential::from_json(JSON1).unwrap();
+    let proof: Signature = credential.proof.clone().unwrap();
+
+    assert_eq!(credential.proofs(), [&proof]);
+    assert_eq!(
+      credential.to_json_value().unwrap()["proof"],
+      proof.to_json_value().unwrap()
+    );
+
+    let mut other: Signature = Signature::new("JcsEd25519Signature2020", "did:example:1234#key-1");
+    other.set_value(SignatureValue::Signature("IOTA".into()));
+    credential.push_signature(other);
+
+    assert_eq!(credential.proof.as_ref(), Some(&proof));
+    assert_eq!(credential.proofs().len(), 2);
+    assert_eq!(credential.proof().unwrap().type_(), "JcsEd25519Signature2020");
+
+    // Proof sets are serialized as an array
+    let json: Value = credential.to_json_value().unwrap();
+
+    assert_eq!(json["proof"].as_array().map(Vec::len), Some(2));
+    assert_eq!(Credential::from_json_value(json).unwrap(), credential);
+
+    credential.set_signature(proof.clone());
+
+    assert_eq!(credential.proofs(), [&proof]);
+  }
 }
@@ -6,6 +6,7 @@
 #![allow(clippy::module_inception)]
 
 mod builder;
+mod claims;
 mod credential;
 mod evidence;
 mod issuer;
@@ -16,6 +17,8 @@ mod status;
 mod subject;
 
 pub use self::builder::CredentialBuilder;
+pub use self::claims::SubjectClaims;
+pub use self::claims::TypedSubject;
 pub use self::credential::Credential;
 pub use self::evidence::Evidence;
 pub use self::issuer::Issuer;
@@ -9,9 +9,15 @@ pub type Result<T, E = Error> = ::core::result::Result<T, E>;
 /// This type represents all possible errors that can occur in the library.
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
+  /// Caused by errors from the [identity_core] crate.
+  #[error("{0}")]
+  CoreError(#[from] identity_core::Error),
   /// Caused by errors from the [identity_did] crate.
   #[error("{0}")]
   DIDError(#[from] identity_did::Error),
+  /// Caused by errors from the [libjose] crate.
+  #[error("{0}")]
+  JoseError(#[from] libjose::Error),
   /// Caused when validating a Credential without a valid base context.
   #[error("Missing Base Context")]
   MissingBaseContext,
@@ -27,4 +33,28 @@ pub enum Error {
   /// Caused when validating a Credential with a malformed subject.
   #[error("Invalid Credential Subject")]
   InvalidSubject,
+  /// Caused when a Credential does not define the JSON-LD terms of typed subject claims.
+  #[error("Missing Subject Context")]
+  MissingSubjectContext,
+  /// Caused when a credential status or status list is malformed.
+  #[error("Invalid Status: {0}")]
+  InvalidStatus(&'static str),
+  /// Caused when a JWT-encoded Credential or Presentation is malformed.
+  #[error("Invalid JWT: {0}")]
+  InvalidJwt(&'static str),
+  /// Caused when a presentation definition is malformed.
+  #[error("Invalid Presentation Definition: {0}")]
+  InvalidDefinition(String),
+  /// Caused when a set of credentials does not satisfy a presentation definition.
+  #[error("Unsatisfied Presentation Definition")]
+  UnsatisfiedDefinition,
+  /// Caused when a credential refresh request is malformed or rejected.
+  #[error("Invalid Refresh Request: {0}")]
+  InvalidRefresh(&'static str),
+  /// Caused when a Credential fails one or more checks of a validation policy.
+  #[error("Invalid Credential: {0:?}")]
+  InvalidCredential(Vec<crate::validation::ValidationFailure>),
+  /// Caused when a presented Credential is not bound to the presentation holder.
+  #[error("Invalid Holder Binding: {0:?}")]
+  InvalidHolderBinding(Vec<crate::validation::ValidationFailure>),
 }
@@ -0,0 +1,262 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Object;
+use identity_core::common::Url;
+use identity_core::common::Value;
+
+/// The requirements of a verifier on the credentials submitted by a holder.
+///
+/// [More Info](https://identity.foundation/presentation-exchange/#presentation-definition)
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct PresentationDefinition {
+  /// A unique identifier of the presentation definition.
+  pub id: String,
+  /// A human-friendly name of the presentation definition.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub name: Option<String>,
+  /// The purpose for which the presentation definition is used.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub purpose: Option<String>,
+  /// The inputs requested by the verifier.
+  pub input_descriptors: Vec<InputDescriptor>,
+  /// Rules for combining the input descriptors.
+  ///
+  /// If empty, every input descriptor MUST be satisfied.
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  pub submission_requirements: Vec<SubmissionRequirement>,
+  /// Additional properties of the presentation definition.
+  ///
+  /// Note: Definitions with a `format` property can't be evaluated.
+  #[serde(flatten)]
+  pub properties: Object,
+}
+
+impl PresentationDefinition {
+  /// Creates a new `PresentationDefinition`.
+  pub fn new(id: impl Into<String>, input_descriptors: Vec<InputDescriptor>) -> Self {
+    Self {
+      id: id.into(),
+      name: None,
+      purpose: None,
+      input_descriptors,
+      submission_requirements: Vec::new(),
+      properties: Object::new(),
+    }
+  }
+
+  /// Returns the input descriptor with the given `id`, if any.
+  pub fn input_descriptor(&self, id: &str) -> Option<&InputDescriptor> {
+    self.input_descriptors.iter().find(|descriptor| descriptor.id == id)
+  }
+}
+
+/// A description of a single input requested by a verifier.
+///
+/// [More Info](https://identity.foundation/presentation-exchange/#input-descriptor-object)
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct InputDescriptor {
+  /// A unique identifier of the input descriptor.
+  pub id: String,
+  /// A human-friendly name of the input descriptor.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub name: Option<String>,
+  /// The purpose for which the input is requested.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub purpose: Option<String>,
+  /// The groups referenced by the `from` property of submission requirements.
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  pub group: Vec<String>,
+  /// The schemas a credential MUST conform to to match the descriptor.
+  ///
+  /// If empty, credentials of any schema match the descriptor.
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  pub schema: Vec<InputSchema>,
+  /// The constraints a credential MUST satisfy to match the descriptor.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub constraints: Option<Constraints>,
+  /// Additional properties of the input descriptor.
+  ///
+  /// Note: Descriptors with a `format` property can't be evaluated.
+  #[serde(flatten)]
+  pub properties: Object,
+}
+
+impl InputDescriptor {
+  /// Creates a new `InputDescriptor` without constraints.
+  pub fn new(id: impl Into<String>) -> Self {
+    Self {
+      id: id.into(),
+      name: None,
+      purpose: None,
+      group: Vec::new(),
+      schema: Vec::new(),
+      constraints: None,
+      properties: Object::new(),
+    }
+  }
+
+  /// Returns the field constraints of the input descriptor.
+  pub fn fields(&self) -> &[Field] {
+    self
+      .constraints
+      .as_ref()
+      .map(|constraints| constraints.fields.as_slice())
+      .unwrap_or_default()
+  }
+}
+
+/// A schema of an [`InputDescriptor`].
+///
+/// A credential conforms to the schema if the `uri` is the `id` of one of its
+/// `credentialSchema` entries, one of its `@context` entries, or identifies
+/// one of its types by the URI fragment.
+///
+/// [More Info](https://identity.foundation/presentation-exchange/spec/v1.0.0/#input-descriptor-object)
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct InputSchema {
+  /// The URI of the schema.
+  pub uri: Url,
+  /// Indicates a credential MUST conform to the schema.
+  #[serde(default, skip_serializing_if = "core::ops::Not::not")]
+  pub required: bool,
+}
+
+impl InputSchema {
+  /// Creates a new `InputSchema` with the given `uri`.
+  pub fn new(uri: Url) -> Self {
+    Self { uri, required: false }
+  }
+}
+
+/// The constraints of an [`InputDescriptor`].
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
+pub struct Constraints {
+  /// Constraints on the properties of a credential.
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  pub fields: Vec<Field>,
+  /// Additional properties of the constraints.
+  ///
+  /// Note: Constraints with a `limit_disclosure` property can't be evaluated.
+  #[serde(flatten)]
+  pub properties: Object,
+}
+
+/// A constraint on the properties of a credential, selected by JSONPath.
+///
+/// [More Info](https://identity.foundation/presentation-exchange/#input-descriptor-object)
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct Field {
+  /// A unique identifier of the field.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub id: Option<String>,
+  /// JSONPath expressions selecting the constrained values, tried in order.
+  pub path: Vec<String>,
+  /// The purpose for which the field is requested.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub purpose: Option<String>,
+  /// A JSON Schema a selected value MUST be valid against.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub filter: Option<Value>,
+  /// Indicates the field MAY be absent.
+  #[serde(default, skip_serializing_if = "core::ops::Not::not")]
+  pub optional: bool,
+  /// Additional properties of the field.
+  ///
+  /// Note: Fields with a `predicate` property can't be evaluated.
+  #[serde(flatten)]
+  pub properties: Object,
+}
+
+impl Field {
+  /// Creates a new `Field` selecting values by the given JSONPath expression.
+  pub fn new(path: impl Into<String>) -> Self {
+    Self {
+      id: None,
+      path: vec![path.into()],
+      purpose: None,
+      filter: None,
+      optional: false,
+      properties: Object::new(),
+    }
+  }
+}
+
+/// A rule of a [`SubmissionRequirement`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Rule {
+  /// All inputs of the requirement MUST be submitted.
+  All,
+  /// A number of inputs of the requirement MUST be submitted.
+  Pick,
+}
+
+/// A rule for combining the input descriptors of a [`PresentationDefinition`].
+///
+/// [More Info](https://identity.foundation/presentation-exchange/#submission-requirements)
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct SubmissionRequirement {
+  /// A human-friendly name of the requirement.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub name: Option<String>,
+  /// The purpose of the requirement.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub purpose: Option<String>,
+  /// The rule applied to the inputs of the requirement.
+  pub rule: Rule,
+  /// The exact number of inputs to pick.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub count: Option<usize>,
+  /// The minimum number of inputs to pick.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub min: Option<usize>,
+  /// The maximum number of inputs to pick.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub max: Option<usize>,
+  /// The group of input descriptors the requirement applies to.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub from: Option<String>,
+  /// The nested requirements the requirement applies to.
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  pub from_nested: Vec<SubmissionRequirement>,
+}
+
+#[cfg(test)]
+mod tests {
+  use identity_core::convert::FromJson;
+  use identity_core::convert::ToJson;
+
+  use crate::exchange::PresentationDefinition;
+  use crate::exchange::Rule;
+
+  const JSON: &str = include_str!("../../tests/fixtures/definition-1.json");
+
+  #[test]
+  fn test_from_json() {
+    let definition: PresentationDefinition = PresentationDefinition::from_json(JSON).unwrap();
+
+    assert_eq!(definition.id, "32f54163-7166-48f1-93d8-ff217bdb0653");
+    assert_eq!(definition.input_descriptors.len(), 3);
+    assert_eq!(definition.input_descriptors[0].group, ["A"]);
+    assert_eq!(
+      definition.input_descriptors[0].schema[0].uri,
+      "https://www.w3.org/2018/credentials/examples/v1"
+    );
+    assert!(!definition.input_descriptors[0].schema[0].required);
+    assert_eq!(definition.input_descriptors[0].fields().len(), 2);
+    assert!(definition.input_descriptors[0].fields()[1].optional);
+    assert!(definition.input_descriptor("passport_input").is_some());
+    assert!(definition.input_descriptor("unknown").is_none());
+
+    assert_eq!(definition.submission_requirements.len(), 2);
+    assert_eq!(definition.submission_requirements[0].rule, Rule::All);
+    assert_eq!(definition.submission_requirements[1].rule, Rule::Pick);
+    assert_eq!(definition.submission_requirements[1].count, Some(1));
+
+    let roundtrip: PresentationDefinition =
+      PresentationDefinition::from_json_value(definition.to_json_value().unwrap()).unwrap();
+
+    assert_eq!(roundtrip, definition);
+  }
+}
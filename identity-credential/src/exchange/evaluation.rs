@@ -0,0 +1,566 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Object;
+use identity_core::common::OneOrMany;
+use identity_core::common::Url;
+use identity_core::common::Value;
+use identity_core::convert::ToJson;
+use jsonschema::JSONSchema;
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+use crate::credential::Credential;
+use crate::error::Error;
+use crate::error::Result;
+use crate::exchange::DescriptorMap;
+use crate::exchange::Field;
+use crate::exchange::InputSchema;
+use crate::exchange::PresentationDefinition;
+use crate::exchange::PresentationSubmission;
+use crate::exchange::Rule;
+use crate::exchange::SubmissionRequirement;
+use crate::presentation::Presentation;
+use crate::presentation::PresentationBuilder;
+
+/// The claim format of submitted credentials.
+const FORMAT: &str = "ldp_vc";
+
+impl PresentationDefinition {
+  /// Evaluates the input descriptors of the definition against a set of
+  /// `credentials`.
+  ///
+  /// # Errors
+  ///
+  /// Fails if a JSONPath expression or filter of the definition is invalid,
+  /// or the definition uses a `format`, `limit_disclosure`, or `predicate`
+  /// property, which are not supported.
+  pub fn evaluate<'a, T>(&'a self, credentials: &'a [Credential<T>]) -> Result<Evaluation<'a, T>>
+  where
+    T: Serialize,
+  {
+    __check_supported(self)?;
+
+    let documents: Vec<Value> = credentials
+      .iter()
+      .map(ToJson::to_json_value)
+      .collect::<Result<_, _>>()?;
+
+    let mut matches: BTreeMap<&'a str, Vec<usize>> = BTreeMap::new();
+
+    for descriptor in self.input_descriptors.iter() {
+      let fields: Vec<(&Field, Option<JSONSchema>)> = descriptor
+        .fields()
+        .iter()
+        .map(|field| __compile(field).map(|filter| (field, filter)))
+        .collect::<Result<_>>()?;
+
+      let mut indices: Vec<usize> = Vec::new();
+
+      for (index, document) in documents.iter().enumerate() {
+        if __matches_schema(&descriptor.schema, document) && __matches(&fields, document)? {
+          indices.push(index);
+        }
+      }
+
+      matches.insert(descriptor.id.as_str(), indices);
+    }
+
+    Ok(Evaluation {
+      definition: self,
+      credentials,
+      matches,
+    })
+  }
+}
+
+/// The result of evaluating a [`PresentationDefinition`] against a set of
+/// credentials.
+#[derive(Clone, Debug)]
+pub struct Evaluation<'a, T> {
+  definition: &'a PresentationDefinition,
+  credentials: &'a [Credential<T>],
+  matches: BTreeMap<&'a str, Vec<usize>>,
+}
+
+impl<'a, T> Evaluation<'a, T> {
+  /// Returns the evaluated presentation definition.
+  pub fn definition(&self) -> &'a PresentationDefinition {
+    self.definition
+  }
+
+  /// Returns the indices of the credentials matching the input descriptor
+  /// identified by `id`.
+  pub fn matches(&self, id: &str) -> &[usize] {
+    self.matches.get(id).map(Vec::as_slice).unwrap_or_default()
+  }
+
+  /// Selects the input descriptors to submit, as required by the submission
+  /// requirements of the definition.
+  ///
+  /// Without submission requirements, all input descriptors are selected.
+  ///
+  /// # Errors
+  ///
+  /// Fails if the credentials do not satisfy the definition.
+  pub fn select(&self) -> Result<Vec<&'a str>> {
+    let mut selected: Vec<&'a str> = Vec::new();
+
+    if self.definition.submission_requirements.is_empty() {
+      for descriptor in self.definition.input_descriptors.iter() {
+        if self.matches(&descriptor.id).is_empty() {
+          return Err(Error::UnsatisfiedDefinition);
+        }
+
+        selected.push(descriptor.id.as_str());
+      }
+    } else {
+      for requirement in self.definition.submission_requirements.iter() {
+        let descriptors: Vec<&'a str> = self
+          .select_requirement(requirement)?
+          .ok_or(Error::UnsatisfiedDefinition)?;
+
+        for descriptor in descriptors {
+          if !selected.contains(&descriptor) {
+            selected.push(descriptor);
+          }
+        }
+      }
+    }
+
+    Ok(selected)
+  }
+
+  /// Creates a [`PresentationSubmission`] identified by `id` and the list of
+  /// submitted credentials, referenced by the submission as
+  /// `$.verifiableCredential[index]`.
+  ///
+  /// Each selected input descriptor is satisfied by the first matching
+  /// credential.
+  pub fn submission(&self, id: impl Into<String>) -> Result<(PresentationSubmission, Vec<&'a Credential<T>>)> {
+    let mut indices: Vec<usize> = Vec::new();
+    let mut descriptor_map: Vec<DescriptorMap> = Vec::new();
+
+    for descriptor in self.select()? {
+      // Selected input descriptors are matched by at least one credential
+      let index: usize = self.matches(descriptor)[0];
+
+      let position: usize = match indices.iter().position(|other| *other == index) {
+        Some(position) => position,
+        None => {
+          indices.push(index);
+          indices.len() - 1
+        }
+      };
+
+      descriptor_map.push(DescriptorMap::new(
+        descriptor,
+        FORMAT,
+        format!("$.verifiableCredential[{}]", position),
+      ));
+    }
+
+    let submission: PresentationSubmission = PresentationSubmission {
+      id: id.into(),
+      definition_id: self.definition.id.clone(),
+      descriptor_map,
+    };
+
+    let credentials: Vec<&'a Credential<T>> = indices.into_iter().map(|index| &self.credentials[index]).collect();
+
+    Ok((submission, credentials))
+  }
+
+  /// Creates an unsigned [`Presentation`] of the selected credentials with an
+  /// embedded [`PresentationSubmission`] identified by `id`.
+  pub fn presentation(&self, id: impl Into<String>) -> Result<Presentation<Object, T>>
+  where
+    T: Clone,
+  {
+    let (submission, credentials): (PresentationSubmission, Vec<&Credential<T>>) = self.submission(id)?;
+
+    let mut presentation: Presentation<Object, T> = PresentationBuilder::default()
+      .context(Url::parse(PresentationSubmission::CONTEXT)?)
+      .type_(PresentationSubmission::TYPE)
+      .property(PresentationSubmission::PROPERTY, submission.to_json_value()?)
+      .build()?;
+
+    // Always serialize as an array to match the paths of the descriptor map
+    presentation.verifiable_credential = OneOrMany::Many(credentials.into_iter().cloned().collect());
+
+    Ok(presentation)
+  }
+
+  fn select_requirement(&self, requirement: &'a SubmissionRequirement) -> Result<Option<Vec<&'a str>>> {
+    // The descriptors selected for each input of the requirement, if satisfied
+    let inputs: Vec<Option<Vec<&'a str>>> = match (requirement.from.as_deref(), requirement.from_nested.as_slice()) {
+      (Some(group), []) => {
+        let inputs: Vec<Option<Vec<&'a str>>> = self
+          .definition
+          .input_descriptors
+          .iter()
+          .filter(|descriptor| descriptor.group.iter().any(|other| other == group))
+          .map(|descriptor| Some(vec![descriptor.id.as_str()]).filter(|_| !self.matches(&descriptor.id).is_empty()))
+          .collect();
+
+        if inputs.is_empty() {
+          return Err(Error::InvalidDefinition(format!("unknown group `{}`", group)));
+        }
+
+        inputs
+      }
+      (None, nested) if !nested.is_empty() => nested
+        .iter()
+        .map(|requirement| self.select_requirement(requirement))
+        .collect::<Result<_>>()?,
+      _ => {
+        return Err(Error::InvalidDefinition(
+          "submission requirement requires one of `from` or `from_nested`".into(),
+        ))
+      }
+    };
+
+    let total: usize = inputs.len();
+    let satisfied: Vec<Vec<&'a str>> = inputs.into_iter().flatten().collect();
+
+    let count: usize = match requirement.rule {
+      Rule::All if satisfied.len() == total => total,
+      Rule::All => return Ok(None),
+      Rule::Pick => match __pick(requirement, satisfied.len()) {
+        Some(count) => count,
+        None => return Ok(None),
+      },
+    };
+
+    Ok(Some(satisfied.into_iter().take(count).flatten().collect()))
+  }
+}
+
+// Returns the number of inputs to pick from the `available` inputs.
+fn __pick(requirement: &SubmissionRequirement, available: usize) -> Option<usize> {
+  if let Some(count) = requirement.count {
+    return Some(count).filter(|count| *count <= available);
+  }
+
+  let min: usize = requirement.min.unwrap_or(0);
+  let max: usize = requirement.max.unwrap_or(usize::MAX);
+
+  Some(available.min(max)).filter(|_| available >= min)
+}
+
+// Rejects definitions with properties that would silently change the result
+// of the evaluation if ignored.
+fn __check_supported(definition: &PresentationDefinition) -> Result<()> {
+  let mut unsupported: Option<&str> = None;
+
+  if definition.properties.contains_key("format") {
+    unsupported = Some("format");
+  }
+
+  for descriptor in definition.input_descriptors.iter() {
+    if descriptor.properties.contains_key("format") {
+      unsupported = Some("format");
+    }
+
+    if let Some(constraints) = descriptor.constraints.as_ref() {
+      if constraints.properties.contains_key("limit_disclosure") {
+        unsupported = Some("limit_disclosure");
+      }
+    }
+
+    if descriptor
+      .fields()
+      .iter()
+      .any(|field| field.properties.contains_key("predicate"))
+    {
+      unsupported = Some("predicate");
+    }
+  }
+
+  match unsupported {
+    Some(property) => Err(Error::InvalidDefinition(format!("unsupported property `{}`", property))),
+    None => Ok(()),
+  }
+}
+
+// Returns `true` if the document conforms to the schemas of an input
+// descriptor: every required schema and at least one schema must match.
+fn __matches_schema(schemas: &[InputSchema], document: &Value) -> bool {
+  if schemas.is_empty() {
+    return true;
+  }
+
+  let matches = |schema: &InputSchema| -> bool {
+    let uri: &str = schema.uri.as_str();
+
+    let context: bool = __strings(&document["@context"]).any(|context| context == uri);
+    let credential_schema: bool = __values(&document["credentialSchema"]).any(|value| value["id"] == uri);
+    let type_: bool = __strings(&document["type"]).any(|type_| schema.uri.fragment() == Some(type_));
+
+    context || credential_schema || type_
+  };
+
+  schemas.iter().filter(|schema| schema.required).all(matches) && schemas.iter().any(matches)
+}
+
+// Returns the values of a JSON-LD property with one or many values.
+fn __values(value: &Value) -> impl Iterator<Item = &Value> {
+  match value {
+    Value::Array(values) => values.iter().collect::<Vec<_>>().into_iter(),
+    Value::Null => Vec::new().into_iter(),
+    value => vec![value].into_iter(),
+  }
+}
+
+fn __strings(value: &Value) -> impl Iterator<Item = &str> {
+  __values(value).filter_map(Value::as_str)
+}
+
+fn __compile(field: &Field) -> Result<Option<JSONSchema>> {
+  field
+    .filter
+    .as_ref()
+    .map(|filter| JSONSchema::compile(filter).map_err(|error| Error::InvalidDefinition(error.to_string())))
+    .transpose()
+}
+
+fn __matches(fields: &[(&Field, Option<JSONSchema>)], document: &Value) -> Result<bool> {
+  for (field, filter) in fields {
+    let mut found: bool = false;
+
+    // The first path selecting a valid value satisfies the field
+    for path in field.path.iter() {
+      let values: Vec<&Value> =
+        jsonpath_lib::select(document, path).map_err(|error| Error::InvalidDefinition(format!("{:?}", error)))?;
+
+      if values
+        .into_iter()
+        .any(|value| filter.as_ref().map_or(true, |filter| filter.is_valid(value)))
+      {
+        found = true;
+        break;
+      }
+    }
+
+    if !found && !field.optional {
+      return Ok(false);
+    }
+  }
+
+  Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+  use identity_core::common::Object;
+  use identity_core::common::Url;
+  use identity_core::common::Value;
+  use identity_core::convert::FromJson;
+  use identity_core::convert::ToJson;
+  use serde_json::json;
+
+  use crate::credential::Credential;
+  use crate::credential::CredentialBuilder;
+  use crate::credential::Subject;
+  use crate::error::Error;
+  use crate::exchange::Evaluation;
+  use crate::exchange::Field;
+  use crate::exchange::InputDescriptor;
+  use crate::exchange::InputSchema;
+  use crate::exchange::PresentationDefinition;
+  use crate::exchange::PresentationSubmission;
+  use crate::presentation::Presentation;
+
+  const JSON1: &str = include_str!("../../tests/fixtures/definition-1.json");
+  const JSON2: &str = include_str!("../../tests/fixtures/credential-6.json");
+
+  fn credential(type_: &str, subject: Value) -> Credential {
+    CredentialBuilder::default()
+      .issuer(Url::parse("https://example.edu/issuers/14").unwrap())
+      .type_(type_)
+      .subject(Subject::from_json_value(subject).unwrap())
+      .build()
+      .unwrap()
+  }
+
+  fn credentials() -> Vec<Credential> {
+    vec![
+      credential("LicenseCredential", json!({ "license": { "number": "abc-123" } })),
+      Credential::from_json(JSON2).unwrap(),
+      credential("PassportCredential", json!({ "passport": { "number": "X123" } })),
+    ]
+  }
+
+  #[test]
+  fn test_evaluate() {
+    let definition: PresentationDefinition = PresentationDefinition::from_json(JSON1).unwrap();
+    let credentials: Vec<Credential> = credentials();
+    let evaluation: Evaluation<'_, Object> = definition.evaluate(&credentials).unwrap();
+
+    assert_eq!(evaluation.matches("degree_input"), [1]);
+    assert_eq!(evaluation.matches("passport_input"), [2]);
+    assert!(evaluation.matches("license_input").is_empty());
+    assert!(evaluation.matches("unknown").is_empty());
+
+    assert_eq!(evaluation.select().unwrap(), ["degree_input", "passport_input"]);
+  }
+
+  #[test]
+  fn test_presentation() {
+    let definition: PresentationDefinition = PresentationDefinition::from_json(JSON1).unwrap();
+    let credentials: Vec<Credential> = credentials();
+    let evaluation: Evaluation<'_, Object> = definition.evaluate(&credentials).unwrap();
+
+    let presentation: Presentation = evaluation.presentation("a30e3b91").unwrap();
+
+    assert_eq!(presentation.verifiable_credential.len(), 2);
+    assert_eq!(presentation.types.get(1).unwrap(), PresentationSubmission::TYPE);
+
+    let submission: PresentationSubmission =
+      PresentationSubmission::from_json_value(presentation.properties[PresentationSubmission::PROPERTY].clone())
+        .unwrap();
+
+    assert_eq!(submission.id, "a30e3b91");
+    assert_eq!(submission.definition_id, definition.id);
+    assert_eq!(submission.descriptor_map.len(), 2);
+
+    // The descriptor map references the matching credentials
+    let document: Value = presentation.to_json_value().unwrap();
+
+    for (descriptor, expected) in submission.descriptor_map.iter().zip([1, 2].iter()) {
+      let selected: Vec<&Value> = jsonpath_lib::select(&document, &descriptor.path).unwrap();
+
+      assert_eq!(selected, [&credentials[*expected].to_json_value().unwrap()]);
+    }
+  }
+
+  #[test]
+  fn test_unsatisfied() {
+    let definition: PresentationDefinition = PresentationDefinition::from_json(JSON1).unwrap();
+
+    // No credential satisfies the `pick` requirement
+    let credentials: Vec<Credential> = credentials().into_iter().take(2).collect();
+    let evaluation: Evaluation<'_, Object> = definition.evaluate(&credentials).unwrap();
+
+    assert!(matches!(evaluation.select(), Err(Error::UnsatisfiedDefinition)));
+    assert!(evaluation.presentation("a30e3b91").is_err());
+  }
+
+  #[test]
+  fn test_without_requirements() {
+    let mut degree: InputDescriptor = InputDescriptor::new("degree");
+    let mut subject: InputDescriptor = InputDescriptor::new("subject");
+
+    degree.constraints = Some(Default::default());
+    degree.constraints.as_mut().unwrap().fields = vec![Field::new("$.credentialSubject.degree.type")];
+
+    subject.constraints = Some(Default::default());
+    subject.constraints.as_mut().unwrap().fields = vec![Field::new("$.credentialSubject.id")];
+
+    let definition: PresentationDefinition = PresentationDefinition::new("definition", vec![degree, subject]);
+    let credentials: Vec<Credential> = credentials();
+    let evaluation: Evaluation<'_, Object> = definition.evaluate(&credentials).unwrap();
+
+    // Both descriptors are satisfied by the same credential
+    let (submission, selected): (PresentationSubmission, Vec<&Credential>) =
+      evaluation.submission("submission").unwrap();
+
+    assert_eq!(selected, [&credentials[1]]);
+    assert_eq!(submission.descriptor_map[0].path, "$.verifiableCredential[0]");
+    assert_eq!(submission.descriptor_map[1].path, "$.verifiableCredential[0]");
+
+    // All descriptors must be satisfied
+    let definition: PresentationDefinition = PresentationDefinition::new(
+      "definition",
+      vec![InputDescriptor::new("any"), {
+        let mut descriptor: InputDescriptor = InputDescriptor::new("none");
+        descriptor.constraints = Some(Default::default());
+        descriptor.constraints.as_mut().unwrap().fields = vec![Field::new("$.credentialSubject.unknown")];
+        descriptor
+      }],
+    );
+
+    let evaluation: Evaluation<'_, Object> = definition.evaluate(&credentials).unwrap();
+
+    assert_eq!(evaluation.matches("any"), [0, 1, 2]);
+    assert!(evaluation.select().is_err());
+  }
+
+  #[test]
+  fn test_invalid_filter() {
+    let mut descriptor: InputDescriptor = InputDescriptor::new("degree");
+    let mut field: Field = Field::new("$.type");
+
+    field.filter = Some(json!({ "type": 42 }));
+    descriptor.constraints = Some(Default::default());
+    descriptor.constraints.as_mut().unwrap().fields = vec![field];
+
+    let definition: PresentationDefinition = PresentationDefinition::new("definition", vec![descriptor]);
+
+    assert!(matches!(
+      definition.evaluate(&credentials()),
+      Err(Error::InvalidDefinition(_))
+    ));
+  }
+
+  #[test]
+  fn test_schema() {
+    let credentials: Vec<Credential> = credentials();
+
+    let evaluate = |schema: Vec<InputSchema>| -> Vec<usize> {
+      let mut descriptor: InputDescriptor = InputDescriptor::new("descriptor");
+      descriptor.schema = schema;
+
+      let definition: PresentationDefinition = PresentationDefinition::new("definition", vec![descriptor]);
+      let evaluation: Evaluation<'_, Object> = definition.evaluate(&credentials).unwrap();
+
+      evaluation.matches("descriptor").to_vec()
+    };
+
+    let context: InputSchema = InputSchema::new(Url::parse("https://www.w3.org/2018/credentials/examples/v1").unwrap());
+    let type_: InputSchema =
+      InputSchema::new(Url::parse("https://www.w3.org/2018/credentials#PassportCredential").unwrap());
+    let unknown: InputSchema = InputSchema::new(Url::parse("https://example.com/schemas/unknown.json").unwrap());
+
+    // Schemas are matched by `@context` and by type
+    assert_eq!(evaluate(vec![context.clone()]), [1]);
+    assert_eq!(evaluate(vec![type_.clone()]), [2]);
+
+    // At least one schema must match
+    assert_eq!(evaluate(vec![context.clone(), type_.clone()]), [1, 2]);
+    assert!(evaluate(vec![unknown.clone()]).is_empty());
+
+    // Every required schema must match
+    let required: InputSchema = InputSchema {
+      required: true,
+      ..unknown
+    };
+
+    assert!(evaluate(vec![context, required]).is_empty());
+  }
+
+  #[test]
+  fn test_unsupported() {
+    let definitions: Vec<Value> = vec![
+      json!({ "id": "definition", "format": { "jwt_vc": { "alg": ["EdDSA"] } }, "input_descriptors": [] }),
+      json!({ "id": "definition", "input_descriptors": [{ "id": "a", "format": { "ldp_vc": {} } }] }),
+      json!({ "id": "definition", "input_descriptors": [{ "id": "a", "constraints": { "limit_disclosure": "required" } }] }),
+      json!({
+        "id": "definition",
+        "input_descriptors": [{
+          "id": "a",
+          "constraints": { "fields": [{ "path": ["$.credentialSubject.age"], "predicate": "required" }] }
+        }]
+      }),
+    ];
+
+    for definition in definitions {
+      let definition: PresentationDefinition = PresentationDefinition::from_json_value(definition).unwrap();
+
+      assert!(matches!(
+        definition.evaluate(&credentials()),
+        Err(Error::InvalidDefinition(_))
+      ));
+    }
+  }
+}
@@ -0,0 +1,20 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Types and an evaluation engine for the
+//! [DIF Presentation Exchange](https://identity.foundation/presentation-exchange/).
+
+mod definition;
+mod evaluation;
+mod submission;
+
+pub use self::definition::Constraints;
+pub use self::definition::Field;
+pub use self::definition::InputDescriptor;
+pub use self::definition::InputSchema;
+pub use self::definition::PresentationDefinition;
+pub use self::definition::Rule;
+pub use self::definition::SubmissionRequirement;
+pub use self::evaluation::Evaluation;
+pub use self::submission::DescriptorMap;
+pub use self::submission::PresentationSubmission;
@@ -0,0 +1,84 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+/// The credentials submitted by a holder to satisfy a
+/// [`PresentationDefinition`][crate::exchange::PresentationDefinition].
+///
+/// [More Info](https://identity.foundation/presentation-exchange/#presentation-submission)
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct PresentationSubmission {
+  /// A unique identifier of the presentation submission.
+  pub id: String,
+  /// The `id` of the presentation definition the submission satisfies.
+  pub definition_id: String,
+  /// The locations of the submitted inputs.
+  pub descriptor_map: Vec<DescriptorMap>,
+}
+
+impl PresentationSubmission {
+  /// The JSON-LD context of a presentation submission embedded in a
+  /// [`Presentation`][crate::presentation::Presentation].
+  pub const CONTEXT: &'static str = "https://identity.foundation/presentation-exchange/submission/v1";
+
+  /// The type of a [`Presentation`][crate::presentation::Presentation] with an
+  /// embedded presentation submission.
+  pub const TYPE: &'static str = "PresentationSubmission";
+
+  /// The name of the [`Presentation`][crate::presentation::Presentation]
+  /// property holding an embedded presentation submission.
+  pub const PROPERTY: &'static str = "presentation_submission";
+}
+
+/// The location of an input submitted for an
+/// [`InputDescriptor`][crate::exchange::InputDescriptor].
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct DescriptorMap {
+  /// The `id` of the satisfied input descriptor.
+  pub id: String,
+  /// The claim format of the input, e.g. `ldp_vc` or `jwt_vc`.
+  pub format: String,
+  /// A JSONPath expression selecting the input in the submission.
+  pub path: String,
+  /// The location of the input within the selected claim.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub path_nested: Option<Box<DescriptorMap>>,
+}
+
+impl DescriptorMap {
+  /// Creates a new `DescriptorMap`.
+  pub fn new(id: impl Into<String>, format: impl Into<String>, path: impl Into<String>) -> Self {
+    Self {
+      id: id.into(),
+      format: format.into(),
+      path: path.into(),
+      path_nested: None,
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use identity_core::convert::FromJson;
+
+  use crate::exchange::DescriptorMap;
+  use crate::exchange::PresentationSubmission;
+
+  const JSON: &str = include_str!("../../tests/fixtures/submission-1.json");
+
+  #[test]
+  fn test_from_json() {
+    let submission: PresentationSubmission = PresentationSubmission::from_json(JSON).unwrap();
+
+    assert_eq!(submission.definition_id, "32f54163-7166-48f1-93d8-ff217bdb0653");
+    assert_eq!(submission.descriptor_map.len(), 2);
+    assert_eq!(
+      submission.descriptor_map[0],
+      DescriptorMap::new("degree_input", "ldp_vc", "$.verifiableCredential[0]")
+    );
+
+    let nested: &DescriptorMap = submission.descriptor_map[1].path_nested.as_deref().unwrap();
+
+    assert_eq!(nested.format, "ldp_vc");
+    assert_eq!(nested.path, "$.innerClaim[1]");
+  }
+}
@@ -0,0 +1,265 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Timestamp;
+use identity_core::convert::FromJson;
+use identity_core::convert::ToJson;
+use libjose::jwt::JwtClaims;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Map;
+use serde_json::Value;
+
+use crate::credential::Credential;
+use crate::credential::Issuer;
+use crate::error::Error;
+use crate::error::Result;
+use crate::presentation::Presentation;
+
+impl<T> Credential<T> {
+  /// Converts the `Credential` into a set of JWT claims.
+  ///
+  /// The `id`, `issuer`, `issuanceDate`, and `expirationDate` properties, as
+  /// well as the `id` of a single `credentialSubject`, are mapped to the
+  /// registered `jti`, `iss`, `nbf`, `exp`, and `sub` claims; all remaining
+  /// properties are stored in the `vc` claim.
+  ///
+  /// Note: Any embedded `proof` is discarded - the JWT is secured by a JWS.
+  pub fn to_jwt_claims(&self) -> Result<JwtClaims>
+  where
+    T: Serialize,
+  {
+    let mut claims: JwtClaims = JwtClaims::new();
+    let mut vc: Map<String, Value> = __to_map(self)?;
+
+    vc.remove("proof");
+
+    if let Some(id) = self.id.as_ref() {
+      vc.remove("id");
+      claims.set_jti(id.as_str());
+    }
+
+    // Issuers with additional properties are kept in the `vc` claim
+    if let Issuer::Url(_) = self.issuer {
+      vc.remove("issuer");
+    }
+
+    claims.set_iss(self.issuer.url().as_str());
+
+    vc.remove("issuanceDate");
+    claims.set_nbf(self.issuance_date.to_unix());
+
+    if let Some(expiration_date) = self.expiration_date {
+      vc.remove("expirationDate");
+      claims.set_exp(expiration_date.to_unix());
+    }
+
+    if let [subject] = self.credential_subject.as_slice() {
+      if let Some(id) = subject.id.as_ref() {
+        if let Some(subject) = __subject_mut(&mut vc) {
+          subject.remove("id");
+        }
+
+        claims.set_sub(id.as_str());
+      }
+    }
+
+    claims.set_vc(vc);
+
+    Ok(claims)
+  }
+
+  /// Creates a `Credential` from a set of JWT claims.
+  ///
+  /// The registered claims take precedence over the corresponding properties
+  /// of the `vc` claim.
+  pub fn from_jwt_claims<U>(claims: &JwtClaims<U>) -> Result<Self>
+  where
+    T: DeserializeOwned,
+  {
+    let mut vc: Map<String, Value> = claims.vc().cloned().ok_or(Error::InvalidJwt("vc"))?;
+
+    if let Some(jti) = claims.jti() {
+      vc.insert("id".into(), jti.into());
+    }
+
+    if let Some(iss) = claims.iss() {
+      match vc.get_mut("issuer") {
+        Some(Value::Object(issuer)) => {
+          issuer.insert("id".into(), iss.into());
+        }
+        _ => {
+          vc.insert("issuer".into(), iss.into());
+        }
+      }
+    }
+
+    if let Some(nbf) = claims.nbf() {
+      vc.insert("issuanceDate".into(), Timestamp::from_unix(nbf)?.to_rfc3339().into());
+    }
+
+    if let Some(exp) = claims.exp() {
+      vc.insert("expirationDate".into(), Timestamp::from_unix(exp)?.to_rfc3339().into());
+    }
+
+    if let Some(sub) = claims.sub() {
+      __subject_mut(&mut vc)
+        .ok_or(Error::InvalidJwt("sub"))?
+        .insert("id".into(), sub.into());
+    }
+
+    Self::from_json_value(Value::Object(vc)).map_err(Into::into)
+  }
+}
+
+impl<T, U> Presentation<T, U> {
+  /// Converts the `Presentation` into a set of JWT claims.
+  ///
+  /// The `id` and `holder` properties are mapped to the registered `jti` and
+  /// `iss` claims; all remaining properties are stored in the `vp` claim.
+  ///
+  /// Note: Any embedded `proof` is discarded - the JWT is secured by a JWS.
+  pub fn to_jwt_claims(&self) -> Result<JwtClaims>
+  where
+    T: Serialize,
+    U: Serialize,
+  {
+    let mut claims: JwtClaims = JwtClaims::new();
+    let mut vp: Map<String, Value> = __to_map(self)?;
+
+    vp.remove("proof");
+
+    if let Some(id) = self.id.as_ref() {
+      vp.remove("id");
+      claims.set_jti(id.as_str());
+    }
+
+    if let Some(holder) = self.holder.as_ref() {
+      vp.remove("holder");
+      claims.set_iss(holder.as_str());
+    }
+
+    claims.set_vp(vp);
+
+    Ok(claims)
+  }
+
+  /// Creates a `Presentation` from a set of JWT claims.
+  ///
+  /// The registered claims take precedence over the corresponding properties
+  /// of the `vp` claim.
+  pub fn from_jwt_claims<V>(claims: &JwtClaims<V>) -> Result<Self>
+  where
+    T: DeserializeOwned,
+    U: DeserializeOwned,
+  {
+    let mut vp: Map<String, Value> = claims.vp().cloned().ok_or(Error::InvalidJwt("vp"))?;
+
+    if let Some(jti) = claims.jti() {
+      vp.insert("id".into(), jti.into());
+    }
+
+    if let Some(iss) = claims.iss() {
+      vp.insert("holder".into(), iss.into());
+    }
+
+    Self::from_json_value(Value::Object(vp)).map_err(Into::into)
+  }
+}
+
+fn __to_map<T>(data: &T) -> Result<Map<String, Value>>
+where
+  T: Serialize,
+{
+  match data.to_json_value()? {
+    Value::Object(object) => Ok(object),
+    _ => Err(Error::InvalidJwt("claims")),
+  }
+}
+
+// Returns the single `credentialSubject` of a serialized credential.
+fn __subject_mut(vc: &mut Map<String, Value>) -> Option<&mut Map<String, Value>> {
+  match vc.get_mut("credentialSubject")? {
+    Value::Object(subject) => Some(subject),
+    Value::Array(subjects) if subjects.len() == 1 => subjects[0].as_object_mut(),
+    _ => None,
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use identity_core::convert::FromJson;
+  use libjose::jwt::JwtClaims;
+  use serde_json::json;
+  use serde_json::Value;
+
+  use crate::credential::Credential;
+  use crate::presentation::Presentation;
+
+  const JSON1: &str = include_str!("../../tests/fixtures/credential-6.json");
+  const JSON2: &str = include_str!("../../tests/fixtures/presentation-1.json");
+
+  #[test]
+  fn test_credential_claims() {
+    let credential: Credential = Credential::from_json(JSON1).unwrap();
+    let claims: JwtClaims = credential.to_jwt_claims().unwrap();
+
+    assert_eq!(claims.jti(), Some("http://example.edu/credentials/3732"));
+    assert_eq!(claims.iss(), Some("https://example.edu/issuers/14"));
+    assert_eq!(claims.sub(), Some("did:example:ebfeb1f712ebc6f1c276e12ec21"));
+    assert_eq!(claims.nbf(), Some(credential.issuance_date.to_unix()));
+    assert_eq!(claims.exp(), credential.expiration_date.map(|date| date.to_unix()));
+
+    let vc: Value = Value::Object(claims.vc().cloned().unwrap());
+
+    assert_eq!(
+      vc,
+      json!({
+        "@context": [
+          "https://www.w3.org/2018/credentials/v1",
+          "https://www.w3.org/2018/credentials/examples/v1"
+        ],
+        "type": ["VerifiableCredential", "UniversityDegreeCredential"],
+        "credentialSubject": {
+          "degree": {
+            "type": "BachelorDegree",
+            "name": "Bachelor of Science in Mechanical Engineering"
+          }
+        }
+      })
+    );
+
+    assert_eq!(Credential::from_jwt_claims(&claims).unwrap(), credential);
+  }
+
+  #[test]
+  fn test_credential_claims_missing_vc() {
+    assert!(Credential::<()>::from_jwt_claims(&JwtClaims::<()>::new()).is_err());
+  }
+
+  #[test]
+  fn test_presentation_claims() {
+    let mut presentation: Presentation = Presentation::from_json(JSON2).unwrap();
+
+    presentation.holder = Some("did:example:holder".parse().unwrap());
+
+    let claims: JwtClaims = presentation.to_jwt_claims().unwrap();
+
+    assert_eq!(claims.jti(), Some("urn:uuid:3978344f-8596-4c3a-a978-8fcaba3903c5"));
+    assert_eq!(claims.iss(), Some("did:example:holder"));
+
+    let vp: &serde_json::Map<String, Value> = claims.vp().unwrap();
+
+    assert!(!vp.contains_key("id"));
+    assert!(!vp.contains_key("holder"));
+    assert!(!vp.contains_key("proof"));
+    assert!(vp["verifiableCredential"][0].get("proof").is_some());
+
+    let decoded: Presentation = Presentation::from_jwt_claims(&claims).unwrap();
+
+    assert_eq!(decoded.id, presentation.id);
+    assert_eq!(decoded.holder, presentation.holder);
+    assert_eq!(decoded.verifiable_credential, presentation.verifiable_credential);
+    assert!(decoded.proofs().is_empty());
+  }
+}
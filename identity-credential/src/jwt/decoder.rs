@@ -0,0 +1,329 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::convert::FromJson;
+use identity_did::did::DID;
+use identity_did::document::Document;
+use identity_did::resolution::InputMetadata;
+use identity_did::resolution::ResolverMethod;
+use identity_did::verification::Method;
+use identity_did::verification::MethodScope;
+use identity_did::verification::MethodType;
+use libjose::jws::Decoder;
+use libjose::jws::JwsAlgorithm;
+use libjose::jws::JwsHeader;
+use libjose::jws::Token;
+use libjose::jwt::JwtClaims;
+use libjose::utils::decode_b64_json;
+use serde::de::DeserializeOwned;
+
+use crate::credential::Credential;
+use crate::error::Error;
+use crate::error::Result;
+use crate::presentation::Presentation;
+
+/// Decodes and verifies JWT-encoded Credentials and Presentations.
+///
+/// The signing key is resolved from the DID Document referenced by the `kid`
+/// header parameter of the JWT. Credentials MUST be signed with an
+/// `assertionMethod` and Presentations with an `authentication` method.
+///
+/// Note: Only the signature is verified - the decoded Credential can be
+/// checked further with a [`ValidationPolicy`][crate::validation::ValidationPolicy].
+#[derive(Clone, Debug)]
+pub struct JwtDecoder<R> {
+  resolver: R,
+}
+
+impl<R> JwtDecoder<R>
+where
+  R: ResolverMethod,
+{
+  /// Creates a new [`JwtDecoder`] resolving DID Documents with `resolver`.
+  pub fn new(resolver: R) -> Self {
+    Self { resolver }
+  }
+
+  /// Decodes and verifies a JWT-encoded [`Credential`].
+  ///
+  /// The `iss` claim MUST be the DID controlling the signing key and the key
+  /// MUST be an `assertionMethod` of the issuer.
+  pub async fn decode_credential<T>(&self, token: &str) -> Result<Credential<T>>
+  where
+    T: DeserializeOwned,
+  {
+    self
+      .decode(token, MethodScope::AssertionMethod)
+      .await
+      .and_then(|claims| Credential::from_jwt_claims(&claims))
+  }
+
+  /// Decodes and verifies a JWT-encoded [`Presentation`].
+  ///
+  /// The `iss` claim MUST be the DID controlling the signing key and the key
+  /// MUST be an `authentication` method of the holder.
+  pub async fn decode_presentation<T, U>(&self, token: &str) -> Result<Presentation<T, U>>
+  where
+    T: DeserializeOwned,
+    U: DeserializeOwned,
+  {
+    self
+      .decode(token, MethodScope::Authentication)
+      .await
+      .and_then(|claims| Presentation::from_jwt_claims(&claims))
+  }
+
+  /// Decodes and verifies a JWT, returning the set of claims.
+  ///
+  /// The signing key MUST be a verification method of the given `scope`.
+  pub async fn decode(&self, token: &str, scope: MethodScope) -> Result<JwtClaims> {
+    let header: JwsHeader = token
+      .split('.')
+      .next()
+      .map(decode_b64_json)
+      .transpose()?
+      .ok_or(Error::InvalidJwt("header"))?;
+
+    let kid: &str = header.kid().ok_or(Error::InvalidJwt("kid"))?;
+    let mut did: DID = kid.parse().map_err(identity_did::Error::from)?;
+
+    did.set_fragment(None);
+
+    if !self.resolver.is_supported(&did) {
+      return Err(Error::InvalidJwt("kid"));
+    }
+
+    let document: Document = self
+      .resolver
+      .read(&did, InputMetadata::default())
+      .await?
+      .map(|document| document.data)
+      .ok_or(Error::InvalidJwt("kid"))?;
+
+    let method: &Method = document
+      .resolve_with_scope(kid, scope)
+      .ok_or(Error::InvalidJwt("kid"))?;
+
+    if method.key_type() != MethodType::Ed25519VerificationKey2018 {
+      return Err(Error::InvalidJwt("kid"));
+    }
+
+    let public: Vec<u8> = method.key_data().try_decode()?;
+
+    let token: Token<'_> = Decoder::new(&public[..])
+      .algorithm(JwsAlgorithm::EdDSA)
+      .key_id(kid)
+      .decode(token.as_bytes())?;
+
+    let claims: JwtClaims = JwtClaims::from_json_slice(&token.claims)?;
+
+    // The issuer/holder MUST control the verification method
+    if claims.iss() != Some(did.as_str()) {
+      return Err(Error::InvalidJwt("iss"));
+    }
+
+    Ok(claims)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use async_trait::async_trait;
+  use futures::executor::block_on;
+  use identity_core::common::Object;
+  use identity_core::common::Url;
+  use identity_core::convert::FromJson;
+  use identity_core::crypto::KeyPair;
+  use identity_core::utils::encode_b58;
+  use identity_did::did::DID;
+  use identity_did::document::Document;
+  use identity_did::document::DocumentBuilder;
+  use identity_did::resolution::DocumentMetadata;
+  use identity_did::resolution::InputMetadata;
+  use identity_did::resolution::MetaDocument;
+  use identity_did::resolution::ResolverMethod;
+  use identity_did::verification::Method;
+  use identity_did::verification::MethodBuilder;
+  use identity_did::verification::MethodData;
+  use identity_did::verification::MethodScope;
+  use identity_did::verification::MethodType;
+
+  use crate::credential::Credential;
+  use crate::jwt::JwtDecoder;
+  use crate::jwt::JwtEncoder;
+  use crate::presentation::Presentation;
+
+  const JSON1: &str = include_str!("../../tests/fixtures/credential-6.json");
+  const JSON2: &str = include_str!("../../tests/fixtures/presentation-1.json");
+
+  struct TestResolver(Document);
+
+  #[async_trait(?Send)]
+  impl ResolverMethod for TestResolver {
+    fn is_supported(&self, did: &DID) -> bool {
+      did.method() == "example"
+    }
+
+    async fn read(&self, did: &DID, _input: InputMetadata) -> identity_did::Result<Option<MetaDocument>> {
+      if did == self.0.id() {
+        Ok(Some(MetaDocument {
+          data: self.0.clone(),
+          meta: DocumentMetadata::default(),
+        }))
+      } else {
+        Ok(None)
+      }
+    }
+  }
+
+  fn setup(scope: MethodScope) -> (KeyPair, Method, TestResolver) {
+    let keypair: KeyPair = KeyPair::new_ed25519().unwrap();
+    let controller: DID = "did:example:1234".parse().unwrap();
+
+    let method: Method = MethodBuilder::default()
+      .id(controller.join("#key-1").unwrap())
+      .controller(controller.clone())
+      .key_type(MethodType::Ed25519VerificationKey2018)
+      .key_data(MethodData::PublicKeyBase58(encode_b58(keypair.public())))
+      .build()
+      .unwrap();
+
+    let builder: DocumentBuilder = DocumentBuilder::default()
+      .id(controller)
+      .verification_method(method.clone());
+
+    let document: Document = match scope {
+      MethodScope::Authentication => builder.authentication(method.id().clone()),
+      MethodScope::AssertionMethod => builder.assertion_method(method.id().clone()),
+      _ => builder,
+    }
+    .build()
+    .unwrap();
+
+    (keypair, method, TestResolver(document))
+  }
+
+  #[test]
+  fn test_credential_roundtrip() {
+    let (keypair, method, resolver): (KeyPair, Method, TestResolver) = setup(MethodScope::AssertionMethod);
+
+    let mut credential: Credential = Credential::from_json(JSON1).unwrap();
+    credential.issuer = Url::parse("did:example:1234").unwrap().into();
+
+    let token: String = JwtEncoder::new(&method, keypair.secret())
+      .unwrap()
+      .encode_credential(&credential)
+      .unwrap();
+
+    let decoder: JwtDecoder<&TestResolver> = JwtDecoder::new(&resolver);
+    let decoded: Credential = block_on(decoder.decode_credential(&token)).unwrap();
+
+    assert_eq!(decoded, credential);
+
+    // The signature does not match the claims
+    let mut segments: Vec<&str> = token.split('.').collect();
+    let other: String = JwtEncoder::new(&method, keypair.secret())
+      .unwrap()
+      .encode_credential(&Credential::from_json(JSON1).unwrap())
+      .unwrap();
+
+    segments[1] = other.split('.').nth(1).unwrap();
+
+    assert!(block_on(decoder.decode_credential::<Object>(&segments.join("."))).is_err());
+  }
+
+  #[test]
+  fn test_credential_invalid_issuer() {
+    let (keypair, method, resolver): (KeyPair, Method, TestResolver) = setup(MethodScope::AssertionMethod);
+
+    // The issuer does not control the signing key
+    let credential: Credential = Credential::from_json(JSON1).unwrap();
+
+    let token: String = JwtEncoder::new(&method, keypair.secret())
+      .unwrap()
+      .encode_credential(&credential)
+      .unwrap();
+
+    let decoder: JwtDecoder<TestResolver> = JwtDecoder::new(resolver);
+
+    assert!(block_on(decoder.decode_credential::<Object>(&token)).is_err());
+  }
+
+  #[test]
+  fn test_presentation_roundtrip() {
+    let (keypair, method, resolver): (KeyPair, Method, TestResolver) = setup(MethodScope::Authentication);
+
+    let mut presentation: Presentation = Presentation::from_json(JSON2).unwrap();
+    presentation.holder = Some(Url::parse("did:example:1234").unwrap());
+    presentation.proof = None;
+
+    let token: String = JwtEncoder::new(&method, keypair.secret())
+      .unwrap()
+      .encode_presentation(&presentation)
+      .unwrap();
+
+    let decoder: JwtDecoder<TestResolver> = JwtDecoder::new(resolver);
+    let decoded: Presentation = block_on(decoder.decode_presentation(&token)).unwrap();
+
+    assert_eq!(decoded, presentation);
+
+    // Keys that cannot be resolved are rejected
+    let other: KeyPair = KeyPair::new_ed25519().unwrap();
+    let mut method: Method = method;
+
+    *method.id_mut() = "did:example:5678#key-1".parse().unwrap();
+
+    let token: String = JwtEncoder::new(&method, other.secret())
+      .unwrap()
+      .encode_presentation(&presentation)
+      .unwrap();
+
+    assert!(block_on(decoder.decode_presentation::<Object, Object>(&token)).is_err());
+  }
+
+  #[test]
+  fn test_invalid_scope() {
+    let mut credential: Credential = Credential::from_json(JSON1).unwrap();
+    credential.issuer = Url::parse("did:example:1234").unwrap().into();
+
+    let mut presentation: Presentation = Presentation::from_json(JSON2).unwrap();
+    presentation.holder = Some(Url::parse("did:example:1234").unwrap());
+    presentation.proof = None;
+
+    // Credentials can't be signed with an authentication method
+    let (keypair, method, resolver): (KeyPair, Method, TestResolver) = setup(MethodScope::Authentication);
+
+    let token: String = JwtEncoder::new(&method, keypair.secret())
+      .unwrap()
+      .encode_credential(&credential)
+      .unwrap();
+
+    let decoder: JwtDecoder<TestResolver> = JwtDecoder::new(resolver);
+
+    assert!(block_on(decoder.decode_credential::<Object>(&token)).is_err());
+
+    // Presentations can't be signed with an assertion method
+    let (keypair, method, resolver): (KeyPair, Method, TestResolver) = setup(MethodScope::AssertionMethod);
+
+    let token: String = JwtEncoder::new(&method, keypair.secret())
+      .unwrap()
+      .encode_presentation(&presentation)
+      .unwrap();
+
+    let decoder: JwtDecoder<TestResolver> = JwtDecoder::new(resolver);
+
+    assert!(block_on(decoder.decode_presentation::<Object, Object>(&token)).is_err());
+
+    // Methods that are not referenced by any relationship are rejected
+    let (keypair, method, resolver): (KeyPair, Method, TestResolver) = setup(MethodScope::VerificationMethod);
+
+    let token: String = JwtEncoder::new(&method, keypair.secret())
+      .unwrap()
+      .encode_credential(&credential)
+      .unwrap();
+
+    let decoder: JwtDecoder<TestResolver> = JwtDecoder::new(resolver);
+
+    assert!(block_on(decoder.decode_credential::<Object>(&token)).is_err());
+  }
+}
@@ -0,0 +1,68 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::crypto::SecretKey;
+use identity_did::verification::Method;
+use identity_did::verification::MethodType;
+use libjose::jws::Encoder;
+use libjose::jws::JwsAlgorithm;
+use libjose::jws::JwsHeader;
+use libjose::jwt::JwtClaims;
+use serde::Serialize;
+
+use crate::credential::Credential;
+use crate::error::Error;
+use crate::error::Result;
+use crate::presentation::Presentation;
+
+/// Encodes Credentials and Presentations as JWTs, signed with the EdDSA
+/// algorithm.
+///
+/// The `kid` header parameter references the DID verification method of the
+/// signing key.
+pub struct JwtEncoder<'a> {
+  header: JwsHeader,
+  secret: &'a SecretKey,
+}
+
+impl<'a> JwtEncoder<'a> {
+  /// Creates a new [`JwtEncoder`] signing with the `secret` key of the given
+  /// `Ed25519VerificationKey2018` verification `method`.
+  pub fn new<U>(method: &Method<U>, secret: &'a SecretKey) -> Result<Self> {
+    if method.key_type() != MethodType::Ed25519VerificationKey2018 {
+      return Err(Error::InvalidJwt("kid"));
+    }
+
+    let mut header: JwsHeader = JwsHeader::new(JwsAlgorithm::EdDSA);
+
+    header.set_kid(method.id().as_str());
+    header.set_typ("JWT");
+
+    Ok(Self { header, secret })
+  }
+
+  /// Encodes the given `credential` as a signed JWT.
+  pub fn encode_credential<T>(&self, credential: &Credential<T>) -> Result<String>
+  where
+    T: Serialize,
+  {
+    self.encode(&credential.to_jwt_claims()?)
+  }
+
+  /// Encodes the given `presentation` as a signed JWT.
+  pub fn encode_presentation<T, U>(&self, presentation: &Presentation<T, U>) -> Result<String>
+  where
+    T: Serialize,
+    U: Serialize,
+  {
+    self.encode(&presentation.to_jwt_claims()?)
+  }
+
+  /// Encodes the given set of `claims` as a signed JWT.
+  pub fn encode(&self, claims: &JwtClaims) -> Result<String> {
+    Encoder::new()
+      .recipient((self.secret.as_ref(), &self.header))
+      .encode_serde(claims)
+      .map_err(Into::into)
+  }
+}
@@ -0,0 +1,15 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! The JWT encoding of Verifiable Credentials and Presentations.
+//!
+//! See the [VC Data Model](https://www.w3.org/TR/vc-data-model/#json-web-token)
+//! for more information.
+
+mod claims;
+mod decoder;
+mod encoder;
+
+pub use self::decoder::JwtDecoder;
+pub use self::encoder::JwtEncoder;
+pub use libjose::jwt::JwtClaims;
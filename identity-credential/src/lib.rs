@@ -25,7 +25,12 @@ extern crate serde;
 
 pub mod credential;
 pub mod error;
+pub mod exchange;
+pub mod jwt;
 pub mod presentation;
+pub mod refresh;
+pub mod status;
+pub mod validation;
 
 pub use self::error::Error;
 pub use self::error::Result;
@@ -137,6 +137,7 @@ mod tests {
   use identity_core::common::Url;
   use identity_core::convert::FromJson;
   use identity_core::crypto::KeyPair;
+  use identity_core::crypto::ProofPurpose;
   use identity_core::utils::encode_b58;
   use identity_did::did::DID;
   use identity_did::document::Document;
@@ -185,7 +186,7 @@ mod tests {
 
     let document: Document = DocumentBuilder::default()
       .id(controller)
-      .verification_method(method)
+      .assertion_method(method)
       .build()
       .unwrap();
 
@@ -199,6 +200,7 @@ mod tests {
     document
       .signer(keypair.secret())
       .method("#key-1")
+      .purpose(ProofPurpose::AssertionMethod)
       .sign(&mut credential)
       .unwrap();
 
@@ -14,7 +14,11 @@ use identity_core::crypto::SetSignature;
 use identity_core::crypto::Signature;
 use identity_core::crypto::TrySignature;
 use identity_core::crypto::TrySignatureMut;
+use serde::Deserialize;
+use serde::Deserializer;
 use serde::Serialize;
+use serde::Serializer;
+use std::vec::IntoIter;
 
 use crate::credential::Credential;
 use crate::credential::Policy;
@@ -24,35 +28,31 @@ use crate::error::Result;
 use crate::presentation::PresentationBuilder;
 
 /// Represents a bundle of one or more [Credential]s.
-#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct Presentation<T = Object, U = Object> {
   /// The JSON-LD context(s) applicable to the `Presentation`.
-  #[serde(rename = "@context")]
   pub context: OneOrMany<Context>,
   /// A unique `URI` referencing the subject of the `Presentation`.
-  #[serde(skip_serializing_if = "Option::is_none")]
   pub id: Option<Url>,
   /// One or more URIs defining the type of the `Presentation`.
-  #[serde(rename = "type")]
   pub types: OneOrMany<String>,
   /// Credential(s) expressing the claims of the `Presentation`.
-  #[serde(default = "Default::default", rename = "verifiableCredential")]
   pub verifiable_credential: OneOrMany<Credential<U>>,
   /// The entity that generated the `Presentation`.
-  #[serde(skip_serializing_if = "Option::is_none")]
   pub holder: Option<Url>,
   /// Service(s) used to refresh an expired `Presentation`.
-  #[serde(default, rename = "refreshService", skip_serializing_if = "OneOrMany::is_empty")]
   pub refresh_service: OneOrMany<Refresh>,
   /// Terms-of-use specified by the `Presentation` holder.
-  #[serde(default, rename = "termsOfUse", skip_serializing_if = "OneOrMany::is_empty")]
   pub terms_of_use: OneOrMany<Policy>,
   /// Miscellaneous properties.
-  #[serde(flatten)]
   pub properties: T,
-  /// Proof(s) used to verify a `Presentation`
-  #[serde(skip_serializing_if = "Option::is_none")]
+  /// Proof used to verify a `Presentation`.
+  ///
+  /// If the `Presentation` has a proof set, this is the first proof of the set -
+  /// see [`Presentation::proofs`].
   pub proof: Option<Signature>,
+  /// The remaining proofs of a proof set.
+  pub(crate) proof_set: Vec<Signature>,
 }
 
 impl<T, U> Presentation<T, U> {
@@ -85,6 +85,7 @@ impl<T, U> Presentation<T, U> {
       terms_of_use: builder.policy.into(),
       properties: builder.properties,
       proof: None,
+      proof_set: Vec::new(),
     };
 
     this.check_structure()?;
@@ -113,14 +114,28 @@ impl<T, U> Presentation<T, U> {
     Ok(())
   }
 
-  /// Returns a reference to the `Presentation` proof.
+  /// Returns a reference to the proof.
+  ///
+  /// If the `Presentation` has a proof set, this is the focused or last proof.
   pub fn proof(&self) -> Option<&Signature> {
-    self.proof.as_ref()
+    self.proofs().into_iter().rev().find(|proof| !proof.is_excluded())
   }
 
-  /// Returns a mutable reference to the `Presentation` proof.
+  /// Returns a mutable reference to the proof.
+  ///
+  /// If the `Presentation` has a proof set, this is the focused or last proof.
   pub fn proof_mut(&mut self) -> Option<&mut Signature> {
-    self.proof.as_mut()
+    self
+      .proof
+      .iter_mut()
+      .chain(self.proof_set.iter_mut())
+      .rev()
+      .find(|proof| !proof.is_excluded())
+  }
+
+  /// Returns all proofs of the proof set or proof chain, in order.
+  pub fn proofs(&self) -> Vec<&Signature> {
+    self.proof.iter().chain(self.proof_set.iter()).collect()
   }
 }
 
@@ -140,19 +155,135 @@ where
 
 impl<T, U> TrySignature for Presentation<T, U> {
   fn signature(&self) -> Option<&Signature> {
-    self.proof.as_ref()
+    self.proof()
+  }
+
+  fn signatures(&self) -> Vec<&Signature> {
+    self.proofs()
   }
 }
 
 impl<T, U> TrySignatureMut for Presentation<T, U> {
   fn signature_mut(&mut self) -> Option<&mut Signature> {
-    self.proof.as_mut()
+    self.proof_mut()
   }
 }
 
 impl<T, U> SetSignature for Presentation<T, U> {
   fn set_signature(&mut self, value: Signature) {
-    self.proof.replace(value);
+    self.proof = Some(value);
+    self.proof_set.clear();
+  }
+
+  fn push_signature(&mut self, value: Signature) {
+    if self.proof.is_none() && self.proof_set.is_empty() {
+      self.proof = Some(value);
+    } else {
+      self.proof_set.push(value);
+    }
+  }
+}
+
+impl<T, U> Serialize for Presentation<T, U>
+where
+  T: Serialize,
+  U: Serialize,
+{
+  fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: Serializer,
+  {
+    // Proofs excluded by a focused proof are not part of the serialized form
+    let proof: Vec<&Signature> = self.proofs().into_iter().filter(|proof| !proof.is_excluded()).collect();
+
+    PresentationRef {
+      context: &self.context,
+      id: self.id.as_ref(),
+      types: &self.types,
+      verifiable_credential: &self.verifiable_credential,
+      holder: self.holder.as_ref(),
+      refresh_service: &self.refresh_service,
+      terms_of_use: &self.terms_of_use,
+      properties: &self.properties,
+      proof: proof.into(),
+    }
+    .serialize(serializer)
+  }
+}
+
+impl<'de, T, U> Deserialize<'de> for Presentation<T, U>
+where
+  T: Deserialize<'de>,
+  U: Deserialize<'de>,
+{
+  fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+  where
+    D: Deserializer<'de>,
+  {
+    PresentationData::deserialize(deserializer).map(Into::into)
+  }
+}
+
+/// The serialized form of a [`Presentation`].
+#[derive(Deserialize)]
+struct PresentationData<T, U> {
+  #[serde(rename = "@context")]
+  context: OneOrMany<Context>,
+  id: Option<Url>,
+  #[serde(rename = "type")]
+  types: OneOrMany<String>,
+  #[serde(default = "Default::default", rename = "verifiableCredential")]
+  verifiable_credential: OneOrMany<Credential<U>>,
+  holder: Option<Url>,
+  #[serde(default, rename = "refreshService")]
+  refresh_service: OneOrMany<Refresh>,
+  #[serde(default, rename = "termsOfUse")]
+  terms_of_use: OneOrMany<Policy>,
+  #[serde(flatten)]
+  properties: T,
+  #[serde(default)]
+  proof: OneOrMany<Signature>,
+}
+
+/// The serialized form of a borrowed [`Presentation`].
+#[derive(Serialize)]
+struct PresentationRef<'a, T, U> {
+  #[serde(rename = "@context")]
+  context: &'a OneOrMany<Context>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  id: Option<&'a Url>,
+  #[serde(rename = "type")]
+  types: &'a OneOrMany<String>,
+  #[serde(rename = "verifiableCredential")]
+  verifiable_credential: &'a OneOrMany<Credential<U>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  holder: Option<&'a Url>,
+  #[serde(rename = "refreshService", skip_serializing_if = "OneOrMany::is_empty")]
+  refresh_service: &'a OneOrMany<Refresh>,
+  #[serde(rename = "termsOfUse", skip_serializing_if = "OneOrMany::is_empty")]
+  terms_of_use: &'a OneOrMany<Policy>,
+  #[serde(flatten)]
+  properties: &'a T,
+  #[serde(skip_serializing_if = "OneOrMany::is_empty")]
+  proof: OneOrMany<&'a Signature>,
+}
+
+impl<T, U> From<PresentationData<T, U>> for Presentation<T, U> {
+  fn from(other: PresentationData<T, U>) -> Self {
+    let mut proofs: IntoIter<Signature> = other.proof.into_vec().into_iter();
+
+    Self {
+      context: other.context,
+      id: other.id,
+      types: other.types,
+      verifiable_credential: other.verifiable_credential,
+      holder: other.holder,
+      refresh_service: other.refresh_service,
+      terms_of_use: other.terms_of_use,
+      properties: other.properties,
+      proof: proofs.next(),
+      proof_set: proofs.collect(),
+    }
   }
 }
 
@@ -0,0 +1,13 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! A protocol for refreshing expiring credentials through the
+//! [refresh service](https://www.w3.org/TR/vc-data-model/#refreshing) of the
+//! credential.
+
+mod request;
+mod service;
+
+pub use self::request::RefreshRequest;
+pub use self::service::RefreshChallenge;
+pub use self::service::RefreshService;
@@ -0,0 +1,167 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Object;
+use identity_core::common::Timestamp;
+use identity_core::common::Url;
+use identity_core::crypto::ProofPurpose;
+use identity_core::crypto::SecretKey;
+use identity_core::crypto::SignatureOptions;
+use identity_did::document::Document;
+use serde::Serialize;
+
+use crate::credential::Credential;
+use crate::error::Error;
+use crate::error::Result;
+use crate::presentation::Presentation;
+use crate::presentation::PresentationBuilder;
+use crate::refresh::RefreshChallenge;
+
+/// A request of a holder to refresh a [`Credential`], sent to the refresh
+/// service of the credential.
+///
+/// The request is a [`Presentation`] of the credential, signed by an
+/// `authentication` method of the holder.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RefreshRequest<T = Object> {
+  presentation: Presentation<Object, T>,
+}
+
+impl<T> RefreshRequest<T> {
+  /// The presentation type of a refresh request.
+  pub const TYPE: &'static str = "CredentialRefreshRequest";
+
+  /// Creates a new, unsigned request to refresh `credential`, held by `holder`.
+  ///
+  /// # Errors
+  ///
+  /// Fails if the credential does not specify a refresh service.
+  pub fn new(credential: Credential<T>, holder: Url) -> Result<Self> {
+    if credential.refresh_service.is_empty() {
+      return Err(Error::InvalidRefresh("refreshService"));
+    }
+
+    PresentationBuilder::default()
+      .type_(Self::TYPE)
+      .holder(holder)
+      .credential(credential)
+      .build()
+      .map(|presentation| Self { presentation })
+  }
+
+  /// Parses a request from a received [`Presentation`].
+  ///
+  /// Note: The proof of the presentation is NOT verified.
+  pub fn from_presentation(presentation: Presentation<Object, T>) -> Result<Self> {
+    if !presentation.types.iter().any(|type_| type_ == Self::TYPE) {
+      return Err(Error::InvalidRefresh("type"));
+    }
+
+    if presentation.verifiable_credential.len() != 1 {
+      return Err(Error::InvalidRefresh("verifiableCredential"));
+    }
+
+    if presentation.holder.is_none() {
+      return Err(Error::InvalidRefresh("holder"));
+    }
+
+    Ok(Self { presentation })
+  }
+
+  /// Returns the credential to refresh.
+  pub fn credential(&self) -> Result<&Credential<T>> {
+    self
+      .presentation
+      .verifiable_credential
+      .get(0)
+      .ok_or(Error::InvalidRefresh("verifiableCredential"))
+  }
+
+  /// Returns the holder of the credential.
+  pub fn holder(&self) -> Result<&Url> {
+    self.presentation.holder.as_ref().ok_or(Error::InvalidRefresh("holder"))
+  }
+
+  /// Returns the underlying [`Presentation`] of the request.
+  pub fn presentation(&self) -> &Presentation<Object, T> {
+    &self.presentation
+  }
+
+  /// Consumes the request and returns the underlying [`Presentation`].
+  pub fn into_presentation(self) -> Presentation<Object, T> {
+    self.presentation
+  }
+
+  /// Signs the request with the `authentication` method `method` of the
+  /// holder `document`.
+  ///
+  /// The `challenge`, issued by the refresh service, protects the request
+  /// against replay.
+  pub fn sign<U, V, W>(
+    &mut self,
+    document: &Document<U, V, W>,
+    method: &str,
+    secret: &SecretKey,
+    challenge: &RefreshChallenge,
+  ) -> Result<()>
+  where
+    T: Serialize,
+  {
+    let options: SignatureOptions = SignatureOptions::new()
+      .purpose(ProofPurpose::Authentication)
+      .created(Timestamp::now())
+      .challenge(challenge.challenge.as_str())
+      .domain(challenge.domain.as_str());
+
+    document
+      .signer(secret)
+      .method(method)
+      .options(options)
+      .sign(&mut self.presentation)
+      .map_err(Into::into)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use identity_core::common::Url;
+  use identity_core::convert::FromJson;
+
+  use crate::credential::Credential;
+  use crate::credential::Refresh;
+  use crate::presentation::Presentation;
+  use crate::refresh::RefreshRequest;
+
+  const JSON: &str = include_str!("../../tests/fixtures/credential-6.json");
+
+  #[test]
+  fn test_new() {
+    let holder: Url = Url::parse("did:example:ebfeb1f712ebc6f1c276e12ec21").unwrap();
+    let mut credential: Credential = Credential::from_json(JSON).unwrap();
+
+    // Credentials without a refresh service can't be refreshed
+    assert!(RefreshRequest::new(credential.clone(), holder.clone()).is_err());
+
+    credential.refresh_service = Refresh::new(
+      Url::parse("https://example.edu/refresh/3732").unwrap(),
+      "ManualRefreshService2018".to_string(),
+    )
+    .into();
+
+    let request: RefreshRequest = RefreshRequest::new(credential.clone(), holder.clone()).unwrap();
+
+    assert_eq!(request.credential().unwrap(), &credential);
+    assert_eq!(request.holder().unwrap(), &holder);
+
+    let presentation: Presentation = request.clone().into_presentation();
+
+    assert!(presentation.types.contains(&RefreshRequest::<()>::TYPE.into()));
+    assert_eq!(RefreshRequest::from_presentation(presentation).unwrap(), request);
+
+    // Presentations of other types are rejected
+    let mut presentation: Presentation = request.into_presentation();
+    presentation.types = Presentation::<()>::base_type().to_string().into();
+
+    assert!(RefreshRequest::from_presentation(presentation).is_err());
+  }
+}
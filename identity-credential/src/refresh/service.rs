@@ -0,0 +1,384 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::convert::TryFrom;
+use core::time::Duration;
+use identity_core::common::Timestamp;
+use identity_core::common::Url;
+use identity_core::crypto::ProofPurpose;
+use identity_core::crypto::Signature;
+use identity_core::crypto::SignatureOptions;
+use identity_core::utils::generate_nonce;
+use identity_did::document::Document;
+use identity_did::verifiable::Revocation;
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+use crate::credential::Credential;
+use crate::error::Error;
+use crate::error::Result;
+use crate::refresh::RefreshRequest;
+use crate::validation::Clock;
+use crate::validation::SystemClock;
+
+/// The default period a [`RefreshChallenge`] can be used for.
+const CHALLENGE_TIMEOUT: Duration = Duration::from_secs(5 * 60);
+
+/// A challenge issued by a [`RefreshService`] for a single [`RefreshRequest`].
+///
+/// The request MUST be signed with the `challenge` and `domain` of the
+/// challenge.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RefreshChallenge {
+  /// A random value, accepted only once by the service.
+  pub challenge: String,
+  /// The domain of the service.
+  pub domain: String,
+}
+
+/// The issuer side of the credential refresh protocol.
+///
+/// A service checks a [`RefreshRequest`] against the original credential and
+/// reissues the credential with new dates, keeping the subject claims.
+///
+/// Each request MUST be signed with a fresh challenge issued by
+/// [`RefreshService::issue_challenge`].
+#[derive(Clone, Debug)]
+pub struct RefreshService<C = SystemClock> {
+  id: Url,
+  validity: Duration,
+  timeout: Duration,
+  challenges: BTreeMap<String, Timestamp>,
+  clock: C,
+}
+
+impl RefreshService {
+  /// Creates a new [`RefreshService`] identified by `id`, reissuing
+  /// credentials valid for the given `validity` period.
+  pub fn new(id: Url, validity: Duration) -> Self {
+    Self {
+      id,
+      validity,
+      timeout: CHALLENGE_TIMEOUT,
+      challenges: BTreeMap::new(),
+      clock: SystemClock,
+    }
+  }
+}
+
+impl<C> RefreshService<C>
+where
+  C: Clock,
+{
+  /// Sets the [`Clock`] used as the source of the current time.
+  pub fn clock<D: Clock>(self, clock: D) -> RefreshService<D> {
+    RefreshService {
+      id: self.id,
+      validity: self.validity,
+      timeout: self.timeout,
+      challenges: self.challenges,
+      clock,
+    }
+  }
+
+  /// Sets the period an issued challenge can be used for, five minutes by
+  /// default.
+  pub fn timeout(mut self, value: Duration) -> Self {
+    self.timeout = value;
+    self
+  }
+
+  /// Issues a new challenge for a single refresh request.
+  ///
+  /// Expired challenges are discarded.
+  pub fn issue_challenge(&mut self) -> Result<RefreshChallenge> {
+    let now: Timestamp = self.clock.now();
+    let timeout: i64 = self.__timeout();
+
+    self
+      .challenges
+      .retain(|_, issued| issued.to_unix().saturating_add(timeout) >= now.to_unix());
+
+    let challenge: String = generate_nonce()?;
+
+    self.challenges.insert(challenge.clone(), now);
+
+    Ok(RefreshChallenge {
+      challenge,
+      domain: self.id.to_string(),
+    })
+  }
+
+  /// Returns the Url identifying the refresh service.
+  pub fn id(&self) -> &Url {
+    &self.id
+  }
+
+  /// Checks `request` against the `original` credential, as stored by the
+  /// issuer, and returns the reissued credential.
+  ///
+  /// The request MUST be signed by an `authentication` method of the `holder`
+  /// DID Document with an unexpired challenge issued by the service, and the
+  /// holder MUST be a subject of the credential. The challenge is consumed,
+  /// even if the request is rejected.
+  ///
+  /// Note: The reissued credential is unsigned and MUST be signed by the issuer.
+  pub fn refresh<T, U, V, W>(
+    &mut self,
+    request: &RefreshRequest<T>,
+    original: &Credential<T>,
+    holder: &Document<U, V, W>,
+  ) -> Result<Credential<T>>
+  where
+    T: Clone + PartialEq + Serialize,
+    V: Revocation,
+  {
+    // The credential MUST be refreshed by this service
+    if !original.refresh_service.iter().any(|service| service.id == self.id) {
+      return Err(Error::InvalidRefresh("refreshService"));
+    }
+
+    // The presented credential MUST be the original
+    if request.credential()? != original {
+      return Err(Error::InvalidRefresh("credential"));
+    }
+
+    let subject: &Url = request.holder()?;
+
+    // The holder MUST be a subject of the credential
+    if !original
+      .credential_subject
+      .iter()
+      .any(|other| other.id.as_ref() == Some(subject))
+    {
+      return Err(Error::InvalidRefresh("holder"));
+    }
+
+    if holder.id().as_str() != subject.as_str() {
+      return Err(Error::InvalidRefresh("holder"));
+    }
+
+    let signature: &Signature = request
+      .presentation()
+      .proof
+      .as_ref()
+      .ok_or(Error::InvalidRefresh("proof"))?;
+
+    let options: &SignatureOptions = signature.options();
+    let challenge: &str = options.challenge.as_deref().ok_or(Error::InvalidRefresh("challenge"))?;
+
+    // Challenges are accepted only once
+    let issued: Timestamp = self
+      .challenges
+      .remove(challenge)
+      .ok_or(Error::InvalidRefresh("challenge"))?;
+
+    let now: Timestamp = self.clock.now();
+
+    if issued.to_unix().saturating_add(self.__timeout()) < now.to_unix() {
+      return Err(Error::InvalidRefresh("challenge"));
+    }
+
+    // The request MUST be signed after the challenge was issued
+    let created: Timestamp = options.created.ok_or(Error::InvalidRefresh("created"))?;
+
+    if created.to_unix() < issued.to_unix() || created.to_unix() > now.to_unix() {
+      return Err(Error::InvalidRefresh("created"));
+    }
+
+    holder
+      .verifier()
+      .purpose(ProofPurpose::Authentication)
+      .challenge(challenge)
+      .domain(self.id.as_str())
+      .verify(request.presentation())?;
+
+    let issuance_date: Timestamp = now;
+    let validity: i64 = i64::try_from(self.validity.as_secs()).unwrap_or(i64::MAX);
+    let expiration_date: Timestamp = Timestamp::from_unix(issuance_date.to_unix().saturating_add(validity))?;
+
+    let mut credential: Credential<T> = original.clone();
+
+    credential.issuance_date = issuance_date;
+    credential.expiration_date = Some(expiration_date);
+    credential.proof = None;
+    credential.proof_set.clear();
+
+    Ok(credential)
+  }
+
+  fn __timeout(&self) -> i64 {
+    i64::try_from(self.timeout.as_secs()).unwrap_or(i64::MAX)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use core::time::Duration;
+  use identity_core::common::Timestamp;
+  use identity_core::common::Url;
+  use identity_core::convert::FromJson;
+  use identity_core::crypto::KeyPair;
+  use identity_core::utils::encode_b58;
+  use identity_did::did::DID;
+  use identity_did::document::Document;
+  use identity_did::document::DocumentBuilder;
+  use identity_did::verification::Method;
+  use identity_did::verification::MethodBuilder;
+  use identity_did::verification::MethodData;
+  use identity_did::verification::MethodType;
+
+  use crate::credential::Credential;
+  use crate::credential::Refresh;
+  use crate::refresh::RefreshChallenge;
+  use crate::refresh::RefreshRequest;
+  use crate::refresh::RefreshService;
+  use crate::validation::SystemClock;
+
+  const JSON: &str = include_str!("../../tests/fixtures/credential-6.json");
+  const SERVICE: &str = "https://example.edu/refresh/3732";
+  const HOLDER: &str = "did:example:ebfeb1f712ebc6f1c276e12ec21";
+
+  fn document(did: &str, keypair: &KeyPair) -> Document {
+    let did: DID = did.parse().unwrap();
+
+    let method: Method = MethodBuilder::default()
+      .id(did.join("#key-1").unwrap())
+      .controller(did.clone())
+      .key_type(MethodType::Ed25519VerificationKey2018)
+      .key_data(MethodData::PublicKeyBase58(encode_b58(keypair.public())))
+      .build()
+      .unwrap();
+
+    DocumentBuilder::default()
+      .id(did)
+      .authentication(method)
+      .build()
+      .unwrap()
+  }
+
+  fn credential() -> Credential {
+    let mut credential: Credential = Credential::from_json(JSON).unwrap();
+
+    credential.refresh_service =
+      Refresh::new(Url::parse(SERVICE).unwrap(), "ManualRefreshService2018".to_string()).into();
+    credential
+  }
+
+  fn timestamp(input: &str) -> Timestamp {
+    Timestamp::parse(input).unwrap()
+  }
+
+  fn refresh_service() -> RefreshService {
+    RefreshService::new(Url::parse(SERVICE).unwrap(), Duration::from_secs(60 * 60 * 24))
+  }
+
+  #[test]
+  fn test_refresh() {
+    let keypair: KeyPair = KeyPair::new_ed25519().unwrap();
+    let holder: Document = document(HOLDER, &keypair);
+    let original: Credential = credential();
+
+    let mut service: RefreshService = refresh_service();
+    let mut request: RefreshRequest = RefreshRequest::new(original.clone(), Url::parse(HOLDER).unwrap()).unwrap();
+
+    let challenge: RefreshChallenge = service.issue_challenge().unwrap();
+
+    assert_eq!(challenge.domain, SERVICE);
+
+    request.sign(&holder, "#key-1", keypair.secret(), &challenge).unwrap();
+
+    let now: Timestamp = Timestamp::now();
+    let refreshed: Credential = service.refresh(&request, &original, &holder).unwrap();
+
+    assert!(refreshed.issuance_date.to_unix() <= now.to_unix() + 1);
+    assert_eq!(
+      refreshed.expiration_date.unwrap().to_unix(),
+      refreshed.issuance_date.to_unix() + 60 * 60 * 24
+    );
+    assert_eq!(refreshed.id, original.id);
+    assert_eq!(refreshed.issuer, original.issuer);
+    assert_eq!(refreshed.credential_subject, original.credential_subject);
+    assert!(refreshed.proofs().is_empty());
+
+    // The challenge can only be used once
+    assert!(service.refresh(&request, &original, &holder).is_err());
+  }
+
+  #[test]
+  fn test_refresh_invalid() {
+    let keypair: KeyPair = KeyPair::new_ed25519().unwrap();
+    let holder: Document = document(HOLDER, &keypair);
+    let original: Credential = credential();
+
+    let mut service: RefreshService = refresh_service();
+    let mut request: RefreshRequest = RefreshRequest::new(original.clone(), Url::parse(HOLDER).unwrap()).unwrap();
+
+    // The request is not signed
+    assert!(service.refresh(&request, &original, &holder).is_err());
+
+    // The challenge was not issued by the service
+    let challenge: RefreshChallenge = RefreshChallenge {
+      challenge: "c0ae1c8e".into(),
+      domain: SERVICE.into(),
+    };
+
+    request.sign(&holder, "#key-1", keypair.secret(), &challenge).unwrap();
+    assert!(service.refresh(&request, &original, &holder).is_err());
+
+    // The request is signed for another domain
+    let mut challenge: RefreshChallenge = service.issue_challenge().unwrap();
+    challenge.domain = "https://example.com/refresh".into();
+
+    request.sign(&holder, "#key-1", keypair.secret(), &challenge).unwrap();
+    assert!(service.refresh(&request, &original, &holder).is_err());
+
+    // The request is signed by another key
+    let other: KeyPair = KeyPair::new_ed25519().unwrap();
+    let challenge: RefreshChallenge = service.issue_challenge().unwrap();
+
+    request.sign(&holder, "#key-1", other.secret(), &challenge).unwrap();
+    assert!(service.refresh(&request, &original, &holder).is_err());
+
+    // A rejected request consumes the challenge
+    request.sign(&holder, "#key-1", keypair.secret(), &challenge).unwrap();
+    assert!(service.refresh(&request, &original, &holder).is_err());
+
+    // The challenge is expired
+    let mut service: RefreshService<Timestamp> = refresh_service().clock(timestamp("2020-06-01T00:00:00Z"));
+    let challenge: RefreshChallenge = service.issue_challenge().unwrap();
+    let mut service: RefreshService = service.clock(SystemClock);
+
+    request.sign(&holder, "#key-1", keypair.secret(), &challenge).unwrap();
+    assert!(service.refresh(&request, &original, &holder).is_err());
+
+    // The request was signed before the challenge was issued
+    let mut service: RefreshService<Timestamp> = refresh_service().clock(timestamp("2100-01-01T00:00:00Z"));
+    let challenge: RefreshChallenge = service.issue_challenge().unwrap();
+    let mut service: RefreshService = service.clock(SystemClock);
+
+    request.sign(&holder, "#key-1", keypair.secret(), &challenge).unwrap();
+    assert!(service.refresh(&request, &original, &holder).is_err());
+
+    let mut service: RefreshService = refresh_service();
+    let challenge: RefreshChallenge = service.issue_challenge().unwrap();
+
+    request.sign(&holder, "#key-1", keypair.secret(), &challenge).unwrap();
+    assert!(service.clone().refresh(&request, &original, &holder).is_ok());
+
+    // The presented credential differs from the original
+    let mut modified: Credential = original.clone();
+    modified.credential_subject.get_mut(0).unwrap().properties.clear();
+
+    assert!(service.refresh(&request, &modified, &holder).is_err());
+
+    // The holder is not a subject of the credential
+    let holder: Document = document("did:example:other", &keypair);
+    let mut request: RefreshRequest =
+      RefreshRequest::new(original.clone(), Url::parse("did:example:other").unwrap()).unwrap();
+    let challenge: RefreshChallenge = service.issue_challenge().unwrap();
+
+    request.sign(&holder, "#key-1", keypair.secret(), &challenge).unwrap();
+    assert!(service.refresh(&request, &original, &holder).is_err());
+  }
+}
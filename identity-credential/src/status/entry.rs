@@ -0,0 +1,274 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::convert::TryFrom;
+use core::str::FromStr;
+use identity_core::common::Object;
+use identity_core::common::Url;
+use identity_core::common::Value;
+
+use crate::credential::Status;
+use crate::error::Error;
+use crate::error::Result;
+
+/// The status list model used by a [`StatusListEntry`].
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum StatusListType {
+  /// The [StatusList2021](https://w3c-ccg.github.io/vc-status-list-2021/) model.
+  StatusList2021,
+  /// The [RevocationList2020](https://w3c-ccg.github.io/vc-status-rl-2020/) model.
+  RevocationList2020,
+}
+
+impl StatusListType {
+  /// Returns the JSON-LD context defining the terms of the status list model.
+  pub fn context(self) -> &'static str {
+    match self {
+      Self::StatusList2021 => "https://w3id.org/vc/status-list/2021/v1",
+      Self::RevocationList2020 => "https://w3id.org/vc-revocation-list-2020/v1",
+    }
+  }
+
+  /// Returns the `type` of a `credentialStatus` entry.
+  pub fn entry_type(self) -> &'static str {
+    match self {
+      Self::StatusList2021 => "StatusList2021Entry",
+      Self::RevocationList2020 => "RevocationList2020Status",
+    }
+  }
+
+  /// Returns the `type` of the status list credential.
+  pub fn credential_type(self) -> &'static str {
+    match self {
+      Self::StatusList2021 => "StatusList2021Credential",
+      Self::RevocationList2020 => "RevocationList2020Credential",
+    }
+  }
+
+  /// Returns the `type` of the status list credential subject.
+  pub fn subject_type(self) -> &'static str {
+    match self {
+      Self::StatusList2021 => "StatusList2021",
+      Self::RevocationList2020 => "RevocationList2020",
+    }
+  }
+
+  fn index_property(self) -> &'static str {
+    match self {
+      Self::StatusList2021 => "statusListIndex",
+      Self::RevocationList2020 => "revocationListIndex",
+    }
+  }
+
+  fn credential_property(self) -> &'static str {
+    match self {
+      Self::StatusList2021 => "statusListCredential",
+      Self::RevocationList2020 => "revocationListCredential",
+    }
+  }
+}
+
+/// The purpose of a status list.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum StatusPurpose {
+  /// A set bit permanently revokes the credential.
+  Revocation,
+  /// A set bit temporarily suspends the credential.
+  Suspension,
+}
+
+impl StatusPurpose {
+  /// Returns the purpose as a string slice.
+  pub fn as_str(self) -> &'static str {
+    match self {
+      Self::Revocation => "revocation",
+      Self::Suspension => "suspension",
+    }
+  }
+}
+
+impl FromStr for StatusPurpose {
+  type Err = Error;
+
+  fn from_str(string: &str) -> Result<Self, Self::Err> {
+    match string {
+      "revocation" => Ok(Self::Revocation),
+      "suspension" => Ok(Self::Suspension),
+      _ => Err(Error::InvalidStatus("statusPurpose")),
+    }
+  }
+}
+
+/// A typed `credentialStatus` entry referencing a bit in a status list.
+///
+/// Converts to and from the untyped [`Status`] of a
+/// [`Credential`][crate::credential::Credential].
+#[derive(Clone, Debug, PartialEq)]
+pub struct StatusListEntry {
+  /// A Url identifying the status entry.
+  pub id: Url,
+  /// The status list model of the entry.
+  pub type_: StatusListType,
+  /// The purpose of the referenced status list.
+  ///
+  /// Always [`StatusPurpose::Revocation`] for `RevocationList2020`.
+  pub purpose: StatusPurpose,
+  /// The index of the credential in the status list.
+  pub index: u32,
+  /// A Url identifying the status list credential.
+  pub credential: Url,
+}
+
+impl StatusListEntry {
+  /// Creates a new `StatusList2021Entry`.
+  pub fn new(id: Url, purpose: StatusPurpose, index: u32, credential: Url) -> Self {
+    Self {
+      id,
+      type_: StatusListType::StatusList2021,
+      purpose,
+      index,
+      credential,
+    }
+  }
+
+  /// Creates a new `RevocationList2020Status`.
+  pub fn revocation_list_2020(id: Url, index: u32, credential: Url) -> Self {
+    Self {
+      id,
+      type_: StatusListType::RevocationList2020,
+      purpose: StatusPurpose::Revocation,
+      index,
+      credential,
+    }
+  }
+}
+
+impl From<StatusListEntry> for Status {
+  fn from(other: StatusListEntry) -> Self {
+    let mut properties: Object = Object::new();
+
+    if other.type_ == StatusListType::StatusList2021 {
+      properties.insert("statusPurpose".into(), other.purpose.as_str().into());
+    }
+
+    properties.insert(other.type_.index_property().into(), other.index.to_string().into());
+    properties.insert(
+      other.type_.credential_property().into(),
+      other.credential.into_string().into(),
+    );
+
+    Status::with_properties(other.id, other.type_.entry_type().to_string(), properties)
+  }
+}
+
+impl TryFrom<&'_ Status> for StatusListEntry {
+  type Error = Error;
+
+  fn try_from(other: &Status) -> Result<Self, Self::Error> {
+    let type_: StatusListType = if other
+      .types
+      .contains(&StatusListType::StatusList2021.entry_type().into())
+    {
+      StatusListType::StatusList2021
+    } else if other
+      .types
+      .contains(&StatusListType::RevocationList2020.entry_type().into())
+    {
+      StatusListType::RevocationList2020
+    } else {
+      return Err(Error::InvalidStatus("type"));
+    };
+
+    let purpose: StatusPurpose = match type_ {
+      StatusListType::StatusList2021 => other
+        .properties
+        .get("statusPurpose")
+        .and_then(Value::as_str)
+        .ok_or(Error::InvalidStatus("statusPurpose"))?
+        .parse()?,
+      StatusListType::RevocationList2020 => StatusPurpose::Revocation,
+    };
+
+    // The index is a string by the specification - accept numbers as well
+    let index: u32 = match other.properties.get(type_.index_property()) {
+      Some(Value::String(index)) => index.parse().ok(),
+      Some(Value::Number(index)) => index.as_u64().and_then(|index| u32::try_from(index).ok()),
+      _ => None,
+    }
+    .ok_or(Error::InvalidStatus("index"))?;
+
+    let credential: Url = other
+      .properties
+      .get(type_.credential_property())
+      .and_then(Value::as_str)
+      .ok_or(Error::InvalidStatus("credential"))
+      .and_then(|url| Url::parse(url).map_err(Into::into))?;
+
+    Ok(Self {
+      id: other.id.clone(),
+      type_,
+      purpose,
+      index,
+      credential,
+    })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use core::convert::TryFrom;
+  use identity_core::common::Url;
+  use identity_core::convert::FromJson;
+
+  use crate::credential::Status;
+  use crate::status::StatusListEntry;
+  use crate::status::StatusListType;
+  use crate::status::StatusPurpose;
+
+  const JSON1: &str = include_str!("../../tests/fixtures/status-2.json");
+  const JSON2: &str = include_str!("../../tests/fixtures/status-3.json");
+
+  #[test]
+  fn test_from_json() {
+    let status: Status = Status::from_json(JSON1).unwrap();
+    let entry: StatusListEntry = StatusListEntry::try_from(&status).unwrap();
+
+    assert_eq!(entry.type_, StatusListType::StatusList2021);
+    assert_eq!(entry.purpose, StatusPurpose::Revocation);
+    assert_eq!(entry.index, 94567);
+    assert_eq!(entry.credential, "https://example.com/credentials/status/3");
+    assert_eq!(Status::from(entry), status);
+
+    let status: Status = Status::from_json(JSON2).unwrap();
+    let entry: StatusListEntry = StatusListEntry::try_from(&status).unwrap();
+
+    assert_eq!(entry.type_, StatusListType::RevocationList2020);
+    assert_eq!(entry.purpose, StatusPurpose::Revocation);
+    assert_eq!(entry.index, 94567);
+    assert_eq!(entry.credential, "https://example.com/credentials/status/3");
+    assert_eq!(Status::from(entry), status);
+  }
+
+  #[test]
+  fn test_invalid() {
+    let status: Status = Status::new(
+      Url::parse("https://example.edu/status/24").unwrap(),
+      "CredentialStatusList2017".to_string(),
+    );
+
+    assert!(StatusListEntry::try_from(&status).is_err());
+
+    let mut status: Status = StatusListEntry::new(
+      Url::parse("https://example.com/credentials/status/3#94567").unwrap(),
+      StatusPurpose::Suspension,
+      94567,
+      Url::parse("https://example.com/credentials/status/3").unwrap(),
+    )
+    .into();
+
+    status.properties.insert("statusListIndex".into(), "-1".into());
+
+    assert!(StatusListEntry::try_from(&status).is_err());
+  }
+}
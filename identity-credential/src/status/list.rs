@@ -0,0 +1,226 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use identity_core::common::BitSet;
+use identity_core::utils::decode_b64;
+use identity_core::utils::encode_b64;
+use std::io::Read;
+use std::io::Write;
+
+use crate::error::Error;
+use crate::error::Result;
+
+/// The minimum number of entries in a status list - 16KB of bits, as
+/// required for herd privacy.
+const MIN_LENGTH: u32 = 16 * 1024 * 8;
+
+/// The maximum size of a decoded status list in bytes.
+const MAX_BYTES: u64 = 16 * 1024 * 1024;
+
+/// The maximum number of entries in a status list, so every encoded list can
+/// be decoded.
+const MAX_LENGTH: u32 = MAX_BYTES as u32 * 8;
+
+/// A list of credential status bits, stored as a compressed [`BitSet`].
+///
+/// A set bit indicates that the credential at the corresponding index is
+/// revoked or suspended, depending on the purpose of the list.
+#[derive(Clone, Debug)]
+pub struct StatusList {
+  bits: BitSet,
+  length: u32,
+}
+
+impl StatusList {
+  /// Creates a new [`StatusList`] with the minimum number of entries.
+  pub fn new() -> Self {
+    Self::with_length(MIN_LENGTH)
+  }
+
+  /// Creates a new [`StatusList`] with `length` entries.
+  ///
+  /// The length is rounded up to the minimum length and a multiple of 8, and
+  /// limited to the maximum length of 128M entries (16MB).
+  pub fn with_length(length: u32) -> Self {
+    let length: u32 = length.max(MIN_LENGTH).min(MAX_LENGTH);
+    let length: u32 = (length + 7) & !7;
+
+    Self {
+      bits: BitSet::new(),
+      length,
+    }
+  }
+
+  /// Returns the total number of entries in the list.
+  pub fn len(&self) -> u32 {
+    self.length
+  }
+
+  /// Returns `true` if no entry of the list is set.
+  pub fn is_empty(&self) -> bool {
+    self.bits.is_empty()
+  }
+
+  /// Returns `true` if the entry at `index` is set.
+  pub fn get(&self, index: u32) -> Result<bool> {
+    self.check_index(index).map(|_| self.bits.contains(index))
+  }
+
+  /// Sets the entry at `index`, revoking or suspending the credential.
+  ///
+  /// Returns `true` if the entry was not previously set.
+  pub fn set(&mut self, index: u32) -> Result<bool> {
+    self.check_index(index)?;
+
+    Ok(self.bits.insert(index))
+  }
+
+  /// Clears the entry at `index`.
+  ///
+  /// Returns `true` if the entry was previously set.
+  pub fn clear(&mut self, index: u32) -> Result<bool> {
+    self.check_index(index)?;
+
+    Ok(self.bits.remove(index))
+  }
+
+  /// Encodes the list as a GZIP-compressed, base64-encoded bitstring.
+  ///
+  /// The entry at index `0` is the most significant bit of the first byte.
+  pub fn encode(&self) -> Result<String> {
+    let mut bytes: Vec<u8> = vec![0; (self.length / 8) as usize];
+
+    for index in self.bits.iter() {
+      bytes[(index / 8) as usize] |= 0x80 >> (index % 8);
+    }
+
+    let mut encoder: GzEncoder<Vec<u8>> = GzEncoder::new(Vec::new(), Compression::default());
+
+    encoder
+      .write_all(&bytes)
+      .map_err(|_| Error::InvalidStatus("encodedList"))?;
+
+    encoder
+      .finish()
+      .map(|data| encode_b64(&data))
+      .map_err(|_| Error::InvalidStatus("encodedList"))
+  }
+
+  /// Decodes a list from a GZIP-compressed, base64-encoded bitstring.
+  pub fn decode(data: &str) -> Result<Self> {
+    let data: Vec<u8> = decode_b64(data)?;
+    let mut bytes: Vec<u8> = Vec::new();
+
+    GzDecoder::new(&data[..])
+      .take(MAX_BYTES + 1)
+      .read_to_end(&mut bytes)
+      .map_err(|_| Error::InvalidStatus("encodedList"))?;
+
+    if bytes.len() as u64 > MAX_BYTES {
+      return Err(Error::InvalidStatus("encodedList"));
+    }
+
+    let mut bits: BitSet = BitSet::new();
+
+    for (offset, byte) in bytes.iter().enumerate() {
+      for bit in 0..8 {
+        if byte & (0x80 >> bit) != 0 {
+          bits.insert(offset as u32 * 8 + bit);
+        }
+      }
+    }
+
+    Ok(Self {
+      bits,
+      length: bytes.len() as u32 * 8,
+    })
+  }
+
+  fn check_index(&self, index: u32) -> Result<()> {
+    if index < self.length {
+      Ok(())
+    } else {
+      Err(Error::InvalidStatus("index"))
+    }
+  }
+}
+
+impl Default for StatusList {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::status::StatusList;
+
+  #[test]
+  fn test_set_clear() {
+    let mut list: StatusList = StatusList::new();
+
+    assert_eq!(list.len(), 131_072);
+    assert!(list.is_empty());
+
+    assert!(list.set(42).unwrap());
+    assert!(!list.set(42).unwrap());
+    assert!(list.get(42).unwrap());
+    assert!(!list.get(43).unwrap());
+
+    assert!(list.clear(42).unwrap());
+    assert!(!list.clear(42).unwrap());
+    assert!(!list.get(42).unwrap());
+
+    assert!(list.set(list.len()).is_err());
+    assert!(list.get(list.len()).is_err());
+  }
+
+  #[test]
+  fn test_encode_decode() {
+    let mut list: StatusList = StatusList::with_length(200_001);
+
+    assert_eq!(list.len(), 200_008);
+
+    for index in [0, 7, 8, 1000, 200_007].iter().copied() {
+      list.set(index).unwrap();
+    }
+
+    let encoded: String = list.encode().unwrap();
+    let decoded: StatusList = StatusList::decode(&encoded).unwrap();
+
+    assert_eq!(decoded.len(), list.len());
+
+    for index in 0..decoded.len() {
+      assert_eq!(decoded.get(index).unwrap(), list.get(index).unwrap());
+    }
+
+    assert!(StatusList::decode("not-a-list").is_err());
+  }
+
+  #[test]
+  fn test_max_length() {
+    let mut list: StatusList = StatusList::with_length(u32::MAX);
+
+    assert_eq!(list.len(), 134_217_728);
+    assert!(list.set(list.len() - 1).unwrap());
+    assert!(list.set(list.len()).is_err());
+
+    let decoded: StatusList = StatusList::decode(&list.encode().unwrap()).unwrap();
+
+    assert_eq!(decoded.len(), list.len());
+    assert!(decoded.get(list.len() - 1).unwrap());
+  }
+
+  #[test]
+  fn test_decode_spec() {
+    // An empty 16KB list from the StatusList2021 specification
+    let list: StatusList =
+      StatusList::decode("H4sIAAAAAAAAA-3BMQEAAADCoPVPbQwfoAAAAAAAAAAAAAAAAAAAAIC3AYbSVKsAQAAA").unwrap();
+
+    assert_eq!(list.len(), 131_072);
+    assert!(list.is_empty());
+  }
+}
@@ -0,0 +1,242 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Object;
+use identity_core::common::Url;
+use identity_core::common::Value;
+
+use crate::credential::Credential;
+use crate::credential::CredentialBuilder;
+use crate::credential::Issuer;
+use crate::credential::Subject;
+use crate::error::Error;
+use crate::error::Result;
+use crate::status::StatusList;
+use crate::status::StatusListEntry;
+use crate::status::StatusListType;
+use crate::status::StatusPurpose;
+
+/// A status list published as a Verifiable Credential.
+#[derive(Clone, Debug)]
+pub struct StatusListCredential {
+  /// A Url identifying the status list credential.
+  pub id: Url,
+  /// The status list model of the credential.
+  pub type_: StatusListType,
+  /// The purpose of the status list.
+  pub purpose: StatusPurpose,
+  /// The list of credential status bits.
+  pub list: StatusList,
+}
+
+impl StatusListCredential {
+  /// Creates a new `StatusList2021Credential`.
+  pub fn new(id: Url, purpose: StatusPurpose, list: StatusList) -> Self {
+    Self {
+      id,
+      type_: StatusListType::StatusList2021,
+      purpose,
+      list,
+    }
+  }
+
+  /// Creates a new `RevocationList2020Credential`.
+  pub fn revocation_list_2020(id: Url, list: StatusList) -> Self {
+    Self {
+      id,
+      type_: StatusListType::RevocationList2020,
+      purpose: StatusPurpose::Revocation,
+      list,
+    }
+  }
+
+  /// Creates a [`StatusListEntry`] referencing the given `index` of the list.
+  ///
+  /// The entry id is the id of the list credential with the index as fragment.
+  pub fn entry(&self, index: u32) -> Result<StatusListEntry> {
+    // Ensure the index is within the bounds of the list
+    self.list.get(index)?;
+
+    let mut id: Url = self.id.clone();
+
+    id.set_fragment(Some(&index.to_string()));
+
+    Ok(StatusListEntry {
+      id,
+      type_: self.type_,
+      purpose: self.purpose,
+      index,
+      credential: self.id.clone(),
+    })
+  }
+
+  /// Returns `true` if the credential referenced by `entry` is revoked or
+  /// suspended by this list.
+  ///
+  /// # Errors
+  ///
+  /// Fails if `entry` does not reference this list or is out of bounds.
+  pub fn check(&self, entry: &StatusListEntry) -> Result<bool> {
+    if entry.credential != self.id {
+      return Err(Error::InvalidStatus("credential"));
+    }
+
+    if entry.type_ != self.type_ || entry.purpose != self.purpose {
+      return Err(Error::InvalidStatus("type"));
+    }
+
+    self.list.get(entry.index)
+  }
+
+  /// Creates an unsigned status list [`Credential`] issued by `issuer`.
+  pub fn to_credential(&self, issuer: impl Into<Issuer>) -> Result<Credential> {
+    let mut properties: Object = Object::new();
+
+    properties.insert("type".into(), self.type_.subject_type().into());
+
+    if self.type_ == StatusListType::StatusList2021 {
+      properties.insert("statusPurpose".into(), self.purpose.as_str().into());
+    }
+
+    properties.insert("encodedList".into(), self.list.encode()?.into());
+
+    CredentialBuilder::default()
+      .context(Url::parse(self.type_.context())?)
+      .id(self.id.clone())
+      .type_(self.type_.credential_type())
+      .issuer(issuer)
+      .subject(Subject::with_id_and_properties(self.id.join("#list")?, properties))
+      .build()
+  }
+
+  /// Parses a status list [`Credential`], e.g. one fetched by a validator.
+  ///
+  /// Note: The proof of the credential is NOT verified.
+  pub fn from_credential<T>(credential: &Credential<T>) -> Result<Self> {
+    let type_: StatusListType = if credential
+      .types
+      .contains(&StatusListType::StatusList2021.credential_type().into())
+    {
+      StatusListType::StatusList2021
+    } else if credential
+      .types
+      .contains(&StatusListType::RevocationList2020.credential_type().into())
+    {
+      StatusListType::RevocationList2020
+    } else {
+      return Err(Error::InvalidStatus("type"));
+    };
+
+    let id: Url = credential.id.clone().ok_or(Error::InvalidStatus("id"))?;
+
+    let subject: &Subject = match credential.credential_subject.as_slice() {
+      [subject] => subject,
+      _ => return Err(Error::InvalidStatus("credentialSubject")),
+    };
+
+    let purpose: StatusPurpose = match type_ {
+      StatusListType::StatusList2021 => subject
+        .properties
+        .get("statusPurpose")
+        .and_then(Value::as_str)
+        .ok_or(Error::InvalidStatus("statusPurpose"))?
+        .parse()?,
+      StatusListType::RevocationList2020 => StatusPurpose::Revocation,
+    };
+
+    let list: StatusList = subject
+      .properties
+      .get("encodedList")
+      .and_then(Value::as_str)
+      .ok_or(Error::InvalidStatus("encodedList"))
+      .and_then(StatusList::decode)?;
+
+    Ok(Self {
+      id,
+      type_,
+      purpose,
+      list,
+    })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use identity_core::common::Url;
+  use identity_core::convert::FromJson;
+  use identity_core::convert::ToJson;
+
+  use crate::credential::Credential;
+  use crate::status::StatusList;
+  use crate::status::StatusListCredential;
+  use crate::status::StatusListEntry;
+  use crate::status::StatusListType;
+  use crate::status::StatusPurpose;
+
+  fn url(input: &str) -> Url {
+    Url::parse(input).unwrap()
+  }
+
+  #[test]
+  fn test_roundtrip() {
+    let mut list: StatusListCredential = StatusListCredential::new(
+      url("https://example.com/credentials/status/3"),
+      StatusPurpose::Suspension,
+      StatusList::new(),
+    );
+
+    let entry: StatusListEntry = list.entry(94567).unwrap();
+
+    assert_eq!(entry.id, "https://example.com/credentials/status/3#94567");
+    assert!(!list.check(&entry).unwrap());
+
+    list.list.set(94567).unwrap();
+
+    let credential: Credential = list.to_credential(url("did:example:issuer")).unwrap();
+
+    assert!(credential.types.contains(&"StatusList2021Credential".into()));
+    assert_eq!(
+      credential.credential_subject.get(0).unwrap().properties["type"],
+      "StatusList2021"
+    );
+
+    let json: String = credential.to_json().unwrap();
+    let parsed: StatusListCredential =
+      StatusListCredential::from_credential(&Credential::from_json(&json).unwrap()).unwrap();
+
+    assert_eq!(parsed.id, list.id);
+    assert_eq!(parsed.type_, StatusListType::StatusList2021);
+    assert_eq!(parsed.purpose, StatusPurpose::Suspension);
+    assert!(parsed.check(&entry).unwrap());
+
+    // Entries for a different purpose or list are rejected
+    let other: StatusListEntry = StatusListEntry {
+      purpose: StatusPurpose::Revocation,
+      ..entry.clone()
+    };
+
+    assert!(parsed.check(&other).is_err());
+
+    let other: StatusListEntry = StatusListEntry {
+      credential: url("https://example.com/credentials/status/4"),
+      ..entry
+    };
+
+    assert!(parsed.check(&other).is_err());
+  }
+
+  #[test]
+  fn test_revocation_list_2020() {
+    let mut list: StatusListCredential =
+      StatusListCredential::revocation_list_2020(url("https://example.com/credentials/status/3"), StatusList::new());
+
+    list.list.set(10).unwrap();
+
+    let credential: Credential = list.to_credential(url("did:example:issuer")).unwrap();
+    let parsed: StatusListCredential = StatusListCredential::from_credential(&credential).unwrap();
+
+    assert_eq!(parsed.type_, StatusListType::RevocationList2020);
+    assert!(parsed.check(&list.entry(10).unwrap()).unwrap());
+    assert!(!parsed.check(&list.entry(11).unwrap()).unwrap());
+  }
+}
@@ -0,0 +1,17 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Credential status lists used to revoke or suspend Verifiable Credentials.
+//!
+//! Supports the [StatusList2021](https://w3c-ccg.github.io/vc-status-list-2021/)
+//! and [RevocationList2020](https://w3c-ccg.github.io/vc-status-rl-2020/) models.
+
+mod entry;
+mod list;
+mod list_credential;
+
+pub use self::entry::StatusListEntry;
+pub use self::entry::StatusListType;
+pub use self::entry::StatusPurpose;
+pub use self::list::StatusList;
+pub use self::list_credential::StatusListCredential;
@@ -0,0 +1,319 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Url;
+use std::collections::BTreeMap;
+
+use crate::credential::Credential;
+use crate::error::Error;
+use crate::error::Result;
+use crate::presentation::Presentation;
+use crate::validation::ValidationFailure;
+
+/// The default type of relationship credentials.
+const RELATIONSHIP_CREDENTIAL: &str = "RelationshipCredential";
+
+/// A rule binding the holder of a [`Presentation`] to the subjects of a
+/// presented [`Credential`].
+///
+/// Rules are ordered from the least to the most strict.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum HolderBinding {
+  /// The holder is not checked.
+  Skip,
+  /// The holder MUST be a subject of the credential, or the subject of a
+  /// relationship credential issued by a subject of the credential.
+  ///
+  /// See [`HolderBindingPolicy::relationship_type`].
+  Delegated,
+  /// The holder MUST be a subject of the credential.
+  Subject,
+}
+
+impl Default for HolderBinding {
+  fn default() -> Self {
+    Self::Skip
+  }
+}
+
+/// A set of [`HolderBinding`] rules, configured per credential type.
+///
+/// A credential with several configured types is checked with the strictest
+/// rule. Credentials marked `nonTransferable` are always checked with
+/// [`HolderBinding::Subject`].
+///
+/// Relationship credentials are only matched by their `type`, `issuer` and
+/// `credentialSubject` - their proofs are NOT verified by the policy.
+#[derive(Clone, Debug)]
+pub struct HolderBindingPolicy {
+  default: HolderBinding,
+  types: BTreeMap<String, HolderBinding>,
+  relationship: String,
+}
+
+impl HolderBindingPolicy {
+  /// Creates a new [`HolderBindingPolicy`] that only enforces `nonTransferable`.
+  pub fn new() -> Self {
+    Self {
+      default: HolderBinding::Skip,
+      types: BTreeMap::new(),
+      relationship: RELATIONSHIP_CREDENTIAL.into(),
+    }
+  }
+
+  /// Sets the rule for credentials without a configured type.
+  pub fn default_binding(mut self, value: HolderBinding) -> Self {
+    self.default = value;
+    self
+  }
+
+  /// Sets the rule for credentials of the given type.
+  pub fn binding(mut self, type_: impl Into<String>, value: HolderBinding) -> Self {
+    self.types.insert(type_.into(), value);
+    self
+  }
+
+  /// Sets the type a credential MUST have to delegate a
+  /// [`HolderBinding::Delegated`] credential to its subject.
+  ///
+  /// Defaults to `RelationshipCredential`.
+  pub fn relationship_type(mut self, value: impl Into<String>) -> Self {
+    self.relationship = value.into();
+    self
+  }
+
+  /// Returns the rule applied to `credential`.
+  pub fn binding_for<T>(&self, credential: &Credential<T>) -> HolderBinding {
+    if credential.non_transferable == Some(true) {
+      return HolderBinding::Subject;
+    }
+
+    credential
+      .types
+      .iter()
+      .filter_map(|type_| self.types.get(type_))
+      .copied()
+      .max()
+      .unwrap_or(self.default)
+  }
+
+  /// Checks the holder of `presentation` against every presented credential
+  /// and returns the list of failures.
+  pub fn validate_presentation<T, U>(&self, presentation: &Presentation<T, U>) -> Vec<ValidationFailure> {
+    let mut failures: Vec<ValidationFailure> = Vec::new();
+
+    for credential in presentation.verifiable_credential.iter() {
+      let binding: HolderBinding = self.binding_for(credential);
+
+      if binding == HolderBinding::Skip {
+        continue;
+      }
+
+      let holder: &Url = match presentation.holder.as_ref() {
+        Some(holder) => holder,
+        None => {
+          failures.push(ValidationFailure::MissingHolder);
+          break;
+        }
+      };
+
+      let bound: bool = __is_subject(credential, holder)
+        || (binding == HolderBinding::Delegated
+          && presentation
+            .verifiable_credential
+            .iter()
+            .any(|relationship| self.is_relationship(relationship, credential, holder)));
+
+      if bound {
+        continue;
+      }
+
+      let id: String = credential.id.as_ref().map(ToString::to_string).unwrap_or_default();
+
+      if credential.non_transferable == Some(true) {
+        failures.push(ValidationFailure::NonTransferable {
+          credential: id,
+          holder: holder.to_string(),
+        });
+      } else {
+        failures.push(ValidationFailure::UnboundHolder {
+          credential: id,
+          holder: holder.to_string(),
+        });
+      }
+    }
+
+    failures
+  }
+
+  /// Checks the holder of `presentation` against every presented credential
+  /// and returns an error with the list of failures if any credential is not
+  /// bound to the holder.
+  pub fn check_presentation<T, U>(&self, presentation: &Presentation<T, U>) -> Result<()> {
+    let failures: Vec<ValidationFailure> = self.validate_presentation(presentation);
+
+    if failures.is_empty() {
+      Ok(())
+    } else {
+      Err(Error::InvalidHolderBinding(failures))
+    }
+  }
+
+  // Returns `true` if `relationship` is a relationship credential issued by a
+  // subject of `credential` to `holder`.
+  fn is_relationship<T>(&self, relationship: &Credential<T>, credential: &Credential<T>, holder: &Url) -> bool {
+    relationship.types.contains(&self.relationship)
+      && __is_subject(relationship, holder)
+      && __is_subject(credential, relationship.issuer.url())
+  }
+}
+
+impl Default for HolderBindingPolicy {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+// Returns `true` if `holder` is a subject of `credential`.
+fn __is_subject<T>(credential: &Credential<T>, holder: &Url) -> bool {
+  credential
+    .credential_subject
+    .iter()
+    .any(|subject| subject.id.as_ref() == Some(holder))
+}
+
+#[cfg(test)]
+mod tests {
+  use identity_core::common::Url;
+  use identity_core::convert::FromJson;
+
+  use crate::credential::Credential;
+  use crate::credential::Subject;
+  use crate::error::Error;
+  use crate::presentation::Presentation;
+  use crate::presentation::PresentationBuilder;
+  use crate::validation::HolderBinding;
+  use crate::validation::HolderBindingPolicy;
+  use crate::validation::ValidationFailure;
+
+  const JSON: &str = include_str!("../../tests/fixtures/credential-6.json");
+  const SUBJECT: &str = "did:example:ebfeb1f712ebc6f1c276e12ec21";
+  const PARENT: &str = "did:example:parent";
+
+  fn url(input: &str) -> Url {
+    Url::parse(input).unwrap()
+  }
+
+  fn presentation(holder: &str, credentials: Vec<Credential>) -> Presentation {
+    credentials
+      .into_iter()
+      .fold(
+        PresentationBuilder::default().holder(url(holder)),
+        |builder, credential| builder.credential(credential),
+      )
+      .build()
+      .unwrap()
+  }
+
+  fn relationship(issuer: &str, subject: &str) -> Credential {
+    let mut credential: Credential = Credential::from_json(JSON).unwrap();
+
+    credential.id = None;
+    credential.types = vec!["VerifiableCredential".to_string(), "RelationshipCredential".to_string()].into();
+    credential.issuer = url(issuer).into();
+    credential.credential_subject = Subject::with_id(url(subject)).into();
+    credential
+  }
+
+  #[test]
+  fn test_subject() {
+    let policy: HolderBindingPolicy =
+      HolderBindingPolicy::new().binding("UniversityDegreeCredential", HolderBinding::Subject);
+
+    let credential: Credential = Credential::from_json(JSON).unwrap();
+
+    assert!(policy
+      .check_presentation(&presentation(SUBJECT, vec![credential.clone()]))
+      .is_ok());
+
+    assert!(matches!(
+      policy.validate_presentation(&presentation(PARENT, vec![credential.clone()])).as_slice(),
+      [ValidationFailure::UnboundHolder { holder, .. }] if holder == PARENT
+    ));
+
+    // The binding is skipped for other credential types
+    assert!(HolderBindingPolicy::new()
+      .check_presentation(&presentation(PARENT, vec![credential]))
+      .is_ok());
+  }
+
+  #[test]
+  fn test_delegated() {
+    let policy: HolderBindingPolicy = HolderBindingPolicy::new().default_binding(HolderBinding::Delegated);
+    let credential: Credential = Credential::from_json(JSON).unwrap();
+
+    assert!(policy
+      .check_presentation(&presentation(PARENT, vec![credential.clone()]))
+      .is_err());
+
+    // The subject authorizes the parent to present the credential
+    let delegation: Credential = relationship(SUBJECT, PARENT);
+
+    assert!(policy
+      .check_presentation(&presentation(PARENT, vec![credential.clone(), delegation]))
+      .is_ok());
+
+    // The relationship MUST be issued by the subject
+    let delegation: Credential = relationship("did:example:other", PARENT);
+
+    assert!(matches!(
+      policy.check_presentation(&presentation(PARENT, vec![credential.clone(), delegation])),
+      Err(Error::InvalidHolderBinding(_))
+    ));
+
+    // The relationship MUST have the configured type
+    let mut delegation: Credential = relationship(SUBJECT, PARENT);
+
+    delegation.types = vec!["VerifiableCredential".to_string(), "GuardianCredential".to_string()].into();
+
+    assert!(policy
+      .check_presentation(&presentation(PARENT, vec![credential.clone(), delegation.clone()]))
+      .is_err());
+
+    assert!(policy
+      .relationship_type("GuardianCredential")
+      .check_presentation(&presentation(PARENT, vec![credential, delegation]))
+      .is_ok());
+  }
+
+  #[test]
+  fn test_non_transferable() {
+    let policy: HolderBindingPolicy = HolderBindingPolicy::new().default_binding(HolderBinding::Delegated);
+    let mut credential: Credential = Credential::from_json(JSON).unwrap();
+
+    credential.non_transferable = Some(true);
+
+    assert_eq!(policy.binding_for(&credential), HolderBinding::Subject);
+
+    let delegation: Credential = relationship(SUBJECT, PARENT);
+
+    assert!(matches!(
+      policy
+        .validate_presentation(&presentation(PARENT, vec![credential.clone(), delegation]))
+        .as_slice(),
+      [ValidationFailure::NonTransferable { .. }]
+    ));
+
+    assert!(policy
+      .check_presentation(&presentation(SUBJECT, vec![credential.clone()]))
+      .is_ok());
+
+    // A holder is required
+    let presentation: Presentation = PresentationBuilder::default().credential(credential).build().unwrap();
+
+    assert_eq!(
+      policy.validate_presentation(&presentation),
+      [ValidationFailure::MissingHolder]
+    );
+  }
+}
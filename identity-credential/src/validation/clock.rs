@@ -0,0 +1,36 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Timestamp;
+
+/// A source of the current time used for temporal validation checks.
+pub trait Clock {
+  /// Returns the current date and time.
+  fn now(&self) -> Timestamp;
+}
+
+impl<T> Clock for &T
+where
+  T: Clock + ?Sized,
+{
+  fn now(&self) -> Timestamp {
+    (**self).now()
+  }
+}
+
+/// A fixed point in time - useful for validating at a historical date.
+impl Clock for Timestamp {
+  fn now(&self) -> Timestamp {
+    *self
+  }
+}
+
+/// A [`Clock`] backed by the system time.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+  fn now(&self) -> Timestamp {
+    Timestamp::now()
+  }
+}
@@ -0,0 +1,141 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Timestamp;
+
+/// A single check that failed during credential validation.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, thiserror::Error)]
+pub enum ValidationFailure {
+  /// The first `@context` entry is not the base credentials context.
+  #[error("Missing Base Context")]
+  MissingBaseContext,
+  /// The credential `type` does not contain `VerifiableCredential`.
+  #[error("Missing Base Type")]
+  MissingBaseType,
+  /// The credential has no `credentialSubject`.
+  #[error("Missing Credential Subject")]
+  MissingSubject,
+  /// The `credentialSubject` at `index` has neither an `id` nor any claims.
+  #[error("Invalid Credential Subject: {index}")]
+  InvalidSubject {
+    /// The index of the malformed subject.
+    index: usize,
+  },
+  /// The `issuanceDate` is in the future.
+  #[error("Credential Not Yet Valid: issued at {issuance_date}, validated at {now}")]
+  NotYetValid {
+    /// The issuance date of the credential.
+    issuance_date: Timestamp,
+    /// The time of validation.
+    now: Timestamp,
+  },
+  /// The `expirationDate` is in the past.
+  #[error("Credential Expired: expired at {expiration_date}, validated at {now}")]
+  Expired {
+    /// The expiration date of the credential.
+    expiration_date: Timestamp,
+    /// The time of validation.
+    now: Timestamp,
+  },
+  /// The credential is revoked by its status list.
+  #[error("Credential Revoked: {index}")]
+  Revoked {
+    /// The index of the credential in the status list.
+    index: u32,
+  },
+  /// The credential is suspended by its status list.
+  #[error("Credential Suspended: {index}")]
+  Suspended {
+    /// The index of the credential in the status list.
+    index: u32,
+  },
+  /// The credential status could not be checked against the status list.
+  #[error("Invalid Credential Status: {0}")]
+  InvalidStatus(String),
+  /// The `credentialSchema` with the given id is not in the schema registry.
+  #[error("Unknown Credential Schema: {0}")]
+  UnknownSchema(String),
+  /// The `credentialSchema` with the given id is not a valid JSON Schema.
+  #[error("Invalid Credential Schema: {schema}: {message}")]
+  InvalidSchema {
+    /// The id of the schema.
+    schema: String,
+    /// A description of the error.
+    message: String,
+  },
+  /// The credential does not conform to the `credentialSchema` with the given id.
+  #[error("Schema Violation: {schema}: {pointer}: {message}")]
+  SchemaViolation {
+    /// The id of the violated schema.
+    schema: String,
+    /// A JSON pointer to the invalid value in the credential.
+    pointer: String,
+    /// A description of the violation.
+    message: String,
+  },
+  /// The credential could not be serialized as a JSON document for schema
+  /// validation.
+  #[error("Invalid Credential Document: {0}")]
+  InvalidDocument(String),
+  /// The term or type is not defined by the JSON-LD contexts of the credential.
+  #[error("Undefined Term: {0}")]
+  UndefinedTerm(String),
+  /// The JSON-LD contexts of the credential could not be processed.
+  #[error("Invalid Context: {0}")]
+  InvalidContext(String),
+  /// The intended `action` is prohibited by the terms of use `policy`.
+  #[error("Prohibited Action: {policy}: {action}")]
+  ProhibitedAction {
+    /// The id of the policy.
+    policy: String,
+    /// The prohibited action.
+    action: String,
+  },
+  /// The intended `action` is not granted by any permission of the terms of
+  /// use `policy`.
+  #[error("Unpermitted Action: {policy}: {action}")]
+  UnpermittedAction {
+    /// The id of the policy.
+    policy: String,
+    /// The unpermitted action.
+    action: String,
+  },
+  /// The obligation `action` of the terms of use `policy` is not fulfilled.
+  #[error("Unfulfilled Obligation: {policy}: {action}")]
+  UnfulfilledObligation {
+    /// The id of the policy.
+    policy: String,
+    /// The obligated action.
+    action: String,
+  },
+  /// The rules of the terms of use `policy` are malformed.
+  #[error("Invalid Terms of Use: {policy}: {message}")]
+  InvalidPolicy {
+    /// The id of the policy.
+    policy: String,
+    /// A description of the error.
+    message: String,
+  },
+  /// The presentation has no holder, but a presented credential must be bound
+  /// to one.
+  #[error("Missing Presentation Holder")]
+  MissingHolder,
+  /// The `holder` of the presentation is neither a subject of the
+  /// `credential` nor authorized by a relationship credential.
+  #[error("Holder Not Bound To Credential: {credential}: {holder}")]
+  UnboundHolder {
+    /// The id of the credential.
+    credential: String,
+    /// The holder of the presentation.
+    holder: String,
+  },
+  /// The non-transferable `credential` is presented by a `holder` that is not
+  /// one of its subjects.
+  #[error("Non-Transferable Credential: {credential}: {holder}")]
+  NonTransferable {
+    /// The id of the credential.
+    credential: String,
+    /// The holder of the presentation.
+    holder: String,
+  },
+}
@@ -0,0 +1,25 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Issuer-independent validation of Verifiable Credentials.
+
+mod binding;
+mod clock;
+mod failure;
+mod policy;
+mod schema;
+mod terms;
+mod usage;
+
+pub use self::binding::HolderBinding;
+pub use self::binding::HolderBindingPolicy;
+pub use self::clock::Clock;
+pub use self::clock::SystemClock;
+pub use self::failure::ValidationFailure;
+pub use self::policy::ValidationPolicy;
+pub use self::schema::LocalSchemaRegistry;
+pub use self::schema::SchemaRegistry;
+pub use self::schema::SchemaScope;
+pub use self::schema::SchemaValidator;
+pub use self::terms::TermValidator;
+pub use self::usage::UsageValidator;
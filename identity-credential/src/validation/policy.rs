@@ -0,0 +1,391 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::convert::TryFrom;
+use core::time::Duration;
+use identity_core::common::Timestamp;
+
+use crate::credential::Credential;
+use crate::error::Error;
+use crate::error::Result;
+use crate::status::StatusListCredential;
+use crate::status::StatusListEntry;
+use crate::status::StatusPurpose;
+use crate::validation::Clock;
+use crate::validation::SystemClock;
+use crate::validation::ValidationFailure;
+
+/// A configurable set of structural and temporal checks for a [`Credential`].
+///
+/// The checks are independent of the issuer and its DID method - signatures
+/// are NOT verified by the policy.
+#[derive(Clone, Debug)]
+pub struct ValidationPolicy<C = SystemClock> {
+  clock: C,
+  clock_skew: Duration,
+  check_structure: bool,
+  check_issuance: bool,
+  check_expiration: bool,
+}
+
+impl ValidationPolicy {
+  /// Creates a new [`ValidationPolicy`] with all checks enabled, using the
+  /// system clock and no allowed clock skew.
+  pub fn new() -> Self {
+    Self {
+      clock: SystemClock,
+      clock_skew: Duration::from_secs(0),
+      check_structure: true,
+      check_issuance: true,
+      check_expiration: true,
+    }
+  }
+}
+
+impl<C> ValidationPolicy<C>
+where
+  C: Clock,
+{
+  /// Sets the [`Clock`] used as the source of the current time.
+  pub fn clock<D: Clock>(self, clock: D) -> ValidationPolicy<D> {
+    ValidationPolicy {
+      clock,
+      clock_skew: self.clock_skew,
+      check_structure: self.check_structure,
+      check_issuance: self.check_issuance,
+      check_expiration: self.check_expiration,
+    }
+  }
+
+  /// Sets the clock skew tolerated by the temporal checks.
+  pub fn clock_skew(mut self, value: Duration) -> Self {
+    self.clock_skew = value;
+    self
+  }
+
+  /// Enables or disables the `@context`, `type`, and `credentialSubject` checks.
+  pub fn check_structure(mut self, value: bool) -> Self {
+    self.check_structure = value;
+    self
+  }
+
+  /// Enables or disables the check that `issuanceDate` is not in the future.
+  pub fn check_issuance(mut self, value: bool) -> Self {
+    self.check_issuance = value;
+    self
+  }
+
+  /// Enables or disables the check that `expirationDate` is not in the past.
+  pub fn check_expiration(mut self, value: bool) -> Self {
+    self.check_expiration = value;
+    self
+  }
+
+  /// Runs all enabled checks and returns the list of failures.
+  ///
+  /// An empty list indicates the `credential` is valid under this policy.
+  pub fn validate<T>(&self, credential: &Credential<T>) -> Vec<ValidationFailure> {
+    let mut failures: Vec<ValidationFailure> = Vec::new();
+
+    if self.check_structure {
+      Self::validate_structure(credential, &mut failures);
+    }
+
+    if self.check_issuance || self.check_expiration {
+      self.validate_dates(credential, &mut failures);
+    }
+
+    failures
+  }
+
+  /// Runs all enabled checks and returns an error with the list of failures
+  /// if any check failed.
+  pub fn check<T>(&self, credential: &Credential<T>) -> Result<()> {
+    let failures: Vec<ValidationFailure> = self.validate(credential);
+
+    if failures.is_empty() {
+      Ok(())
+    } else {
+      Err(Error::InvalidCredential(failures))
+    }
+  }
+
+  /// Checks the `credentialStatus` entries of `credential` that reference the
+  /// given, already fetched, status list credential and returns the list of
+  /// failures.
+  ///
+  /// The status list credential MUST be issued by the issuer of `credential`.
+  ///
+  /// Note: The proof of the status list credential is NOT verified.
+  pub fn validate_status<T, U>(
+    &self,
+    credential: &Credential<T>,
+    status_list: &Credential<U>,
+  ) -> Vec<ValidationFailure> {
+    let mut failures: Vec<ValidationFailure> = Vec::new();
+
+    // A status list of another issuer can't revoke or suspend the credential
+    if status_list.issuer.url() != credential.issuer.url() {
+      failures.push(ValidationFailure::InvalidStatus(
+        "status list issuer does not match the credential issuer".into(),
+      ));
+      return failures;
+    }
+
+    let status_list: StatusListCredential = match StatusListCredential::from_credential(status_list) {
+      Ok(status_list) => status_list,
+      Err(error) => {
+        failures.push(ValidationFailure::InvalidStatus(error.to_string()));
+        return failures;
+      }
+    };
+
+    // Entries referencing other status lists are ignored
+    let entries: _ = credential
+      .credential_status
+      .iter()
+      .filter_map(|status| StatusListEntry::try_from(status).ok())
+      .filter(|entry| entry.credential == status_list.id && entry.purpose == status_list.purpose);
+
+    let mut checked: bool = false;
+
+    for entry in entries {
+      checked = true;
+
+      match (status_list.check(&entry), entry.purpose) {
+        (Ok(false), _) => {}
+        (Ok(true), StatusPurpose::Revocation) => failures.push(ValidationFailure::Revoked { index: entry.index }),
+        (Ok(true), StatusPurpose::Suspension) => failures.push(ValidationFailure::Suspended { index: entry.index }),
+        (Err(error), _) => failures.push(ValidationFailure::InvalidStatus(error.to_string())),
+      }
+    }
+
+    if !checked {
+      failures.push(ValidationFailure::InvalidStatus(
+        "no status entry references the status list".into(),
+      ));
+    }
+
+    failures
+  }
+
+  fn validate_structure<T>(credential: &Credential<T>, failures: &mut Vec<ValidationFailure>) {
+    // The base context MUST be present and in the first position
+    if credential.context.get(0) != Some(Credential::<T>::base_context()) {
+      failures.push(ValidationFailure::MissingBaseContext);
+    }
+
+    // The set of types MUST contain the base type
+    if !credential
+      .types
+      .iter()
+      .any(|type_| type_ == Credential::<T>::base_type())
+    {
+      failures.push(ValidationFailure::MissingBaseType);
+    }
+
+    // Credentials MUST have at least one subject
+    if credential.credential_subject.is_empty() {
+      failures.push(ValidationFailure::MissingSubject);
+    }
+
+    // Each subject is defined as one or more properties - no empty objects
+    for (index, subject) in credential.credential_subject.iter().enumerate() {
+      if subject.id.is_none() && subject.properties.is_empty() {
+        failures.push(ValidationFailure::InvalidSubject { index });
+      }
+    }
+  }
+
+  fn validate_dates<T>(&self, credential: &Credential<T>, failures: &mut Vec<ValidationFailure>) {
+    let now: Timestamp = self.clock.now();
+    let skew: i64 = i64::try_from(self.clock_skew.as_secs()).unwrap_or(i64::MAX);
+
+    if self.check_issuance && credential.issuance_date.to_unix() > now.to_unix().saturating_add(skew) {
+      failures.push(ValidationFailure::NotYetValid {
+        issuance_date: credential.issuance_date,
+        now,
+      });
+    }
+
+    if let Some(expiration_date) = credential.expiration_date.filter(|_| self.check_expiration) {
+      if expiration_date.to_unix().saturating_add(skew) < now.to_unix() {
+        failures.push(ValidationFailure::Expired { expiration_date, now });
+      }
+    }
+  }
+}
+
+impl Default for ValidationPolicy {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use core::time::Duration;
+  use identity_core::common::Context;
+  use identity_core::common::Object;
+  use identity_core::common::Timestamp;
+  use identity_core::common::Url;
+  use identity_core::convert::FromJson;
+
+  use crate::credential::Credential;
+  use crate::credential::Status;
+  use crate::credential::Subject;
+  use crate::status::StatusList;
+  use crate::status::StatusListCredential;
+  use crate::status::StatusPurpose;
+  use crate::validation::ValidationFailure;
+  use crate::validation::ValidationPolicy;
+
+  const JSON: &str = include_str!("../../tests/fixtures/credential-6.json");
+
+  fn timestamp(input: &str) -> Timestamp {
+    Timestamp::parse(input).unwrap()
+  }
+
+  fn credential() -> Credential {
+    let mut credential: Credential = Credential::from_json(JSON).unwrap();
+    credential.issuance_date = timestamp("2020-01-01T00:00:00Z");
+    credential.expiration_date = Some(timestamp("2021-01-01T00:00:00Z"));
+    credential
+  }
+
+  #[test]
+  fn test_valid() {
+    let policy: ValidationPolicy<Timestamp> = ValidationPolicy::new().clock(timestamp("2020-06-01T00:00:00Z"));
+
+    assert!(policy.validate(&credential()).is_empty());
+    assert!(policy.check(&credential()).is_ok());
+  }
+
+  #[test]
+  fn test_temporal() {
+    let credential: Credential = credential();
+
+    let policy: ValidationPolicy<Timestamp> = ValidationPolicy::new().clock(timestamp("2019-12-31T23:59:30Z"));
+
+    assert_eq!(
+      policy.validate(&credential),
+      [ValidationFailure::NotYetValid {
+        issuance_date: credential.issuance_date,
+        now: timestamp("2019-12-31T23:59:30Z"),
+      }]
+    );
+
+    // The issuance date is within the allowed clock skew
+    let policy: ValidationPolicy<Timestamp> = policy.clock_skew(Duration::from_secs(60));
+    assert!(policy.validate(&credential).is_empty());
+
+    let policy: ValidationPolicy<Timestamp> = ValidationPolicy::new().clock(timestamp("2021-01-01T00:00:30Z"));
+
+    assert_eq!(
+      policy.validate(&credential),
+      [ValidationFailure::Expired {
+        expiration_date: timestamp("2021-01-01T00:00:00Z"),
+        now: timestamp("2021-01-01T00:00:30Z"),
+      }]
+    );
+
+    // The expiration date is within the allowed clock skew
+    assert!(policy
+      .clone()
+      .clock_skew(Duration::from_secs(60))
+      .validate(&credential)
+      .is_empty());
+
+    // The expiration check can be disabled
+    assert!(policy.check_expiration(false).validate(&credential).is_empty());
+  }
+
+  #[test]
+  fn test_structure() {
+    let mut credential: Credential = credential();
+
+    credential.context = Context::Url(Url::parse("https://example.com/context").unwrap()).into();
+    credential.types = "ExampleCredential".to_string().into();
+    credential.credential_subject = vec![Subject::from_json(r#"{"claim": true}"#).unwrap(), Subject::new()].into();
+
+    let policy: ValidationPolicy<Timestamp> = ValidationPolicy::new().clock(timestamp("2020-06-01T00:00:00Z"));
+
+    assert_eq!(
+      policy.validate(&credential),
+      [
+        ValidationFailure::MissingBaseContext,
+        ValidationFailure::MissingBaseType,
+        ValidationFailure::InvalidSubject { index: 1 },
+      ]
+    );
+
+    assert!(policy.check(&credential).is_err());
+    assert!(policy.check_structure(false).validate(&credential).is_empty());
+
+    let credential: Credential<Object> = Credential {
+      credential_subject: Vec::new().into(),
+      ..credential
+    };
+
+    assert!(ValidationPolicy::new()
+      .clock(timestamp("2020-06-01T00:00:00Z"))
+      .validate(&credential)
+      .contains(&ValidationFailure::MissingSubject));
+  }
+
+  #[test]
+  fn test_status() {
+    let mut credential: Credential = credential();
+    let mut status_list: StatusListCredential = StatusListCredential::new(
+      Url::parse("https://example.edu/status/1").unwrap(),
+      StatusPurpose::Revocation,
+      StatusList::new(),
+    );
+
+    credential.credential_status = Status::from(status_list.entry(42).unwrap()).into();
+
+    let policy: ValidationPolicy = ValidationPolicy::new();
+    let issuer: Url = Url::parse("https://example.edu/issuers/14").unwrap();
+
+    let list: Credential = status_list.to_credential(issuer.clone()).unwrap();
+    assert!(policy.validate_status(&credential, &list).is_empty());
+
+    status_list.list.set(42).unwrap();
+
+    let list: Credential = status_list.to_credential(issuer.clone()).unwrap();
+    assert_eq!(
+      policy.validate_status(&credential, &list),
+      [ValidationFailure::Revoked { index: 42 }]
+    );
+
+    // The credential does not reference the status list
+    let other: StatusListCredential = StatusListCredential::new(
+      Url::parse("https://example.edu/status/2").unwrap(),
+      StatusPurpose::Revocation,
+      StatusList::new(),
+    );
+
+    let list: Credential = other.to_credential(issuer).unwrap();
+    assert!(matches!(
+      policy.validate_status(&credential, &list).as_slice(),
+      [ValidationFailure::InvalidStatus(_)]
+    ));
+
+    // The status list is issued by another issuer
+    let mallory: Url = Url::parse("https://example.com/issuers/mallory").unwrap();
+
+    status_list.list.clear(42).unwrap();
+
+    let list: Credential = status_list.to_credential(mallory).unwrap();
+    assert!(matches!(
+      policy.validate_status(&credential, &list).as_slice(),
+      [ValidationFailure::InvalidStatus(_)]
+    ));
+
+    // The status list credential is malformed
+    assert!(matches!(
+      policy.validate_status(&credential, &credential).as_slice(),
+      [ValidationFailure::InvalidStatus(_)]
+    ));
+  }
+}
@@ -0,0 +1,401 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Url;
+use identity_core::common::Value;
+use identity_core::convert::ToJson;
+use jsonschema::JSONSchema;
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+use crate::credential::Credential;
+use crate::credential::Schema;
+use crate::error::Error;
+use crate::error::Result;
+use crate::validation::ValidationFailure;
+
+/// The `credentialSchema` types enforced by a [`SchemaValidator`].
+const SCHEMA_TYPES: &[&str] = &["JsonSchemaValidator2018", "JsonSchema"];
+
+/// A source of JSON Schemas referenced by the `credentialSchema` of a
+/// [`Credential`].
+pub trait SchemaRegistry {
+  /// Returns the JSON Schema identified by `id`, if known.
+  fn get(&self, id: &Url) -> Option<&Value>;
+}
+
+impl<T> SchemaRegistry for &T
+where
+  T: SchemaRegistry + ?Sized,
+{
+  fn get(&self, id: &Url) -> Option<&Value> {
+    (**self).get(id)
+  }
+}
+
+/// A [`SchemaRegistry`] backed by an in-memory map of schemas.
+#[derive(Clone, Debug, Default)]
+pub struct LocalSchemaRegistry {
+  schemas: BTreeMap<Url, Value>,
+}
+
+impl LocalSchemaRegistry {
+  /// Creates a new, empty [`LocalSchemaRegistry`].
+  pub fn new() -> Self {
+    Self {
+      schemas: BTreeMap::new(),
+    }
+  }
+
+  /// Adds a JSON Schema identified by `id` to the registry.
+  ///
+  /// Returns the schema previously registered with the same `id`, if any.
+  pub fn insert(&mut self, id: Url, schema: Value) -> Option<Value> {
+    self.schemas.insert(id, schema)
+  }
+
+  /// Removes the JSON Schema identified by `id` from the registry.
+  pub fn remove(&mut self, id: &Url) -> Option<Value> {
+    self.schemas.remove(id)
+  }
+}
+
+impl SchemaRegistry for LocalSchemaRegistry {
+  fn get(&self, id: &Url) -> Option<&Value> {
+    self.schemas.get(id)
+  }
+}
+
+/// The part of a [`Credential`] validated against its `credentialSchema`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SchemaScope {
+  /// Each `credentialSubject` is validated individually.
+  CredentialSubject,
+  /// The entire credential document is validated.
+  Credential,
+}
+
+impl Default for SchemaScope {
+  fn default() -> Self {
+    Self::CredentialSubject
+  }
+}
+
+/// Validates a [`Credential`] against the JSON Schemas referenced by its
+/// `credentialSchema` property.
+///
+/// Only schemas of type `JsonSchemaValidator2018` (or `JsonSchema`) are
+/// enforced; all schemas are resolved from a [`SchemaRegistry`].
+///
+/// Schemas must be self-contained - a `$ref` to a location outside of the
+/// schema is reported as a [`ValidationFailure::InvalidSchema`].
+#[derive(Clone, Debug)]
+pub struct SchemaValidator<R> {
+  registry: R,
+  scope: SchemaScope,
+}
+
+impl<R> SchemaValidator<R>
+where
+  R: SchemaRegistry,
+{
+  /// Creates a new [`SchemaValidator`] resolving schemas from `registry`.
+  pub fn new(registry: R) -> Self {
+    Self {
+      registry,
+      scope: SchemaScope::default(),
+    }
+  }
+
+  /// Sets the part of the credential validated against its schemas.
+  pub fn scope(mut self, value: SchemaScope) -> Self {
+    self.scope = value;
+    self
+  }
+
+  /// Validates `credential` against all of its enforced schemas and returns
+  /// the list of failures.
+  ///
+  /// The `pointer` of each [`ValidationFailure::SchemaViolation`] is a JSON
+  /// pointer into the serialized credential.
+  pub fn validate<T>(&self, credential: &Credential<T>) -> Vec<ValidationFailure>
+  where
+    T: Serialize,
+  {
+    let mut failures: Vec<ValidationFailure> = Vec::new();
+
+    let schemas: Vec<&Schema> = credential
+      .credential_schema
+      .iter()
+      .filter(|schema| schema.types.iter().any(|type_| SCHEMA_TYPES.contains(&type_.as_str())))
+      .collect();
+
+    if schemas.is_empty() {
+      return failures;
+    }
+
+    let document: Value = match credential.to_json_value() {
+      Ok(document) => document,
+      Err(error) => {
+        failures.push(ValidationFailure::InvalidDocument(error.to_string()));
+        return failures;
+      }
+    };
+
+    let targets: Vec<(String, &Value)> = match self.scope {
+      SchemaScope::Credential => vec![(String::new(), &document)],
+      SchemaScope::CredentialSubject => match &document["credentialSubject"] {
+        Value::Array(subjects) => subjects
+          .iter()
+          .enumerate()
+          .map(|(index, subject)| (format!("/credentialSubject/{}", index), subject))
+          .collect(),
+        subject => vec![("/credentialSubject".to_string(), subject)],
+      },
+    };
+
+    for schema in schemas {
+      self.validate_schema(schema, &targets, &mut failures);
+    }
+
+    failures
+  }
+
+  /// Validates `credential` against all of its enforced schemas and returns
+  /// an error with the list of failures if any schema is violated.
+  pub fn check<T>(&self, credential: &Credential<T>) -> Result<()>
+  where
+    T: Serialize,
+  {
+    let failures: Vec<ValidationFailure> = self.validate(credential);
+
+    if failures.is_empty() {
+      Ok(())
+    } else {
+      Err(Error::InvalidCredential(failures))
+    }
+  }
+
+  fn validate_schema(&self, schema: &Schema, targets: &[(String, &Value)], failures: &mut Vec<ValidationFailure>) {
+    let id: String = schema.id.to_string();
+
+    let document: &Value = match self.registry.get(&schema.id) {
+      Some(document) => document,
+      None => {
+        failures.push(ValidationFailure::UnknownSchema(id));
+        return;
+      }
+    };
+
+    // References are not resolved from the registry, so they would fail to
+    // compile or be fetched from elsewhere
+    if let Some(reference) = __external_ref(document) {
+      failures.push(ValidationFailure::InvalidSchema {
+        schema: id,
+        message: format!("unsupported external reference: {}", reference),
+      });
+
+      return;
+    }
+
+    let compiled: JSONSchema = match JSONSchema::compile(document) {
+      Ok(compiled) => compiled,
+      Err(error) => {
+        failures.push(ValidationFailure::InvalidSchema {
+          schema: id,
+          message: error.to_string(),
+        });
+
+        return;
+      }
+    };
+
+    for (prefix, target) in targets {
+      if let Err(errors) = compiled.validate(target) {
+        for error in errors {
+          failures.push(ValidationFailure::SchemaViolation {
+            schema: id.clone(),
+            pointer: __pointer(prefix, error.instance_path.clone().into_vec()),
+            message: error.to_string(),
+          });
+        }
+      }
+    }
+  }
+}
+
+// Returns the first `$ref` of `schema` that does not refer to a location
+// within the schema itself.
+fn __external_ref(schema: &Value) -> Option<&str> {
+  match schema {
+    Value::Object(object) => object.iter().find_map(|(key, value)| match value {
+      Value::String(reference) if key == "$ref" && !reference.starts_with('#') => Some(reference.as_str()),
+      value => __external_ref(value),
+    }),
+    Value::Array(values) => values.iter().find_map(__external_ref),
+    _ => None,
+  }
+}
+
+// Appends the escaped `chunks` to the JSON pointer `prefix` (RFC 6901).
+fn __pointer(prefix: &str, chunks: Vec<String>) -> String {
+  chunks.iter().fold(prefix.to_string(), |mut pointer, chunk| {
+    pointer.push('/');
+    pointer.push_str(&chunk.replace('~', "~0").replace('/', "~1"));
+    pointer
+  })
+}
+
+#[cfg(test)]
+mod tests {
+  use identity_core::common::Url;
+  use identity_core::common::Value;
+  use identity_core::convert::FromJson;
+  use serde_json::json;
+
+  use crate::credential::Credential;
+  use crate::credential::Schema;
+  use crate::credential::Subject;
+  use crate::validation::LocalSchemaRegistry;
+  use crate::validation::SchemaScope;
+  use crate::validation::SchemaValidator;
+  use crate::validation::ValidationFailure;
+
+  const JSON: &str = include_str!("../../tests/fixtures/credential-6.json");
+  const SCHEMA: &str = "https://example.org/examples/degree.json";
+
+  fn registry() -> LocalSchemaRegistry {
+    let schema: Value = json!({
+      "type": "object",
+      "required": ["id", "degree"],
+      "properties": {
+        "id": { "type": "string" },
+        "degree": {
+          "type": "object",
+          "required": ["type", "name"],
+          "properties": {
+            "type": { "type": "string" },
+            "name": { "type": "string" }
+          }
+        }
+      }
+    });
+
+    let mut registry: LocalSchemaRegistry = LocalSchemaRegistry::new();
+    registry.insert(Url::parse(SCHEMA).unwrap(), schema);
+    registry
+  }
+
+  fn credential(schema: &str) -> Credential {
+    let mut credential: Credential = Credential::from_json(JSON).unwrap();
+
+    credential.credential_schema =
+      Schema::new(Url::parse(schema).unwrap(), "JsonSchemaValidator2018".to_string()).into();
+    credential
+  }
+
+  #[test]
+  fn test_valid() {
+    let registry: LocalSchemaRegistry = registry();
+    let validator: SchemaValidator<&LocalSchemaRegistry> = SchemaValidator::new(&registry);
+
+    assert!(validator.validate(&credential(SCHEMA)).is_empty());
+    assert!(validator.check(&credential(SCHEMA)).is_ok());
+  }
+
+  #[test]
+  fn test_violation() {
+    let registry: LocalSchemaRegistry = registry();
+    let validator: SchemaValidator<&LocalSchemaRegistry> = SchemaValidator::new(&registry);
+
+    let mut credential: Credential = credential(SCHEMA);
+    let subject: &mut Subject = credential.credential_subject.get_mut(0).unwrap();
+
+    subject.properties.get_mut("degree").unwrap()["name"] = json!(42);
+
+    let failures: Vec<ValidationFailure> = validator.validate(&credential);
+
+    assert!(matches!(
+      failures.as_slice(),
+      [ValidationFailure::SchemaViolation { schema, pointer, .. }]
+        if schema == SCHEMA && pointer == "/credentialSubject/degree/name"
+    ));
+
+    assert!(validator.check(&credential).is_err());
+  }
+
+  #[test]
+  fn test_scope_credential() {
+    let mut registry: LocalSchemaRegistry = registry();
+
+    registry.insert(
+      Url::parse(SCHEMA).unwrap(),
+      json!({
+        "type": "object",
+        "required": ["credentialSubject", "expirationDate"],
+        "properties": {
+          "issuer": { "type": "object" }
+        }
+      }),
+    );
+
+    let validator: SchemaValidator<LocalSchemaRegistry> = SchemaValidator::new(registry).scope(SchemaScope::Credential);
+    let failures: Vec<ValidationFailure> = validator.validate(&credential(SCHEMA));
+
+    assert!(matches!(
+      failures.as_slice(),
+      [ValidationFailure::SchemaViolation { pointer, .. }] if pointer == "/issuer"
+    ));
+  }
+
+  #[test]
+  fn test_external_ref() {
+    let mut registry: LocalSchemaRegistry = registry();
+
+    registry.insert(
+      Url::parse("https://example.org/examples/subject.json").unwrap(),
+      json!({
+        "type": "object",
+        "properties": {
+          "id": { "$ref": "#/definitions/id" },
+          "degree": { "$ref": "https://example.org/examples/degree.json" }
+        },
+        "definitions": {
+          "id": { "type": "string" }
+        }
+      }),
+    );
+
+    let validator: SchemaValidator<&LocalSchemaRegistry> = SchemaValidator::new(&registry);
+    let failures: Vec<ValidationFailure> = validator.validate(&credential("https://example.org/examples/subject.json"));
+
+    // References within the schema are fine, references to other schemas are not
+    assert!(matches!(
+      failures.as_slice(),
+      [ValidationFailure::InvalidSchema { message, .. }] if message.contains(SCHEMA)
+    ));
+  }
+
+  #[test]
+  fn test_unknown_schema() {
+    let registry: LocalSchemaRegistry = registry();
+    let validator: SchemaValidator<&LocalSchemaRegistry> = SchemaValidator::new(&registry);
+
+    assert_eq!(
+      validator.validate(&credential("https://example.org/examples/unknown.json")),
+      [ValidationFailure::UnknownSchema(
+        "https://example.org/examples/unknown.json".to_string()
+      )]
+    );
+
+    // Schemas of other types are not enforced
+    let mut credential: Credential = credential("https://example.org/examples/unknown.json");
+    credential.credential_schema = Schema::new(
+      Url::parse("https://example.org/examples/unknown.json").unwrap(),
+      "ZkpExampleSchema2018".to_string(),
+    )
+    .into();
+
+    assert!(validator.validate(&credential).is_empty());
+  }
+}
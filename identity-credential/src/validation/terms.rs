@@ -0,0 +1,114 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Value;
+use identity_core::convert::ToJson;
+use identity_core::jsonld::undefined_terms;
+use identity_core::jsonld::DocumentLoader;
+use serde::Serialize;
+
+use crate::credential::Credential;
+use crate::error::Error;
+use crate::error::Result;
+use crate::validation::ValidationFailure;
+
+/// Validates that every term and type of a [`Credential`] is defined by its
+/// JSON-LD contexts.
+///
+/// Claims with undefined terms are dropped from the canonical form of a
+/// credential and are therefore not covered by its Linked Data Proof.
+#[derive(Clone, Debug)]
+pub struct TermValidator<L> {
+  loader: L,
+}
+
+impl<L> TermValidator<L>
+where
+  L: DocumentLoader,
+{
+  /// Creates a new [`TermValidator`] resolving contexts with `loader`.
+  pub fn new(loader: L) -> Self {
+    Self { loader }
+  }
+
+  /// Validates the terms of `credential` and returns the list of failures.
+  pub fn validate<T>(&self, credential: &Credential<T>) -> Vec<ValidationFailure>
+  where
+    T: Serialize,
+  {
+    let terms: Result<Vec<String>> = credential
+      .to_json_value()
+      .and_then(|document: Value| undefined_terms(&document, &self.loader))
+      .map_err(Into::into);
+
+    match terms {
+      Ok(terms) => terms.into_iter().map(ValidationFailure::UndefinedTerm).collect(),
+      Err(error) => vec![ValidationFailure::InvalidContext(error.to_string())],
+    }
+  }
+
+  /// Validates the terms of `credential` and returns an error with the list
+  /// of failures if any term is undefined.
+  pub fn check<T>(&self, credential: &Credential<T>) -> Result<()>
+  where
+    T: Serialize,
+  {
+    let failures: Vec<ValidationFailure> = self.validate(credential);
+
+    if failures.is_empty() {
+      Ok(())
+    } else {
+      Err(Error::InvalidCredential(failures))
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use identity_core::convert::FromJson;
+  use identity_core::jsonld::CachedLoader;
+  use serde_json::json;
+
+  use crate::credential::Credential;
+  use crate::validation::TermValidator;
+  use crate::validation::ValidationFailure;
+
+  const JSON: &str = include_str!("../../tests/fixtures/credential-6.json");
+  const EXAMPLES: &str = "https://www.w3.org/2018/credentials/examples/v1";
+
+  #[test]
+  fn test_terms() {
+    let credential: Credential = Credential::from_json(JSON).unwrap();
+    let mut loader: CachedLoader = CachedLoader::new();
+
+    // The examples context is not bundled
+    assert!(matches!(
+      TermValidator::new(&loader).validate(&credential).as_slice(),
+      [ValidationFailure::InvalidContext(_)]
+    ));
+
+    loader
+      .insert(
+        EXAMPLES,
+        json!({
+          "@context": {
+            "UniversityDegreeCredential": "https://example.org/examples#UniversityDegreeCredential",
+            "degree": "https://example.org/examples#degree",
+          }
+        }),
+      )
+      .unwrap();
+
+    let validator: TermValidator<&CachedLoader> = TermValidator::new(&loader);
+
+    assert_eq!(
+      validator.validate(&credential),
+      [
+        ValidationFailure::UndefinedTerm("BachelorDegree".into()),
+        ValidationFailure::UndefinedTerm("name".into()),
+      ]
+    );
+
+    assert!(validator.check(&credential).is_err());
+  }
+}
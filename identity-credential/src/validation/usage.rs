@@ -0,0 +1,497 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::OneOrMany;
+use identity_core::common::Url;
+use identity_core::common::Value;
+use identity_core::convert::FromJson;
+
+use crate::credential::Credential;
+use crate::credential::Policy;
+use crate::error::Error;
+use crate::error::Result;
+use crate::presentation::Presentation;
+use crate::validation::ValidationFailure;
+
+/// The `assignee` of a rule applying to every verifier.
+const ALL_VERIFIERS: &str = "AllVerifiers";
+
+/// The kind of an ODRL rule.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum RuleKind {
+  Prohibition,
+  Permission,
+  Obligation,
+}
+
+/// An ODRL rule of a terms-of-use [`Policy`].
+#[derive(Clone, Debug, Deserialize)]
+struct Rule {
+  #[serde(default)]
+  assignee: Option<Value>,
+  #[serde(default)]
+  target: Option<Value>,
+  #[serde(default)]
+  action: OneOrMany<String>,
+  #[serde(default)]
+  constraint: Option<Value>,
+}
+
+impl Rule {
+  fn applies(&self, kind: RuleKind, verifier: &Url, target: Option<&Url>) -> bool {
+    // Parties we can't identify match prohibitions and obligations but not
+    // permissions - this errs on the side of restricting the verifier.
+    let unknown: bool = kind != RuleKind::Permission;
+
+    let assignee: bool = match self.assignee.as_ref().map(__parties) {
+      None => true,
+      Some(None) => unknown,
+      Some(Some(assignees)) => assignees
+        .iter()
+        .any(|assignee| *assignee == ALL_VERIFIERS || *assignee == verifier.as_str()),
+    };
+
+    let target: bool = match (self.target.as_ref().map(__parties), target) {
+      (None, _) => true,
+      (Some(None), _) => unknown,
+      (Some(Some(expected)), Some(target)) => expected.contains(&target.as_str()),
+      (Some(Some(_)), None) => false,
+    };
+
+    assignee && target
+  }
+}
+
+/// Evaluates the ODRL-style `termsOfUse` of a [`Credential`] or
+/// [`Presentation`] against the actions a verifier intends to perform.
+///
+/// Every policy is evaluated as follows:
+///
+/// - A `prohibition` fails if it forbids an intended action.
+/// - If the policy has any `permission`, each intended action MUST be
+///   permitted by one of them.
+/// - An `obligation` fails unless the verifier agrees to fulfill it.
+///
+/// Rules apply to the verifier if their `assignee` is absent, `AllVerifiers`,
+/// or the verifier id, and to a document if their `target` is absent or the
+/// document id. Both may be given as a list, in which case any entry matches.
+/// Parties of any other shape match prohibitions and obligations, but never
+/// grant a permission.
+///
+/// Rules with a `constraint` are not supported and reported as an invalid
+/// policy.
+///
+/// [More Info](https://www.w3.org/TR/vc-data-model/#terms-of-use)
+#[derive(Clone, Debug)]
+pub struct UsageValidator {
+  verifier: Url,
+  actions: Vec<String>,
+  duties: Vec<String>,
+}
+
+impl UsageValidator {
+  /// Creates a new [`UsageValidator`] for the verifier identified by `verifier`.
+  pub fn new(verifier: Url) -> Self {
+    Self {
+      verifier,
+      actions: Vec::new(),
+      duties: Vec::new(),
+    }
+  }
+
+  /// Adds an action the verifier intends to perform, e.g. `Archival`.
+  pub fn action(mut self, value: impl Into<String>) -> Self {
+    self.actions.push(value.into());
+    self
+  }
+
+  /// Adds an obligation the verifier agrees to fulfill, e.g. `Delete`.
+  pub fn duty(mut self, value: impl Into<String>) -> Self {
+    self.duties.push(value.into());
+    self
+  }
+
+  /// Evaluates the terms of use of `credential` and returns the list of
+  /// failures.
+  pub fn validate<T>(&self, credential: &Credential<T>) -> Vec<ValidationFailure> {
+    let mut failures: Vec<ValidationFailure> = Vec::new();
+
+    self.validate_policies(&credential.terms_of_use, credential.id.as_ref(), &mut failures);
+
+    failures
+  }
+
+  /// Evaluates the terms of use of `credential` and returns an error with the
+  /// list of failures if any intended action is not allowed.
+  pub fn check<T>(&self, credential: &Credential<T>) -> Result<()> {
+    __check(self.validate(credential))
+  }
+
+  /// Evaluates the terms of use of `presentation` and of every presented
+  /// credential, and returns the list of failures.
+  pub fn validate_presentation<T, U>(&self, presentation: &Presentation<T, U>) -> Vec<ValidationFailure> {
+    let mut failures: Vec<ValidationFailure> = Vec::new();
+
+    self.validate_policies(&presentation.terms_of_use, presentation.id.as_ref(), &mut failures);
+
+    for credential in presentation.verifiable_credential.iter() {
+      self.validate_policies(&credential.terms_of_use, credential.id.as_ref(), &mut failures);
+    }
+
+    failures
+  }
+
+  /// Evaluates the terms of use of `presentation` and of every presented
+  /// credential, and returns an error with the list of failures if any
+  /// intended action is not allowed.
+  pub fn check_presentation<T, U>(&self, presentation: &Presentation<T, U>) -> Result<()> {
+    __check(self.validate_presentation(presentation))
+  }
+
+  fn validate_policies(
+    &self,
+    policies: &OneOrMany<Policy>,
+    target: Option<&Url>,
+    failures: &mut Vec<ValidationFailure>,
+  ) {
+    for policy in policies.iter() {
+      let id: String = policy.id.as_ref().map(ToString::to_string).unwrap_or_default();
+
+      let mut rules = |kind: &str| -> Option<Vec<Rule>> {
+        match policy.properties.get(kind) {
+          Some(rules) => match OneOrMany::<Rule>::from_json_value(rules.clone()) {
+            Ok(rules) => Some(rules.into_vec()),
+            Err(error) => {
+              failures.push(ValidationFailure::InvalidPolicy {
+                policy: id.clone(),
+                message: error.to_string(),
+              });
+
+              None
+            }
+          },
+          None => Some(Vec::new()),
+        }
+      };
+
+      let (prohibitions, permissions, obligations): (Vec<Rule>, Vec<Rule>, Vec<Rule>) =
+        match (rules("prohibition"), rules("permission"), rules("obligation")) {
+          (Some(prohibitions), Some(permissions), Some(obligations)) => (prohibitions, permissions, obligations),
+          _ => continue,
+        };
+
+      let constrained: bool = prohibitions
+        .iter()
+        .chain(permissions.iter())
+        .chain(obligations.iter())
+        .any(|rule| rule.constraint.is_some());
+
+      if constrained {
+        failures.push(ValidationFailure::InvalidPolicy {
+          policy: id.clone(),
+          message: "unsupported rule constraint".into(),
+        });
+
+        continue;
+      }
+
+      for action in self.actions.iter() {
+        let prohibited: bool = prohibitions
+          .iter()
+          .filter(|rule| rule.applies(RuleKind::Prohibition, &self.verifier, target))
+          .any(|rule| rule.action.contains(action));
+
+        if prohibited {
+          failures.push(ValidationFailure::ProhibitedAction {
+            policy: id.clone(),
+            action: action.clone(),
+          });
+        }
+
+        let permitted: bool = permissions.is_empty()
+          || permissions
+            .iter()
+            .filter(|rule| rule.applies(RuleKind::Permission, &self.verifier, target))
+            .any(|rule| rule.action.contains(action));
+
+        if !permitted {
+          failures.push(ValidationFailure::UnpermittedAction {
+            policy: id.clone(),
+            action: action.clone(),
+          });
+        }
+      }
+
+      for rule in obligations
+        .iter()
+        .filter(|rule| rule.applies(RuleKind::Obligation, &self.verifier, target))
+      {
+        for duty in rule.action.iter() {
+          if !self.duties.contains(duty) {
+            failures.push(ValidationFailure::UnfulfilledObligation {
+              policy: id.clone(),
+              action: duty.clone(),
+            });
+          }
+        }
+      }
+    }
+  }
+}
+
+// Returns the identifiers of ODRL parties or assets, given as a string, an
+// object with an `id` or `uid`, or an array of those. Returns `None` if any
+// party has an unknown shape.
+fn __parties(value: &Value) -> Option<Vec<&str>> {
+  match value {
+    Value::String(id) => Some(vec![id.as_str()]),
+    Value::Object(object) => object
+      .get("id")
+      .or_else(|| object.get("uid"))
+      .and_then(Value::as_str)
+      .map(|id| vec![id]),
+    Value::Array(values) => values
+      .iter()
+      .map(__parties)
+      .collect::<Option<Vec<Vec<&str>>>>()
+      .map(|parties| parties.into_iter().flatten().collect()),
+    _ => None,
+  }
+}
+
+fn __check(failures: Vec<ValidationFailure>) -> Result<()> {
+  if failures.is_empty() {
+    Ok(())
+  } else {
+    Err(Error::InvalidCredential(failures))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use identity_core::common::Url;
+  use identity_core::convert::FromJson;
+  use serde_json::json;
+
+  use crate::credential::Credential;
+  use crate::credential::Policy;
+  use crate::presentation::Presentation;
+  use crate::presentation::PresentationBuilder;
+  use crate::validation::UsageValidator;
+  use crate::validation::ValidationFailure;
+
+  const JSON: &str = include_str!("../../tests/fixtures/credential-11.json");
+  const POLICY: &str = "http://example.com/policies/credential/4";
+
+  fn verifier(url: &str) -> UsageValidator {
+    UsageValidator::new(Url::parse(url).unwrap())
+  }
+
+  fn policy(json: serde_json::Value) -> Policy {
+    Policy::from_json_value(json).unwrap()
+  }
+
+  #[test]
+  fn test_prohibition() {
+    let credential: Credential = Credential::from_json(JSON).unwrap();
+
+    assert!(verifier("https://verifier.example.org/")
+      .action("Verification")
+      .check(&credential)
+      .is_ok());
+
+    assert_eq!(
+      verifier("https://verifier.example.org/")
+        .action("Archival")
+        .validate(&credential),
+      [ValidationFailure::ProhibitedAction {
+        policy: POLICY.into(),
+        action: "Archival".into(),
+      }]
+    );
+  }
+
+  #[test]
+  fn test_permission() {
+    let mut credential: Credential = Credential::from_json(JSON).unwrap();
+
+    // The credential may only be used by the wine shop
+    credential.terms_of_use = policy(json!({
+      "type": "HolderPolicy",
+      "permission": [{
+        "assignee": "https://wineonline.example.org/",
+        "target": "http://example.edu/credentials/3732",
+        "action": ["Verification"]
+      }]
+    }))
+    .into();
+
+    assert!(verifier("https://wineonline.example.org/")
+      .action("Verification")
+      .check(&credential)
+      .is_ok());
+
+    assert!(matches!(
+      verifier("https://other.example.org/").action("Verification").validate(&credential).as_slice(),
+      [ValidationFailure::UnpermittedAction { action, .. }] if action == "Verification"
+    ));
+
+    assert!(verifier("https://wineonline.example.org/")
+      .action("Archival")
+      .check(&credential)
+      .is_err());
+  }
+
+  #[test]
+  fn test_obligation() {
+    let mut credential: Credential = Credential::from_json(JSON).unwrap();
+
+    credential.terms_of_use = policy(json!({
+      "type": "HolderPolicy",
+      "obligation": { "assignee": "AllVerifiers", "action": "Delete" }
+    }))
+    .into();
+
+    assert!(matches!(
+      verifier("https://verifier.example.org/").validate(&credential).as_slice(),
+      [ValidationFailure::UnfulfilledObligation { action, .. }] if action == "Delete"
+    ));
+
+    assert!(verifier("https://verifier.example.org/")
+      .duty("Delete")
+      .check(&credential)
+      .is_ok());
+  }
+
+  #[test]
+  fn test_parties() {
+    let mut credential: Credential = Credential::from_json(JSON).unwrap();
+
+    credential.terms_of_use = policy(json!({
+      "type": "HolderPolicy",
+      "prohibition": [{
+        "assignee": ["https://other.example.org/", { "uid": "https://verifier.example.org/" }],
+        "target": ["http://example.edu/credentials/1", "http://example.edu/credentials/3732"],
+        "action": ["Archival"]
+      }]
+    }))
+    .into();
+
+    assert!(verifier("https://verifier.example.org/")
+      .action("Archival")
+      .check(&credential)
+      .is_err());
+
+    assert!(verifier("https://wineonline.example.org/")
+      .action("Archival")
+      .check(&credential)
+      .is_ok());
+
+    // Parties of an unknown shape match every verifier for prohibitions
+    credential.terms_of_use = policy(json!({
+      "type": "HolderPolicy",
+      "prohibition": [{ "assignee": { "@type": "PartyCollection" }, "action": ["Archival"] }]
+    }))
+    .into();
+
+    assert!(verifier("https://wineonline.example.org/")
+      .action("Archival")
+      .check(&credential)
+      .is_err());
+  }
+
+  #[test]
+  fn test_unknown_parties() {
+    let mut credential: Credential = Credential::from_json(JSON).unwrap();
+
+    // Permissions are never granted to parties of an unknown shape
+    credential.terms_of_use = policy(json!({
+      "type": "HolderPolicy",
+      "permission": [{ "assignee": { "@type": "PartyCollection" }, "action": ["Verification"] }]
+    }))
+    .into();
+
+    assert!(matches!(
+      verifier("https://verifier.example.org/").action("Verification").validate(&credential).as_slice(),
+      [ValidationFailure::UnpermittedAction { action, .. }] if action == "Verification"
+    ));
+
+    credential.terms_of_use = policy(json!({
+      "type": "HolderPolicy",
+      "permission": [{ "target": { "@type": "AssetCollection" }, "action": ["Verification"] }]
+    }))
+    .into();
+
+    assert!(verifier("https://verifier.example.org/")
+      .action("Verification")
+      .check(&credential)
+      .is_err());
+
+    // Obligations of parties of an unknown shape still apply
+    credential.terms_of_use = policy(json!({
+      "type": "HolderPolicy",
+      "obligation": [{ "assignee": { "@type": "PartyCollection" }, "action": ["Delete"] }]
+    }))
+    .into();
+
+    assert!(matches!(
+      verifier("https://verifier.example.org/").validate(&credential).as_slice(),
+      [ValidationFailure::UnfulfilledObligation { action, .. }] if action == "Delete"
+    ));
+  }
+
+  #[test]
+  fn test_constraint() {
+    let mut credential: Credential = Credential::from_json(JSON).unwrap();
+
+    credential.terms_of_use = policy(json!({
+      "type": "HolderPolicy",
+      "permission": [{
+        "action": ["Verification"],
+        "constraint": [{ "leftOperand": "dateTime", "operator": "lt", "rightOperand": "2030-01-01" }]
+      }]
+    }))
+    .into();
+
+    assert!(matches!(
+      verifier("https://verifier.example.org/")
+        .action("Verification")
+        .validate(&credential)
+        .as_slice(),
+      [ValidationFailure::InvalidPolicy { message, .. }] if message == "unsupported rule constraint"
+    ));
+  }
+
+  #[test]
+  fn test_presentation() {
+    let credential: Credential = Credential::from_json(JSON).unwrap();
+
+    let presentation: Presentation = PresentationBuilder::default()
+      .id(Url::parse("http://example.org/presentations/1").unwrap())
+      .credential(credential)
+      .policy(policy(json!({
+        "type": "HolderPolicy",
+        "prohibition": [{ "assignee": "AllVerifiers", "action": ["3rdPartyCorrelation"] }]
+      })))
+      .build()
+      .unwrap();
+
+    let failures: Vec<ValidationFailure> = verifier("https://verifier.example.org/")
+      .action("Archival")
+      .action("3rdPartyCorrelation")
+      .validate_presentation(&presentation);
+
+    assert_eq!(failures.len(), 2);
+
+    // Malformed rules are reported
+    let mut presentation: Presentation = presentation;
+    presentation.terms_of_use = policy(json!({ "type": "HolderPolicy", "prohibition": 42 })).into();
+
+    assert!(matches!(
+      verifier("https://verifier.example.org/")
+        .validate_presentation(&presentation)
+        .as_slice(),
+      [ValidationFailure::InvalidPolicy { .. }, ..]
+    ));
+  }
+}
@@ -294,6 +294,27 @@ impl<T, U, V> Document<T, U, V> {
     self.resolve(query).ok_or(Error::QueryMethodNotFound)
   }
 
+  /// Returns the first verification [`method`][`Method`] with an `id` property
+  /// matching the provided `query` in the verification relationship of the
+  /// given `scope`.
+  pub fn resolve_with_scope<'query, Q>(&self, query: Q, scope: MethodScope) -> Option<&Method<U>>
+  where
+    Q: Into<MethodQuery<'query>>,
+  {
+    let query: MethodQuery<'query> = query.into();
+
+    let method: Option<&MethodRef<U>> = match scope {
+      MethodScope::VerificationMethod => return self.verification_method.query(query),
+      MethodScope::Authentication => self.authentication.query(query),
+      MethodScope::AssertionMethod => self.assertion_method.query(query),
+      MethodScope::KeyAgreement => self.key_agreement.query(query),
+      MethodScope::CapabilityDelegation => self.capability_delegation.query(query),
+      MethodScope::CapabilityInvocation => self.capability_invocation.query(query),
+    };
+
+    method.and_then(|method| self.resolve_ref(method))
+  }
+
   /// Returns a mutable reference to the first verification [`method`][`Method`]
   /// with an `id` property matching the provided `query`.
   pub fn resolve_mut<'query, Q>(&mut self, query: Q) -> Option<&mut Method<U>>
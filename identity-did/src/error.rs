@@ -47,6 +47,19 @@ pub enum Error {
   #[error("Invalid Verification Method - Duplicate")]
   InvalidMethodDuplicate,
 
+  #[error("Invalid Proof Purpose")]
+  InvalidProofPurpose,
+  #[error("Invalid Proof Challenge")]
+  InvalidProofChallenge,
+  #[error("Invalid Proof Domain")]
+  InvalidProofDomain,
+  #[error("Invalid Proof Nonce")]
+  InvalidProofNonce,
+  #[error("Invalid Proof - Expired")]
+  InvalidProofExpired,
+  #[error("Invalid Proof - Created In The Future")]
+  InvalidProofCreated,
+
   #[error("Unknown Method Scope")]
   UnknownMethodScope,
   #[error("Unknown Method Type")]
@@ -60,6 +73,8 @@ pub enum Error {
   InvalidKeyDataBase16,
   #[error("Invalid Base58 Key Data")]
   InvalidKeyDataBase58,
+  #[error("Invalid JWK Key Data")]
+  InvalidKeyDataJwk,
 
   #[error("Missing Resolution DID")]
   MissingResolutionDID,
@@ -75,4 +90,10 @@ pub enum Error {
   InvalidDIDFragment,
   #[error("Invalid DID Resolution Service")]
   InvalidServiceProtocol,
+  #[error("Invalid DID Method")]
+  InvalidDIDMethod,
+  #[error("Invalid DID Method Id")]
+  InvalidDIDMethodId,
+  #[error("Invalid DID Resolution Document")]
+  InvalidResolutionDocument,
 }
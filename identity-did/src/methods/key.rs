@@ -0,0 +1,487 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use async_trait::async_trait;
+use core::convert::TryFrom;
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::str::FromStr;
+use identity_core::crypto::KeyPair;
+use identity_core::crypto::KeyType;
+use identity_core::crypto::PublicKey;
+use identity_core::utils::decode_b58;
+use identity_core::utils::ed25519_to_x25519_public;
+use identity_core::utils::encode_b58;
+use identity_core::utils::encode_ec_public;
+
+use crate::did::DID;
+use crate::document::Document;
+use crate::document::DocumentBuilder;
+use crate::error::Error;
+use crate::error::Result;
+use crate::resolution::DocumentMetadata;
+use crate::resolution::InputMetadata;
+use crate::resolution::MetaDocument;
+use crate::resolution::ResolverMethod;
+use crate::utils::encode_jwk;
+use crate::verification::Method;
+use crate::verification::MethodData;
+use crate::verification::MethodType;
+
+/// The name of the `did:key` method.
+const METHOD: &str = "key";
+
+/// The multibase prefix of base58-btc encoded data.
+const MULTIBASE_B58: char = 'z';
+
+/// The multicodec prefixes of the supported public keys.
+const MULTICODEC_ED25519: [u8; 2] = [0xed, 0x01];
+const MULTICODEC_X25519: [u8; 2] = [0xec, 0x01];
+const MULTICODEC_SECP256K1: [u8; 2] = [0xe7, 0x01];
+const MULTICODEC_P256: [u8; 2] = [0x80, 0x24];
+
+/// The length of Ed25519/X25519 public keys.
+const OKP_PUBLIC_KEY_LENGTH: usize = 32;
+
+/// The length of compressed secp256k1/P-256 public keys.
+const EC_COMPRESSED_LENGTH: usize = 33;
+
+/// A [`did:key`][SPEC] identifier.
+///
+/// A `did:key` encodes a single public key as a multibase (base58-btc)
+/// multicodec value; the DID Document is derived from the key alone.
+///
+/// Ed25519, X25519, secp256k1 and P-256 keys can be expanded to a DID
+/// Document. P-256 keys are expressed as `JsonWebKey2020` methods.
+///
+/// The document of an Ed25519 identifier also contains an X25519 key agreement
+/// method derived from the Ed25519 public key.
+///
+/// [SPEC]: https://w3c-ccg.github.io/did-method-key/
+#[derive(Clone, Debug, PartialEq)]
+pub struct KeyDID {
+  did: DID,
+  type_: KeyType,
+  public: Vec<u8>,
+}
+
+impl KeyDID {
+  /// Creates a new [`KeyDID`] from the public key of the given [`KeyPair`].
+  pub fn new(keypair: &KeyPair) -> Result<Self> {
+    Self::from_public(keypair.type_(), keypair.public().as_ref())
+  }
+
+  /// Creates a new [`KeyDID`] from a [key type][`KeyType`] and public key.
+  ///
+  /// Elliptic curve public keys are expected to be the concatenated `x` and `y`
+  /// coordinates of the curve point.
+  ///
+  /// # Errors
+  ///
+  /// Fails if the key type is not supported or `public` has an invalid length.
+  pub fn from_public(type_: KeyType, public: &[u8]) -> Result<Self> {
+    let (encoded, public): (String, Vec<u8>) = encode_multikey(type_, public)?;
+    let did: DID = format!("did:{}:{}", METHOD, encoded).parse()?;
+
+    Ok(Self { did, type_, public })
+  }
+
+  /// Returns the DID.
+  pub fn did(&self) -> &DID {
+    &self.did
+  }
+
+  /// Returns the [type][`KeyType`] of the encoded public key.
+  pub fn key_type(&self) -> KeyType {
+    self.type_
+  }
+
+  /// Returns the encoded public key.
+  ///
+  /// Elliptic curve public keys are returned as compressed curve points.
+  pub fn public_key(&self) -> &[u8] {
+    &self.public
+  }
+
+  /// Returns the DID fragment of the verification method.
+  pub fn fragment(&self) -> &str {
+    self.did.method_id()
+  }
+
+  /// Expands the DID into a DID Document.
+  ///
+  /// The document is derived from the public key alone and contains a single
+  /// verification method, referenced by all relevant verification relationships.
+  ///
+  /// # Errors
+  ///
+  /// Fails if the key type can not be expanded.
+  pub fn to_document(&self) -> Result<Document> {
+    expand_multikey(&self.did, self.fragment(), self.type_, &self.public)
+  }
+}
+
+impl TryFrom<&DID> for KeyDID {
+  type Error = Error;
+
+  fn try_from(other: &DID) -> Result<Self, Self::Error> {
+    if other.method() != METHOD {
+      return Err(Error::InvalidDIDMethod);
+    }
+
+    let (type_, public): (KeyType, Vec<u8>) = decode_multikey(other.method_id())?;
+
+    Ok(Self {
+      did: format!("did:{}:{}", METHOD, other.method_id()).parse()?,
+      type_,
+      public,
+    })
+  }
+}
+
+impl FromStr for KeyDID {
+  type Err = Error;
+
+  fn from_str(string: &str) -> Result<Self, Self::Err> {
+    string
+      .parse()
+      .map_err(Into::into)
+      .and_then(|did: DID| Self::try_from(&did))
+  }
+}
+
+impl Display for KeyDID {
+  fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+    Display::fmt(&self.did, f)
+  }
+}
+
+/// A [`ResolverMethod`] for `did:key` identifiers.
+///
+/// Resolution is performed entirely offline.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct KeyResolver;
+
+impl KeyResolver {
+  /// Creates a new [`KeyResolver`].
+  pub const fn new() -> Self {
+    Self
+  }
+}
+
+#[async_trait(?Send)]
+impl ResolverMethod for KeyResolver {
+  fn is_supported(&self, did: &DID) -> bool {
+    did.method() == METHOD
+  }
+
+  async fn read(&self, did: &DID, _input: InputMetadata) -> Result<Option<MetaDocument>> {
+    let document: Document = KeyDID::try_from(did)?.to_document()?;
+
+    Ok(Some(MetaDocument {
+      data: document,
+      meta: DocumentMetadata::new(),
+    }))
+  }
+}
+
+/// Encodes a public key as a multibase (base58-btc) multicodec value.
+///
+/// Returns the encoded value and the public key, compressed if it is an
+/// elliptic curve key.
+pub(crate) fn encode_multikey(type_: KeyType, public: &[u8]) -> Result<(String, Vec<u8>)> {
+  let public: Vec<u8> = match type_ {
+    KeyType::Ed25519 | KeyType::X25519 if public.len() == OKP_PUBLIC_KEY_LENGTH => public.to_vec(),
+    KeyType::Secp256k1 | KeyType::P256 => encode_ec_public(type_, public).map_err(|_| Error::InvalidKeyData)?,
+    _ => return Err(Error::InvalidKeyData),
+  };
+
+  let data: Vec<u8> = [&__multicodec(type_)?[..], &public].concat();
+
+  Ok((format!("{}{}", MULTIBASE_B58, encode_b58(&data)), public))
+}
+
+/// Decodes a multibase (base58-btc) multicodec value into a public key.
+pub(crate) fn decode_multikey(value: &str) -> Result<(KeyType, Vec<u8>)> {
+  let encoded: &str = value.strip_prefix(MULTIBASE_B58).ok_or(Error::InvalidKeyDataBase58)?;
+  let data: Vec<u8> = decode_b58(encoded).map_err(|_| Error::InvalidKeyDataBase58)?;
+
+  let (type_, length): (KeyType, usize) = match data.get(..2) {
+    Some(prefix) if prefix == &MULTICODEC_ED25519[..] => (KeyType::Ed25519, OKP_PUBLIC_KEY_LENGTH),
+    Some(prefix) if prefix == &MULTICODEC_X25519[..] => (KeyType::X25519, OKP_PUBLIC_KEY_LENGTH),
+    Some(prefix) if prefix == &MULTICODEC_SECP256K1[..] => (KeyType::Secp256k1, EC_COMPRESSED_LENGTH),
+    Some(prefix) if prefix == &MULTICODEC_P256[..] => (KeyType::P256, EC_COMPRESSED_LENGTH),
+    _ => return Err(Error::InvalidKeyData),
+  };
+
+  if data.len() != length + 2 {
+    return Err(Error::InvalidKeyData);
+  }
+
+  Ok((type_, data[2..].to_vec()))
+}
+
+/// Expands a DID encoding a single public key into a DID Document.
+///
+/// The verification method is identified by the multibase-encoded key and
+/// referenced by all relationships relevant to the key type. Ed25519 keys are
+/// additionally converted to an X25519 key agreement method.
+pub(crate) fn expand_multikey(did: &DID, encoded: &str, type_: KeyType, public: &[u8]) -> Result<Document> {
+  let id: DID = did.join(format!("#{}", encoded))?;
+  let method: Method = key_method(id.clone(), did.clone(), type_, public)?;
+
+  let builder: DocumentBuilder = DocumentBuilder::new(Default::default())
+    .id(did.clone())
+    .verification_method(method);
+
+  let builder: DocumentBuilder = match type_ {
+    KeyType::X25519 => builder.key_agreement(id),
+    KeyType::Ed25519 => {
+      let public: PublicKey = ed25519_to_x25519_public(public).map_err(|_| Error::InvalidKeyData)?;
+      let (encoded, public): (String, Vec<u8>) = encode_multikey(KeyType::X25519, public.as_ref())?;
+      let agreement: DID = did.join(format!("#{}", encoded))?;
+      let method: Method = key_method(agreement.clone(), did.clone(), KeyType::X25519, &public)?;
+
+      __signing(builder, id)
+        .verification_method(method)
+        .key_agreement(agreement)
+    }
+    _ => __signing(builder, id),
+  };
+
+  builder.build()
+}
+
+/// Creates a verification method for a public key decoded by [`decode_multikey`].
+///
+/// Fails if the key type can not be represented as a verification method.
+pub(crate) fn key_method(id: DID, controller: DID, type_: KeyType, public: &[u8]) -> Result<Method> {
+  let (key_type, key_data): (MethodType, MethodData) = match type_ {
+    KeyType::Ed25519 => (MethodType::Ed25519VerificationKey2018, MethodData::new_b58(public)),
+    KeyType::X25519 => (MethodType::X25519KeyAgreementKey2019, MethodData::new_b58(public)),
+    KeyType::Secp256k1 => (
+      MethodType::EcdsaSecp256k1VerificationKey2019,
+      MethodData::new_b58(public),
+    ),
+    KeyType::P256 => (
+      MethodType::JsonWebKey2020,
+      MethodData::new_jwk(&encode_jwk(type_, public)?)?,
+    ),
+    KeyType::Bls12381G2 => return Err(Error::InvalidMethodType),
+  };
+
+  Method::builder(Default::default())
+    .id(id)
+    .controller(controller)
+    .key_type(key_type)
+    .key_data(key_data)
+    .build()
+}
+
+// Adds the relationships of a signing key to the document.
+fn __signing(builder: DocumentBuilder, id: DID) -> DocumentBuilder {
+  builder
+    .authentication(id.clone())
+    .assertion_method(id.clone())
+    .capability_delegation(id.clone())
+    .capability_invocation(id)
+}
+
+fn __multicodec(type_: KeyType) -> Result<[u8; 2]> {
+  match type_ {
+    KeyType::Ed25519 => Ok(MULTICODEC_ED25519),
+    KeyType::X25519 => Ok(MULTICODEC_X25519),
+    KeyType::Secp256k1 => Ok(MULTICODEC_SECP256K1),
+    KeyType::P256 => Ok(MULTICODEC_P256),
+    KeyType::Bls12381G2 => Err(Error::InvalidKeyData),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use core::convert::TryFrom;
+  use futures::executor::block_on;
+  use identity_core::common::Object;
+  use identity_core::crypto::KeyPair;
+  use identity_core::crypto::KeyType;
+  use identity_core::json;
+  use libjose::jwk::Jwk;
+  use libjose::jwk::JwkParams;
+
+  use crate::did::DID;
+  use crate::document::Document;
+  use crate::methods::KeyDID;
+  use crate::methods::KeyResolver;
+  use crate::resolution::resolve;
+  use crate::resolution::InputMetadata;
+  use crate::resolution::Resolution;
+  use crate::verifiable::Properties;
+  use crate::verification::Method;
+  use crate::verification::MethodType;
+
+  // Test vectors from the did:key specification
+  const ED25519: &str = "did:key:z6MkhaXgBZDvotDkL5257faiztiGiC2QtKLGpbnnEGta2doK";
+  const ED25519_X25519: &str = "z6LSj72tK8brWgZja8NLRwPigth2T9QRiG1uH9oKZuKjdh9p";
+  const SECP256K1: &str = "did:key:zQ3shokFTS3brHcDQrn82RUDfCZESWL1ZdCEJwekUDPQiYBme";
+  const P256: &str = "did:key:zDnaerDaTF5BXEavCrfRZEk316dpbLsfPDZ3WJ5hRTPFU2169";
+
+  #[test]
+  fn test_roundtrip() {
+    for (type_, prefix) in [
+      (KeyType::Ed25519, "did:key:z6Mk"),
+      (KeyType::X25519, "did:key:z6LS"),
+      (KeyType::Secp256k1, "did:key:zQ3s"),
+      (KeyType::P256, "did:key:zDn"),
+    ]
+    .iter()
+    {
+      let keypair: KeyPair = KeyPair::new(*type_).unwrap();
+      let did: KeyDID = KeyDID::new(&keypair).unwrap();
+
+      assert!(did.to_string().starts_with(prefix));
+      assert_eq!(did.key_type(), *type_);
+      assert_eq!(did.to_string().parse::<KeyDID>().unwrap(), did);
+    }
+  }
+
+  #[test]
+  fn test_invalid() {
+    assert!("did:example:z6Mk".parse::<KeyDID>().is_err());
+    assert!("did:key:6MkhaXgBZDvotDkL5257faiztiGiC2QtKLGpbnnEGta2doK"
+      .parse::<KeyDID>()
+      .is_err());
+    assert!("did:key:z3".parse::<KeyDID>().is_err());
+  }
+
+  #[test]
+  fn test_document() {
+    let keypair: KeyPair = KeyPair::new_ed25519().unwrap();
+    let did: KeyDID = KeyDID::new(&keypair).unwrap();
+    let document: Document = did.to_document().unwrap();
+
+    // Expansion is deterministic
+    assert_eq!(document, did.to_document().unwrap());
+    assert_eq!(document.id(), did.did());
+    assert_eq!(document.authentication().len(), 1);
+    assert_eq!(document.capability_invocation().len(), 1);
+    assert_eq!(document.key_agreement().len(), 1);
+
+    let method: &Method = document.verification_method().head().unwrap();
+
+    assert_eq!(method.key_type(), MethodType::Ed25519VerificationKey2018);
+    assert_eq!(method.key_data().try_decode().unwrap(), keypair.public().as_ref());
+    assert_eq!(method.id().fragment(), Some(did.fragment()));
+
+    // The key agreement key is derived from the Ed25519 key
+    let method: &Method = document.verification_method().tail().unwrap();
+
+    assert_eq!(method.key_type(), MethodType::X25519KeyAgreementKey2019);
+    assert_eq!(
+      method.key_data().try_decode().unwrap(),
+      keypair.to_x25519().unwrap().public().as_ref()
+    );
+
+    // X25519 keys are only used for key agreement
+    let did: KeyDID = KeyDID::new(&KeyPair::new_x25519().unwrap()).unwrap();
+    let document: Document = did.to_document().unwrap();
+
+    assert!(document.authentication().is_empty());
+    assert_eq!(document.key_agreement().len(), 1);
+
+    // P-256 keys are expressed as JSON Web Keys
+    let keypair: KeyPair = KeyPair::new_p256().unwrap();
+    let did: KeyDID = KeyDID::new(&keypair).unwrap();
+    let document: Document = did.to_document().unwrap();
+    let method: &Method = document.verification_method().head().unwrap();
+
+    assert_eq!(method.key_type(), MethodType::JsonWebKey2020);
+    assert_eq!(method.key_data().try_decode().unwrap(), keypair.public().as_ref());
+  }
+
+  #[test]
+  fn test_vectors() {
+    let did: KeyDID = ED25519.parse().unwrap();
+    let document: Document = did.to_document().unwrap();
+    let agreement: DID = did.did().join(format!("#{}", ED25519_X25519)).unwrap();
+
+    assert_eq!(did.key_type(), KeyType::Ed25519);
+    assert_eq!(document.key_agreement().len(), 1);
+    assert_eq!(document.key_agreement().head().unwrap().id(), &agreement);
+    assert!(document.resolve(&agreement).is_some());
+
+    let did: KeyDID = SECP256K1.parse().unwrap();
+    let document: Document = did.to_document().unwrap();
+    let method: &Method = document.verification_method().head().unwrap();
+
+    assert_eq!(did.key_type(), KeyType::Secp256k1);
+    assert_eq!(method.key_type(), MethodType::EcdsaSecp256k1VerificationKey2019);
+    assert_eq!(method.key_data().try_decode().unwrap(), did.public_key());
+    assert!(document.key_agreement().is_empty());
+
+    let did: KeyDID = P256.parse().unwrap();
+    let document: Document = did.to_document().unwrap();
+    let method: &Method = document.verification_method().head().unwrap();
+    let jwk: Jwk = method.try_to_jwk().unwrap();
+
+    assert_eq!(did.key_type(), KeyType::P256);
+    assert_eq!(method.key_type(), MethodType::JsonWebKey2020);
+
+    match jwk.params() {
+      JwkParams::Ec(params) => {
+        assert_eq!(params.crv, "P-256");
+        assert_eq!(params.x, "fyNYMN0976ci7xqiSdag3buk-ZCwgXU4kz9XNkBlNUI");
+        assert_eq!(params.y, "hW2ojTNfH7Jbi8--CJUo3OCbH3y5n91g-IMA9MLMbTU");
+      }
+      _ => panic!("expected an EC key"),
+    }
+  }
+
+  #[test]
+  fn test_sign_verify() {
+    for (type_, context) in [
+      (KeyType::Ed25519, "https://w3id.org/security/v2"),
+      (KeyType::Secp256k1, "https://w3id.org/security/v2"),
+      (KeyType::P256, "https://w3id.org/security/suites/jws-2020/v1"),
+    ]
+    .iter()
+    {
+      let keypair: KeyPair = KeyPair::new(*type_).unwrap();
+      let did: KeyDID = KeyDID::new(&keypair).unwrap();
+      let expanded: Document = did.to_document().unwrap();
+      let method: Method = Method::clone(expanded.verification_method().head().unwrap());
+
+      let mut properties: Object = Object::new();
+
+      properties.insert("@context".into(), json!(["https://www.w3.org/ns/did/v1", context]));
+
+      let mut document: Document<Properties> = Document::builder(Properties::new(properties))
+        .id(did.did().clone())
+        .authentication(method)
+        .build()
+        .unwrap();
+
+      document
+        .sign_this(format!("#{}", did.fragment()).as_str(), keypair.secret())
+        .unwrap();
+
+      assert!(document.verify_this().is_ok());
+    }
+  }
+
+  #[test]
+  fn test_resolve() {
+    let keypair: KeyPair = KeyPair::new_ed25519().unwrap();
+    let did: KeyDID = KeyDID::new(&keypair).unwrap();
+    let url: DID = did.did().join(format!("#{}", did.fragment())).unwrap();
+
+    assert_eq!(KeyDID::try_from(&url).unwrap(), did);
+
+    block_on(async {
+      let resolution: Resolution = resolve(&did.to_string(), InputMetadata::new(), KeyResolver::new())
+        .await
+        .unwrap();
+
+      assert_eq!(resolution.document.unwrap(), did.to_document().unwrap());
+    });
+  }
+}
@@ -0,0 +1,16 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Implementations of ledger-independent DID methods.
+
+mod key;
+mod peer;
+mod web;
+
+pub use self::key::KeyDID;
+pub use self::key::KeyResolver;
+pub use self::peer::PeerBuilder;
+pub use self::peer::PeerDID;
+pub use self::peer::PeerResolver;
+pub use self::web::Fetcher;
+pub use self::web::WebResolver;
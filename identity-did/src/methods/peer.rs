@@ -0,0 +1,601 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use async_trait::async_trait;
+use core::convert::TryFrom;
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::str::FromStr;
+use identity_core::common::Object;
+use identity_core::common::Value;
+use identity_core::convert::FromJson;
+use identity_core::convert::ToJson;
+use identity_core::crypto::KeyPair;
+use identity_core::crypto::KeyType;
+use libjose::utils::decode_b64;
+use libjose::utils::encode_b64;
+
+use crate::did::DID;
+use crate::document::Document;
+use crate::document::DocumentBuilder;
+use crate::error::Error;
+use crate::error::Result;
+use crate::methods::key::decode_multikey;
+use crate::methods::key::encode_multikey;
+use crate::methods::key::expand_multikey;
+use crate::methods::key::key_method;
+use crate::resolution::DocumentMetadata;
+use crate::resolution::InputMetadata;
+use crate::resolution::MetaDocument;
+use crate::resolution::ResolverMethod;
+use crate::service::Service;
+use crate::verification::Method;
+use crate::verification::MethodScope;
+
+/// The name of the `did:peer` method.
+const METHOD: &str = "peer";
+
+/// The numeric algorithm of an inception key without a document.
+const NUMALGO_0: char = '0';
+
+/// The numeric algorithm of multiple inception keys and services.
+const NUMALGO_2: char = '2';
+
+/// The separator of the elements of a `numalgo 2` peer DID.
+const SEPARATOR: char = '.';
+
+/// The purpose code of an encoded service.
+const PURPOSE_SERVICE: char = 'S';
+
+/// The abbreviations of service properties and values.
+const ABBREVIATIONS: &[(&str, &str)] = &[
+  ("type", "t"),
+  ("serviceEndpoint", "s"),
+  ("routingKeys", "r"),
+  ("accept", "a"),
+];
+
+/// The service type abbreviations.
+const SERVICE_TYPES: &[(&str, &str)] = &[("DIDCommMessaging", "dm")];
+
+/// A [`did:peer`][SPEC] identifier.
+///
+/// Peer DIDs are resolved without a verifiable data registry:
+///
+/// - `numalgo 0` DIDs encode a single inception key, and expand to a document
+///   like a `did:key`.
+/// - `numalgo 2` DIDs encode multiple keys, each with a verification
+///   relationship, and services with abbreviated properties.
+///
+/// [SPEC]: https://identity.foundation/peer-did-method-spec/
+#[derive(Clone, Debug, PartialEq)]
+pub struct PeerDID {
+  did: DID,
+}
+
+impl PeerDID {
+  /// Creates a new `numalgo 0` [`PeerDID`] from the public key of the given
+  /// [`KeyPair`].
+  pub fn new(keypair: &KeyPair) -> Result<Self> {
+    Self::from_public(keypair.type_(), keypair.public().as_ref())
+  }
+
+  /// Creates a new `numalgo 0` [`PeerDID`] from a [key type][`KeyType`] and
+  /// public key.
+  ///
+  /// Elliptic curve public keys are expected to be the concatenated `x` and `y`
+  /// coordinates of the curve point.
+  pub fn from_public(type_: KeyType, public: &[u8]) -> Result<Self> {
+    let (encoded, _): (String, Vec<u8>) = encode_multikey(type_, public)?;
+
+    Ok(Self {
+      did: format!("did:{}:{}{}", METHOD, NUMALGO_0, encoded).parse()?,
+    })
+  }
+
+  /// Creates a [`PeerBuilder`] to configure a new `numalgo 2` [`PeerDID`].
+  pub fn builder() -> PeerBuilder {
+    PeerBuilder::new()
+  }
+
+  /// Returns the DID.
+  pub fn did(&self) -> &DID {
+    &self.did
+  }
+
+  /// Returns the numeric algorithm used to generate the DID.
+  pub fn numalgo(&self) -> char {
+    self.did.method_id().chars().next().unwrap_or(NUMALGO_0)
+  }
+
+  /// Expands the DID into a DID Document.
+  ///
+  /// Keys of a `numalgo 2` DID are identified by `#key-1`, `#key-2`, ... and
+  /// services by `#service`, `#service-1`, ... in the order of encoding.
+  ///
+  /// # Errors
+  ///
+  /// Fails if the DID contains an invalid key or service.
+  pub fn to_document(&self) -> Result<Document> {
+    let (numalgo, encoded): (char, &str) = __split(&self.did)?;
+
+    match numalgo {
+      NUMALGO_0 => {
+        let (type_, public): (KeyType, Vec<u8>) = decode_multikey(encoded)?;
+
+        expand_multikey(&self.did, encoded, type_, &public)
+      }
+      NUMALGO_2 => {
+        let mut builder: DocumentBuilder = DocumentBuilder::new(Default::default()).id(self.did.clone());
+        let mut keys: usize = 0;
+        let mut services: usize = 0;
+
+        for element in encoded.split(SEPARATOR).skip(1) {
+          let mut chars = element.chars();
+
+          let purpose: char = chars.next().ok_or(Error::InvalidDIDMethodId)?;
+          let value: &str = chars.as_str();
+
+          if purpose == PURPOSE_SERVICE {
+            let fragment: String = match services {
+              0 => "#service".to_string(),
+              index => format!("#service-{}", index),
+            };
+
+            builder = builder.service(__decode_service(self.did.join(fragment)?, value)?);
+            services += 1;
+            continue;
+          }
+
+          keys += 1;
+
+          let (type_, public): (KeyType, Vec<u8>) = decode_multikey(value)?;
+          let id: DID = self.did.join(format!("#key-{}", keys))?;
+          let method: Method = key_method(id.clone(), self.did.clone(), type_, &public)?;
+
+          builder = match __scope(purpose)? {
+            MethodScope::Authentication => builder.authentication(id),
+            MethodScope::AssertionMethod => builder.assertion_method(id),
+            MethodScope::KeyAgreement => builder.key_agreement(id),
+            MethodScope::CapabilityDelegation => builder.capability_delegation(id),
+            MethodScope::CapabilityInvocation => builder.capability_invocation(id),
+            MethodScope::VerificationMethod => builder,
+          };
+
+          builder = builder.verification_method(method);
+        }
+
+        builder.build()
+      }
+      _ => Err(Error::InvalidDIDMethodId),
+    }
+  }
+}
+
+impl TryFrom<&DID> for PeerDID {
+  type Error = Error;
+
+  fn try_from(other: &DID) -> Result<Self, Self::Error> {
+    if other.method() != METHOD {
+      return Err(Error::InvalidDIDMethod);
+    }
+
+    let peer: Self = Self {
+      did: format!("did:{}:{}", METHOD, other.method_id()).parse()?,
+    };
+
+    let valid: bool = match __split(&peer.did)? {
+      (NUMALGO_0, _) => true,
+      (NUMALGO_2, encoded) => encoded.starts_with(SEPARATOR),
+      _ => false,
+    };
+
+    if !valid {
+      return Err(Error::InvalidDIDMethodId);
+    }
+
+    // Decode all keys and services
+    peer.to_document()?;
+
+    Ok(peer)
+  }
+}
+
+impl FromStr for PeerDID {
+  type Err = Error;
+
+  fn from_str(string: &str) -> Result<Self, Self::Err> {
+    string
+      .parse()
+      .map_err(Into::into)
+      .and_then(|did: DID| Self::try_from(&did))
+  }
+}
+
+impl Display for PeerDID {
+  fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+    Display::fmt(&self.did, f)
+  }
+}
+
+/// A `PeerBuilder` is used to generate a customized `numalgo 2` [`PeerDID`].
+#[derive(Clone, Debug, Default)]
+pub struct PeerBuilder {
+  keys: Vec<(MethodScope, KeyType, Vec<u8>)>,
+  services: Vec<Service>,
+}
+
+impl PeerBuilder {
+  /// Creates a new `PeerBuilder`.
+  pub fn new() -> Self {
+    Self {
+      keys: Vec::new(),
+      services: Vec::new(),
+    }
+  }
+
+  /// Adds a public key with the given verification relationship.
+  ///
+  /// Elliptic curve public keys are expected to be the concatenated `x` and `y`
+  /// coordinates of the curve point.
+  pub fn key(mut self, scope: MethodScope, type_: KeyType, public: impl AsRef<[u8]>) -> Self {
+    self.keys.push((scope, type_, public.as_ref().to_vec()));
+    self
+  }
+
+  /// Adds a public key used for key agreement.
+  pub fn key_agreement(self, type_: KeyType, public: impl AsRef<[u8]>) -> Self {
+    self.key(MethodScope::KeyAgreement, type_, public)
+  }
+
+  /// Adds a public key used for authentication.
+  pub fn authentication(self, type_: KeyType, public: impl AsRef<[u8]>) -> Self {
+    self.key(MethodScope::Authentication, type_, public)
+  }
+
+  /// Adds a service.
+  ///
+  /// Note: The service `id` is not encoded in the DID.
+  pub fn service(mut self, value: Service) -> Self {
+    self.services.push(value);
+    self
+  }
+
+  /// Returns a new `numalgo 2` [`PeerDID`] based on the `PeerBuilder`
+  /// configuration.
+  ///
+  /// # Errors
+  ///
+  /// Fails if a key or service can not be encoded.
+  pub fn build(self) -> Result<PeerDID> {
+    let mut output: String = format!("did:{}:{}", METHOD, NUMALGO_2);
+
+    for (scope, type_, public) in self.keys.iter() {
+      let (encoded, _): (String, Vec<u8>) = encode_multikey(*type_, public)?;
+
+      output.push(SEPARATOR);
+      output.push(__purpose(*scope)?);
+      output.push_str(&encoded);
+    }
+
+    for service in self.services.iter() {
+      output.push(SEPARATOR);
+      output.push(PURPOSE_SERVICE);
+      output.push_str(&__encode_service(service)?);
+    }
+
+    Ok(PeerDID { did: output.parse()? })
+  }
+}
+
+/// A [`ResolverMethod`] for `did:peer` identifiers.
+///
+/// Resolution is performed entirely offline.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct PeerResolver;
+
+impl PeerResolver {
+  /// Creates a new [`PeerResolver`].
+  pub const fn new() -> Self {
+    Self
+  }
+}
+
+#[async_trait(?Send)]
+impl ResolverMethod for PeerResolver {
+  fn is_supported(&self, did: &DID) -> bool {
+    did.method() == METHOD
+  }
+
+  async fn read(&self, did: &DID, _input: InputMetadata) -> Result<Option<MetaDocument>> {
+    let document: Document = PeerDID::try_from(did)?.to_document()?;
+
+    Ok(Some(MetaDocument {
+      data: document,
+      meta: DocumentMetadata::new(),
+    }))
+  }
+}
+
+// Splits the method-specific id into the numeric algorithm and the encoded
+// value.
+fn __split(did: &DID) -> Result<(char, &str)> {
+  let mut chars = did.method_id().chars();
+  let numalgo: char = chars.next().ok_or(Error::InvalidDIDMethodId)?;
+
+  Ok((numalgo, chars.as_str()))
+}
+
+fn __purpose(scope: MethodScope) -> Result<char> {
+  match scope {
+    MethodScope::AssertionMethod => Ok('A'),
+    MethodScope::KeyAgreement => Ok('E'),
+    MethodScope::Authentication => Ok('V'),
+    MethodScope::CapabilityInvocation => Ok('I'),
+    MethodScope::CapabilityDelegation => Ok('D'),
+    MethodScope::VerificationMethod => Err(Error::UnknownMethodScope),
+  }
+}
+
+fn __scope(purpose: char) -> Result<MethodScope> {
+  match purpose {
+    'A' => Ok(MethodScope::AssertionMethod),
+    'E' => Ok(MethodScope::KeyAgreement),
+    'V' => Ok(MethodScope::Authentication),
+    'I' => Ok(MethodScope::CapabilityInvocation),
+    'D' => Ok(MethodScope::CapabilityDelegation),
+    _ => Err(Error::UnknownMethodScope),
+  }
+}
+
+// Encodes a service as abbreviated base64url JSON, without the service `id`.
+//
+// The abbreviated properties are encoded in the order of the specification,
+// followed by all other properties.
+fn __encode_service(service: &Service) -> Result<String> {
+  let mut object: Object = Object::from_json_value(service.to_json_value()?)?;
+  let mut output: Vec<(String, Value)> = Vec::new();
+
+  object.remove("id");
+
+  for (key, short) in ABBREVIATIONS.iter() {
+    let value: Value = match (*key, object.remove(*key)) {
+      ("type", Some(Value::String(type_))) => Value::String(__abbreviate(SERVICE_TYPES, &type_, false)),
+      (_, Some(value)) => value,
+      (_, None) => continue,
+    };
+
+    output.push((short.to_string(), value));
+  }
+
+  output.extend(object);
+
+  // JSON objects preserve the insertion order of their properties
+  let output: Value = output.into_iter().collect();
+
+  Ok(encode_b64(output.to_json_vec()?))
+}
+
+// Decodes an abbreviated base64url JSON service and sets its `id`.
+fn __decode_service(id: DID, value: &str) -> Result<Service> {
+  let data: Vec<u8> = decode_b64(value).map_err(|_| Error::InvalidDIDMethodId)?;
+  let object: Object = Object::from_json_slice(&data)?;
+  let mut output: Object = Object::new();
+
+  for (key, value) in object {
+    let key: String = __abbreviate(ABBREVIATIONS, &key, true);
+
+    let value: Value = match (key.as_str(), value) {
+      ("type", Value::String(type_)) => Value::String(__abbreviate(SERVICE_TYPES, &type_, true)),
+      (_, value) => value,
+    };
+
+    output.insert(key, value);
+  }
+
+  output.insert("id".into(), id.to_string().into());
+
+  Service::from_json_value(output.to_json_value()?).map_err(Into::into)
+}
+
+// Replaces a term with its abbreviation, or the reverse if `expand` is set.
+fn __abbreviate(table: &[(&str, &str)], term: &str, expand: bool) -> String {
+  table
+    .iter()
+    .find(|(long, short)| if expand { *short == term } else { *long == term })
+    .map(|(long, short)| if expand { *long } else { *short })
+    .unwrap_or(term)
+    .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+  use core::convert::TryFrom;
+  use futures::executor::block_on;
+  use identity_core::common::Url;
+  use identity_core::crypto::KeyPair;
+  use identity_core::crypto::KeyType;
+  use identity_core::json;
+
+  use crate::did::DID;
+  use crate::document::Document;
+  use crate::methods::KeyDID;
+  use crate::methods::PeerDID;
+  use crate::methods::PeerResolver;
+  use crate::resolution::resolve;
+  use crate::resolution::InputMetadata;
+  use crate::resolution::Resolution;
+  use crate::service::Service;
+  use crate::verification::Method;
+
+  // Examples from the did:peer specification
+  const NUMALGO_0: &str = "did:peer:0z6MkpTHR8VNsBxYAAWHut2Geadd9jSwuBV8xRoAnwWsdvktH";
+  const NUMALGO_2: &str = "did:peer:2\
+    .Ez6LSbysY2xFMRpGMhb7tFTLMpeuPRaqaWM1yECx2AtzE3KCc\
+    .Vz6MkqRYqQiSgvZQdnBytw86Qbs2ZWUkGv22od935YF4s8M7V\
+    .Vz6MkgoLTnTypo3tDRwCkZXSccTPHRLhF4ZnjhueYAFpEX6vg\
+    .SeyJ0IjoiZG0iLCJzIjoiaHR0cHM6Ly9leGFtcGxlLmNvbS9lbmRwb2ludCIsInIiOlsiZGlkOmV4YW1wbGU6c29tZW1lZGlhdG9yI3NvbWVrZXkiXSwiYSI6WyJkaWRjb21tL3YyIiwiZGlkY29tbS9haXAyO2Vudj1yZmM1ODciXX0";
+
+  fn service() -> Service {
+    Service::builder(Default::default())
+      .id("did:example:123#didcomm".parse().unwrap())
+      .type_("DIDCommMessaging")
+      .service_endpoint(Url::parse("https://example.com/endpoint").unwrap())
+      .build()
+      .unwrap()
+  }
+
+  #[test]
+  fn test_numalgo0() {
+    let keypair: KeyPair = KeyPair::new_ed25519().unwrap();
+    let peer: PeerDID = PeerDID::new(&keypair).unwrap();
+    let key: KeyDID = KeyDID::new(&keypair).unwrap();
+
+    assert!(peer.to_string().starts_with("did:peer:0z6Mk"));
+    assert_eq!(peer.numalgo(), '0');
+    assert_eq!(peer.to_string().parse::<PeerDID>().unwrap(), peer);
+
+    // The document matches the equivalent `did:key` document
+    let document: Document = peer.to_document().unwrap();
+    let expected: Document = key.to_document().unwrap();
+
+    assert_eq!(document.authentication().len(), expected.authentication().len());
+    assert_eq!(
+      document.methods().next().unwrap().key_data(),
+      expected.methods().next().unwrap().key_data()
+    );
+  }
+
+  #[test]
+  fn test_numalgo2() {
+    let agreement: KeyPair = KeyPair::new_x25519().unwrap();
+    let authentication: KeyPair = KeyPair::new_ed25519().unwrap();
+
+    let peer: PeerDID = PeerDID::builder()
+      .key_agreement(KeyType::X25519, agreement.public())
+      .authentication(KeyType::Ed25519, authentication.public())
+      .service(service())
+      .service(service())
+      .build()
+      .unwrap();
+
+    assert!(peer.to_string().starts_with("did:peer:2.Ez6LS"));
+    assert_eq!(peer.numalgo(), '2');
+
+    let document: Document = peer.to_document().unwrap();
+
+    assert_eq!(document.id(), peer.did());
+    assert_eq!(document.key_agreement().len(), 1);
+    assert_eq!(document.authentication().len(), 1);
+
+    let reference: &DID = document.key_agreement().head().unwrap().id();
+    assert_eq!(reference.fragment(), Some("key-1"));
+
+    let services: Vec<&str> = document
+      .service()
+      .iter()
+      .map(|service| service.id().fragment().unwrap())
+      .collect();
+
+    assert_eq!(services, ["service", "service-1"]);
+    assert_eq!(document.service().head().unwrap().type_(), "DIDCommMessaging");
+    assert_eq!(
+      document.service().head().unwrap().service_endpoint().as_str(),
+      "https://example.com/endpoint"
+    );
+  }
+
+  #[test]
+  fn test_numalgo0_example() {
+    let peer: PeerDID = NUMALGO_0.parse().unwrap();
+    let key: KeyDID = "did:key:z6MkpTHR8VNsBxYAAWHut2Geadd9jSwuBV8xRoAnwWsdvktH"
+      .parse()
+      .unwrap();
+    let document: Document = peer.to_document().unwrap();
+    let method: &Method = document.verification_method().head().unwrap();
+
+    assert_eq!(peer.numalgo(), '0');
+    assert_eq!(document.id().as_str(), NUMALGO_0);
+    assert_eq!(
+      method.id().fragment(),
+      Some("z6MkpTHR8VNsBxYAAWHut2Geadd9jSwuBV8xRoAnwWsdvktH")
+    );
+    assert_eq!(method.key_data().try_decode().unwrap(), key.public_key());
+    assert_eq!(document.authentication().len(), 1);
+    assert_eq!(document.key_agreement().len(), 1);
+  }
+
+  #[test]
+  fn test_numalgo2_example() {
+    let peer: PeerDID = NUMALGO_2.parse().unwrap();
+    let document: Document = peer.to_document().unwrap();
+
+    assert_eq!(peer.numalgo(), '2');
+    assert_eq!(document.verification_method().len(), 3);
+    assert_eq!(document.key_agreement().len(), 1);
+    assert_eq!(document.authentication().len(), 2);
+
+    let service: &Service = document.service().head().unwrap();
+
+    assert_eq!(service.id().fragment(), Some("service"));
+    assert_eq!(service.type_(), "DIDCommMessaging");
+    assert_eq!(service.service_endpoint().as_str(), "https://example.com/endpoint");
+    assert_eq!(
+      service.properties()["routingKeys"],
+      json!(["did:example:somemediator#somekey"])
+    );
+    assert_eq!(
+      service.properties()["accept"],
+      json!(["didcomm/v2", "didcomm/aip2;env=rfc587"])
+    );
+
+    // Encoding the same keys and service yields the same DID
+    let public = |index: usize| -> Vec<u8> {
+      document.verification_method().as_slice()[index]
+        .key_data()
+        .try_decode()
+        .unwrap()
+    };
+
+    let encoded: PeerDID = PeerDID::builder()
+      .key_agreement(KeyType::X25519, public(0))
+      .authentication(KeyType::Ed25519, public(1))
+      .authentication(KeyType::Ed25519, public(2))
+      .service(service.clone())
+      .build()
+      .unwrap();
+
+    assert_eq!(encoded, peer);
+  }
+
+  #[test]
+  fn test_invalid() {
+    assert!("did:peer:1z6Mk".parse::<PeerDID>().is_err());
+    assert!("did:peer:0abc".parse::<PeerDID>().is_err());
+    assert!("did:key:0z6Mk".parse::<PeerDID>().is_err());
+
+    // The elements of `numalgo 2` DIDs are decoded when parsing
+    let did: DID = "did:peer:2.Xz6Mk".parse().unwrap();
+    assert!(PeerDID::try_from(&did).is_err());
+
+    let did: DID = format!("{}.Sabc", NUMALGO_2).parse().unwrap();
+    assert!(PeerDID::try_from(&did).is_err());
+  }
+
+  #[test]
+  fn test_resolve() {
+    let keypair: KeyPair = KeyPair::new_x25519().unwrap();
+    let peer: PeerDID = PeerDID::builder()
+      .key_agreement(KeyType::X25519, keypair.public())
+      .build()
+      .unwrap();
+
+    block_on(async {
+      let resolution: Resolution = resolve(&peer.to_string(), InputMetadata::new(), PeerResolver::new())
+        .await
+        .unwrap();
+
+      assert_eq!(resolution.document.unwrap(), peer.to_document().unwrap());
+    });
+  }
+}
@@ -0,0 +1,241 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use async_trait::async_trait;
+use identity_core::common::Url;
+use identity_core::convert::FromJson;
+
+use crate::did::DID;
+use crate::document::Document;
+use crate::error::Error;
+use crate::error::Result;
+use crate::resolution::DocumentMetadata;
+use crate::resolution::InputMetadata;
+use crate::resolution::MetaDocument;
+use crate::resolution::ResolverMethod;
+
+/// The name of the `did:web` method.
+const METHOD: &str = "web";
+
+/// The path of a DID Document hosted at the root of a domain.
+const WELL_KNOWN: &str = ".well-known";
+
+/// The file name of a hosted DID Document.
+const DOCUMENT: &str = "did.json";
+
+/// A trait for fetching the contents of a remote resource.
+///
+/// This is implemented by HTTP clients to allow [`WebResolver`] to be used
+/// without depending on a specific client.
+#[async_trait(?Send)]
+pub trait Fetcher {
+  /// Fetches the contents of the resource at `url`.
+  ///
+  /// Returns `None` if the resource does not exist.
+  async fn fetch(&self, url: &Url) -> Result<Option<Vec<u8>>>;
+}
+
+#[async_trait(?Send)]
+impl<T> Fetcher for &'_ T
+where
+  T: Fetcher + Send + Sync,
+{
+  async fn fetch(&self, url: &Url) -> Result<Option<Vec<u8>>> {
+    (**self).fetch(url).await
+  }
+}
+
+/// A [`ResolverMethod`] for [`did:web`][SPEC] identifiers.
+///
+/// DID Documents are retrieved with the given [`Fetcher`] from the HTTPS URL
+/// derived from the DID, and rejected unless their `id` matches the DID.
+///
+/// [SPEC]: https://w3c-ccg.github.io/did-method-web/
+#[derive(Clone, Debug)]
+pub struct WebResolver<F> {
+  fetcher: F,
+}
+
+impl<F> WebResolver<F> {
+  /// Creates a new [`WebResolver`] fetching documents with `fetcher`.
+  pub const fn new(fetcher: F) -> Self {
+    Self { fetcher }
+  }
+
+  /// Returns a reference to the [`Fetcher`].
+  pub fn fetcher(&self) -> &F {
+    &self.fetcher
+  }
+
+  /// Returns the URL of the DID Document identified by `did`.
+  ///
+  /// `did:web:example.com` maps to `https://example.com/.well-known/did.json`
+  /// and `did:web:example.com:user:alice` maps to
+  /// `https://example.com/user/alice/did.json`. A percent-encoded port in the
+  /// domain name is decoded.
+  ///
+  /// # Errors
+  ///
+  /// Fails if `did` is not a valid `did:web` identifier.
+  pub fn document_url(did: &DID) -> Result<Url> {
+    if did.method() != METHOD {
+      return Err(Error::InvalidDIDMethod);
+    }
+
+    let mut segments = did.method_id().split(':');
+
+    let host: String = match segments.next() {
+      Some(host) if !host.is_empty() => host.replace("%3A", ":").replace("%3a", ":"),
+      _ => return Err(Error::InvalidDIDMethodId),
+    };
+
+    let path: Vec<&str> = segments.collect();
+
+    if path.iter().any(|segment| segment.is_empty()) {
+      return Err(Error::InvalidDIDMethodId);
+    }
+
+    let path: String = if path.is_empty() {
+      format!("{}/{}", WELL_KNOWN, DOCUMENT)
+    } else {
+      format!("{}/{}", path.join("/"), DOCUMENT)
+    };
+
+    Url::parse(format!("https://{}/{}", host, path)).map_err(|_| Error::InvalidDIDMethodId)
+  }
+}
+
+#[async_trait(?Send)]
+impl<F> ResolverMethod for WebResolver<F>
+where
+  F: Fetcher,
+{
+  fn is_supported(&self, did: &DID) -> bool {
+    did.method() == METHOD
+  }
+
+  async fn read(&self, did: &DID, _input: InputMetadata) -> Result<Option<MetaDocument>> {
+    let url: Url = Self::document_url(did)?;
+
+    let data: Vec<u8> = match self.fetcher.fetch(&url).await? {
+      Some(data) => data,
+      None => return Ok(None),
+    };
+
+    let document: Document = Document::from_json_slice(&data)?;
+
+    // The document MUST be identified by the resolved DID.
+    let expected: DID = format!("did:{}:{}", METHOD, did.method_id()).parse()?;
+
+    if document.id() != &expected {
+      return Err(Error::InvalidResolutionDocument);
+    }
+
+    Ok(Some(MetaDocument {
+      data: document,
+      meta: DocumentMetadata::new(),
+    }))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use async_trait::async_trait;
+  use futures::executor::block_on;
+  use identity_core::common::Url;
+  use std::collections::BTreeMap;
+
+  use crate::did::DID;
+  use crate::error::Error;
+  use crate::error::Result;
+  use crate::methods::Fetcher;
+  use crate::methods::WebResolver;
+  use crate::resolution::resolve;
+  use crate::resolution::ErrorKind;
+  use crate::resolution::InputMetadata;
+  use crate::resolution::Resolution;
+
+  #[derive(Default)]
+  struct MemoryFetcher(BTreeMap<String, String>);
+
+  impl MemoryFetcher {
+    fn insert(mut self, url: &str, data: &str) -> Self {
+      self.0.insert(url.into(), data.into());
+      self
+    }
+  }
+
+  #[async_trait(?Send)]
+  impl Fetcher for MemoryFetcher {
+    async fn fetch(&self, url: &Url) -> Result<Option<Vec<u8>>> {
+      Ok(self.0.get(url.as_str()).map(|data| data.as_bytes().to_vec()))
+    }
+  }
+
+  fn url(did: &str) -> Result<String> {
+    did
+      .parse::<DID>()
+      .map_err(Into::into)
+      .and_then(|did| WebResolver::<MemoryFetcher>::document_url(&did))
+      .map(|url| url.to_string())
+  }
+
+  fn document(id: &str) -> String {
+    format!(r#"{{"id": "{}"}}"#, id)
+  }
+
+  #[test]
+  fn test_document_url() {
+    assert_eq!(
+      url("did:web:w3c-ccg.github.io").unwrap(),
+      "https://w3c-ccg.github.io/.well-known/did.json"
+    );
+    assert_eq!(
+      url("did:web:w3c-ccg.github.io:user:alice").unwrap(),
+      "https://w3c-ccg.github.io/user/alice/did.json"
+    );
+    assert_eq!(
+      url("did:web:example.com%3A3000:user:alice").unwrap(),
+      "https://example.com:3000/user/alice/did.json"
+    );
+
+    assert!(matches!(url("did:key:example.com"), Err(Error::InvalidDIDMethod)));
+    assert!(url("did:web:example.com::alice").is_err());
+  }
+
+  #[test]
+  fn test_resolve() {
+    let fetcher: MemoryFetcher = MemoryFetcher::default()
+      .insert(
+        "https://example.com/.well-known/did.json",
+        &document("did:web:example.com"),
+      )
+      .insert(
+        "https://example.com/user/alice/did.json",
+        &document("did:web:example.com:user:bob"),
+      );
+
+    let resolver: WebResolver<MemoryFetcher> = WebResolver::new(fetcher);
+
+    block_on(async {
+      let resolution: Resolution = resolve("did:web:example.com", InputMetadata::new(), &resolver)
+        .await
+        .unwrap();
+
+      assert_eq!(resolution.document.unwrap().id().as_str(), "did:web:example.com");
+
+      // Documents not found are reported in the resolution metadata
+      let resolution: Resolution = resolve("did:web:other.com", InputMetadata::new(), &resolver)
+        .await
+        .unwrap();
+
+      assert_eq!(resolution.metadata.error, Some(ErrorKind::NotFound));
+
+      // The document id MUST match the DID
+      let resolution: Result<Resolution> =
+        resolve("did:web:example.com:user:alice", InputMetadata::new(), &resolver).await;
+
+      assert!(matches!(resolution, Err(Error::InvalidResolutionDocument)));
+    });
+  }
+}
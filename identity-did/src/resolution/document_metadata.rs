@@ -17,6 +17,21 @@ pub struct DocumentMetadata {
   /// [More Info](https://www.w3.org/TR/did-spec-registries/#updated)
   #[serde(skip_serializing_if = "Option::is_none")]
   pub updated: Option<Timestamp>,
+  /// The version of the resolved DID document.
+  ///
+  /// [More Info](https://www.w3.org/TR/did-spec-registries/#versionId)
+  #[serde(rename = "versionId", skip_serializing_if = "Option::is_none")]
+  pub version_id: Option<String>,
+  /// The version of the next update of the resolved DID document, if any.
+  ///
+  /// [More Info](https://www.w3.org/TR/did-spec-registries/#nextVersionId)
+  #[serde(rename = "nextVersionId", skip_serializing_if = "Option::is_none")]
+  pub next_version_id: Option<String>,
+  /// The timestamp of the next update of the resolved DID document, if any.
+  ///
+  /// [More Info](https://www.w3.org/TR/did-spec-registries/#nextUpdate)
+  #[serde(rename = "nextUpdate", skip_serializing_if = "Option::is_none")]
+  pub next_update: Option<Timestamp>,
   /// Additional document metadata properties.
   #[serde(flatten)]
   pub properties: Object,
@@ -28,6 +43,9 @@ impl DocumentMetadata {
     Self {
       created: None,
       updated: None,
+      version_id: None,
+      next_version_id: None,
+      next_update: None,
       properties: Object::new(),
     }
   }
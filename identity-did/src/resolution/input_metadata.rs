@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use identity_core::common::Object;
+use identity_core::common::Timestamp;
 
 /// The content type of a JSON DID Document.
 pub const MIME_DID: &str = "application/did+json";
@@ -9,9 +10,6 @@ pub const MIME_DID: &str = "application/did+json";
 /// The content type of a JSON-LD DID Document.
 pub const MIME_DID_LD: &str = "application/did+ld+json";
 
-// TODO: Support versioning via `version-id`/`version-time`
-// TODO: Support caching via `no-cache`
-
 /// Input options used to configure a [DID resolution][SPEC] process.
 ///
 /// [SPEC]: https://www.w3.org/TR/did-core/#dfn-did-resolution
@@ -24,6 +22,23 @@ pub struct InputMetadata {
   /// [More Info](https://www.w3.org/TR/did-spec-registries/#accept)
   #[serde(skip_serializing_if = "Option::is_none")]
   pub accept: Option<String>,
+  /// Requests that caching is disabled and a fresh DID document is retrieved.
+  ///
+  /// [More Info](https://www.w3.org/TR/did-spec-registries/#no-cache)
+  #[serde(rename = "no-cache", skip_serializing_if = "Option::is_none")]
+  pub no_cache: Option<bool>,
+  /// The version of the DID document to resolve, identified by a method-specific
+  /// version id.
+  ///
+  /// [More Info](https://www.w3.org/TR/did-spec-registries/#versionId-param)
+  #[serde(rename = "version-id", skip_serializing_if = "Option::is_none")]
+  pub version_id: Option<String>,
+  /// The version of the DID document to resolve, identified by the time it was
+  /// valid at.
+  ///
+  /// [More Info](https://www.w3.org/TR/did-spec-registries/#versionTime-param)
+  #[serde(rename = "version-time", skip_serializing_if = "Option::is_none")]
+  pub version_time: Option<Timestamp>,
   /// Additional input metadata properties.
   #[serde(flatten)]
   pub properties: Object,
@@ -34,6 +49,9 @@ impl InputMetadata {
   pub fn new() -> Self {
     Self {
       accept: None,
+      no_cache: None,
+      version_id: None,
+      version_time: None,
       properties: Object::new(),
     }
   }
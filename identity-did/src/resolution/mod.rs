@@ -12,6 +12,7 @@ mod impls;
 mod input_metadata;
 mod resolution;
 mod resolution_metadata;
+mod resolver;
 mod resource;
 mod traits;
 
@@ -25,6 +26,7 @@ pub use self::input_metadata::MIME_DID;
 pub use self::input_metadata::MIME_DID_LD;
 pub use self::resolution::Resolution;
 pub use self::resolution_metadata::ResolutionMetadata;
+pub use self::resolver::Resolver;
 pub use self::resource::PrimaryResource;
 pub use self::resource::Resource;
 pub use self::resource::SecondaryResource;
@@ -29,6 +29,11 @@ pub struct ResolutionMetadata {
   /// The parsed DID that was used for resolution.
   #[serde(skip_serializing_if = "Option::is_none")]
   pub resolved: Option<DID>,
+  /// Whether the DID document was served from a cache.
+  ///
+  /// Note: This is only set when resolving with a caching [`Resolver`][crate::resolution::Resolver].
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub cached: Option<bool>,
   /// Additional resolution metadata properties.
   #[serde(flatten)]
   pub properties: Object,
@@ -42,6 +47,7 @@ impl ResolutionMetadata {
       content_type: None,
       duration: Duration::from_secs(0),
       resolved: None,
+      cached: None,
       properties: Object::new(),
     }
   }
@@ -0,0 +1,373 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use async_trait::async_trait;
+use core::cell::Cell;
+use core::fmt::Debug;
+use core::fmt::Formatter;
+use core::time::Duration;
+use identity_core::common::Timestamp;
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+
+use crate::did::DID;
+use crate::error::Result;
+use crate::resolution::resolve;
+use crate::resolution::InputMetadata;
+use crate::resolution::MetaDocument;
+use crate::resolution::Resolution;
+use crate::resolution::ResolverMethod;
+
+type Method = Box<dyn ResolverMethod + Send + Sync>;
+
+/// The default maximum number of cached documents.
+const DEFAULT_CAPACITY: usize = 1024;
+
+/// A DID Resolver supporting multiple DID methods.
+///
+/// Resolution is dispatched to the [`ResolverMethod`] registered for the
+/// method of the input DID. Resolved documents are optionally cached for a
+/// fixed duration; the cache is bypassed if the `no-cache` input option or a
+/// document version is set.
+///
+/// Expired documents are evicted when a new document is cached, and the
+/// oldest document is evicted once the cache is full.
+pub struct Resolver {
+  methods: BTreeMap<String, Method>,
+  ttl: Option<Duration>,
+  capacity: usize,
+  cache: Mutex<BTreeMap<String, (Timestamp, MetaDocument)>>,
+}
+
+impl Resolver {
+  /// Creates a new [`Resolver`] without any supported DID methods.
+  pub fn new() -> Self {
+    Self {
+      methods: BTreeMap::new(),
+      ttl: None,
+      capacity: DEFAULT_CAPACITY,
+      cache: Mutex::new(BTreeMap::new()),
+    }
+  }
+
+  /// Registers the [`ResolverMethod`] used to resolve DIDs of the given `method`.
+  pub fn method<R>(mut self, name: impl Into<String>, method: R) -> Self
+  where
+    R: ResolverMethod + Send + Sync + 'static,
+  {
+    self.methods.insert(name.into(), Box::new(method));
+    self
+  }
+
+  /// Enables caching of resolved documents for the given duration.
+  pub fn cache(mut self, ttl: Duration) -> Self {
+    self.ttl = Some(ttl);
+    self
+  }
+
+  /// Sets the maximum number of cached documents.
+  ///
+  /// Defaults to 1024.
+  pub fn cache_capacity(mut self, capacity: usize) -> Self {
+    self.capacity = capacity;
+    self
+  }
+
+  /// Returns `true` if a [`ResolverMethod`] is registered for `method`.
+  pub fn supports(&self, method: &str) -> bool {
+    self.methods.contains_key(method)
+  }
+
+  /// Removes all cached documents.
+  pub fn clear_cache(&self) {
+    if let Ok(mut cache) = self.cache.lock() {
+      cache.clear();
+    }
+  }
+
+  /// Resolves a DID into a DID Document using the [`ResolverMethod`]
+  /// registered for the method of the DID.
+  ///
+  /// If caching is enabled, the resolution metadata reports whether the
+  /// document was served from the cache.
+  pub async fn resolve(&self, did: &str, input: InputMetadata) -> Result<Resolution> {
+    let cached: Cell<Option<bool>> = Cell::new(None);
+    let no_cache: bool = input.no_cache.unwrap_or(false);
+
+    let mut resolution: Resolution = resolve(
+      did,
+      input,
+      Dispatch {
+        resolver: self,
+        cached: &cached,
+        no_cache,
+      },
+    )
+    .await?;
+
+    resolution.metadata.cached = cached.get();
+
+    Ok(resolution)
+  }
+
+  fn lookup(&self, did: &DID) -> Option<&Method> {
+    self.methods.get(did.method())
+  }
+
+  fn load(&self, key: &str, ttl: Duration) -> Option<MetaDocument> {
+    let mut cache = self.cache.lock().ok()?;
+    let now: Timestamp = Timestamp::now();
+
+    match cache.get(key) {
+      Some((created, document)) if !__expired(created, &now, ttl) => Some(document.clone()),
+      Some(_) => {
+        cache.remove(key);
+        None
+      }
+      None => None,
+    }
+  }
+
+  fn store(&self, key: String, document: MetaDocument, ttl: Duration) {
+    if self.capacity == 0 {
+      return;
+    }
+
+    if let Ok(mut cache) = self.cache.lock() {
+      let now: Timestamp = Timestamp::now();
+
+      let expired: Vec<String> = cache
+        .iter()
+        .filter(|(_, (created, _))| __expired(created, &now, ttl))
+        .map(|(key, _)| key.clone())
+        .collect();
+
+      for expired in expired.iter() {
+        cache.remove(expired);
+      }
+
+      if !cache.contains_key(&key) && cache.len() >= self.capacity {
+        let oldest: Option<String> = cache
+          .iter()
+          .min_by_key(|(_, (created, _))| *created)
+          .map(|(key, _)| key.clone());
+
+        if let Some(oldest) = oldest {
+          cache.remove(&oldest);
+        }
+      }
+
+      cache.insert(key, (now, document));
+    }
+  }
+}
+
+impl Default for Resolver {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl Debug for Resolver {
+  fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+    f.debug_struct("Resolver")
+      .field("methods", &self.methods.keys().collect::<Vec<_>>())
+      .field("ttl", &self.ttl)
+      .field("capacity", &self.capacity)
+      .finish()
+  }
+}
+
+// Returns `true` if a document cached at `created` is older than `ttl`.
+fn __expired(created: &Timestamp, now: &Timestamp, ttl: Duration) -> bool {
+  let elapsed: i64 = now.to_unix() - created.to_unix();
+
+  elapsed < 0 || elapsed as u64 >= ttl.as_secs()
+}
+
+// A `ResolverMethod` dispatching to the methods of a `Resolver`.
+struct Dispatch<'a> {
+  resolver: &'a Resolver,
+  cached: &'a Cell<Option<bool>>,
+  no_cache: bool,
+}
+
+#[async_trait(?Send)]
+impl ResolverMethod for Dispatch<'_> {
+  fn is_supported(&self, did: &DID) -> bool {
+    self
+      .resolver
+      .lookup(did)
+      .map(|method| method.is_supported(did))
+      .unwrap_or(false)
+  }
+
+  async fn read(&self, did: &DID, input: InputMetadata) -> Result<Option<MetaDocument>> {
+    let method: &Method = match self.resolver.lookup(did) {
+      Some(method) => method,
+      None => return Ok(None),
+    };
+
+    // Versioned documents are not cached.
+    let versioned: bool = input.version_id.is_some() || input.version_time.is_some();
+
+    let ttl: Duration = match self.resolver.ttl {
+      Some(ttl) if !versioned => ttl,
+      _ => return method.read(did, input).await,
+    };
+
+    let key: String = did.to_string();
+
+    if !self.no_cache {
+      if let Some(document) = self.resolver.load(&key, ttl) {
+        self.cached.set(Some(true));
+        return Ok(Some(document));
+      }
+    }
+
+    self.cached.set(Some(false));
+
+    let document: Option<MetaDocument> = method.read(did, input).await?;
+
+    if let Some(document) = document.as_ref() {
+      self.resolver.store(key, document.clone(), ttl);
+    }
+
+    Ok(document)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use async_trait::async_trait;
+  use core::time::Duration;
+  use futures::executor::block_on;
+  use std::sync::atomic::AtomicUsize;
+  use std::sync::atomic::Ordering;
+  use std::sync::Arc;
+
+  use crate::did::DID;
+  use crate::document::Document;
+  use crate::document::DocumentBuilder;
+  use crate::error::Result;
+  use crate::resolution::DocumentMetadata;
+  use crate::resolution::ErrorKind;
+  use crate::resolution::InputMetadata;
+  use crate::resolution::MetaDocument;
+  use crate::resolution::Resolution;
+  use crate::resolution::Resolver;
+  use crate::resolution::ResolverMethod;
+
+  #[derive(Default)]
+  struct Counter(Arc<AtomicUsize>);
+
+  #[async_trait(?Send)]
+  impl ResolverMethod for Counter {
+    fn is_supported(&self, did: &DID) -> bool {
+      did.method() == "test"
+    }
+
+    async fn read(&self, did: &DID, _input: InputMetadata) -> Result<Option<MetaDocument>> {
+      self.0.fetch_add(1, Ordering::SeqCst);
+
+      let document: Document = DocumentBuilder::new(Default::default()).id(did.clone()).build()?;
+
+      Ok(Some(MetaDocument {
+        data: document,
+        meta: DocumentMetadata::new(),
+      }))
+    }
+  }
+
+  #[test]
+  fn test_dispatch() {
+    let resolver: Resolver = Resolver::new().method("test", Counter::default());
+
+    block_on(async {
+      let resolution: Resolution = resolver.resolve("did:test:1234", InputMetadata::new()).await.unwrap();
+
+      assert!(resolution.document.is_some());
+      assert_eq!(resolution.metadata.cached, None);
+
+      let resolution: Resolution = resolver.resolve("did:other:1234", InputMetadata::new()).await.unwrap();
+
+      assert_eq!(resolution.metadata.error, Some(ErrorKind::NotSupported));
+    });
+  }
+
+  #[test]
+  fn test_cache() {
+    let reads: Arc<AtomicUsize> = Arc::new(AtomicUsize::new(0));
+
+    let resolver: Resolver = Resolver::new()
+      .method("test", Counter(Arc::clone(&reads)))
+      .cache(Duration::from_secs(60));
+
+    block_on(async {
+      let resolution: Resolution = resolver.resolve("did:test:1234", InputMetadata::new()).await.unwrap();
+      assert_eq!(resolution.metadata.cached, Some(false));
+
+      let resolution: Resolution = resolver.resolve("did:test:1234", InputMetadata::new()).await.unwrap();
+      assert_eq!(resolution.metadata.cached, Some(true));
+      assert_eq!(reads.load(Ordering::SeqCst), 1);
+
+      // The cache is bypassed with `no-cache`
+      let mut input: InputMetadata = InputMetadata::new();
+      input.no_cache = Some(true);
+
+      let resolution: Resolution = resolver.resolve("did:test:1234", input).await.unwrap();
+      assert_eq!(resolution.metadata.cached, Some(false));
+      assert_eq!(reads.load(Ordering::SeqCst), 2);
+
+      resolver.clear_cache();
+
+      let resolution: Resolution = resolver.resolve("did:test:1234", InputMetadata::new()).await.unwrap();
+      assert_eq!(resolution.metadata.cached, Some(false));
+      assert_eq!(reads.load(Ordering::SeqCst), 3);
+    });
+  }
+
+  #[test]
+  fn test_cache_expired() {
+    let reads: Arc<AtomicUsize> = Arc::new(AtomicUsize::new(0));
+
+    let resolver: Resolver = Resolver::new()
+      .method("test", Counter(Arc::clone(&reads)))
+      .cache(Duration::from_secs(0));
+
+    block_on(async {
+      resolver.resolve("did:test:1234", InputMetadata::new()).await.unwrap();
+
+      let resolution: Resolution = resolver.resolve("did:test:1234", InputMetadata::new()).await.unwrap();
+      assert_eq!(resolution.metadata.cached, Some(false));
+      assert_eq!(reads.load(Ordering::SeqCst), 2);
+
+      // Expired documents are evicted
+      resolver.resolve("did:test:5678", InputMetadata::new()).await.unwrap();
+      assert_eq!(resolver.cache.lock().unwrap().len(), 1);
+    });
+  }
+
+  #[test]
+  fn test_cache_capacity() {
+    let reads: Arc<AtomicUsize> = Arc::new(AtomicUsize::new(0));
+
+    let resolver: Resolver = Resolver::new()
+      .method("test", Counter(Arc::clone(&reads)))
+      .cache(Duration::from_secs(60))
+      .cache_capacity(2);
+
+    block_on(async {
+      resolver.resolve("did:test:1", InputMetadata::new()).await.unwrap();
+      resolver.resolve("did:test:2", InputMetadata::new()).await.unwrap();
+      resolver.resolve("did:test:3", InputMetadata::new()).await.unwrap();
+
+      assert_eq!(resolver.cache.lock().unwrap().len(), 2);
+      assert_eq!(reads.load(Ordering::SeqCst), 3);
+
+      let resolution: Resolution = resolver.resolve("did:test:3", InputMetadata::new()).await.unwrap();
+      assert_eq!(resolution.metadata.cached, Some(true));
+      assert_eq!(reads.load(Ordering::SeqCst), 3);
+    });
+  }
+}
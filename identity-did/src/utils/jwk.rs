@@ -0,0 +1,168 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::crypto::KeyType;
+use identity_core::utils::decode_ec_public;
+use identity_core::utils::encode_ec_public;
+use libjose::jwk::EcCurve;
+use libjose::jwk::EcxCurve;
+use libjose::jwk::EdCurve;
+use libjose::jwk::Jwk;
+use libjose::jwk::JwkParams;
+use libjose::jwk::JwkParamsEc;
+use libjose::jwk::JwkParamsOkp;
+use libjose::utils::decode_b64;
+use libjose::utils::encode_b64;
+
+use crate::error::Error;
+use crate::error::Result;
+
+/// The length of an encoded curve point coordinate or OKP public key.
+const COORDINATE_LENGTH: usize = 32;
+
+/// Creates a public [`Jwk`] from a [key type][`KeyType`] and public key.
+///
+/// Elliptic curve public keys may be encoded as SEC1 points or as the
+/// concatenated `x` and `y` coordinates of the curve point.
+///
+/// # Errors
+///
+/// Fails if the key type has no JWK representation or `public` has an
+/// invalid length.
+pub fn encode_jwk(type_: KeyType, public: &[u8]) -> Result<Jwk> {
+  let mut jwk: Jwk = match type_ {
+    KeyType::Ed25519 => okp_params(EdCurve::Ed25519.name(), public)?,
+    KeyType::X25519 => okp_params(EcxCurve::X25519.name(), public)?,
+    KeyType::Secp256k1 => ec_params(EcCurve::Secp256K1, type_, public)?,
+    KeyType::P256 => ec_params(EcCurve::P256, type_, public)?,
+    KeyType::Bls12381G2 => return Err(Error::InvalidKeyDataJwk),
+  };
+
+  match type_ {
+    KeyType::Ed25519 => jwk.set_alg("EdDSA"),
+    KeyType::Secp256k1 => jwk.set_alg("ES256K"),
+    KeyType::P256 => jwk.set_alg("ES256"),
+    KeyType::X25519 | KeyType::Bls12381G2 => {}
+  }
+
+  Ok(jwk)
+}
+
+/// Returns the [key type][`KeyType`] and public key of the given [`Jwk`].
+///
+/// Elliptic curve public keys are returned as compressed SEC1 points.
+///
+/// # Errors
+///
+/// Fails if the JWK does not contain a supported public key.
+pub fn decode_jwk(jwk: &Jwk) -> Result<(KeyType, Vec<u8>)> {
+  match jwk.params() {
+    JwkParams::Okp(JwkParamsOkp { crv, x, .. }) => {
+      let type_: KeyType = if crv == EdCurve::Ed25519.name() {
+        KeyType::Ed25519
+      } else if crv == EcxCurve::X25519.name() {
+        KeyType::X25519
+      } else {
+        return Err(Error::InvalidKeyDataJwk);
+      };
+
+      Ok((type_, decode_coordinate(x)?))
+    }
+    JwkParams::Ec(params) => {
+      let type_: KeyType = match params.try_ec_curve() {
+        Ok(EcCurve::Secp256K1) => KeyType::Secp256k1,
+        Ok(EcCurve::P256) => KeyType::P256,
+        Ok(_) | Err(_) => return Err(Error::InvalidKeyDataJwk),
+      };
+
+      let x: Vec<u8> = decode_coordinate(&params.x)?;
+      let y: Vec<u8> = decode_coordinate(&params.y)?;
+
+      encode_ec_public(type_, &[x, y].concat())
+        .map(|public| (type_, public))
+        .map_err(|_| Error::InvalidKeyDataJwk)
+    }
+    JwkParams::Rsa(_) | JwkParams::Oct(_) => Err(Error::InvalidKeyDataJwk),
+  }
+}
+
+fn okp_params(crv: &str, public: &[u8]) -> Result<Jwk> {
+  if public.len() != COORDINATE_LENGTH {
+    return Err(Error::InvalidKeyDataJwk);
+  }
+
+  Ok(Jwk::from_params(JwkParamsOkp {
+    crv: crv.to_string(),
+    x: encode_b64(public),
+    d: None,
+  }))
+}
+
+fn ec_params(curve: EcCurve, type_: KeyType, public: &[u8]) -> Result<Jwk> {
+  let public: Vec<u8> = decode_ec_public(type_, public).map_err(|_| Error::InvalidKeyDataJwk)?;
+  let (x, y): (&[u8], &[u8]) = public.split_at(COORDINATE_LENGTH);
+
+  Ok(Jwk::from_params(JwkParamsEc {
+    crv: curve.name().to_string(),
+    x: encode_b64(x),
+    y: encode_b64(y),
+    d: None,
+  }))
+}
+
+fn decode_coordinate(data: &str) -> Result<Vec<u8>> {
+  let data: Vec<u8> = decode_b64(data).map_err(|_| Error::InvalidKeyDataJwk)?;
+
+  if data.len() != COORDINATE_LENGTH {
+    return Err(Error::InvalidKeyDataJwk);
+  }
+
+  Ok(data)
+}
+
+#[cfg(test)]
+mod tests {
+  use identity_core::convert::FromJson;
+  use identity_core::crypto::KeyPair;
+  use identity_core::crypto::KeyType;
+  use libjose::jwk::Jwk;
+
+  use crate::utils::decode_jwk;
+  use crate::utils::encode_jwk;
+
+  #[test]
+  fn test_roundtrip() {
+    for type_ in [KeyType::Ed25519, KeyType::X25519, KeyType::Secp256k1, KeyType::P256].iter() {
+      let keypair: KeyPair = KeyPair::new(*type_).unwrap();
+      let jwk: Jwk = encode_jwk(*type_, keypair.public().as_ref()).unwrap();
+      let (kty, public): (KeyType, Vec<u8>) = decode_jwk(&jwk).unwrap();
+
+      assert_eq!(kty, *type_);
+      assert_eq!(public, keypair.public().as_ref());
+    }
+  }
+
+  #[test]
+  fn test_decode_rfc8037() {
+    // https://tools.ietf.org/html/rfc8037#appendix-A.2
+    let jwk: Jwk =
+      Jwk::from_json(r#"{"kty":"OKP","crv":"Ed25519","x":"11qYAYKxCrfVS_7TyWQHOg7hcvPapiMlrwIaaPcHURo"}"#).unwrap();
+
+    let (kty, public): (KeyType, Vec<u8>) = decode_jwk(&jwk).unwrap();
+
+    assert_eq!(kty, KeyType::Ed25519);
+    assert_eq!(public[..4], [0xd7, 0x5a, 0x98, 0x01]);
+  }
+
+  #[test]
+  fn test_invalid() {
+    let jwk: Jwk = Jwk::from_json(
+      r#"{"kty":"oct","k":"AyM1SysPpbyDfgZld3umj1qzKObwVMkoqQ-EstJQLr_T-1qS0gZH75aKtMN3Yj0iPS4hcgUuTwjAzZr1Z9CAow"}"#,
+    )
+    .unwrap();
+
+    assert!(decode_jwk(&jwk).is_err());
+    assert!(encode_jwk(KeyType::Ed25519, &[0; 16]).is_err());
+    assert!(encode_jwk(KeyType::Bls12381G2, &[0; 96]).is_err());
+  }
+}
@@ -2,7 +2,10 @@
 // SPDX-License-Identifier: Apache-2.0
 
 mod did_key;
+mod jwk;
 mod ordered_set;
 
 pub use self::did_key::DIDKey;
+pub use self::jwk::decode_jwk;
+pub use self::jwk::encode_jwk;
 pub use self::ordered_set::OrderedSet;
@@ -3,6 +3,12 @@
 
 use core::any::Any;
 use identity_core::common::BitSet;
+use identity_core::common::Timestamp;
+use identity_core::crypto::bbs::BbsPlus;
+use identity_core::crypto::bbs::BbsProofVerifier;
+use identity_core::crypto::bbs::BbsProver;
+use identity_core::crypto::bbs::BbsSigner;
+use identity_core::crypto::bbs::BbsVerifier;
 use identity_core::crypto::merkle_key::Blake2b256;
 use identity_core::crypto::merkle_key::MerkleDigest;
 use identity_core::crypto::merkle_key::MerkleKey;
@@ -11,33 +17,122 @@ use identity_core::crypto::merkle_key::MerkleSigner;
 use identity_core::crypto::merkle_key::MerkleTag;
 use identity_core::crypto::merkle_key::MerkleVerifier;
 use identity_core::crypto::merkle_key::Sha256;
+use identity_core::crypto::merkle_key::Sha512Trunc256;
 use identity_core::crypto::merkle_key::SigningKey;
 use identity_core::crypto::merkle_key::VerificationKey;
 use identity_core::crypto::merkle_tree::Proof;
+use identity_core::crypto::EcdsaSecp256k1;
 use identity_core::crypto::Ed25519;
+use identity_core::crypto::HandleSigner;
 use identity_core::crypto::JcsEd25519;
+use identity_core::crypto::JsonWebSignature2020;
+use identity_core::crypto::KeyHandle;
+use identity_core::crypto::KeyType;
+use identity_core::crypto::ProofPurpose;
 use identity_core::crypto::PublicKey;
+use identity_core::crypto::Secp256k1;
 use identity_core::crypto::SecretKey;
 use identity_core::crypto::SetSignature;
 use identity_core::crypto::Sign;
 use identity_core::crypto::Signature;
+use identity_core::crypto::SignatureOptions;
+use identity_core::crypto::SignatureSuite;
 use identity_core::crypto::Signer;
 use identity_core::crypto::TrySignature;
 use identity_core::crypto::TrySignatureMut;
 use identity_core::crypto::Verifier;
 use identity_core::crypto::Verify;
+use identity_core::crypto::P256;
 use identity_core::error::Error as CoreError;
+use serde::de::DeserializeOwned;
 use serde::Serialize;
 
 use crate::document::Document;
 use crate::error::Error;
 use crate::error::Result;
+use crate::utils::decode_jwk;
 use crate::verifiable::Properties;
 use crate::verifiable::Revocation;
 use crate::verification::Method;
 use crate::verification::MethodQuery;
+use crate::verification::MethodScope;
 use crate::verification::MethodType;
 
+// =============================================================================
+// Method Suites
+// =============================================================================
+
+/// The signature suite used by verification methods that are not handled
+/// with a dedicated signing procedure (BBS+ and Merkle Key Collections).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum MethodSuite {
+  JcsEd25519,
+  EcdsaSecp256k1,
+  JwsEd25519,
+  JwsSecp256k1,
+  JwsP256,
+}
+
+impl MethodSuite {
+  fn new<U>(method: &Method<U>) -> Result<Self> {
+    match method.key_type() {
+      MethodType::Ed25519VerificationKey2018 => Ok(Self::JcsEd25519),
+      MethodType::EcdsaSecp256k1VerificationKey2019 => Ok(Self::EcdsaSecp256k1),
+      MethodType::JsonWebKey2020 => match jwk_key_type(method)? {
+        KeyType::Ed25519 => Ok(Self::JwsEd25519),
+        KeyType::Secp256k1 => Ok(Self::JwsSecp256k1),
+        KeyType::P256 => Ok(Self::JwsP256),
+        KeyType::X25519 | KeyType::Bls12381G2 => Err(Error::InvalidMethodType),
+      },
+      MethodType::Bls12381G2Key2020 | MethodType::MerkleKeyCollection2021 => {
+        // BBS+ signatures and Merkle Key Collections use dedicated signers
+        Err(Error::InvalidMethodType)
+      }
+      MethodType::X25519KeyAgreementKey2019 => {
+        // Key agreement keys can't be used to create signatures
+        Err(Error::InvalidMethodType)
+      }
+    }
+  }
+
+  fn key_type(self) -> KeyType {
+    match self {
+      Self::JcsEd25519 | Self::JwsEd25519 => KeyType::Ed25519,
+      Self::EcdsaSecp256k1 | Self::JwsSecp256k1 => KeyType::Secp256k1,
+      Self::JwsP256 => KeyType::P256,
+    }
+  }
+}
+
+/// Evaluates `$expr` with `$suite` bound to the signature suite type of the
+/// given [`MethodSuite`].
+macro_rules! with_suite {
+  ($method_suite:expr, $suite:ident => $expr:expr) => {
+    match $method_suite {
+      MethodSuite::JcsEd25519 => {
+        type $suite = JcsEd25519<Ed25519>;
+        $expr
+      }
+      MethodSuite::EcdsaSecp256k1 => {
+        type $suite = EcdsaSecp256k1<Secp256k1>;
+        $expr
+      }
+      MethodSuite::JwsEd25519 => {
+        type $suite = JsonWebSignature2020<Ed25519>;
+        $expr
+      }
+      MethodSuite::JwsSecp256k1 => {
+        type $suite = JsonWebSignature2020<Secp256k1>;
+        $expr
+      }
+      MethodSuite::JwsP256 => {
+        type $suite = JsonWebSignature2020<P256>;
+        $expr
+      }
+    }
+  };
+}
+
 // =============================================================================
 // Generic Crypto Extensions
 // =============================================================================
@@ -61,15 +156,27 @@ impl<T, U, V> Document<Properties<T>, U, V> {
     self.properties_mut().proof_mut()
   }
 
+  pub fn proofs(&self) -> Vec<&Signature> {
+    self.properties().proofs()
+  }
+
   pub fn set_proof(&mut self, signature: Signature) {
     self.properties_mut().set_proof(signature);
   }
+
+  pub fn push_proof(&mut self, signature: Signature) {
+    self.properties_mut().push_proof(signature);
+  }
 }
 
 impl<T, U, V> TrySignature for Document<Properties<T>, U, V> {
   fn signature(&self) -> Option<&Signature> {
     self.proof()
   }
+
+  fn signatures(&self) -> Vec<&Signature> {
+    self.proofs()
+  }
 }
 
 impl<T, U, V> TrySignatureMut for Document<Properties<T>, U, V> {
@@ -82,6 +189,10 @@ impl<T, U, V> SetSignature for Document<Properties<T>, U, V> {
   fn set_signature(&mut self, signature: Signature) {
     self.set_proof(signature)
   }
+
+  fn push_signature(&mut self, signature: Signature) {
+    self.push_proof(signature)
+  }
 }
 
 // =============================================================================
@@ -94,39 +205,80 @@ where
   U: Serialize,
   V: Serialize,
 {
+  /// Signs the document with the given `authentication` method.
+  ///
+  /// The signature has the proof purpose `authentication`.
   pub fn sign_this<'query, Q>(&mut self, query: Q, secret: &SecretKey) -> Result<()>
   where
     Q: Into<MethodQuery<'query>>,
   {
-    let method: &Method<U> = self.try_resolve(query)?;
+    let method: &Method<U> = resolve_method(self, query.into(), ProofPurpose::Authentication)?;
     let fragment: String = method.try_into_fragment()?;
+    let options: SignatureOptions = SignatureOptions::new().purpose(ProofPurpose::Authentication);
 
     match method.key_type() {
-      MethodType::Ed25519VerificationKey2018 => {
-        JcsEd25519::<Ed25519>::create_signature(self, &fragment, secret.as_ref())?;
+      MethodType::Bls12381G2Key2020 => {
+        BbsSigner::create_signature_with_options(self, &fragment, secret.as_ref(), options)?;
       }
       MethodType::MerkleKeyCollection2021 => {
         // Documents can't be signed with Merkle Key Collections
         return Err(Error::InvalidMethodType);
       }
+      _ => {
+        let suite: MethodSuite = MethodSuite::new(method)?;
+
+        with_suite!(suite, S => S::create_signature_with_options(self, &fragment, secret.as_ref(), options))?;
+      }
     }
 
     Ok(())
   }
 
+  /// Signs the document with the given `authentication` method and the key
+  /// referenced by the [`KeyHandle`].
+  ///
+  /// The signature has the proof purpose `authentication`.
+  pub async fn sign_this_with_handle<'query, Q, H>(&mut self, query: Q, handle: &H) -> Result<()>
+  where
+    Q: Into<MethodQuery<'query>>,
+    H: KeyHandle + ?Sized,
+  {
+    let method: &Method<U> = resolve_method(self, query.into(), ProofPurpose::Authentication)?;
+    let fragment: String = method.try_into_fragment()?;
+    let suite: MethodSuite = handle_suite(method, handle)?;
+    let options: SignatureOptions = SignatureOptions::new().purpose(ProofPurpose::Authentication);
+
+    create_with_handle(self, &fragment, handle, suite, options, SignMode::Replace).await
+  }
+
+  /// Verifies the signature of the document.
+  ///
+  /// The signature must have the proof purpose `authentication`; signatures
+  /// created before proof purposes were required have none and are treated
+  /// as `authentication`.
   pub fn verify_this(&self) -> Result<()> {
     let signature: &Signature = self.try_signature()?;
-    let method: &Method<U> = self.try_resolve(signature)?;
+
+    if !matches!(signature.options().purpose, None | Some(ProofPurpose::Authentication)) {
+      return Err(Error::InvalidProofPurpose);
+    }
+
+    let method: &Method<U> = resolve_method(self, signature.into(), ProofPurpose::Authentication)?;
     let public: PublicKey = method.key_data().try_decode()?.into();
 
     match method.key_type() {
-      MethodType::Ed25519VerificationKey2018 => {
-        JcsEd25519::<Ed25519>::verify_signature(self, public.as_ref())?;
+      MethodType::Bls12381G2Key2020 => {
+        BbsVerifier::verify_signature(self, public.as_ref())?;
       }
       MethodType::MerkleKeyCollection2021 => {
         // Documents can't be signed with Merkle Key Collections
         return Err(Error::InvalidMethodType);
       }
+      _ => {
+        let suite: MethodSuite = MethodSuite::new(method)?;
+
+        with_suite!(suite, S => S::verify_signature(self, public.as_ref()))?;
+      }
     }
 
     Ok(())
@@ -140,22 +292,67 @@ impl<T, U, V> Document<T, U, V> {
     DocumentSigner::new(self, secret)
   }
 
+  /// Creates a new [`DocumentHandleSigner`] that can be used to create digital
+  /// signatures from verification methods in this DID Document without
+  /// access to the secret key.
+  pub fn handle_signer<'base, H>(&'base self, handle: &'base H) -> DocumentHandleSigner<'base, '_, T, U, V, H>
+  where
+    H: KeyHandle + ?Sized,
+  {
+    DocumentHandleSigner::new(self, handle)
+  }
+
   /// Creates a new [`DocumentVerifier`] that can be used to verify signatures
   /// created with this DID Document.
   pub fn verifier(&self) -> DocumentVerifier<'_, T, U, V> {
     DocumentVerifier::new(self)
   }
+
+  /// Derives a selective disclosure proof from the signed `that`, revealing
+  /// only the properties identified by the JSON Pointers in `reveal`.
+  ///
+  /// The verification method of the signature must be resolvable from this
+  /// DID Document.
+  ///
+  /// # Errors
+  ///
+  /// Fails if the signature was not created with a method supporting
+  /// selective disclosure or the proof can not be derived.
+  pub fn derive_proof<X, Y>(&self, that: &X, reveal: &[&str], nonce: &[u8]) -> Result<Y>
+  where
+    X: Serialize + TrySignature,
+    Y: DeserializeOwned + SetSignature,
+  {
+    let signature: &Signature = that.try_signature()?;
+    let purpose: ProofPurpose = signature.options().purpose.ok_or(Error::InvalidProofPurpose)?;
+    let method: &Method<U> = resolve_method(self, signature.into(), purpose)?;
+    let data: Vec<u8> = method.key_data().try_decode()?;
+
+    match method.key_type() {
+      MethodType::Bls12381G2Key2020 => BbsProver::derive(that, &data, reveal, nonce).map_err(Into::into),
+      _ => Err(Error::InvalidMethodType),
+    }
+  }
 }
 
 // =============================================================================
 // Document Signer - Simplifying Digital Signature Creation Since 2021
 // =============================================================================
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum SignMode {
+  Replace,
+  Append,
+  Chain,
+}
+
 pub struct DocumentSigner<'base, 'query, 'proof, T, U, V> {
   document: &'base Document<T, U, V>,
   secret: &'base SecretKey,
   method: Option<MethodQuery<'query>>,
   merkle_key: Option<(&'proof PublicKey, &'proof dyn Any)>,
+  options: SignatureOptions,
+  mode: SignMode,
 }
 
 impl<'base, T, U, V> DocumentSigner<'base, '_, '_, T, U, V> {
@@ -165,8 +362,43 @@ impl<'base, T, U, V> DocumentSigner<'base, '_, '_, T, U, V> {
       secret,
       method: None,
       merkle_key: None,
+      options: SignatureOptions::new(),
+      mode: SignMode::Replace,
     }
   }
+
+  /// Sets the proof options of the created signature.
+  ///
+  /// The options replace a proof purpose set with [`purpose`][Self::purpose]
+  /// and must include a proof purpose - the verification method must be part
+  /// of the matching verification relationship.
+  pub fn options(mut self, value: SignatureOptions) -> Self {
+    self.options = value;
+    self
+  }
+
+  /// Sets the proof purpose of the created signature.
+  ///
+  /// The verification method must be part of the matching verification
+  /// relationship.
+  pub fn purpose(mut self, value: ProofPurpose) -> Self {
+    self.options.purpose = Some(value);
+    self
+  }
+
+  /// Adds the signature to the proof set of the signed data instead of
+  /// replacing existing signatures.
+  pub fn append(mut self) -> Self {
+    self.mode = SignMode::Append;
+    self
+  }
+
+  /// Adds the signature to the proof chain of the signed data - the signature
+  /// covers all existing signatures.
+  pub fn chain(mut self) -> Self {
+    self.mode = SignMode::Chain;
+    self
+  }
 }
 
 impl<'base, 'query, T, U, V> DocumentSigner<'base, 'query, '_, T, U, V> {
@@ -194,19 +426,22 @@ impl<T, U, V> DocumentSigner<'_, '_, '_, T, U, V> {
   ///
   /// # Errors
   ///
-  /// Fails if an unsupported verification method is used, document
-  /// serialization fails, or the signature operation fails.
+  /// Fails if no proof purpose is set, the verification method is not part
+  /// of the matching verification relationship, an unsupported verification
+  /// method is used, document serialization fails, or the signature operation
+  /// fails.
   pub fn sign<X>(&self, that: &mut X) -> Result<()>
   where
     X: Serialize + SetSignature,
   {
     let query: MethodQuery<'_> = self.method.ok_or(Error::QueryMethodNotFound)?;
-    let method: &Method<U> = self.document.try_resolve(query)?;
+    let purpose: ProofPurpose = self.options.purpose.ok_or(Error::InvalidProofPurpose)?;
+    let method: &Method<U> = resolve_method(self.document, query, purpose)?;
     let fragment: String = method.try_into_fragment()?;
 
     match method.key_type() {
-      MethodType::Ed25519VerificationKey2018 => {
-        JcsEd25519::<Ed25519>::create_signature(that, &fragment, self.secret.as_ref())?;
+      MethodType::Bls12381G2Key2020 => {
+        self.create::<X, BbsSigner, [u8]>(that, &fragment, self.secret.as_ref())?;
       }
       MethodType::MerkleKeyCollection2021 => {
         let data: Vec<u8> = method.key_data().try_decode()?;
@@ -218,11 +453,28 @@ impl<T, U, V> DocumentSigner<'_, '_, '_, T, U, V> {
           (MerkleTag::ED25519, MerkleTag::BLAKE2B_256) => {
             self.merkle_key_sign::<X, Blake2b256, Ed25519>(that, fragment)?;
           }
+          (MerkleTag::ED25519, MerkleTag::SHA512_256) => {
+            self.merkle_key_sign::<X, Sha512Trunc256, Ed25519>(that, fragment)?;
+          }
+          (MerkleTag::SECP256K1, MerkleTag::SHA256) => {
+            self.merkle_key_sign::<X, Sha256, Secp256k1>(that, fragment)?;
+          }
+          (MerkleTag::SECP256K1, MerkleTag::BLAKE2B_256) => {
+            self.merkle_key_sign::<X, Blake2b256, Secp256k1>(that, fragment)?;
+          }
+          (MerkleTag::SECP256K1, MerkleTag::SHA512_256) => {
+            self.merkle_key_sign::<X, Sha512Trunc256, Secp256k1>(that, fragment)?;
+          }
           (_, _) => {
             return Err(Error::InvalidMethodType);
           }
         }
       }
+      _ => {
+        let suite: MethodSuite = MethodSuite::new(method)?;
+
+        with_suite!(suite, S => self.create::<X, S, [u8]>(that, &fragment, self.secret.as_ref()))?;
+      }
     }
 
     Ok(())
@@ -243,13 +495,122 @@ impl<T, U, V> DocumentSigner<'_, '_, '_, T, U, V> {
 
         let skey: SigningKey<'_, D> = SigningKey::from_borrowed(public, self.secret, proof);
 
-        MerkleSigner::<D, S>::create_signature(that, &fragment, &skey)?;
+        self.create::<X, MerkleSigner<D, S>, SigningKey<'_, D>>(that, &fragment, &skey)?;
 
         Ok(())
       }
       None => Err(Error::CoreError(CoreError::InvalidKeyFormat)),
     }
   }
+
+  fn create<X, S, K>(&self, that: &mut X, fragment: &str, secret: &K) -> Result<()>
+  where
+    X: Serialize + SetSignature,
+    S: Signer<K>,
+    K: ?Sized,
+  {
+    let options: SignatureOptions = self.options.clone();
+
+    match self.mode {
+      SignMode::Replace => S::create_signature_with_options(that, fragment, secret, options)?,
+      SignMode::Append => S::append_signature(that, fragment, secret, options)?,
+      SignMode::Chain => S::chain_signature(that, fragment, secret, options)?,
+    }
+
+    Ok(())
+  }
+}
+
+// =============================================================================
+// Document Handle Signer - Signing Without Secrets
+// =============================================================================
+
+pub struct DocumentHandleSigner<'base, 'query, T, U, V, H: ?Sized> {
+  document: &'base Document<T, U, V>,
+  handle: &'base H,
+  method: Option<MethodQuery<'query>>,
+  options: SignatureOptions,
+  mode: SignMode,
+}
+
+impl<'base, 'query, T, U, V, H> DocumentHandleSigner<'base, 'query, T, U, V, H>
+where
+  H: KeyHandle + ?Sized,
+{
+  pub fn new(document: &'base Document<T, U, V>, handle: &'base H) -> Self {
+    Self {
+      document,
+      handle,
+      method: None,
+      options: SignatureOptions::new(),
+      mode: SignMode::Replace,
+    }
+  }
+
+  pub fn method<Q>(mut self, value: Q) -> Self
+  where
+    Q: Into<MethodQuery<'query>>,
+  {
+    self.method = Some(value.into());
+    self
+  }
+
+  /// Sets the proof options of the created signature.
+  ///
+  /// The options replace a proof purpose set with [`purpose`][Self::purpose]
+  /// and must include a proof purpose - the verification method must be part
+  /// of the matching verification relationship.
+  pub fn options(mut self, value: SignatureOptions) -> Self {
+    self.options = value;
+    self
+  }
+
+  /// Sets the proof purpose of the created signature.
+  ///
+  /// The verification method must be part of the matching verification
+  /// relationship.
+  pub fn purpose(mut self, value: ProofPurpose) -> Self {
+    self.options.purpose = Some(value);
+    self
+  }
+
+  /// Adds the signature to the proof set of the signed data instead of
+  /// replacing existing signatures.
+  pub fn append(mut self) -> Self {
+    self.mode = SignMode::Append;
+    self
+  }
+
+  /// Adds the signature to the proof chain of the signed data - the signature
+  /// covers all existing signatures.
+  pub fn chain(mut self) -> Self {
+    self.mode = SignMode::Chain;
+    self
+  }
+
+  /// Signs the provided data with the configured verification method and the
+  /// key referenced by the [`KeyHandle`].
+  ///
+  /// # Errors
+  ///
+  /// Fails if no proof purpose is set, the verification method is not part
+  /// of the matching verification relationship, an unsupported verification
+  /// method is used, the key type of the handle does not match the
+  /// verification method, document serialization fails, or the signature
+  /// operation fails.
+  pub async fn sign<X>(&self, that: &mut X) -> Result<()>
+  where
+    X: Serialize + SetSignature,
+  {
+    let query: MethodQuery<'_> = self.method.ok_or(Error::QueryMethodNotFound)?;
+    let purpose: ProofPurpose = self.options.purpose.ok_or(Error::InvalidProofPurpose)?;
+    let method: &Method<U> = resolve_method(self.document, query, purpose)?;
+    let fragment: String = method.try_into_fragment()?;
+    let suite: MethodSuite = handle_suite(method, self.handle)?;
+    let options: SignatureOptions = self.options.clone();
+
+    create_with_handle(that, &fragment, self.handle, suite, options, self.mode).await
+  }
 }
 
 // =============================================================================
@@ -258,11 +619,59 @@ impl<T, U, V> DocumentSigner<'_, '_, '_, T, U, V> {
 
 pub struct DocumentVerifier<'base, T, U, V> {
   document: &'base Document<T, U, V>,
+  purpose: Option<ProofPurpose>,
+  default_purpose: Option<ProofPurpose>,
+  challenge: Option<String>,
+  domain: Option<String>,
+  nonce: Option<String>,
 }
 
 impl<'base, T, U, V> DocumentVerifier<'base, T, U, V> {
   pub fn new(document: &'base Document<T, U, V>) -> Self {
-    Self { document }
+    Self {
+      document,
+      purpose: None,
+      default_purpose: None,
+      challenge: None,
+      domain: None,
+      nonce: None,
+    }
+  }
+
+  /// Requires signatures to have the given proof purpose.
+  pub fn purpose(mut self, value: ProofPurpose) -> Self {
+    self.purpose = Some(value);
+    self
+  }
+
+  /// Treats signatures without a proof purpose as having the given purpose.
+  ///
+  /// Signatures created before proof purposes were required have none and
+  /// are rejected otherwise.
+  pub fn default_purpose(mut self, value: ProofPurpose) -> Self {
+    self.default_purpose = Some(value);
+    self
+  }
+
+  /// Requires signatures to include the given challenge.
+  pub fn challenge(mut self, value: impl Into<String>) -> Self {
+    self.challenge = Some(value.into());
+    self
+  }
+
+  /// Requires signatures to be restricted to the given domain.
+  pub fn domain(mut self, value: impl Into<String>) -> Self {
+    self.domain = Some(value.into());
+    self
+  }
+
+  /// Requires signatures to include the given nonce.
+  ///
+  /// Derived BBS+ proofs must instead have been created with the given nonce
+  /// and are rejected if no nonce is expected.
+  pub fn nonce(mut self, value: impl Into<String>) -> Self {
+    self.nonce = Some(value.into());
+    self
   }
 }
 
@@ -270,24 +679,113 @@ impl<T, U, V> DocumentVerifier<'_, T, U, V>
 where
   U: Revocation,
 {
-  /// Verifies the signature of the provided data.
+  /// Verifies every signature of the provided data.
+  ///
+  /// Every signature must have a proof purpose, unless a default purpose is
+  /// set, and the verification method must be part of the matching
+  /// verification relationship.
   ///
   /// # Errors
   ///
-  /// Fails if an unsupported verification method is used, document
-  /// serialization fails, or the verification operation fails.
+  /// Fails if the data has no signatures, an unsupported verification method
+  /// is used, the proof options do not match the expected values, a
+  /// signature is expired or created in the future, document serialization
+  /// fails, or any verification operation fails.
   pub fn verify<X>(&self, that: &X) -> Result<()>
+  where
+    X: Serialize + TrySignature,
+  {
+    if that.signatures().is_empty() {
+      return Err(Error::CoreError(CoreError::MissingSignature));
+    }
+
+    self.verify_each(that).into_iter().collect()
+  }
+
+  /// Verifies the signature at `index` of the proof set of the provided data.
+  ///
+  /// # Errors
+  ///
+  /// Fails if no signature exists at `index` or the signature is invalid.
+  pub fn verify_at<X>(&self, that: &X, index: usize) -> Result<()>
+  where
+    X: Serialize + TrySignature,
+  {
+    if !that.focus_signature(Some(index)) {
+      return Err(Error::CoreError(CoreError::MissingSignature));
+    }
+
+    let result: Result<()> = self.__verify(that);
+
+    that.focus_signature(None);
+
+    result
+  }
+
+  /// Verifies every signature of the provided data and returns the results
+  /// in proof set order.
+  pub fn verify_each<X>(&self, that: &X) -> Vec<Result<()>>
+  where
+    X: Serialize + TrySignature,
+  {
+    (0..that.signatures().len())
+      .map(|index| self.verify_at(that, index))
+      .collect()
+  }
+
+  /// Verifies the provided data, requiring at least one valid signature.
+  ///
+  /// # Errors
+  ///
+  /// Fails if the data has no signatures or no signature is valid; the error
+  /// of the last signature is returned.
+  pub fn verify_any<X>(&self, that: &X) -> Result<()>
+  where
+    X: Serialize + TrySignature,
+  {
+    let mut error: Error = Error::CoreError(CoreError::MissingSignature);
+
+    for index in 0..that.signatures().len() {
+      match self.verify_at(that, index) {
+        Ok(()) => return Ok(()),
+        Err(err) => error = err,
+      }
+    }
+
+    Err(error)
+  }
+
+  // Verifies the focused signature of the provided data.
+  fn __verify<X>(&self, that: &X) -> Result<()>
   where
     X: Serialize + TrySignature,
   {
     let signature: &Signature = that.try_signature()?;
-    let method: &Method<U> = self.document.try_resolve(signature)?;
+
+    self.check_options(signature)?;
+
+    let purpose: ProofPurpose = self.resolve_purpose(signature.options())?;
+    let method: &Method<U> = resolve_method(self.document, signature.into(), purpose)?;
     let data: Vec<u8> = method.key_data().try_decode()?;
 
     match method.key_type() {
-      MethodType::Ed25519VerificationKey2018 => {
-        JcsEd25519::<Ed25519>::verify_signature(that, &data)?;
-      }
+      MethodType::Bls12381G2Key2020 => match signature.type_() {
+        BbsPlus::TYPE_SIG => {
+          BbsVerifier::verify_signature(that, &data)?;
+        }
+        BbsPlus::TYPE_PROOF => {
+          let nonce: Vec<u8> = BbsProofVerifier::extract_nonce(signature.value())?;
+
+          if self.nonce.as_deref().map(str::as_bytes) != Some(&*nonce) {
+            return Err(Error::InvalidProofNonce);
+          }
+
+          BbsProofVerifier::verify_signature(that, &data)?;
+        }
+        _ => {
+          return Err(Error::CoreError(CoreError::InvalidProofValue("signature name")));
+        }
+      },
       MethodType::MerkleKeyCollection2021 => match MerkleKey::extract_tags(&data)? {
         (MerkleTag::ED25519, MerkleTag::SHA256) => {
           self.merkle_key_verify::<X, Sha256, Ed25519>(that, method, &data)?;
@@ -295,15 +793,79 @@ where
         (MerkleTag::ED25519, MerkleTag::BLAKE2B_256) => {
           self.merkle_key_verify::<X, Blake2b256, Ed25519>(that, method, &data)?;
         }
+        (MerkleTag::ED25519, MerkleTag::SHA512_256) => {
+          self.merkle_key_verify::<X, Sha512Trunc256, Ed25519>(that, method, &data)?;
+        }
+        (MerkleTag::SECP256K1, MerkleTag::SHA256) => {
+          self.merkle_key_verify::<X, Sha256, Secp256k1>(that, method, &data)?;
+        }
+        (MerkleTag::SECP256K1, MerkleTag::BLAKE2B_256) => {
+          self.merkle_key_verify::<X, Blake2b256, Secp256k1>(that, method, &data)?;
+        }
+        (MerkleTag::SECP256K1, MerkleTag::SHA512_256) => {
+          self.merkle_key_verify::<X, Sha512Trunc256, Secp256k1>(that, method, &data)?;
+        }
         (_, _) => {
           return Err(Error::InvalidMethodType);
         }
       },
+      _ => {
+        let suite: MethodSuite = MethodSuite::new(method)?;
+
+        with_suite!(suite, S => S::verify_signature(that, &data))?;
+      }
+    }
+
+    Ok(())
+  }
+
+  // Checks the proof options of a signature against the expected values.
+  //
+  // A challenge or domain in the proof options is only meaningful if it is
+  // checked, so it must be expected by the verifier.
+  fn check_options(&self, signature: &Signature) -> Result<()> {
+    let options: &SignatureOptions = signature.options();
+    let purpose: ProofPurpose = self.resolve_purpose(options)?;
+
+    if self.purpose.is_some() && Some(purpose) != self.purpose {
+      return Err(Error::InvalidProofPurpose);
+    }
+
+    if options.challenge != self.challenge {
+      return Err(Error::InvalidProofChallenge);
+    }
+
+    if options.domain != self.domain {
+      return Err(Error::InvalidProofDomain);
+    }
+
+    // The nonce of a derived BBS+ proof is checked against the proof value
+    if self.nonce.is_some() && options.nonce != self.nonce && signature.type_() != BbsPlus::TYPE_PROOF {
+      return Err(Error::InvalidProofNonce);
+    }
+
+    let now: Timestamp = Timestamp::now();
+
+    if matches!(options.created, Some(created) if created > now) {
+      return Err(Error::InvalidProofCreated);
+    }
+
+    if matches!(options.expires, Some(expires) if expires < now) {
+      return Err(Error::InvalidProofExpired);
     }
 
     Ok(())
   }
 
+  // Returns the proof purpose of a signature, falling back to the default
+  // purpose for signatures without one.
+  fn resolve_purpose(&self, options: &SignatureOptions) -> Result<ProofPurpose> {
+    options
+      .purpose
+      .or(self.default_purpose)
+      .ok_or(Error::InvalidProofPurpose)
+  }
+
   fn merkle_key_verify<X, D, S>(&self, that: &X, method: &Method<U>, data: &[u8]) -> Result<()>
   where
     X: Serialize + TrySignature,
@@ -322,3 +884,76 @@ where
     Ok(())
   }
 }
+
+// =============================================================================
+// =============================================================================
+
+// Resolves the verification method of a signature with the given proof
+// purpose - the method must be part of the matching verification relationship.
+fn resolve_method<'a, T, U, V>(
+  document: &'a Document<T, U, V>,
+  query: MethodQuery<'_>,
+  purpose: ProofPurpose,
+) -> Result<&'a Method<U>> {
+  match document.resolve_with_scope(query, MethodScope::from(purpose)) {
+    Some(method) => Ok(method),
+    None => document.try_resolve(query).and(Err(Error::InvalidProofPurpose)),
+  }
+}
+
+fn handle_suite<U, H>(method: &Method<U>, handle: &H) -> Result<MethodSuite>
+where
+  H: KeyHandle + ?Sized,
+{
+  let suite: MethodSuite = MethodSuite::new(method)?;
+
+  if suite.key_type() != handle.key_type() {
+    return Err(Error::CoreError(CoreError::InvalidKeyFormat));
+  }
+
+  Ok(suite)
+}
+
+async fn create_with_handle<X, H>(
+  that: &mut X,
+  fragment: &str,
+  handle: &H,
+  suite: MethodSuite,
+  options: SignatureOptions,
+  mode: SignMode,
+) -> Result<()>
+where
+  X: Serialize + SetSignature,
+  H: KeyHandle + ?Sized,
+{
+  with_suite!(suite, S => create_with_suite::<X, S, H>(that, fragment, handle, options, mode).await)
+}
+
+async fn create_with_suite<X, S, H>(
+  that: &mut X,
+  fragment: &str,
+  handle: &H,
+  options: SignatureOptions,
+  mode: SignMode,
+) -> Result<()>
+where
+  X: Serialize + SetSignature,
+  S: SignatureSuite,
+  H: KeyHandle + ?Sized,
+{
+  match mode {
+    SignMode::Replace => HandleSigner::<S>::create_signature(that, fragment, handle, options).await?,
+    SignMode::Append => HandleSigner::<S>::append_signature(that, fragment, handle, options).await?,
+    SignMode::Chain => HandleSigner::<S>::chain_signature(that, fragment, handle, options).await?,
+  }
+
+  Ok(())
+}
+
+fn jwk_key_type<U>(method: &Method<U>) -> Result<KeyType> {
+  method
+    .key_data()
+    .try_to_jwk()
+    .and_then(|jwk| decode_jwk(&jwk))
+    .map(|(type_, _)| type_)
+}
@@ -5,6 +5,7 @@ mod document;
 mod properties;
 mod traits;
 
+pub use self::document::DocumentHandleSigner;
 pub use self::document::DocumentSigner;
 pub use self::document::DocumentVerifier;
 pub use self::properties::Properties;
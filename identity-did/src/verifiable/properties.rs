@@ -6,18 +6,18 @@ use core::ops::DerefMut;
 use identity_core::common::Object;
 use identity_core::crypto::SetSignature;
 use identity_core::crypto::Signature;
+use identity_core::crypto::SignatureSet;
 use identity_core::crypto::TrySignature;
 use identity_core::crypto::TrySignatureMut;
 
 /// A generic container for a set of properties (`T`) and a
-/// [`digital signature`][Signature].
+/// [`set of digital signatures`][SignatureSet].
 #[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Deserialize, Serialize)]
 pub struct Properties<T = Object> {
   #[serde(flatten)]
   pub(crate) properties: T,
-  // TODO: Support multiple signatures (?)
-  #[serde(skip_serializing_if = "Option::is_none")]
-  pub(crate) proof: Option<Signature>,
+  #[serde(default, skip_serializing_if = "SignatureSet::is_empty")]
+  pub(crate) proof: SignatureSet,
 }
 
 impl<T> Properties<T> {
@@ -25,31 +25,41 @@ impl<T> Properties<T> {
   pub const fn new(properties: T) -> Self {
     Self {
       properties,
-      proof: None,
+      proof: SignatureSet::new(),
     }
   }
 
   /// Creates a new `Properties` object with the given `proof`.
-  pub const fn with_proof(properties: T, proof: Signature) -> Self {
+  pub fn with_proof(properties: T, proof: Signature) -> Self {
     Self {
       properties,
-      proof: Some(proof),
+      proof: proof.into(),
     }
   }
 
   /// Returns a reference to the [`proof`][`Signature`].
   pub fn proof(&self) -> Option<&Signature> {
-    self.proof.as_ref()
+    self.proof.current()
   }
 
   /// Returns a mutable reference to the [`proof`][`Signature`].
   pub fn proof_mut(&mut self) -> Option<&mut Signature> {
-    self.proof.as_mut()
+    self.proof.current_mut()
   }
 
-  /// Sets the value of the [`proof`][`Signature`].
+  /// Returns all [`proofs`][`Signature`] of the proof set.
+  pub fn proofs(&self) -> Vec<&Signature> {
+    self.proof.signatures()
+  }
+
+  /// Sets the value of the [`proof`][`Signature`], replacing existing proofs.
   pub fn set_proof(&mut self, signature: Signature) {
-    self.proof = Some(signature);
+    self.proof.set(signature);
+  }
+
+  /// Adds a [`proof`][`Signature`] to the end of the proof set.
+  pub fn push_proof(&mut self, signature: Signature) {
+    self.proof.push(signature);
   }
 }
 
@@ -71,6 +81,10 @@ impl<T> TrySignature for Properties<T> {
   fn signature(&self) -> Option<&Signature> {
     self.proof()
   }
+
+  fn signatures(&self) -> Vec<&Signature> {
+    self.proofs()
+  }
 }
 
 impl<T> TrySignatureMut for Properties<T> {
@@ -83,4 +97,8 @@ impl<T> SetSignature for Properties<T> {
   fn set_signature(&mut self, signature: Signature) {
     self.set_proof(signature)
   }
+
+  fn push_signature(&mut self, signature: Signature) {
+    self.push_proof(signature)
+  }
 }
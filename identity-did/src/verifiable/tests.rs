@@ -1,22 +1,35 @@
 // Copyright 2020-2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use identity_core::common::Object;
+use identity_core::common::Timestamp;
+use identity_core::common::Value;
+use identity_core::convert::FromJson;
 use identity_core::crypto::merkle_key::MerkleKey;
 use identity_core::crypto::merkle_key::Sha256;
 use identity_core::crypto::merkle_tree::Hash;
 use identity_core::crypto::merkle_tree::Proof;
 use identity_core::crypto::Ed25519;
+use identity_core::crypto::JcsEd25519;
 use identity_core::crypto::KeyCollection;
 use identity_core::crypto::KeyPair;
+use identity_core::crypto::KeyType;
+use identity_core::crypto::ProofPurpose;
 use identity_core::crypto::PublicKey;
 use identity_core::crypto::SecretKey;
 use identity_core::crypto::SetSignature;
 use identity_core::crypto::Signature;
+use identity_core::crypto::SignatureOptions;
+use identity_core::crypto::SignatureSet;
+use identity_core::crypto::SignatureValue;
+use identity_core::crypto::Signer as _;
 use identity_core::crypto::TrySignature;
 use identity_core::crypto::TrySignatureMut;
+use identity_core::json;
 
 use crate::did::DID;
 use crate::document::Document;
+use crate::utils::encode_jwk;
 use crate::verifiable::Properties;
 use crate::verification::Method;
 use crate::verification::MethodData;
@@ -53,6 +66,103 @@ impl SetSignature for That {
   }
 }
 
+#[derive(Debug, Serialize)]
+struct Claims {
+  #[serde(flatten)]
+  data: Object,
+  #[serde(skip_serializing_if = "SignatureSet::is_empty")]
+  proof: SignatureSet,
+}
+
+impl Claims {
+  // Linked Data signature suites can only sign JSON-LD documents
+  fn new() -> Self {
+    Self::with_context(json!("https://www.w3.org/2018/credentials/v1"))
+  }
+
+  fn with_context(context: Value) -> Self {
+    let data: Value = json!({
+      "@context": context,
+      "type": ["VerifiableCredential"],
+      "issuer": "did:example:1234",
+      "issuanceDate": "2010-01-01T19:23:24Z",
+      "credentialSubject": { "id": "did:example:5678" },
+    });
+
+    Self {
+      data: Object::from_json_value(data).unwrap(),
+      proof: SignatureSet::new(),
+    }
+  }
+}
+
+impl TrySignature for Claims {
+  fn signature(&self) -> Option<&Signature> {
+    self.proof.current()
+  }
+
+  fn signatures(&self) -> Vec<&Signature> {
+    self.proof.signatures()
+  }
+}
+
+impl TrySignatureMut for Claims {
+  fn signature_mut(&mut self) -> Option<&mut Signature> {
+    self.proof.current_mut()
+  }
+}
+
+impl SetSignature for Claims {
+  fn set_signature(&mut self, signature: Signature) {
+    self.proof.set(signature);
+  }
+
+  fn push_signature(&mut self, signature: Signature) {
+    self.proof.push(signature);
+  }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+struct Person {
+  #[serde(skip_serializing_if = "Option::is_none")]
+  name: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  age: Option<u32>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  proof: Option<Signature>,
+}
+
+impl TrySignature for Person {
+  fn signature(&self) -> Option<&Signature> {
+    self.proof.as_ref()
+  }
+}
+
+impl TrySignatureMut for Person {
+  fn signature_mut(&mut self) -> Option<&mut Signature> {
+    self.proof.as_mut()
+  }
+}
+
+impl SetSignature for Person {
+  fn set_signature(&mut self, signature: Signature) {
+    self.proof = Some(signature);
+  }
+}
+
+// Returns DID Document properties with a JSON-LD context.
+fn properties() -> Properties {
+  properties_with_context(json!(["https://www.w3.org/ns/did/v1", "https://w3id.org/security/v2"]))
+}
+
+fn properties_with_context(context: Value) -> Properties {
+  let mut properties: Object = Object::new();
+
+  properties.insert("@context".into(), context);
+
+  Properties::new(properties)
+}
+
 // ===========================================================================
 // ===========================================================================
 
@@ -71,7 +181,194 @@ fn test_sign_verify_this_ed25519() {
 
   let mut document: Document<Properties> = Document::builder(Default::default())
     .id(controller)
-    .verification_method(method)
+    .authentication(method)
+    .build()
+    .unwrap();
+
+  assert!(document.verify_this().is_err());
+
+  document.sign_this("#key-1", key.secret()).unwrap();
+
+  assert!(document.verify_this().is_ok());
+}
+
+#[test]
+fn test_verify_this_without_purpose() {
+  let key: KeyPair = KeyPair::new_ed25519().unwrap();
+  let controller: DID = "did:example:1234".parse().unwrap();
+
+  let method: Method = Method::builder(Default::default())
+    .id(controller.join("#key-1").unwrap())
+    .controller(controller.clone())
+    .key_type(MethodType::Ed25519VerificationKey2018)
+    .key_data(MethodData::new_b58(key.public()))
+    .build()
+    .unwrap();
+
+  let mut document: Document<Properties> = Document::builder(Default::default())
+    .id(controller)
+    .authentication(method)
+    .build()
+    .unwrap();
+
+  // Documents signed before proof purposes were required have none
+  JcsEd25519::<Ed25519>::create_signature(&mut document, "#key-1", key.secret().as_ref()).unwrap();
+
+  assert_eq!(document.try_signature().unwrap().options().purpose, None);
+  assert!(document.verify_this().is_ok());
+
+  // A missing purpose is only accepted with a default purpose
+  assert!(document.verifier().verify(&document).is_err());
+  assert!(document
+    .verifier()
+    .default_purpose(ProofPurpose::Authentication)
+    .verify(&document)
+    .is_ok());
+  assert!(document
+    .verifier()
+    .purpose(ProofPurpose::AssertionMethod)
+    .default_purpose(ProofPurpose::Authentication)
+    .verify(&document)
+    .is_err());
+}
+
+#[test]
+fn test_sign_verify_this_secp256k1() {
+  let key: KeyPair = KeyPair::new_secp256k1().unwrap();
+  let controller: DID = "did:example:1234".parse().unwrap();
+
+  let method: Method = Method::builder(Default::default())
+    .id(controller.join("#key-1").unwrap())
+    .controller(controller.clone())
+    .key_type(MethodType::EcdsaSecp256k1VerificationKey2019)
+    .key_data(MethodData::new_b58(key.public()))
+    .build()
+    .unwrap();
+
+  let mut document: Document<Properties> = Document::builder(properties())
+    .id(controller)
+    .authentication(method)
+    .build()
+    .unwrap();
+
+  assert!(document.verify_this().is_err());
+
+  document.sign_this("#key-1", key.secret()).unwrap();
+
+  assert!(document.verify_this().is_ok());
+}
+
+#[test]
+fn test_sign_verify_that_secp256k1() {
+  let key: KeyPair = KeyPair::new_secp256k1().unwrap();
+  let controller: DID = "did:example:1234".parse().unwrap();
+
+  let method: Method = Method::builder(Default::default())
+    .id(controller.join("#key-1").unwrap())
+    .controller(controller.clone())
+    .key_type(MethodType::EcdsaSecp256k1VerificationKey2019)
+    .key_data(MethodData::new_b58(key.public()))
+    .build()
+    .unwrap();
+
+  let document: Document<Properties> = Document::builder(Default::default())
+    .id(controller)
+    .assertion_method(method)
+    .build()
+    .unwrap();
+
+  let mut that: Claims = Claims::new();
+
+  assert!(document.verifier().verify(&that).is_err());
+
+  document
+    .signer(key.secret())
+    .method("#key-1")
+    .purpose(ProofPurpose::AssertionMethod)
+    .sign(&mut that)
+    .unwrap();
+
+  assert!(document.verifier().verify(&that).is_ok());
+
+  // Claims not defined by the JSON-LD context can not be signed
+  let mut that: That = That::new(123);
+
+  assert!(document
+    .signer(key.secret())
+    .method("#key-1")
+    .purpose(ProofPurpose::AssertionMethod)
+    .sign(&mut that)
+    .is_err());
+}
+
+#[test]
+fn test_sign_verify_that_jwk() {
+  for type_ in [KeyType::Ed25519, KeyType::Secp256k1, KeyType::P256].iter() {
+    let key: KeyPair = KeyPair::new(*type_).unwrap();
+    let controller: DID = "did:example:1234".parse().unwrap();
+
+    let method: Method = Method::builder(Default::default())
+      .id(controller.join("#key-1").unwrap())
+      .controller(controller.clone())
+      .key_type(MethodType::JsonWebKey2020)
+      .key_data(MethodData::new_jwk(&encode_jwk(*type_, key.public().as_ref()).unwrap()).unwrap())
+      .build()
+      .unwrap();
+
+    assert_eq!(method.key_data().try_decode().unwrap(), key.public().as_ref());
+    assert_eq!(method.try_to_jwk().unwrap().kid(), Some("did:example:1234#key-1"));
+
+    let properties: Properties = properties_with_context(json!([
+      "https://www.w3.org/ns/did/v1",
+      "https://w3id.org/security/suites/jws-2020/v1",
+    ]));
+
+    let mut document: Document<Properties> = Document::builder(properties)
+      .id(controller)
+      .authentication(method)
+      .build()
+      .unwrap();
+
+    let mut that: Claims = Claims::with_context(json!([
+      "https://www.w3.org/2018/credentials/v1",
+      "https://w3id.org/security/suites/jws-2020/v1",
+    ]));
+
+    assert!(document.verifier().verify(&that).is_err());
+
+    document
+      .signer(key.secret())
+      .method("#key-1")
+      .purpose(ProofPurpose::Authentication)
+      .sign(&mut that)
+      .unwrap();
+
+    // JsonWebKey2020 methods sign with JsonWebSignature2020
+    assert_eq!(that.signature().unwrap().type_(), "JsonWebSignature2020");
+    assert!(document.verifier().verify(&that).is_ok());
+
+    document.sign_this("#key-1", key.secret()).unwrap();
+
+    assert!(document.verify_this().is_ok());
+  }
+}
+
+#[test]
+fn test_sign_verify_this_bls12381g2() {
+  let key: KeyPair = KeyPair::new_bls12381g2().unwrap();
+  let controller: DID = "did:example:1234".parse().unwrap();
+
+  let method: Method = Method::builder(Default::default())
+    .id(controller.join("#key-1").unwrap())
+    .controller(controller.clone())
+    .key_type(MethodType::Bls12381G2Key2020)
+    .key_data(MethodData::new_b58(key.public()))
+    .build()
+    .unwrap();
+
+  let mut document: Document<Properties> = Document::builder(Default::default())
+    .id(controller)
+    .authentication(method)
     .build()
     .unwrap();
 
@@ -82,6 +379,57 @@ fn test_sign_verify_this_ed25519() {
   assert!(document.verify_this().is_ok());
 }
 
+#[test]
+fn test_sign_derive_verify_that_bls12381g2() {
+  let key: KeyPair = KeyPair::new_bls12381g2().unwrap();
+  let controller: DID = "did:example:1234".parse().unwrap();
+
+  let method: Method = Method::builder(Default::default())
+    .id(controller.join("#key-1").unwrap())
+    .controller(controller.clone())
+    .key_type(MethodType::Bls12381G2Key2020)
+    .key_data(MethodData::new_b58(key.public()))
+    .build()
+    .unwrap();
+
+  let document: Document<Properties> = Document::builder(Default::default())
+    .id(controller)
+    .assertion_method(method)
+    .build()
+    .unwrap();
+
+  let mut that: Person = Person {
+    name: Some("Alice".into()),
+    age: Some(42),
+    proof: None,
+  };
+
+  assert!(document.verifier().verify(&that).is_err());
+
+  document
+    .signer(key.secret())
+    .method("#key-1")
+    .purpose(ProofPurpose::AssertionMethod)
+    .sign(&mut that)
+    .unwrap();
+
+  assert!(document.verifier().verify(&that).is_ok());
+
+  let mut derived: Person = document.derive_proof(&that, &["/age"], b"nonce").unwrap();
+
+  assert_eq!(derived.name, None);
+  assert_eq!(derived.age, Some(42));
+  assert!(document.verifier().nonce("nonce").verify(&derived).is_ok());
+
+  // The derived proof is only valid for the nonce it was created with
+  assert!(document.verifier().verify(&derived).is_err());
+  assert!(document.verifier().nonce("other").verify(&derived).is_err());
+
+  derived.age = Some(43);
+
+  assert!(document.verifier().nonce("nonce").verify(&derived).is_err());
+}
+
 #[test]
 fn test_sign_verify_that_merkle_key_ed25519_sha256() {
   let total: usize = 1 << 11;
@@ -104,7 +452,7 @@ fn test_sign_verify_that_merkle_key_ed25519_sha256() {
 
   let document: Document<Properties> = Document::builder(Default::default())
     .id(controller)
-    .verification_method(method)
+    .assertion_method(method)
     .build()
     .unwrap();
 
@@ -119,8 +467,301 @@ fn test_sign_verify_that_merkle_key_ed25519_sha256() {
     .signer(secret)
     .method("#key-collection")
     .merkle_key((public, &proof))
+    .purpose(ProofPurpose::AssertionMethod)
+    .sign(&mut that)
+    .unwrap();
+
+  assert!(document.verifier().verify(&that).is_ok());
+}
+
+#[test]
+fn test_sign_verify_proof_set_and_chain() {
+  let key1: KeyPair = KeyPair::new_ed25519().unwrap();
+  let key2: KeyPair = KeyPair::new_secp256k1().unwrap();
+  let controller: DID = "did:example:1234".parse().unwrap();
+
+  let method1: Method = Method::builder(Default::default())
+    .id(controller.join("#key-1").unwrap())
+    .controller(controller.clone())
+    .key_type(MethodType::Ed25519VerificationKey2018)
+    .key_data(MethodData::new_b58(key1.public()))
+    .build()
+    .unwrap();
+
+  let method2: Method = Method::builder(Default::default())
+    .id(controller.join("#key-2").unwrap())
+    .controller(controller.clone())
+    .key_type(MethodType::EcdsaSecp256k1VerificationKey2019)
+    .key_data(MethodData::new_b58(key2.public()))
+    .build()
+    .unwrap();
+
+  let document: Document<Properties> = Document::builder(Default::default())
+    .id(controller)
+    .assertion_method(method1)
+    .assertion_method(method2)
+    .build()
+    .unwrap();
+
+  let mut that: Claims = Claims::new();
+
+  assert!(document.verifier().verify(&that).is_err());
+  assert!(document.verifier().verify_any(&that).is_err());
+
+  // Proofs without an identifier can not be referenced by a proof chain
+  assert!(document
+    .signer(key1.secret())
+    .method("#key-1")
+    .chain()
+    .purpose(ProofPurpose::AssertionMethod)
+    .sign(&mut that)
+    .is_err());
+
+  document
+    .signer(key1.secret())
+    .method("#key-1")
+    .options(SignatureOptions::new().id("urn:uuid:proof-1"))
+    .purpose(ProofPurpose::AssertionMethod)
+    .append()
+    .sign(&mut that)
+    .unwrap();
+  document
+    .signer(key2.secret())
+    .method("#key-2")
+    .options(SignatureOptions::new().id("urn:uuid:proof-2"))
+    .purpose(ProofPurpose::AssertionMethod)
+    .append()
+    .sign(&mut that)
+    .unwrap();
+  document
+    .signer(key1.secret())
+    .method("#key-1")
+    .chain()
+    .purpose(ProofPurpose::AssertionMethod)
     .sign(&mut that)
     .unwrap();
 
+  assert_eq!(that.proof.len(), 3);
+  assert_eq!(that.proof[1].options().previous_proof, None);
+  assert_eq!(
+    that.proof[2].options().previous_proof.as_deref(),
+    Some("urn:uuid:proof-2")
+  );
+  assert!(document.verifier().verify_each(&that).iter().all(Result::is_ok));
   assert!(document.verifier().verify(&that).is_ok());
+
+  // Tampering with the second proof invalidates the proof chained to it
+  let value: SignatureValue = that.proof[0].value().clone();
+  let original: SignatureValue = that.proof[1].value().clone();
+  let mut proofs: Vec<Signature> = that.proof.clone().into();
+  proofs[1].set_value(value);
+  that.proof = proofs.into();
+
+  let results: Vec<bool> = document
+    .verifier()
+    .verify_each(&that)
+    .iter()
+    .map(Result::is_ok)
+    .collect();
+
+  assert_eq!(results, [true, false, false]);
+  assert!(document.verifier().verify(&that).is_err());
+  assert!(document.verifier().verify_any(&that).is_ok());
+  assert!(document.verifier().verify_at(&that, 3).is_err());
+
+  // The chained proof does not cover proofs it does not reference
+  let mut proofs: Vec<Signature> = that.proof.clone().into();
+  proofs[0].set_value(original.clone());
+  proofs[1].set_value(original);
+  that.proof = proofs.into();
+
+  let results: Vec<bool> = document
+    .verifier()
+    .verify_each(&that)
+    .iter()
+    .map(Result::is_ok)
+    .collect();
+
+  assert_eq!(results, [false, true, true]);
+
+  // A chained proof that references a missing proof is invalid
+  let mut proofs: Vec<Signature> = that.proof.clone().into();
+  proofs.remove(1);
+  that.proof = proofs.into();
+
+  assert!(document.verifier().verify_at(&that, 1).is_err());
+  assert!(document.verifier().verify(&that).is_err());
+
+  // Replacing the signature discards the proof set
+  document
+    .signer(key1.secret())
+    .method("#key-1")
+    .purpose(ProofPurpose::AssertionMethod)
+    .sign(&mut that)
+    .unwrap();
+
+  assert_eq!(that.proof.len(), 1);
+  assert!(document.verifier().verify(&that).is_ok());
+}
+
+#[test]
+fn test_sign_verify_proof_options() {
+  let key: KeyPair = KeyPair::new_ed25519().unwrap();
+  let controller: DID = "did:example:1234".parse().unwrap();
+
+  let method: Method = Method::builder(Default::default())
+    .id(controller.join("#key-1").unwrap())
+    .controller(controller.clone())
+    .key_type(MethodType::Ed25519VerificationKey2018)
+    .key_data(MethodData::new_b58(key.public()))
+    .build()
+    .unwrap();
+
+  let document: Document<Properties> = Document::builder(Default::default())
+    .id(controller)
+    .assertion_method(method)
+    .build()
+    .unwrap();
+
+  let options: SignatureOptions = SignatureOptions::new()
+    .purpose(ProofPurpose::AssertionMethod)
+    .created(Timestamp::parse("2021-01-01T00:00:00Z").unwrap())
+    .domain("example.com")
+    .challenge("1f44d55f");
+
+  let mut that: That = That::new(123);
+
+  // The method is not an authentication method
+  assert!(document
+    .signer(key.secret())
+    .method("#key-1")
+    .options(options.clone().purpose(ProofPurpose::Authentication))
+    .sign(&mut that)
+    .is_err());
+
+  document
+    .signer(key.secret())
+    .method("#key-1")
+    .options(options.clone())
+    .sign(&mut that)
+    .unwrap();
+
+  assert_eq!(that.proof.as_ref().unwrap().options(), &options);
+
+  assert!(document
+    .verifier()
+    .purpose(ProofPurpose::AssertionMethod)
+    .challenge("1f44d55f")
+    .domain("example.com")
+    .verify(&that)
+    .is_ok());
+
+  assert!(document
+    .verifier()
+    .purpose(ProofPurpose::Authentication)
+    .challenge("1f44d55f")
+    .domain("example.com")
+    .verify(&that)
+    .is_err());
+
+  // The challenge and domain of the proof must be checked by the verifier
+  assert!(document.verifier().verify(&that).is_err());
+  assert!(document.verifier().domain("example.com").verify(&that).is_err());
+  assert!(document.verifier().challenge("1f44d55f").verify(&that).is_err());
+
+  assert!(document
+    .verifier()
+    .challenge("00000000")
+    .domain("example.com")
+    .verify(&that)
+    .is_err());
+  assert!(document
+    .verifier()
+    .challenge("1f44d55f")
+    .domain("example.org")
+    .verify(&that)
+    .is_err());
+
+  // The nonce is checked if the verifier expects one
+  assert!(document
+    .verifier()
+    .challenge("1f44d55f")
+    .domain("example.com")
+    .nonce("8e2c6b0a")
+    .verify(&that)
+    .is_err());
+
+  // The proof options are covered by the signature
+  that.proof.as_mut().unwrap().options_mut().challenge = Some("00000000".into());
+
+  assert!(document
+    .verifier()
+    .challenge("00000000")
+    .domain("example.com")
+    .verify(&that)
+    .is_err());
+
+  // Signatures without a proof purpose are rejected
+  assert!(document.signer(key.secret()).method("#key-1").sign(&mut that).is_err());
+
+  let mut unscoped: SignatureOptions = options.clone();
+  unscoped.purpose = None;
+
+  assert!(document
+    .signer(key.secret())
+    .method("#key-1")
+    .options(unscoped)
+    .sign(&mut that)
+    .is_err());
+
+  document
+    .signer(key.secret())
+    .method("#key-1")
+    .options(options.clone())
+    .sign(&mut that)
+    .unwrap();
+
+  that.proof.as_mut().unwrap().options_mut().purpose = None;
+
+  assert!(document
+    .verifier()
+    .challenge("1f44d55f")
+    .domain("example.com")
+    .verify(&that)
+    .is_err());
+
+  // Signatures created in the future are rejected
+  let mut future: SignatureOptions = options.clone();
+  future.created = Some(Timestamp::parse("2999-01-01T00:00:00Z").unwrap());
+
+  document
+    .signer(key.secret())
+    .method("#key-1")
+    .options(future)
+    .sign(&mut that)
+    .unwrap();
+
+  assert!(document
+    .verifier()
+    .challenge("1f44d55f")
+    .domain("example.com")
+    .verify(&that)
+    .is_err());
+
+  // Expired signatures are rejected
+  let options: SignatureOptions = options.expires(Timestamp::parse("2021-01-02T00:00:00Z").unwrap());
+
+  document
+    .signer(key.secret())
+    .method("#key-1")
+    .options(options)
+    .sign(&mut that)
+    .unwrap();
+
+  assert!(document
+    .verifier()
+    .challenge("1f44d55f")
+    .domain("example.com")
+    .verify(&that)
+    .is_err());
 }
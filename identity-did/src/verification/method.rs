@@ -8,11 +8,14 @@ use core::fmt::Result as FmtResult;
 use core::iter::once;
 use identity_core::common::Object;
 use identity_core::convert::ToJson;
+use identity_core::crypto::KeyType;
+use libjose::jwk::Jwk;
 use serde::Serialize;
 
 use crate::did::DID;
 use crate::error::Error;
 use crate::error::Result;
+use crate::utils::encode_jwk;
 use crate::verification::MethodBuilder;
 use crate::verification::MethodData;
 use crate::verification::MethodRef;
@@ -108,6 +111,36 @@ impl<T> Method<T> {
       .map(|fragment| once('#').chain(fragment.chars()).collect())
   }
 
+  /// Converts the public key of the verification `Method` to a [`Jwk`].
+  ///
+  /// The `kid` of the returned JWK is set to the verification `Method` id.
+  ///
+  /// # Errors
+  ///
+  /// Fails if the verification method type has no JWK representation or the
+  /// method data is invalid.
+  pub fn try_to_jwk(&self) -> Result<Jwk> {
+    let type_: KeyType = match self.key_type {
+      MethodType::JsonWebKey2020 => {
+        let mut jwk: Jwk = self.key_data.try_to_jwk()?;
+        jwk.set_kid(self.id.as_str());
+        return Ok(jwk);
+      }
+      MethodType::Ed25519VerificationKey2018 => KeyType::Ed25519,
+      MethodType::EcdsaSecp256k1VerificationKey2019 => KeyType::Secp256k1,
+      MethodType::X25519KeyAgreementKey2019 => KeyType::X25519,
+      MethodType::MerkleKeyCollection2021 | MethodType::Bls12381G2Key2020 => {
+        return Err(Error::InvalidMethodType);
+      }
+    };
+
+    let mut jwk: Jwk = encode_jwk(type_, &self.key_data.try_decode()?)?;
+
+    jwk.set_kid(self.id.as_str());
+
+    Ok(jwk)
+  }
+
   /// Creates a new [`MethodRef`] from `self`.
   pub fn into_ref(self) -> MethodRef<T> {
     MethodRef::Embed(self)
@@ -2,13 +2,17 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use identity_core::common::Object;
+use identity_core::convert::FromJson;
+use identity_core::convert::ToJson;
 use identity_core::utils::decode_b16;
 use identity_core::utils::decode_b58;
 use identity_core::utils::encode_b16;
 use identity_core::utils::encode_b58;
+use libjose::jwk::Jwk;
 
 use crate::error::Error;
 use crate::error::Result;
+use crate::utils::decode_jwk;
 
 /// Supported verification method data formats.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
@@ -31,6 +35,17 @@ impl MethodData {
     Self::PublicKeyBase58(encode_b58(&data))
   }
 
+  /// Creates a new `MethodData` variant from the public components of `jwk`.
+  ///
+  /// # Errors
+  ///
+  /// Fails if the JWK can not be represented as a JSON object.
+  pub fn new_jwk(jwk: &Jwk) -> Result<Self> {
+    Object::from_json_value(jwk.to_public().to_json_value()?)
+      .map(Self::PublicKeyJwk)
+      .map_err(Into::into)
+  }
+
   /// Returns a `Vec<u8>` containing the decoded bytes of the `MethodData`.
   ///
   /// This is generally a public key identified by a `MethodType` value.
@@ -43,7 +58,23 @@ impl MethodData {
     match self {
       Self::PublicKeyBase58(input) => decode_b58(input).map_err(|_| Error::InvalidKeyDataBase58),
       Self::PublicKeyHex(input) => decode_b16(input).map_err(|_| Error::InvalidKeyDataBase16),
-      Self::PublicKeyJwk(_) => Err(Error::InvalidKeyData),
+      Self::PublicKeyJwk(_) => self.try_to_jwk().and_then(|jwk| decode_jwk(&jwk)).map(|(_, data)| data),
+    }
+  }
+
+  /// Returns the [`Jwk`] of a `PublicKeyJwk` variant.
+  ///
+  /// # Errors
+  ///
+  /// Fails if `MethodData` is not a `PublicKeyJwk` variant or has invalid
+  /// content.
+  pub fn try_to_jwk(&self) -> Result<Jwk> {
+    match self {
+      Self::PublicKeyJwk(input) => input
+        .to_json_value()
+        .and_then(Jwk::from_json_value)
+        .map_err(|_| Error::InvalidKeyDataJwk),
+      Self::PublicKeyBase58(_) | Self::PublicKeyHex(_) => Err(Error::InvalidKeyData),
     }
   }
 }
@@ -11,14 +11,22 @@ use crate::error::Result;
 #[non_exhaustive]
 pub enum MethodType {
   Ed25519VerificationKey2018,
+  EcdsaSecp256k1VerificationKey2019,
   MerkleKeyCollection2021,
+  X25519KeyAgreementKey2019,
+  Bls12381G2Key2020,
+  JsonWebKey2020,
 }
 
 impl MethodType {
   pub const fn as_str(self) -> &'static str {
     match self {
       Self::Ed25519VerificationKey2018 => "Ed25519VerificationKey2018",
+      Self::EcdsaSecp256k1VerificationKey2019 => "EcdsaSecp256k1VerificationKey2019",
       Self::MerkleKeyCollection2021 => "MerkleKeyCollection2021",
+      Self::X25519KeyAgreementKey2019 => "X25519KeyAgreementKey2019",
+      Self::Bls12381G2Key2020 => "Bls12381G2Key2020",
+      Self::JsonWebKey2020 => "JsonWebKey2020",
     }
   }
 }
@@ -29,7 +37,11 @@ impl FromStr for MethodType {
   fn from_str(string: &str) -> Result<Self, Self::Err> {
     match string {
       "Ed25519VerificationKey2018" => Ok(Self::Ed25519VerificationKey2018),
+      "EcdsaSecp256k1VerificationKey2019" => Ok(Self::EcdsaSecp256k1VerificationKey2019),
       "MerkleKeyCollection2021" => Ok(Self::MerkleKeyCollection2021),
+      "X25519KeyAgreementKey2019" => Ok(Self::X25519KeyAgreementKey2019),
+      "Bls12381G2Key2020" => Ok(Self::Bls12381G2Key2020),
+      "JsonWebKey2020" => Ok(Self::JsonWebKey2020),
       _ => Err(Error::UnknownMethodType),
     }
   }
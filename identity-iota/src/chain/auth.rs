@@ -70,6 +70,11 @@ impl AuthChain {
     Ok(Self { current, history: None })
   }
 
+  /// Returns the superseded auth documents, oldest first.
+  pub fn history(&self) -> &[Document] {
+    self.history.as_deref().unwrap_or(&[])
+  }
+
   /// Returns a reference to the latest `Document`.
   pub fn current(&self) -> &Document {
     &self.current
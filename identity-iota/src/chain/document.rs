@@ -97,6 +97,33 @@ impl DocumentChain {
     Ok(self.auth_chain.current)
   }
 
+  /// Returns every version of the document, oldest first, with the Tangle
+  /// message Id of each: every auth document followed by the diffs of the
+  /// latest auth document.
+  ///
+  /// Diffs of superseded auth documents are not included - nothing orders
+  /// them before the next auth document, so they may have been published
+  /// after it.
+  pub fn versions(&self) -> Result<Vec<(MessageId, Document)>> {
+    let mut versions: Vec<(MessageId, Document)> = self
+      .auth_chain
+      .history()
+      .iter()
+      .map(|document| (*document.message_id(), document.clone()))
+      .collect();
+
+    let mut document: Document = self.auth_chain.current.clone();
+
+    versions.push((*self.auth_message_id(), document.clone()));
+
+    for diff in self.diff_chain.iter() {
+      document.merge(diff)?;
+      versions.push((*diff.message_id(), document.clone()));
+    }
+
+    Ok(versions)
+  }
+
   /// Returns a reference to the latest document.
   pub fn current(&self) -> &Document {
     self.document.as_ref().unwrap_or_else(|| self.auth_chain.current())
@@ -170,3 +197,130 @@ impl Display for DocumentChain {
     }
   }
 }
+
+#[cfg(test)]
+pub(crate) mod tests {
+  use identity_core::common::Timestamp;
+  use identity_core::crypto::KeyPair;
+  use iota::MessageId;
+
+  use crate::chain::AuthChain;
+  use crate::chain::DocumentChain;
+  use crate::did::Document;
+  use crate::did::DocumentDiff;
+  use crate::tangle::TangleRef;
+
+  // Returns a chain with an auth document published in message `[1; 32]` and
+  // updated at 2021-01-01, followed by diffs published in messages `[2; 32]`
+  // and `[3; 32]`, updated at 2021-02-01 and 2021-03-01.
+  pub(crate) fn document_chain() -> DocumentChain {
+    let keypair: KeyPair = KeyPair::new_ed25519().unwrap();
+    let mut document: Document = Document::from_keypair(&keypair).unwrap();
+
+    document.set_updated(Timestamp::parse("2021-01-01T00:00:00Z").unwrap());
+    document.sign(keypair.secret()).unwrap();
+    document.set_message_id(MessageId::new([1; 32]));
+
+    let mut chain: DocumentChain = DocumentChain::new(AuthChain::new(document).unwrap());
+
+    for (index, updated) in ["2021-02-01T00:00:00Z", "2021-03-01T00:00:00Z"].iter().enumerate() {
+      let mut update: Document = chain.current().clone();
+
+      update.set_updated(Timestamp::parse(updated).unwrap());
+
+      let mut diff: DocumentDiff = chain
+        .current()
+        .diff(&update, *chain.diff_message_id(), keypair.secret())
+        .unwrap();
+
+      diff.set_message_id(MessageId::new([index as u8 + 2; 32]));
+
+      chain.try_push_diff(diff).unwrap();
+    }
+
+    chain
+  }
+
+  #[test]
+  fn test_versions() {
+    let chain: DocumentChain = document_chain();
+    let versions: Vec<(MessageId, Document)> = chain.versions().unwrap();
+
+    let message_ids: Vec<MessageId> = versions.iter().map(|(message_id, _)| *message_id).collect();
+
+    assert_eq!(
+      message_ids,
+      [
+        MessageId::new([1; 32]),
+        MessageId::new([2; 32]),
+        MessageId::new([3; 32])
+      ]
+    );
+
+    let updated: Vec<String> = versions
+      .iter()
+      .map(|(_, document)| document.updated().to_string())
+      .collect();
+
+    assert_eq!(
+      updated,
+      ["2021-01-01T00:00:00Z", "2021-02-01T00:00:00Z", "2021-03-01T00:00:00Z"]
+    );
+
+    // The latest version is the current document
+    assert_eq!(&versions[2].1, chain.current());
+    assert_eq!(chain.diff_message_id(), &versions[2].0);
+  }
+
+  #[test]
+  fn test_versions_superseded_diff() {
+    let keypair: KeyPair = KeyPair::new_ed25519().unwrap();
+    let mut document: Document = Document::from_keypair(&keypair).unwrap();
+
+    document.sign(keypair.secret()).unwrap();
+    document.set_message_id(MessageId::new([1; 32]));
+
+    let mut chain: DocumentChain = DocumentChain::new(AuthChain::new(document.clone()).unwrap());
+
+    // A diff of the first auth document
+    let mut update: Document = chain.current().clone();
+    update.properties_mut().insert("foo".into(), 1.into());
+
+    let mut diff: DocumentDiff = chain
+      .current()
+      .diff(&update, *chain.diff_message_id(), keypair.secret())
+      .unwrap();
+
+    diff.set_message_id(MessageId::new([2; 32]));
+    chain.try_push_diff(diff).unwrap();
+
+    // A new auth document supersedes the first one and its diffs
+    let mut auth: Document = chain.current().clone();
+
+    auth.set_previous_message_id(*chain.auth_message_id());
+    chain.current().sign_data(&mut auth, keypair.secret()).unwrap();
+    auth.set_message_id(MessageId::new([3; 32]));
+    chain.try_push_auth(auth).unwrap();
+
+    // A diff of the first auth document published after it was superseded
+    let mut update: Document = document.clone();
+    update.properties_mut().insert("foo".into(), 2.into());
+
+    let mut diff: DocumentDiff = document
+      .diff(&update, MessageId::new([2; 32]), keypair.secret())
+      .unwrap();
+
+    diff.set_message_id(MessageId::new([4; 32]));
+
+    assert!(chain.try_push_diff(diff).is_err());
+
+    let message_ids: Vec<MessageId> = chain
+      .versions()
+      .unwrap()
+      .into_iter()
+      .map(|(message_id, _)| message_id)
+      .collect();
+
+    assert_eq!(message_ids, [MessageId::new([1; 32]), MessageId::new([3; 32])]);
+  }
+}
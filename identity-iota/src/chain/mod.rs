@@ -8,3 +8,6 @@ mod document;
 pub use self::auth::AuthChain;
 pub use self::diff::DiffChain;
 pub use self::document::DocumentChain;
+
+#[cfg(test)]
+pub(crate) use self::document::tests::document_chain;
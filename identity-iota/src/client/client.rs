@@ -166,6 +166,14 @@ impl Client {
     DocumentChain::with_diff_chain(auth, diff)
   }
 
+  /// Reads every version of the DID document identified by `did`, oldest first.
+  ///
+  /// Every version is paired with the Tangle message Id that published it -
+  /// see [`DocumentChain::versions`].
+  pub async fn read_document_versions(&self, did: &DID) -> Result<Vec<(MessageId, Document)>> {
+    self.read_document_chain(did).await?.versions()
+  }
+
   pub async fn read_messages(&self, address: &str) -> Result<Messages> {
     let message_ids: Box<[MessageId]> = self.client.get_message().index(address).await?;
 
@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use async_trait::async_trait;
+use identity_core::common::Timestamp;
 use identity_core::convert::SerdeInto;
 use identity_did::did::DID as CoreDID;
 use identity_did::error::Error;
@@ -10,11 +11,22 @@ use identity_did::resolution::DocumentMetadata;
 use identity_did::resolution::InputMetadata;
 use identity_did::resolution::MetaDocument;
 use identity_did::resolution::ResolverMethod;
+use iota::MessageId;
 
+use crate::chain::DocumentChain;
 use crate::client::Client;
 use crate::did::Document;
 use crate::did::DID;
 
+/// Resolves `did:iota` documents, optionally at a previous version.
+///
+/// A `version-id` selects the document published in the Tangle message with
+/// that id. A `version-time` selects the latest document with an `updated`
+/// property not later than the given time.
+///
+/// Note: The `updated` property, and the `nextUpdate` metadata derived from
+/// it, is asserted by the DID controller - it is NOT the time the document was
+/// attached to the Tangle.
 #[async_trait(?Send)]
 impl ResolverMethod for Client {
   fn is_supported(&self, did: &CoreDID) -> bool {
@@ -23,20 +35,146 @@ impl ResolverMethod for Client {
       .unwrap_or(false)
   }
 
-  async fn read(&self, did: &CoreDID, _input: InputMetadata) -> Result<Option<MetaDocument>> {
-    let document: Document = DID::try_from_borrowed(did)
-      .map_err(|_| Error::MissingResolutionDID)
-      .map(|did| self.read_document(&did))?
+  async fn read(&self, did: &CoreDID, input: InputMetadata) -> Result<Option<MetaDocument>> {
+    let did: DID = DID::try_from_borrowed(did)
+      .map_err(|_| Error::MissingResolutionDID)?
+      .clone();
+
+    if input.version_id.is_none() && input.version_time.is_none() {
+      let chain: DocumentChain = self
+        .read_document_chain(&did)
+        .await
+        .map_err(|_| Error::MissingResolutionDocument)?;
+
+      let version: MessageId = *chain.diff_message_id();
+      let document: Document = chain.fold().map_err(|_| Error::MissingResolutionDocument)?;
+
+      return __meta_document(document, version, None).map(Some);
+    }
+
+    // Replay the document chain and select the requested version.
+    let versions: Vec<(MessageId, Document)> = self
+      .read_document_versions(&did)
       .await
       .map_err(|_| Error::MissingResolutionDocument)?;
 
-    let mut meta: DocumentMetadata = DocumentMetadata::new();
-    meta.created = Some(document.created());
-    meta.updated = Some(document.updated());
+    __select_version(versions, &input)
+  }
+}
+
+// Selects the document version identified by the `version-id` or, if not set,
+// the controller-asserted `version-time` of `input`.
+fn __select_version(mut versions: Vec<(MessageId, Document)>, input: &InputMetadata) -> Result<Option<MetaDocument>> {
+  let index: Option<usize> = match (input.version_id.as_deref(), input.version_time) {
+    (Some(version_id), _) => versions
+      .iter()
+      .position(|(message_id, _)| message_id.to_string() == version_id),
+    (None, Some(version_time)) => versions
+      .iter()
+      .rposition(|(_, document)| document.updated() <= version_time),
+    (None, None) => None,
+  };
+
+  let index: usize = match index {
+    Some(index) => index,
+    None => return Ok(None),
+  };
+
+  let next: Option<(MessageId, Timestamp)> = versions
+    .get(index + 1)
+    .map(|(message_id, document)| (*message_id, document.updated()));
+
+  let (version, document): (MessageId, Document) = versions.swap_remove(index);
+
+  __meta_document(document, version, next).map(Some)
+}
+
+fn __meta_document(
+  document: Document,
+  version: MessageId,
+  next: Option<(MessageId, Timestamp)>,
+) -> Result<MetaDocument> {
+  let mut meta: DocumentMetadata = DocumentMetadata::new();
+  meta.created = Some(document.created());
+  meta.updated = Some(document.updated());
+  meta.version_id = Some(version.to_string());
+
+  if let Some((message_id, timestamp)) = next {
+    meta.next_version_id = Some(message_id.to_string());
+    meta.next_update = Some(timestamp);
+  }
+
+  Ok(MetaDocument {
+    data: document.serde_into()?,
+    meta,
+  })
+}
+
+#[cfg(test)]
+mod tests {
+  use identity_core::common::Timestamp;
+  use identity_did::resolution::InputMetadata;
+  use identity_did::resolution::MetaDocument;
+  use iota::MessageId;
+
+  use super::__select_version;
+  use crate::chain::document_chain;
+
+  fn select(version_id: Option<[u8; 32]>, version_time: Option<&str>) -> Option<MetaDocument> {
+    let mut input: InputMetadata = InputMetadata::new();
+
+    input.version_id = version_id.map(|message_id| MessageId::new(message_id).to_string());
+    input.version_time = version_time.map(|time| Timestamp::parse(time).unwrap());
+
+    __select_version(document_chain().versions().unwrap(), &input).unwrap()
+  }
+
+  fn timestamp(input: &str) -> Option<Timestamp> {
+    Some(Timestamp::parse(input).unwrap())
+  }
+
+  #[test]
+  fn test_select_version_id() {
+    let document: MetaDocument = select(Some([2; 32]), None).unwrap();
+
+    assert_eq!(document.meta.version_id, Some(MessageId::new([2; 32]).to_string()));
+    assert_eq!(document.meta.updated, timestamp("2021-02-01T00:00:00Z"));
+    assert_eq!(document.meta.next_version_id, Some(MessageId::new([3; 32]).to_string()));
+    assert_eq!(document.meta.next_update, timestamp("2021-03-01T00:00:00Z"));
+
+    // The `version-id` takes precedence over the `version-time`
+    let document: MetaDocument = select(Some([1; 32]), Some("2021-03-01T00:00:00Z")).unwrap();
+
+    assert_eq!(document.meta.version_id, Some(MessageId::new([1; 32]).to_string()));
+
+    // The latest version has no next version
+    let document: MetaDocument = select(Some([3; 32]), None).unwrap();
+
+    assert_eq!(document.meta.next_version_id, None);
+    assert_eq!(document.meta.next_update, None);
+
+    // Unknown message ids are not resolved
+    assert!(select(Some([9; 32]), None).is_none());
+  }
+
+  #[test]
+  fn test_select_version_time() {
+    let document: MetaDocument = select(None, Some("2021-02-15T00:00:00Z")).unwrap();
+
+    assert_eq!(document.meta.version_id, Some(MessageId::new([2; 32]).to_string()));
+    assert_eq!(document.meta.next_version_id, Some(MessageId::new([3; 32]).to_string()));
+
+    // Documents are valid from their `updated` time
+    let document: MetaDocument = select(None, Some("2021-03-01T00:00:00Z")).unwrap();
+
+    assert_eq!(document.meta.version_id, Some(MessageId::new([3; 32]).to_string()));
+
+    // Later times resolve the latest version
+    let document: MetaDocument = select(None, Some("2030-01-01T00:00:00Z")).unwrap();
+
+    assert_eq!(document.meta.version_id, Some(MessageId::new([3; 32]).to_string()));
 
-    Ok(Some(MetaDocument {
-      data: document.serde_into()?,
-      meta,
-    }))
+    // Times before the first version are not resolved
+    assert!(select(None, Some("2020-12-31T23:59:59Z")).is_none());
   }
 }
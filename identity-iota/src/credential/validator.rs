@@ -3,14 +3,21 @@
 
 use identity_core::common::Object;
 use identity_core::convert::FromJson;
+use identity_core::crypto::bbs::BbsPlus;
+use identity_core::crypto::ProofPurpose;
+use identity_core::crypto::TrySignature;
 use identity_credential::credential::Credential;
 use identity_credential::presentation::Presentation;
+use identity_credential::validation::HolderBindingPolicy;
+use identity_credential::validation::UsageValidator;
+use identity_credential::validation::ValidationFailure;
 use serde::de::DeserializeOwned;
 use serde::Serialize;
 use std::collections::BTreeMap;
 
 use crate::client::Client;
 use crate::did::Document;
+use crate::did::Verifier;
 use crate::did::DID;
 use crate::error::Error;
 use crate::error::Result;
@@ -20,6 +27,7 @@ pub struct CredentialValidation<T = Object> {
   pub credential: Credential<T>,
   pub issuer: DocumentValidation,
   pub subjects: BTreeMap<String, DocumentValidation>,
+  pub failures: Vec<ValidationFailure>,
   pub verified: bool,
 }
 
@@ -28,6 +36,7 @@ pub struct PresentationValidation<T = Object, U = Object> {
   pub presentation: Presentation<T, U>,
   pub holder: DocumentValidation,
   pub credentials: Vec<CredentialValidation<U>>,
+  pub failures: Vec<ValidationFailure>,
   pub verified: bool,
 }
 
@@ -42,12 +51,67 @@ pub struct DocumentValidation {
 #[derive(Clone, Copy, Debug)]
 pub struct CredentialValidator<'a> {
   client: &'a Client,
+  binding: Option<&'a HolderBindingPolicy>,
+  usage: Option<&'a UsageValidator>,
+  nonce: Option<&'a str>,
+  challenge: Option<&'a str>,
+  domain: Option<&'a str>,
 }
 
 impl<'a> CredentialValidator<'a> {
   /// Creates a new `CredentialValidator`.
   pub const fn new(client: &'a Client) -> Self {
-    Self { client }
+    Self {
+      client,
+      binding: None,
+      usage: None,
+      nonce: None,
+      challenge: None,
+      domain: None,
+    }
+  }
+
+  /// Sets the holder binding rules enforced by presentation validation.
+  ///
+  /// Without a policy only `nonTransferable` credentials are bound to the
+  /// presentation holder.
+  pub fn holder_binding(mut self, value: &'a HolderBindingPolicy) -> Self {
+    self.binding = Some(value);
+    self
+  }
+
+  /// Sets the intended usage checked against the `termsOfUse` of credentials
+  /// and presentations.
+  ///
+  /// Without a validator terms of use are not evaluated.
+  pub fn terms_of_use(mut self, value: &'a UsageValidator) -> Self {
+    self.usage = Some(value);
+    self
+  }
+
+  /// Sets the nonce expected in selective disclosure proofs derived from
+  /// BBS+ signed credentials.
+  ///
+  /// Without a nonce derived credentials are rejected.
+  pub fn nonce(mut self, value: &'a str) -> Self {
+    self.nonce = Some(value);
+    self
+  }
+
+  /// Sets the challenge expected in the proof of presentations.
+  ///
+  /// Without a challenge presentations with a challenge are rejected.
+  pub fn challenge(mut self, value: &'a str) -> Self {
+    self.challenge = Some(value);
+    self
+  }
+
+  /// Sets the domain expected in the proof of presentations.
+  ///
+  /// Without a domain presentations restricted to a domain are rejected.
+  pub fn domain(mut self, value: &'a str) -> Self {
+    self.domain = Some(value);
+    self
   }
 
   /// Deserializes the given JSON-encoded `Credential` and validates
@@ -71,7 +135,7 @@ impl<'a> CredentialValidator<'a> {
 
   /// Validates the `Credential` proof and all relevant DID documents.
   ///
-  /// Note: The credential is expected to have a proof created by the issuing party.
+  /// Note: The credential is expected to have an `assertionMethod` proof created by the issuing party.
   /// Note: The credential issuer URL is expected to be a valid DID.
   /// Note: Credential subject IDs are expected to be valid DIDs (if present).
   pub async fn validate_credential<T>(&self, credential: Credential<T>) -> Result<CredentialValidation<T>>
@@ -93,27 +157,17 @@ impl<'a> CredentialValidator<'a> {
       subjects.insert(id.to_string(), self.validate_document(id.as_str()).await?);
     }
 
-    // Verify the credential signature using the issuers DID Document
-    let credential_verified: bool = issuer_doc.document.verify_data(&credential).is_ok();
-
-    // Check if all subjects have valid signatures
-    let subjects_verified: bool = subjects.values().all(|subject| subject.verified);
-
-    // The credential is truly verified if all associated documents are verified
-    let verified: bool = issuer_doc.verified && credential_verified && subjects_verified;
-
-    Ok(CredentialValidation {
-      credential,
-      issuer: issuer_doc,
-      subjects,
-      verified,
-    })
+    Ok(__credential_validation(
+      credential, issuer_doc, subjects, self.usage, self.nonce,
+    ))
   }
 
   /// Validates the `Presentation` proof and all relevant DID documents.
   ///
   /// Note: The presentation holder is expected to be a valid DID.
-  /// Note: The presentation is expected to have a proof created by the holder.
+  /// Note: The presentation is expected to have an `authentication` proof
+  /// created by the holder with the expected challenge and domain.
+  /// Note: Presentations with credentials not bound to the holder are rejected.
   pub async fn validate_presentation<T, U>(
     &self,
     presentation: Presentation<T, U>,
@@ -128,6 +182,12 @@ impl<'a> CredentialValidator<'a> {
       .map(|holder| holder.as_str())
       .ok_or(Error::InvalidPresentationHolder)?;
 
+    // Check that every credential is bound to the holder.
+    match self.binding {
+      Some(binding) => binding.check_presentation(&presentation)?,
+      None => HolderBindingPolicy::new().check_presentation(&presentation)?,
+    }
+
     // Resolve the holder DID Document and validate the digital signature.
     let holder_doc: DocumentValidation = self.validate_document(holder_url).await?;
 
@@ -139,18 +199,27 @@ impl<'a> CredentialValidator<'a> {
     }
 
     // Verify the presentation signature using the holders DID Document
-    let presentation_verified: bool = holder_doc.document.verify_data(&presentation).is_ok();
+    let presentation_verified: bool =
+      __presentation_verified(&holder_doc.document, &presentation, self.challenge, self.domain);
 
     // Check if all credentials are verified
     let credentials_verified: bool = credentials.iter().all(|credential| credential.verified);
 
+    // Evaluate the terms of use of the presentation and all credentials
+    let failures: Vec<ValidationFailure> = self
+      .usage
+      .map(|usage| usage.validate_presentation(&presentation))
+      .unwrap_or_default();
+
     // The presentation is truly verified if all associated documents are verified
-    let verified: bool = holder_doc.verified && presentation_verified && credentials_verified;
+    // and the intended usage is allowed
+    let verified: bool = holder_doc.verified && presentation_verified && credentials_verified && failures.is_empty();
 
     Ok(PresentationValidation {
       presentation,
       holder: holder_doc,
       credentials,
+      failures,
       verified,
     })
   }
@@ -168,3 +237,329 @@ impl<'a> CredentialValidator<'a> {
     })
   }
 }
+
+fn __credential_validation<T>(
+  credential: Credential<T>,
+  issuer: DocumentValidation,
+  subjects: BTreeMap<String, DocumentValidation>,
+  usage: Option<&UsageValidator>,
+  nonce: Option<&str>,
+) -> CredentialValidation<T>
+where
+  T: Serialize,
+{
+  let verifier: Verifier<'_> = issuer.document.verifier().purpose(ProofPurpose::AssertionMethod);
+
+  // The expected nonce only applies to proofs derived from BBS+ signatures
+  let verifier: Verifier<'_> = match (nonce, credential.try_signature()) {
+    (Some(nonce), Ok(signature)) if signature.type_() == BbsPlus::TYPE_PROOF => verifier.nonce(nonce),
+    _ => verifier,
+  };
+
+  // Verify the credential signature using the issuers DID Document
+  let credential_verified: bool = verifier.verify(&credential).is_ok();
+
+  // Check if all subjects have valid signatures
+  let subjects_verified: bool = subjects.values().all(|subject| subject.verified);
+
+  // Evaluate the terms of use against the intended usage
+  let failures: Vec<ValidationFailure> = usage.map(|usage| usage.validate(&credential)).unwrap_or_default();
+
+  // The credential is truly verified if all associated documents are verified
+  // and the intended usage is allowed
+  let verified: bool = issuer.verified && credential_verified && subjects_verified && failures.is_empty();
+
+  CredentialValidation {
+    credential,
+    issuer,
+    subjects,
+    failures,
+    verified,
+  }
+}
+
+fn __presentation_verified<T, U>(
+  holder: &Document,
+  presentation: &Presentation<T, U>,
+  challenge: Option<&str>,
+  domain: Option<&str>,
+) -> bool
+where
+  T: Serialize,
+  U: Serialize,
+{
+  let verifier: Verifier<'_> = holder.verifier().purpose(ProofPurpose::Authentication);
+
+  let verifier: Verifier<'_> = match challenge {
+    Some(challenge) => verifier.challenge(challenge),
+    None => verifier,
+  };
+
+  let verifier: Verifier<'_> = match domain {
+    Some(domain) => verifier.domain(domain),
+    None => verifier,
+  };
+
+  verifier.verify(presentation).is_ok()
+}
+
+#[cfg(test)]
+mod tests {
+  use identity_core::common::Object;
+  use identity_core::common::Timestamp;
+  use identity_core::common::Url;
+  use identity_core::convert::FromJson;
+  use identity_core::crypto::KeyPair;
+  use identity_core::crypto::ProofPurpose;
+  use identity_core::crypto::SignatureOptions;
+  use identity_core::json;
+  use identity_credential::credential::Credential;
+  use identity_credential::credential::CredentialBuilder;
+  use identity_credential::credential::Policy;
+  use identity_credential::credential::Subject;
+  use identity_credential::presentation::Presentation;
+  use identity_credential::presentation::PresentationBuilder;
+  use identity_credential::validation::UsageValidator;
+  use identity_credential::validation::ValidationFailure;
+  use identity_did::verification::MethodScope;
+  use std::collections::BTreeMap;
+
+  use super::__credential_validation;
+  use super::__presentation_verified;
+  use super::CredentialValidation;
+  use super::DocumentValidation;
+  use crate::did::Document;
+  use crate::did::Method;
+
+  fn issuer() -> (Document, KeyPair) {
+    let keypair: KeyPair = KeyPair::new_ed25519().unwrap();
+    let bbs: KeyPair = KeyPair::new_bls12381g2().unwrap();
+    let mut document: Document = Document::from_keypair(&keypair).unwrap();
+
+    let method: Method = Method::from_did(document.id().clone(), &bbs, "bbs").unwrap();
+    assert!(document.insert_method(MethodScope::AssertionMethod, method));
+
+    document.sign(keypair.secret()).unwrap();
+
+    (document, bbs)
+  }
+
+  fn document_validation(document: &Document) -> DocumentValidation {
+    DocumentValidation {
+      did: document.id().clone(),
+      document: document.clone(),
+      metadata: Object::new(),
+      verified: document.verify().is_ok(),
+    }
+  }
+
+  #[test]
+  fn test_validate_derived_credential() {
+    let (document, bbs): (Document, KeyPair) = issuer();
+
+    let subject: Subject = Subject::from_json_value(json!({
+      "id": "did:example:ebfeb1f712ebc6f1c276e12ec21",
+      "name": "Alice",
+      "degree": "Bachelor of Science and Arts",
+    }))
+    .unwrap();
+
+    let mut credential: Credential = CredentialBuilder::default()
+      .issuer(Url::parse(document.id().as_str()).unwrap())
+      .issuance_date(Timestamp::parse("2021-01-01T00:00:00Z").unwrap())
+      .type_("UniversityDegreeCredential")
+      .subject(subject)
+      .build()
+      .unwrap();
+
+    document
+      .as_document()
+      .signer(bbs.secret())
+      .method("#bbs")
+      .purpose(ProofPurpose::AssertionMethod)
+      .sign(&mut credential)
+      .unwrap();
+
+    let reveal: &[&str] = &[
+      "/@context",
+      "/type",
+      "/issuer",
+      "/issuanceDate",
+      "/credentialSubject/id",
+      "/credentialSubject/degree",
+    ];
+
+    let nonce: &str = "verifier-nonce";
+    let derived: Credential = document.derive_data(&credential, reveal, nonce.as_bytes()).unwrap();
+
+    assert!(derived.credential_subject[0].properties.get("name").is_none());
+
+    let validation: CredentialValidation = __credential_validation(
+      derived.clone(),
+      document_validation(&document),
+      BTreeMap::new(),
+      None,
+      Some(nonce),
+    );
+
+    assert!(validation.verified);
+
+    // A derived credential is only valid for the expected nonce
+    let validation: CredentialValidation = __credential_validation(
+      derived.clone(),
+      document_validation(&document),
+      BTreeMap::new(),
+      None,
+      Some("replayed-nonce"),
+    );
+
+    assert!(!validation.verified);
+
+    let validation: CredentialValidation = __credential_validation(
+      derived.clone(),
+      document_validation(&document),
+      BTreeMap::new(),
+      None,
+      None,
+    );
+
+    assert!(!validation.verified);
+
+    // Modifying a disclosed property invalidates the derived credential
+    let mut modified: Credential = derived;
+    modified.issuance_date = Timestamp::parse("2021-02-01T00:00:00Z").unwrap();
+
+    let validation: CredentialValidation = __credential_validation(
+      modified,
+      document_validation(&document),
+      BTreeMap::new(),
+      None,
+      Some(nonce),
+    );
+
+    assert!(!validation.verified);
+  }
+
+  #[test]
+  fn test_validate_terms_of_use() {
+    let (document, bbs): (Document, KeyPair) = issuer();
+
+    let mut credential: Credential = CredentialBuilder::default()
+      .id(Url::parse("http://example.edu/credentials/3732").unwrap())
+      .issuer(Url::parse(document.id().as_str()).unwrap())
+      .issuance_date(Timestamp::parse("2021-01-01T00:00:00Z").unwrap())
+      .subject(Subject::with_id(
+        Url::parse("did:example:ebfeb1f712ebc6f1c276e12ec21").unwrap(),
+      ))
+      .policy(
+        Policy::from_json_value(json!({
+          "type": "IssuerPolicy",
+          "prohibition": [{ "assignee": "AllVerifiers", "action": ["Archival"] }]
+        }))
+        .unwrap(),
+      )
+      .build()
+      .unwrap();
+
+    document
+      .as_document()
+      .signer(bbs.secret())
+      .method("#bbs")
+      .purpose(ProofPurpose::AssertionMethod)
+      .sign(&mut credential)
+      .unwrap();
+
+    let verifier: Url = Url::parse("https://verifier.example.org/").unwrap();
+
+    let usage: UsageValidator = UsageValidator::new(verifier.clone()).action("Verification");
+    let validation: CredentialValidation = __credential_validation(
+      credential.clone(),
+      document_validation(&document),
+      BTreeMap::new(),
+      Some(&usage),
+      None,
+    );
+
+    assert!(validation.failures.is_empty());
+    assert!(validation.verified);
+
+    let usage: UsageValidator = UsageValidator::new(verifier).action("Archival");
+    let validation: CredentialValidation = __credential_validation(
+      credential,
+      document_validation(&document),
+      BTreeMap::new(),
+      Some(&usage),
+      None,
+    );
+
+    assert!(matches!(
+      validation.failures.as_slice(),
+      [ValidationFailure::ProhibitedAction { action, .. }] if action == "Archival"
+    ));
+    assert!(!validation.verified);
+  }
+
+  #[test]
+  fn test_validate_proof_purpose() {
+    let keypair: KeyPair = KeyPair::new_ed25519().unwrap();
+    let mut document: Document = Document::from_keypair(&keypair).unwrap();
+
+    document.sign(keypair.secret()).unwrap();
+
+    let mut credential: Credential = CredentialBuilder::default()
+      .issuer(Url::parse(document.id().as_str()).unwrap())
+      .issuance_date(Timestamp::parse("2021-01-01T00:00:00Z").unwrap())
+      .subject(Subject::with_id(
+        Url::parse("did:example:ebfeb1f712ebc6f1c276e12ec21").unwrap(),
+      ))
+      .build()
+      .unwrap();
+
+    // Credentials must be signed with an assertion method
+    document.sign_data(&mut credential, keypair.secret()).unwrap();
+
+    let validation: CredentialValidation =
+      __credential_validation(credential, document_validation(&document), BTreeMap::new(), None, None);
+
+    assert!(!validation.verified);
+
+    let mut presentation: Presentation = PresentationBuilder::default()
+      .holder(Url::parse(document.id().as_str()).unwrap())
+      .build()
+      .unwrap();
+
+    let options: SignatureOptions = SignatureOptions::new()
+      .purpose(ProofPurpose::Authentication)
+      .challenge("99612b24-63d9-11ea-b99f-4f66f3e4f81a")
+      .domain("example.com");
+
+    document
+      .as_document()
+      .signer(keypair.secret())
+      .method("#authentication")
+      .options(options)
+      .sign(&mut presentation)
+      .unwrap();
+
+    // Presentations must be signed for the expected challenge and domain
+    assert!(__presentation_verified(
+      &document,
+      &presentation,
+      Some("99612b24-63d9-11ea-b99f-4f66f3e4f81a"),
+      Some("example.com"),
+    ));
+    assert!(!__presentation_verified(&document, &presentation, None, None));
+    assert!(!__presentation_verified(
+      &document,
+      &presentation,
+      Some("99612b24-63d9-11ea-b99f-4f66f3e4f81a"),
+      Some("example.org"),
+    ));
+    assert!(!__presentation_verified(
+      &document,
+      &presentation,
+      Some("replayed-challenge"),
+      Some("example.com"),
+    ));
+  }
+}
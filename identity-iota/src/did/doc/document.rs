@@ -10,7 +10,9 @@ use core::ops::Deref;
 use identity_core::common::Object;
 use identity_core::common::Timestamp;
 use identity_core::convert::SerdeInto;
+use identity_core::crypto::KeyHandle;
 use identity_core::crypto::KeyPair;
+use identity_core::crypto::ProofPurpose;
 use identity_core::crypto::SecretKey;
 use identity_core::crypto::SetSignature;
 use identity_core::crypto::Signature;
@@ -26,6 +28,7 @@ use identity_did::verification::MethodRef;
 use identity_did::verification::MethodScope;
 use identity_did::verification::MethodType;
 use iota::MessageId;
+use serde::de::DeserializeOwned;
 use serde::Serialize;
 
 use crate::client::Client;
@@ -67,8 +70,7 @@ impl Document {
   pub fn from_keypair(keypair: &KeyPair) -> Result<Self> {
     let method: Method = Method::from_keypair(keypair, "authentication")?;
 
-    // SAFETY: We don't create invalid Methods
-    Ok(unsafe { Self::from_authentication_unchecked(method) })
+    Self::from_authentication(method)
   }
 
   /// Creates a new DID Document from the given verification [`method`][Method].
@@ -125,14 +127,12 @@ impl Document {
   fn check_authentication(method: &CoreMethod) -> Result<()> {
     Method::check_validity(method)?;
 
-    // Ensure the verification method type is supported
+    // Ensure the verification method type is supported. IOTA DID Documents
+    // are not JSON-LD documents so they can only be signed with JCS suites.
     match method.key_type() {
-      MethodType::Ed25519VerificationKey2018 => {}
-      MethodType::MerkleKeyCollection2021 => return Err(Error::InvalidDocumentAuthType),
-      _ => {}
+      MethodType::Ed25519VerificationKey2018 => Ok(()),
+      _ => Err(Error::InvalidDocumentAuthType),
     }
-
-    Ok(())
   }
 
   /// Returns a reference to the underlying [`Document`][`CoreDocument`].
@@ -266,6 +266,26 @@ impl Document {
     self.document.sign_this(&key, secret).map_err(Into::into)
   }
 
+  /// Signs the DID document with the default authentication method and the
+  /// key referenced by `handle`.
+  ///
+  /// # Errors
+  ///
+  /// Fails if an unsupported verification method is used, document
+  /// serialization fails, or the signature operation fails.
+  pub async fn sign_with_handle<H>(&mut self, handle: &H) -> Result<()>
+  where
+    H: KeyHandle + ?Sized,
+  {
+    let key: String = self.authentication_id().to_string();
+
+    self
+      .document
+      .sign_this_with_handle(&key, handle)
+      .await
+      .map_err(Into::into)
+  }
+
   /// Verifies the signature of the DID document.
   ///
   /// # Errors
@@ -278,6 +298,8 @@ impl Document {
 
   /// Signs the provided data with the default authentication method.
   ///
+  /// The signature has the proof purpose `authentication`.
+  ///
   /// # Errors
   ///
   /// Fails if an unsupported verification method is used, document
@@ -290,15 +312,44 @@ impl Document {
       .document
       .signer(secret)
       .method(self.authentication_id())
+      .purpose(ProofPurpose::Authentication)
       .sign(data)
       .map_err(Into::into)
   }
 
+  /// Signs the provided data with the default authentication method and the
+  /// key referenced by `handle`.
+  ///
+  /// The signature has the proof purpose `authentication`.
+  ///
+  /// # Errors
+  ///
+  /// Fails if an unsupported verification method is used, document
+  /// serialization fails, or the signature operation fails.
+  pub async fn sign_data_with_handle<X, H>(&self, data: &mut X, handle: &H) -> Result<()>
+  where
+    X: Serialize + SetSignature,
+    H: KeyHandle + ?Sized,
+  {
+    self
+      .document
+      .handle_signer(handle)
+      .method(self.authentication_id())
+      .purpose(ProofPurpose::Authentication)
+      .sign(data)
+      .await
+      .map_err(Into::into)
+  }
+
   /// Verifies the signature of the provided data.
   ///
   /// Note: It is assumed that the signature was created using a verification
   /// method contained within the DID Document.
   ///
+  /// Signatures without a proof purpose, such as those of documents and
+  /// diffs published before proof purposes were required, are treated as
+  /// `authentication`.
+  ///
   /// # Errors
   ///
   /// Fails if an unsupported verification method is used, document
@@ -307,7 +358,30 @@ impl Document {
   where
     X: Serialize + TrySignature,
   {
-    self.document.verifier().verify(data).map_err(Into::into)
+    self
+      .document
+      .verifier()
+      .default_purpose(ProofPurpose::Authentication)
+      .verify(data)
+      .map_err(Into::into)
+  }
+
+  /// Derives a selective disclosure proof from the provided data, revealing
+  /// only the properties identified by the JSON Pointers in `reveal`.
+  ///
+  /// Note: It is assumed that the signature was created using a BBS+
+  /// verification method contained within the DID Document.
+  ///
+  /// # Errors
+  ///
+  /// Fails if an unsupported verification method is used or the derive
+  /// operation fails.
+  pub fn derive_data<X, Y>(&self, data: &X, reveal: &[&str], nonce: &[u8]) -> Result<Y>
+  where
+    X: Serialize + TrySignature,
+    Y: DeserializeOwned + SetSignature,
+  {
+    self.document.derive_proof(data, reveal, nonce).map_err(Into::into)
   }
 
   // ===========================================================================
@@ -330,6 +404,25 @@ impl Document {
     Ok(diff)
   }
 
+  /// Creates a `DocumentDiff` representing the changes between `self` and `other`.
+  ///
+  /// The returned `DocumentDiff` will have a digital signature created using the
+  /// default authentication method and the key referenced by `handle`.
+  ///
+  /// # Errors
+  ///
+  /// Fails if the diff operation or signature operation fails.
+  pub async fn diff_with_handle<H>(&self, other: &Self, message: MessageId, handle: &H) -> Result<DocumentDiff>
+  where
+    H: KeyHandle + ?Sized,
+  {
+    let mut diff: DocumentDiff = DocumentDiff::new(self, other, message)?;
+
+    self.sign_data_with_handle(&mut diff, handle).await?;
+
+    Ok(diff)
+  }
+
   /// Verifies a `DocumentDiff` signature and merges the changes into `self`.
   ///
   /// If merging fails `self` remains unmodified, otherwise `self` represents
@@ -430,6 +523,10 @@ impl TrySignature for Document {
   fn signature(&self) -> Option<&Signature> {
     self.document.proof()
   }
+
+  fn signatures(&self) -> Vec<&Signature> {
+    self.document.proofs()
+  }
 }
 
 impl TrySignatureMut for Document {
@@ -442,6 +539,10 @@ impl SetSignature for Document {
   fn set_signature(&mut self, signature: Signature) {
     self.document.set_proof(signature)
   }
+
+  fn push_signature(&mut self, signature: Signature) {
+    self.document.push_proof(signature)
+  }
 }
 
 impl TangleRef for Document {
@@ -466,14 +567,32 @@ impl TangleRef for Document {
 mod tests {
 
   use crate::did::doc::Document;
+  use crate::did::DocumentDiff;
+  use crate::did::Method;
+  use futures::executor::block_on;
+  use identity_core::common::Timestamp;
+  use identity_core::common::Url;
   use identity_core::convert::FromJson;
   use identity_core::convert::SerdeInto;
+  use identity_core::convert::ToJson;
+  use identity_core::crypto::Ed25519;
+  use identity_core::crypto::JcsEd25519;
   use identity_core::crypto::KeyPair;
   use identity_core::crypto::KeyType;
+  use identity_core::crypto::ProofPurpose;
   use identity_core::crypto::PublicKey;
   use identity_core::crypto::SecretKey;
+  use identity_core::crypto::Signer as _;
+  use identity_core::crypto::TrySignature;
+  use identity_core::jsonld::BundledLoader;
+  use identity_core::utils::encode_b58;
+  use identity_credential::credential::Credential;
+  use identity_credential::credential::CredentialBuilder;
+  use identity_credential::credential::Subject;
   use identity_did::verification::MethodData;
+  use identity_did::verification::MethodScope;
   use identity_did::verification::MethodType;
+  use iota::MessageId;
 
   const DID_ID: &str = "did:iota:HGE4tecHWL2YiZv5qAGtH7gaeQcaz2Z1CR15GWmMjY1M";
   const DID_AUTH: &str = "did:iota:HGE4tecHWL2YiZv5qAGtH7gaeQcaz2Z1CR15GWmMjY1M#authentication";
@@ -547,4 +666,118 @@ mod tests {
 
     assert_eq!(Document::check_authentication(document.authentication()).is_ok(), true);
   }
+
+  #[test]
+  fn test_key_agreement() {
+    let keypair: KeyPair = generate_testkey();
+    let x25519: KeyPair = keypair.to_x25519().unwrap();
+    let mut document: Document = Document::from_keypair(&keypair).unwrap();
+
+    let method: Method = Method::from_did(document.id().clone(), &x25519, "key-agreement").unwrap();
+
+    assert_eq!(method.key_type(), MethodType::X25519KeyAgreementKey2019);
+    assert!(document.insert_method(MethodScope::KeyAgreement, method));
+
+    let json: _ = document.to_json_value().unwrap();
+
+    assert_eq!(json["keyAgreement"][0]["type"], "X25519KeyAgreementKey2019");
+    assert_eq!(json["keyAgreement"][0]["publicKeyBase58"], encode_b58(x25519.public()));
+
+    // Key agreement methods can't be used as the document authentication method.
+    let method: Method = Method::from_keypair(&x25519, "authentication").unwrap();
+    assert!(Document::from_authentication(method).is_err());
+  }
+
+  #[test]
+  fn test_json_web_key() {
+    let keypair: KeyPair = generate_testkey();
+    let p256: KeyPair = KeyPair::new_p256().unwrap();
+    let mut document: Document = Document::from_keypair(&keypair).unwrap();
+
+    // JSON Web Keys can't be used as the document authentication method.
+    assert!(Document::from_keypair(&p256).is_err());
+
+    let method: Method = Method::from_did(document.id().clone(), &p256, "key-jwk").unwrap();
+
+    assert_eq!(method.key_type(), MethodType::JsonWebKey2020);
+    assert!(document.insert_method(MethodScope::AssertionMethod, method));
+
+    let json: _ = document.to_json_value().unwrap();
+
+    assert_eq!(json["assertionMethod"][0]["publicKeyJwk"]["kty"], "EC");
+    assert_eq!(json["assertionMethod"][0]["publicKeyJwk"]["crv"], "P-256");
+
+    document.sign(keypair.secret()).unwrap();
+
+    assert!(document.verify().is_ok());
+
+    // JSON Web Keys sign JSON-LD data with JsonWebSignature2020
+    let mut credential: Credential = CredentialBuilder::default()
+      .context(Url::parse(BundledLoader::JWS_2020_V1).unwrap())
+      .issuer(Url::parse(document.id().as_str()).unwrap())
+      .issuance_date(Timestamp::parse("2021-01-01T00:00:00Z").unwrap())
+      .subject(Subject::with_id(Url::parse("did:example:subject").unwrap()))
+      .build()
+      .unwrap();
+
+    document
+      .as_document()
+      .signer(p256.secret())
+      .method("#key-jwk")
+      .purpose(ProofPurpose::AssertionMethod)
+      .sign(&mut credential)
+      .unwrap();
+
+    assert_eq!(credential.proof().unwrap().type_(), "JsonWebSignature2020");
+    assert!(document.verify_data(&credential).is_ok());
+  }
+
+  #[test]
+  fn test_verify_without_purpose() {
+    let keypair: KeyPair = generate_testkey();
+    let mut document: Document = Document::from_keypair(&keypair).unwrap();
+
+    // Documents and diffs published before proof purposes were required are
+    // signed without one
+    JcsEd25519::<Ed25519>::create_signature(&mut document, "#authentication", keypair.secret().as_ref()).unwrap();
+
+    assert_eq!(document.try_signature().unwrap().options().purpose, None);
+    assert!(document.verify().is_ok());
+
+    let mut updated: Document = document.clone();
+    updated.properties_mut().insert("foo".into(), 123.into());
+
+    let mut diff: DocumentDiff = DocumentDiff::new(&document, &updated, MessageId::null()).unwrap();
+
+    JcsEd25519::<Ed25519>::create_signature(&mut diff, "#authentication", keypair.secret().as_ref()).unwrap();
+
+    assert_eq!(diff.try_signature().unwrap().options().purpose, None);
+    assert!(document.verify_data(&diff).is_ok());
+  }
+
+  #[test]
+  fn test_sign_with_handle() {
+    block_on(async {
+      let keypair: KeyPair = generate_testkey();
+      let mut document: Document = Document::from_keypair(&keypair).unwrap();
+
+      document.sign_with_handle(&keypair).await.unwrap();
+
+      assert!(document.verify().is_ok());
+
+      let mut updated: Document = document.clone();
+      updated.properties_mut().insert("foo".into(), 123.into());
+
+      let diff: DocumentDiff = document
+        .diff_with_handle(&updated, MessageId::null(), &keypair)
+        .await
+        .unwrap();
+
+      assert!(document.verify_data(&diff).is_ok());
+
+      // The key type of the handle must match the verification method
+      let other: KeyPair = KeyPair::new_secp256k1().unwrap();
+      assert!(document.sign_with_handle(&other).await.is_err());
+    })
+  }
 }
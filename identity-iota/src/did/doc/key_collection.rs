@@ -0,0 +1,342 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::convert::TryInto;
+use core::marker::PhantomData;
+use identity_core::common::BitSet;
+use identity_core::crypto::merkle_key::MerkleDigest;
+use identity_core::crypto::merkle_key::Sha256;
+use identity_core::crypto::merkle_tree::Proof;
+use identity_core::crypto::KeyCollection;
+use identity_core::crypto::KeyType;
+use identity_core::crypto::ProofPurpose;
+use identity_core::crypto::PublicKey;
+use identity_core::crypto::SecretKey;
+use identity_core::crypto::SetSignature;
+use identity_core::utils::decode_b58;
+use identity_core::utils::encode_b58;
+use identity_did::verifiable::Revocation;
+use identity_did::verification::MethodScope;
+use iota::MessageId;
+use serde::de;
+use serde::Deserialize;
+use serde::Deserializer;
+use serde::Serialize;
+use serde::Serializer;
+
+use crate::did::Document;
+use crate::did::DocumentDiff;
+use crate::did::Method;
+use crate::error::Error;
+use crate::error::Result;
+
+/// A [`KeyCollection`] managed as a `MerkleKeyCollection2021` verification
+/// method of an IOTA DID Document.
+///
+/// Ties together the creation of the verification method, signing with
+/// individual keys of the collection, and revocation of compromised keys.
+///
+/// Note: The serialized form of the collection includes the secret keys and
+/// must be stored securely.
+#[derive(Clone, Debug)]
+pub struct MerkleKeyCollection<D = Sha256> {
+  fragment: String,
+  keys: KeyCollection,
+  marker: PhantomData<D>,
+}
+
+impl<D> MerkleKeyCollection<D>
+where
+  D: MerkleDigest,
+{
+  /// Creates a new [`MerkleKeyCollection`] with `count` keys of the given
+  /// [`type`][`KeyType`], identified by the method `fragment`.
+  pub fn new(type_: KeyType, count: usize, fragment: impl Into<String>) -> Result<Self> {
+    KeyCollection::new(type_, count).map(|keys| Self::from_keys(keys, fragment))
+  }
+
+  /// Creates a new [`MerkleKeyCollection`] from an existing [`KeyCollection`].
+  pub fn from_keys(keys: KeyCollection, fragment: impl Into<String>) -> Self {
+    Self {
+      fragment: fragment.into(),
+      keys,
+      marker: PhantomData,
+    }
+  }
+
+  /// Returns the verification method fragment of the collection.
+  pub fn fragment(&self) -> &str {
+    &self.fragment
+  }
+
+  /// Returns a reference to the underlying [`KeyCollection`].
+  pub fn keys(&self) -> &KeyCollection {
+    &self.keys
+  }
+
+  /// Creates a `MerkleKeyCollection2021` verification method for the
+  /// collection, controlled by the given DID Document.
+  pub fn to_method(&self, document: &Document) -> Result<Method> {
+    Method::create_merkle_key::<D, _>(document.id().clone(), &self.keys, self.fragment())
+  }
+
+  /// Embeds the verification method of the collection in the given DID
+  /// Document.
+  ///
+  /// # Errors
+  ///
+  /// Fails if the document already contains a method with the same id.
+  pub fn embed(&self, document: &mut Document, scope: MethodScope) -> Result<()> {
+    let method: Method = self.to_method(document)?;
+
+    if !document.insert_method(scope, method) {
+      return Err(identity_did::Error::InvalidMethodDuplicate.into());
+    }
+
+    Ok(())
+  }
+
+  /// Signs the provided data with the key at `index` of the collection.
+  ///
+  /// The signature has the proof purpose `assertionMethod`.
+  ///
+  /// # Errors
+  ///
+  /// Fails if `index` is out of bounds, the verification method of the
+  /// collection is not an `assertionMethod` of the document, or the signature
+  /// operation fails.
+  pub fn sign<X>(&self, document: &Document, index: usize, data: &mut X) -> Result<()>
+  where
+    X: Serialize + SetSignature,
+  {
+    let (public, secret, proof): (&PublicKey, &SecretKey, Proof<D>) = self.key(index)?;
+
+    document
+      .signer(secret)
+      .method(self.fragment())
+      .merkle_key((public, &proof))
+      .purpose(ProofPurpose::AssertionMethod)
+      .sign(data)
+      .map_err(Into::into)
+  }
+
+  /// Returns `true` if the key at `index` is revoked in the given DID Document.
+  ///
+  /// # Errors
+  ///
+  /// Fails if `index` is out of bounds or the verification method of the
+  /// collection can't be resolved.
+  pub fn is_revoked(&self, document: &Document, index: usize) -> Result<bool> {
+    self.check_index(index)?;
+
+    let index: u32 = index.try_into().map_err(|_| Error::InvalidKeyCollectionIndex(index))?;
+
+    let revocation: Option<BitSet> = document.try_resolve(self.fragment())?.revocation()?;
+
+    Ok(revocation.map_or(false, |revocation| revocation.contains(index)))
+  }
+
+  /// Revokes the keys at the given `indices` by updating the revocation set
+  /// of the verification method in the given DID Document.
+  ///
+  /// Returns `true` if any key was not previously revoked.
+  ///
+  /// # Errors
+  ///
+  /// Fails if any index is out of bounds, in which case the document is not
+  /// modified.
+  pub fn revoke(&self, document: &mut Document, indices: &[usize]) -> Result<bool> {
+    for index in indices.iter().copied() {
+      self.check_index(index)?;
+    }
+
+    let method: &mut Method = Method::try_from_mut(document.try_resolve_mut(self.fragment())?)?;
+    let mut revoked: bool = false;
+
+    for index in indices.iter().copied() {
+      revoked |= method.revoke_merkle_key(index)?;
+    }
+
+    Ok(revoked)
+  }
+
+  /// Revokes the keys at the given `indices` and returns a signed
+  /// [`DocumentDiff`] with the updated revocation set, ready to be published.
+  ///
+  /// The diff is signed with the default authentication method and `secret`.
+  pub fn revoke_diff(
+    &self,
+    document: &Document,
+    indices: &[usize],
+    message: MessageId,
+    secret: &SecretKey,
+  ) -> Result<DocumentDiff> {
+    let mut updated: Document = document.clone();
+
+    self.revoke(&mut updated, indices)?;
+
+    document.diff(&updated, message, secret)
+  }
+
+  fn key(&self, index: usize) -> Result<(&PublicKey, &SecretKey, Proof<D>)> {
+    let public: Option<&PublicKey> = self.keys.public(index);
+    let secret: Option<&SecretKey> = self.keys.secret(index);
+    let proof: Option<Proof<D>> = self.keys.merkle_proof(index);
+
+    match (public, secret, proof) {
+      (Some(public), Some(secret), Some(proof)) => Ok((public, secret, proof)),
+      _ => Err(Error::InvalidKeyCollectionIndex(index)),
+    }
+  }
+
+  fn check_index(&self, index: usize) -> Result<()> {
+    if index < self.keys.len() {
+      Ok(())
+    } else {
+      Err(Error::InvalidKeyCollectionIndex(index))
+    }
+  }
+}
+
+// =============================================================================
+// Serialization
+// =============================================================================
+
+#[derive(Deserialize, Serialize)]
+struct MerkleKeyCollectionData {
+  fragment: String,
+  #[serde(rename = "type")]
+  type_: KeyType,
+  public: Vec<String>,
+  secret: Vec<String>,
+}
+
+impl<D> Serialize for MerkleKeyCollection<D> {
+  fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: Serializer,
+  {
+    let data: MerkleKeyCollectionData = MerkleKeyCollectionData {
+      fragment: self.fragment.clone(),
+      type_: self.keys.type_(),
+      public: self.keys.iter_public().map(encode_b58).collect(),
+      secret: self.keys.iter_secret().map(encode_b58).collect(),
+    };
+
+    data.serialize(serializer)
+  }
+}
+
+impl<'de, D> Deserialize<'de> for MerkleKeyCollection<D> {
+  fn deserialize<T>(deserializer: T) -> Result<Self, T::Error>
+  where
+    T: Deserializer<'de>,
+  {
+    let data: MerkleKeyCollectionData = MerkleKeyCollectionData::deserialize(deserializer)?;
+
+    if data.public.len() != data.secret.len() {
+      return Err(de::Error::custom("mismatched key collection length"));
+    }
+
+    let public: Vec<PublicKey> = data
+      .public
+      .iter()
+      .map(|key| decode_b58(key).map(Into::into))
+      .collect::<Result<_, _>>()
+      .map_err(de::Error::custom)?;
+
+    let secret: Vec<SecretKey> = data
+      .secret
+      .iter()
+      .map(|key| decode_b58(key).map(Into::into))
+      .collect::<Result<_, _>>()
+      .map_err(de::Error::custom)?;
+
+    let keys: KeyCollection =
+      KeyCollection::from_iterator(data.type_, public.into_iter().zip(secret)).map_err(de::Error::custom)?;
+
+    Ok(Self {
+      fragment: data.fragment,
+      keys,
+      marker: PhantomData,
+    })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use identity_core::common::Url;
+  use identity_core::convert::FromJson;
+  use identity_core::convert::ToJson;
+  use identity_core::crypto::KeyPair;
+  use identity_core::crypto::KeyType;
+  use identity_credential::credential::Credential;
+  use identity_credential::credential::CredentialBuilder;
+  use identity_credential::credential::Subject;
+  use identity_did::verification::MethodScope;
+  use iota::MessageId;
+
+  use crate::did::Document;
+  use crate::did::DocumentDiff;
+  use crate::did::MerkleKeyCollection;
+
+  fn credential(document: &Document) -> Credential {
+    CredentialBuilder::default()
+      .issuer(Url::parse(document.id().as_str()).unwrap())
+      .type_("MyCredential")
+      .subject(Subject::from_json(r#"{"claim": true}"#).unwrap())
+      .build()
+      .unwrap()
+  }
+
+  #[test]
+  fn test_lifecycle() {
+    let keypair: KeyPair = KeyPair::new_ed25519().unwrap();
+    let mut document: Document = Document::from_keypair(&keypair).unwrap();
+
+    let collection: MerkleKeyCollection = MerkleKeyCollection::new(KeyType::Ed25519, 8, "key-collection").unwrap();
+
+    collection.embed(&mut document, MethodScope::AssertionMethod).unwrap();
+
+    // The method can only be embedded once
+    assert!(collection.embed(&mut document, MethodScope::AssertionMethod).is_err());
+
+    let mut credential: Credential = credential(&document);
+
+    collection.sign(&document, 3, &mut credential).unwrap();
+
+    assert!(document.verify_data(&credential).is_ok());
+    assert!(!collection.is_revoked(&document, 3).unwrap());
+
+    // Keys outside of the collection can't be used
+    assert!(collection.sign(&document, 8, &mut credential).is_err());
+    assert!(collection.is_revoked(&document, 8).is_err());
+
+    // Out-of-range indices are rejected without modifying the document
+    let mut updated: Document = document.clone();
+
+    assert!(collection.revoke(&mut updated, &[2, 8]).is_err());
+    assert_eq!(updated, document);
+
+    // Revoke the key and merge the signed diff
+    let diff: DocumentDiff = collection
+      .revoke_diff(&document, &[3], MessageId::null(), keypair.secret())
+      .unwrap();
+
+    document.merge(&diff).unwrap();
+
+    assert!(collection.is_revoked(&document, 3).unwrap());
+    assert!(!collection.is_revoked(&document, 4).unwrap());
+    assert!(document.verify_data(&credential).is_err());
+
+    // Restore the collection from its serialized form
+    let json: String = collection.to_json().unwrap();
+    let restored: MerkleKeyCollection = MerkleKeyCollection::from_json(&json).unwrap();
+
+    assert_eq!(restored.fragment(), collection.fragment());
+
+    restored.sign(&document, 4, &mut credential).unwrap();
+
+    assert!(document.verify_data(&credential).is_ok());
+  }
+}
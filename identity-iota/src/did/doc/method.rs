@@ -15,6 +15,7 @@ use identity_core::crypto::KeyCollection;
 use identity_core::crypto::KeyPair;
 use identity_core::crypto::KeyType;
 use identity_did::error::Result as DIDResult;
+use identity_did::utils::encode_jwk;
 use identity_did::verifiable::Revocation;
 use identity_did::verification::Method as CoreMethod;
 use identity_did::verification::MethodBuilder;
@@ -48,7 +49,7 @@ impl Method {
       .id(key.into())
       .controller(did.into())
       .key_type(MethodType::MerkleKeyCollection2021)
-      .key_data(MethodData::new_b58(&keys.encode_merkle_key::<D>()))
+      .key_data(MethodData::new_b58(&keys.try_encode_merkle_key::<D>()?))
       .build()
       .map_err(Into::into)
       .map(Self)
@@ -80,6 +81,25 @@ impl Method {
         builder = builder.key_type(MethodType::Ed25519VerificationKey2018);
         builder = builder.key_data(MethodData::new_b58(keypair.public()));
       }
+      KeyType::Secp256k1 => {
+        builder = builder.key_type(MethodType::EcdsaSecp256k1VerificationKey2019);
+        builder = builder.key_data(MethodData::new_b58(keypair.public()));
+      }
+      KeyType::P256 => {
+        builder = builder.key_type(MethodType::JsonWebKey2020);
+        builder = builder.key_data(MethodData::new_jwk(&encode_jwk(
+          KeyType::P256,
+          keypair.public().as_ref(),
+        )?)?);
+      }
+      KeyType::X25519 => {
+        builder = builder.key_type(MethodType::X25519KeyAgreementKey2019);
+        builder = builder.key_data(MethodData::new_b58(keypair.public()));
+      }
+      KeyType::Bls12381G2 => {
+        builder = builder.key_type(MethodType::Bls12381G2Key2020);
+        builder = builder.key_data(MethodData::new_b58(keypair.public()));
+      }
     }
 
     Ok(Self(builder.build()?))
@@ -3,6 +3,7 @@
 
 mod diff;
 mod document;
+mod key_collection;
 mod method;
 mod properties;
 
@@ -10,5 +11,6 @@ pub use self::diff::DocumentDiff;
 pub use self::document::Document;
 pub use self::document::Signer;
 pub use self::document::Verifier;
+pub use self::key_collection::MerkleKeyCollection;
 pub use self::method::Method;
 pub use self::properties::Properties;
@@ -9,6 +9,7 @@ mod url;
 
 pub use self::doc::Document;
 pub use self::doc::DocumentDiff;
+pub use self::doc::MerkleKeyCollection;
 pub use self::doc::Method;
 pub use self::doc::Properties;
 pub use self::doc::Signer;
@@ -51,4 +51,6 @@ pub enum Error {
   CannotRemoveAuthMethod,
   #[error("Cannot Revoke Verification Method")]
   CannotRevokeMethod,
+  #[error("Invalid Key Collection Index: {0}")]
+  InvalidKeyCollectionIndex(usize),
 }
@@ -30,6 +30,9 @@ pub mod core {
 
   #[doc(inline)]
   pub use identity_core::json;
+
+  #[doc(inline)]
+  pub use identity_core::jsonld;
 }
 
 pub mod crypto {
@@ -47,7 +50,12 @@ pub mod credential {
 
   pub use identity_credential::credential::*;
   pub use identity_credential::error::*;
+  pub use identity_credential::exchange::*;
+  pub use identity_credential::jwt::*;
   pub use identity_credential::presentation::*;
+  pub use identity_credential::refresh::*;
+  pub use identity_credential::status::*;
+  pub use identity_credential::validation::*;
 }
 
 #[cfg(feature = "identifier")]
@@ -67,6 +75,7 @@ pub mod did {
   pub use identity_did::did::Error as DIDError;
   pub use identity_did::did::DID;
 
+  pub use identity_did::methods;
   pub use identity_did::resolution;
   pub use identity_did::verifiable;
 }